@@ -0,0 +1,344 @@
+// SOW-054: Hot reload - edit the RON content while the game runs.
+//
+// Dev mode only (`cargo run -- --hot-reload`). A timer polls the watched
+// files' modification stamps; when any changes, load_content re-reads and
+// re-validates everything exactly as a launch would. Valid content swaps
+// into GameAssets in one write and the screens that were built from it
+// (deck builder, shop, map) are rebuilt. Invalid content leaves the running
+// game alone and puts the validation error on screen until the next save
+// fixes it.
+//
+// The story patterns and headline fragments (narrative_patterns.ron,
+// headlines.ron) are watched beside narrative_defaults.ron and rebuild the
+// composer and the paper's desk on a swap.
+//
+// SOW-056: the mod packs found at launch are layered in on every reload and
+// their files are watched too (installing or removing a pack needs a
+// restart).
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+
+use super::loader::{load_content, load_story_tables};
+use super::mods::{ModPack, MODS_DIR};
+use super::registry::GameAssets;
+use crate::game_state::GameState;
+use crate::locale::tr;
+use crate::models::deck_builder::DeckBuilder;
+use crate::models::narrative::StoryComposer;
+use crate::save::{AccountState, SaveData};
+use crate::ui::components::*;
+use crate::ui::theme;
+
+/// How often the watched files are polled
+const POLL_SECONDS: f32 = 0.5;
+
+/// Watched files under the assets root and each pack (plus every file in
+/// cards/ and the narrative tables, see STORY_FILES)
+const WATCHED_FILES: [&str; 6] = [
+    "buyers.ron",
+    "data/shop_locations.ron",
+    "narc_deck.ron",
+    "relics.ron",
+    "bosses.ron",
+    "victories.ron",
+];
+
+/// The locale-resolved narrative tables, watched under the assets root only
+const STORY_FILES: [&str; 3] = ["narrative_defaults.ron", "narrative_patterns.ron", "headlines.ron"];
+
+/// Error lines the banner shows - a RON parse error can run long
+const BANNER_MAX_LINES: usize = 8;
+
+/// A watched file and its (modified, length), None while it's missing
+type Stamp = (PathBuf, Option<(SystemTime, u64)>);
+
+pub struct HotReloadPlugin;
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ContentWatch::new("assets"))
+            .init_resource::<ContentError>()
+            .add_systems(Startup, spawn_content_error_banner)
+            .add_systems(
+                Update,
+                (
+                    hot_reload_system,
+                    refresh_reloaded_content_system,
+                    update_content_error_banner_system,
+                )
+                    .chain()
+                    .run_if(not(in_state(GameState::AssetLoading))),
+            );
+    }
+}
+
+/// The watched files as of the last reload attempt
+#[derive(Resource)]
+pub struct ContentWatch {
+    root: String,
+    /// SOW-056: packs as discovered at launch
+    packs: Vec<ModPack>,
+    stamps: Vec<Stamp>,
+    timer: Timer,
+    /// Set by a swap, taken by the refresh
+    swapped: bool,
+}
+
+/// The last reload's validation error; None once content loads cleanly
+#[derive(Resource, Debug, Default)]
+pub struct ContentError(pub Option<String>);
+
+impl ContentWatch {
+    pub fn new(root: &str) -> Self {
+        let packs = super::mods::discover_packs(Path::new(MODS_DIR)).unwrap_or_default();
+        Self {
+            root: root.to_string(),
+            stamps: content_stamps(root, &packs),
+            packs,
+            timer: Timer::from_seconds(POLL_SECONDS, TimerMode::Repeating),
+            swapped: false,
+        }
+    }
+}
+
+/// A content folder's watched files, in a stable order
+fn watched_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir.join("cards"))
+        .map(|dir| dir.filter_map(|entry| entry.ok().map(|e| e.path())).collect())
+        .unwrap_or_default();
+    paths.sort();
+    paths.extend(WATCHED_FILES.iter().map(|file| dir.join(file)));
+    paths
+}
+
+/// Every watched file's stamp, in a stable order. A new, removed or edited
+/// card file changes the list.
+fn content_stamps(root: &str, packs: &[ModPack]) -> Vec<Stamp> {
+    let mut paths = watched_paths(Path::new(root));
+    paths.extend(STORY_FILES.iter().map(|file| PathBuf::from(crate::locale::asset_path(root, file))));
+    for pack in packs {
+        paths.extend(watched_paths(&pack.dir));
+        paths.push(pack.dir.join("narrative_defaults.ron"));
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let stamp = std::fs::metadata(&path)
+                .ok()
+                .and_then(|meta| Some((meta.modified().ok()?, meta.len())));
+            (path, stamp)
+        })
+        .collect()
+}
+
+/// What the banner says under its heading: the error, cut to fit
+fn banner_text(error: &str) -> String {
+    let mut lines: Vec<&str> = error.lines().take(BANNER_MAX_LINES).collect();
+    if error.lines().count() > BANNER_MAX_LINES {
+        lines.push("...");
+    }
+    lines.join("\n")
+}
+
+/// Poll the watched files; on a change, re-validate and swap or report
+pub fn hot_reload_system(
+    time: Res<Time>,
+    mut watch: ResMut<ContentWatch>,
+    mut error: ResMut<ContentError>,
+    asset_server: Res<AssetServer>,
+    mut game_assets: ResMut<GameAssets>,
+    mut commands: Commands,
+) {
+    if !watch.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let stamps = content_stamps(&watch.root, &watch.packs);
+    if stamps == watch.stamps {
+        return;
+    }
+    watch.stamps = stamps;
+
+    let loaded = load_content(&watch.root, &watch.packs)
+        .and_then(|content| content.strict())
+        .and_then(|content| Ok((content, load_story_tables(&watch.root)?)));
+    let (content, (patterns, desk)) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            warn!("Hot reload rejected - keeping the running content: {}", e);
+            error.0 = Some(e);
+            return;
+        }
+    };
+    content.install(&asset_server, &mut game_assets);
+    error.0 = None;
+    watch.swapped = true;
+    info!("Hot reload: content swapped in");
+
+    commands.insert_resource(StoryComposer::with_patterns(game_assets.narrative_defaults.clone(), patterns));
+    commands.insert_resource(desk);
+}
+
+/// After a swap: rebuild what was built from the old content
+pub fn refresh_reloaded_content_system(
+    mut watch: ResMut<ContentWatch>,
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    deck_builder: Option<Res<DeckBuilder>>,
+    save_data: Option<Res<SaveData>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !std::mem::take(&mut watch.swapped) {
+        return;
+    }
+
+    // The deck builder's pool comes from the new cards; the selection
+    // stays (cards that no longer exist drop out)
+    if let Some(deck_builder) = deck_builder {
+        let unlocked = save_data
+            .as_ref()
+            .map(|data| data.account.unlocked_cards.clone())
+            .unwrap_or_else(AccountState::starting_collection);
+        let evolved = save_data
+            .as_ref()
+            .map(|data| data.active_character().evolved_cards.clone())
+            .unwrap_or_default();
+        commands.insert_resource(DeckBuilder::for_dealer(
+            &game_assets,
+            &unlocked,
+            &deck_builder.selected_ids(),
+            &evolved,
+        ));
+    }
+
+    // The hub's shop and map were spawned from the old content - re-enter
+    // DeckBuilding to respawn it (the OnEnter chain is re-entrant, see
+    // SOW-021's decay fix). A run in progress picks it up on the way home.
+    if *state.get() == GameState::DeckBuilding {
+        next_state.set(GameState::DeckBuilding);
+    }
+}
+
+/// The (hidden) error banner, above every screen
+fn spawn_content_error_banner(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(theme::CONTENT_ERROR_BG),
+            GlobalZIndex(200),
+            ContentErrorBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn((
+                Text::new(tr("reload.rejected")),
+                TextFont::from_font_size(18.0),
+                TextColor(theme::CONTENT_ERROR_TEXT),
+            ));
+            banner.spawn((
+                Text::new(""),
+                TextFont::from_font_size(13.0),
+                TextColor(Color::WHITE),
+                ContentErrorText,
+            ));
+        });
+}
+
+/// Show the banner while the last reload failed
+fn update_content_error_banner_system(
+    error: Res<ContentError>,
+    mut banner_query: Query<&mut Node, With<ContentErrorBanner>>,
+    mut text_query: Query<&mut Text, With<ContentErrorText>>,
+) {
+    if !error.is_changed() {
+        return;
+    }
+    if let Ok(mut banner) = banner_query.single_mut() {
+        banner.display = if error.0.is_some() { Display::Flex } else { Display::None };
+    }
+    if let (Some(e), Ok(mut text)) = (&error.0, text_query.single_mut()) {
+        **text = banner_text(e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stamps_change_when_watched_content_does() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        std::fs::create_dir(dir.path().join("cards")).unwrap();
+        std::fs::write(dir.path().join("buyers.ron"), "[]").unwrap();
+        let before = content_stamps(root, &[]);
+        assert_eq!(before, content_stamps(root, &[]));
+
+        std::fs::write(dir.path().join("buyers.ron"), "[ ]").unwrap();
+        let edited = content_stamps(root, &[]);
+        assert_ne!(before, edited);
+
+        std::fs::write(dir.path().join("cards/cover.ron"), "[]").unwrap();
+        let added = content_stamps(root, &[]);
+        assert_ne!(edited, added);
+
+        std::fs::remove_file(dir.path().join("buyers.ron")).unwrap();
+        assert_ne!(added, content_stamps(root, &[]));
+
+        // The story tables are watched too
+        let settled = content_stamps(root, &[]);
+        std::fs::write(dir.path().join("headlines.ron"), "{}").unwrap();
+        let headlines = content_stamps(root, &[]);
+        assert_ne!(settled, headlines);
+        std::fs::write(dir.path().join("narrative_patterns.ron"), "[]").unwrap();
+        assert_ne!(headlines, content_stamps(root, &[]));
+
+        // Files nobody watches don't trigger a reload
+        let settled = content_stamps(root, &[]);
+        std::fs::write(dir.path().join("notes.txt"), "todo").unwrap();
+        assert_eq!(settled, content_stamps(root, &[]));
+    }
+
+    #[test]
+    fn broken_story_tables_are_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_str().unwrap();
+        let (patterns, _) = load_story_tables(root).unwrap();
+        assert_eq!(patterns.len(), crate::models::narrative::DynamicPattern::shipped_patterns().len());
+
+        std::fs::write(dir.path().join("headlines.ron"), "{ (").unwrap();
+        let err = load_story_tables(root).err().unwrap();
+        assert!(err.contains("headlines.ron"), "{err}");
+    }
+
+    #[test]
+    fn broken_content_is_an_error_not_a_panic() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("cards")).unwrap();
+        std::fs::write(dir.path().join("cards/products.ron"), "[ (id: ").unwrap();
+        let err = load_content(dir.path().to_str().unwrap(), &[]).err().unwrap();
+        assert!(err.contains("products.ron"), "{err}");
+    }
+
+    #[test]
+    fn banner_cuts_long_errors() {
+        assert_eq!(banner_text("one\ntwo"), "one\ntwo");
+        let long = (0..20).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+        let text = banner_text(&long);
+        assert_eq!(text.lines().count(), BANNER_MAX_LINES + 1);
+        assert!(text.ends_with("..."));
+    }
+}
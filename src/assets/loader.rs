@@ -24,61 +24,43 @@ impl Plugin for AssetLoaderPlugin {
 fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>, mut game_assets: ResMut<GameAssets>) {
     info!("Loading game assets from RON files...");
 
-    // Load narrative defaults first (includes resolution clauses)
-    match load_narrative_defaults("assets/narrative_defaults.ron") {
-        Ok(defaults) => {
-            game_assets.narrative_defaults = defaults;
-            info!("Loaded narrative defaults (with resolution clauses)");
-        }
+    // SOW-054: the RON content is read and validated by load_content (hot
+    // reload builds from the same function) - startup keeps its fail-loud
+    // rules on top of what it returns
+    // SOW-056: mod packs from mods/, layered in load order
+    let packs = match super::mods::discover_packs(std::path::Path::new(super::mods::MODS_DIR)) {
+        Ok(packs) => packs,
         Err(e) => {
-            warn!("Failed to load narrative_defaults.ron: {} - using empty defaults", e);
-        }
-    }
-
-    // Load products (no default merging - StoryComposer handles fallback)
-    match load_and_validate_cards("assets/cards/products.ron", "Product") {
-        Ok(cards) => {
-            for card in &cards {
-                validate_card(&card, "Product").expect("Product validation failed");
-
-                // Debug: Check ALL products
-                if let Some(frags) = &card.narrative_fragments {
-                    eprintln!("DEBUG: {} has {} product_clauses", card.name, frags.product_clauses.len());
-                } else {
-                    eprintln!("DEBUG: {} narrative_fragments is NONE!", card.name);
-                }
-
-                game_assets.products.insert(card.name.clone(), card.clone());
-            }
-            info!("Loaded {} products", game_assets.products.len());
+            error!("Failed to load mod packs: {}", e);
+            panic!("Critical asset loading failure - {}", e);
         }
+    };
+    let content = match load_content("assets", &packs) {
+        Ok(content) => content,
         Err(e) => {
-            error!("Failed to load products.ron: {}", e);
-            panic!("Critical asset loading failure - fix products.ron");
+            error!("Failed to load game content: {}", e);
+            panic!("Critical asset loading failure - {}", e);
         }
+    };
+
+    // Demand strings, persona areas, card areas and the fresh collection:
+    // fail loudly in debug, log an error (but keep running) in release
+    #[cfg(debug_assertions)]
+    if let Some(e) = content.authoring_errors.first() {
+        panic!("{}", e);
+    }
+    #[cfg(not(debug_assertions))]
+    for e in &content.authoring_errors {
+        error!("{}", e);
     }
 
-    // Load locations (no default merging - StoryComposer handles fallback)
-    match load_and_validate_cards("assets/cards/locations.ron", "Location") {
-        Ok(cards) => {
-            for card in &cards {
-                validate_card(&card, "Location").expect("Location validation failed");
-                game_assets.locations.insert(card.name.clone(), card.clone());
-            }
-            info!("Loaded {} locations", game_assets.locations.len());
-        }
-        Err(e) => {
-            error!("Failed to load locations.ron: {}", e);
-            panic!("Critical asset loading failure - fix locations.ron");
-        }
+    if let Err(e) = &content.narrative_defaults {
+        warn!("Failed to load narrative_defaults.ron: {} - using empty defaults", e);
     }
 
     // Load background images for locations
     load_background_images(&asset_server, &mut game_assets);
 
-    // SOW-033: actor portraits load AFTER buyers + shop_locations (their
-    // `portrait` / `narc_portrait` RON fields drive the map) - see below.
-
     // Load card template
     game_assets.card_template = asset_server.load("art/card-template.png");
     info!("Loading card template image");
@@ -91,311 +73,720 @@ fn load_game_assets(mut commands: Commands, asset_server: Res<AssetServer>, mut
     game_assets.card_back = asset_server.load("art/card-back.png");
     info!("Loading card back image");
 
-    // Load evidence card definitions
-    let mut evidence_defs = HashMap::new();
-    match load_and_validate_cards("assets/cards/evidence.ron", "Evidence") {
-        Ok(cards) => {
-            for card in cards {
-                evidence_defs.insert(card.id.clone(), card);
+    // SOW-033: actor portraits are loaded by install, from the buyers' and
+    // areas' RON portrait fields
+    content.install(&asset_server, &mut game_assets);
+
+    // SOW-046: story shapes from narrative_patterns.ron - absent file means
+    // the shipped set; a broken one fails loud in debug like the rest
+    let patterns = match load_narrative_patterns(&crate::locale::asset_path("assets", "narrative_patterns.ron")) {
+        Some(Ok(patterns)) => {
+            info!("Loaded {} narrative patterns", patterns.len());
+            patterns
+        }
+        Some(Err(e)) => {
+            #[cfg(debug_assertions)]
+            panic!("Narrative pattern validation failed: {}", e);
+            #[cfg(not(debug_assertions))]
+            {
+                error!("Narrative pattern validation failed: {} - using shipped patterns", e);
+                crate::models::narrative::DynamicPattern::shipped_patterns()
             }
-            info!("Loaded {} evidence card definitions", evidence_defs.len());
         }
-        Err(e) => {
-            error!("Failed to load evidence.ron: {}", e);
-            panic!("Critical asset loading failure - fix evidence.ron");
+        None => {
+            info!("No narrative_patterns.ron - using shipped patterns");
+            crate::models::narrative::DynamicPattern::shipped_patterns()
         }
-    }
+    };
 
-    // Load conviction card definitions
-    let mut conviction_defs = HashMap::new();
-    match load_and_validate_cards("assets/cards/convictions.ron", "Conviction") {
-        Ok(cards) => {
-            for card in cards {
-                conviction_defs.insert(card.id.clone(), card);
-            }
-            info!("Loaded {} conviction card definitions", conviction_defs.len());
+    // Create StoryComposer resource with full narrative defaults (handles fallback internally)
+    let story_composer = crate::models::narrative::StoryComposer::with_patterns(
+        game_assets.narrative_defaults.clone(),
+        patterns,
+    );
+    commands.insert_resource(story_composer);
+    info!("Created StoryComposer resource with defaults");
+
+    // SOW-052: the morning paper's fragments - same absent/broken rules as
+    // the narrative patterns
+    let headline_desk = match load_headlines(&crate::locale::asset_path("assets", "headlines.ron")) {
+        Some(Ok(desk)) => {
+            info!("Loaded headlines.ron");
+            desk
         }
-        Err(e) => {
-            error!("Failed to load convictions.ron: {}", e);
-            panic!("Critical asset loading failure - fix convictions.ron");
+        Some(Err(e)) => {
+            #[cfg(debug_assertions)]
+            panic!("Headline validation failed: {}", e);
+            #[cfg(not(debug_assertions))]
+            {
+                error!("Headline validation failed: {} - using shipped headlines", e);
+                crate::models::narrative::HeadlineDesk::shipped()
+            }
         }
-    }
+        None => crate::models::narrative::HeadlineDesk::shipped(),
+    };
+    commands.insert_resource(headline_desk);
 
-    // SOW-027: narc compositions are built AFTER shop locations load (their
-    // area keys are validated against the loaded areas) - see below
+    // SOW-056: the save records which packs its empire was played with
+    commands.insert_resource(super::mods::ModPacks(packs));
 
-    // Load cover
-    match load_and_validate_cards("assets/cards/cover.ron", "Cover") {
-        Ok(cards) => {
-            game_assets.cover = cards;
-            info!("Loaded {} cover cards", game_assets.cover.len());
+    game_assets.assets_loaded = true;
+    info!("All game assets loaded successfully!");
+}
+
+/// SOW-027: area id -> HeatTier name -> assembled narc deck
+type NarcCompositions = HashMap<String, HashMap<String, Vec<Card>>>;
+
+/// SOW-054: everything GameAssets holds that comes from RON (no image
+/// handles), read and validated from one assets root. Startup and hot
+/// reload both build from load_content, so a reload checks exactly what a
+/// launch would.
+pub struct GameContent {
+    products: HashMap<String, Card>,
+    locations: HashMap<String, Card>,
+    narc_compositions: NarcCompositions,
+    cover: Vec<Card>,
+    insurance: Vec<Card>,
+    modifiers: Vec<Card>,
+    buyers: Vec<BuyerPersona>,
+    shop_locations: Vec<crate::models::shop_location::ShopLocationDef>,
+    /// A broken narrative_defaults.ron is survivable (empty defaults)
+    narrative_defaults: Result<crate::models::narrative::NarrativeFragments, String>,
+    /// SOW-059
+    relics: Vec<crate::models::relic::RelicDef>,
+    /// SOW-061
+    bosses: Vec<crate::models::boss::BossDef>,
+    /// SOW-062
+    victories: Vec<crate::models::victory::VictoryDef>,
+    /// (portrait key, file under art/actors/), every file checked on disk
+    portraits: Vec<(String, String)>,
+    /// Authoring errors that fail loud in debug and only log in release
+    authoring_errors: Vec<String>,
+}
+
+impl GameContent {
+    /// Every problem at once - hot reload only swaps in content a debug
+    /// launch would accept, narrative defaults included
+    pub fn strict(self) -> Result<Self, String> {
+        if let Err(e) = &self.narrative_defaults {
+            return Err(format!("narrative_defaults.ron: {e}"));
         }
-        Err(e) => {
-            error!("Failed to load cover.ron: {}", e);
-            panic!("Critical asset loading failure - fix cover.ron");
+        if !self.authoring_errors.is_empty() {
+            return Err(self.authoring_errors.join("\n"));
         }
+        Ok(self)
     }
 
-    // Load insurance
-    match load_and_validate_cards("assets/cards/insurance.ron", "Insurance") {
-        Ok(cards) => {
-            game_assets.insurance = cards;
-            info!("Loaded {} insurance cards", game_assets.insurance.len());
+    /// Swap this content into GameAssets in one go. Image handles stay;
+    /// portraits are re-mapped since buyers and areas name them.
+    pub fn install(self, asset_server: &AssetServer, game_assets: &mut GameAssets) {
+        game_assets.products = self.products;
+        game_assets.locations = self.locations;
+        game_assets.narc_compositions = self.narc_compositions;
+        game_assets.cover = self.cover;
+        game_assets.insurance = self.insurance;
+        game_assets.modifiers = self.modifiers;
+        game_assets.buyers = self.buyers;
+        game_assets.zone_graph = crate::models::shop_location::ZoneGraph::from_areas(&self.shop_locations);
+        game_assets.shop_locations = self.shop_locations;
+        game_assets.narrative_defaults = self.narrative_defaults.unwrap_or_default();
+        game_assets.relics = self.relics;
+        game_assets.bosses = self.bosses;
+        game_assets.victories = self.victories;
+
+        game_assets.actor_portraits.clear();
+        for (key, filename) in self.portraits {
+            let handle = asset_server.load(format!("art/actors/{filename}"));
+            game_assets.actor_portraits.insert(key, handle);
         }
-        Err(e) => {
-            error!("Failed to load insurance.ron: {}", e);
-            panic!("Critical asset loading failure - fix insurance.ron");
+        info!(
+            "Loaded {} actor portraits (RON-mapped buyers/narcs + dealer pool)",
+            game_assets.actor_portraits.len()
+        );
+    }
+
+    /// SOW-026: every player-ownable card across the loaded pools
+    fn player_cards(&self) -> Vec<Card> {
+        self.products
+            .values()
+            .chain(self.locations.values())
+            .chain(self.cover.iter())
+            .chain(self.insurance.iter())
+            .chain(self.modifiers.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// SOW-054: read and validate every RON content file under `root`. Hard
+/// errors (unreadable files, unknown ids, invalid cards) are the Err;
+/// checks that only fail loud in debug land in `authoring_errors`.
+/// SOW-056: mod packs layer over the files, in load order, before any of
+/// it is validated.
+pub fn load_content(root: &str, packs: &[super::mods::ModPack]) -> Result<GameContent, String> {
+    let mut raw = read_raw_content(root)?;
+    for pack in packs {
+        super::mods::layer_pack(&mut raw, pack)?;
+        info!("Layered mod pack {} {}", pack.manifest.id, pack.manifest.version);
+    }
+    build_content(root, raw)
+}
+
+/// SOW-056: the content files as authored, before any cross-file
+/// resolution - what a mod pack layers onto
+pub struct RawContent {
+    pub products: Vec<Card>,
+    pub locations: Vec<Card>,
+    pub evidence: Vec<Card>,
+    pub convictions: Vec<Card>,
+    pub cover: Vec<Card>,
+    pub insurance: Vec<Card>,
+    pub modifiers: Vec<Card>,
+    pub buyers: Vec<BuyerPersona>,
+    pub shop_locations: Vec<crate::models::shop_location::ShopLocationDef>,
+    pub narc: NarcCompositionsFile,
+    pub narrative_defaults: Result<crate::models::narrative::NarrativeFragments, String>,
+    /// SOW-059: empty when the file is absent
+    pub relics: Vec<crate::models::relic::RelicDef>,
+    /// SOW-061: empty when the file is absent
+    pub bosses: Vec<crate::models::boss::BossDef>,
+    /// SOW-062: empty when the file is absent
+    pub victories: Vec<crate::models::victory::VictoryDef>,
+}
+
+fn read_raw_content(root: &str) -> Result<RawContent, String> {
+    let path = |file: &str| format!("{}/{}", root.trim_end_matches('/'), file);
+    Ok(RawContent {
+        // SOW-053: story files come from the active locale's folder when it has them
+        narrative_defaults: load_narrative_defaults(&crate::locale::asset_path(root, "narrative_defaults.ron")),
+        products: load_and_validate_cards(&path("cards/products.ron"), "Product")?,
+        locations: load_and_validate_cards(&path("cards/locations.ron"), "Location")?,
+        evidence: load_and_validate_cards(&path("cards/evidence.ron"), "Evidence")?,
+        convictions: load_and_validate_cards(&path("cards/convictions.ron"), "Conviction")?,
+        cover: load_and_validate_cards(&path("cards/cover.ron"), "Cover")?,
+        insurance: load_and_validate_cards(&path("cards/insurance.ron"), "Insurance")?,
+        modifiers: load_and_validate_cards(&path("cards/modifiers.ron"), "Modifier")?,
+        shop_locations: load_shop_locations(&path("data/shop_locations.ron"))?,
+        narc: load_narc_compositions(&path("narc_deck.ron"))?,
+        buyers: load_and_validate_buyers(&path("buyers.ron"))?,
+        relics: load_relics(&path("relics.ron")).unwrap_or(Ok(Vec::new()))?,
+        bosses: load_bosses(&path("bosses.ron")).unwrap_or(Ok(Vec::new()))?,
+        victories: load_victories(&path("victories.ron")).unwrap_or(Ok(Vec::new()))?,
+    })
+}
+
+/// SOW-056: validate the (layered) files and resolve them into GameContent.
+/// `root` is only needed for the portrait art.
+fn build_content(root: &str, raw: RawContent) -> Result<GameContent, String> {
+    let mut authoring_errors = Vec::new();
+
+    // Narrative defaults first (includes resolution clauses)
+    let narrative_defaults = raw.narrative_defaults;
+    if narrative_defaults.is_ok() {
+        info!("Loaded narrative defaults (with resolution clauses)");
+    }
+
+    // Load products (no default merging - StoryComposer handles fallback)
+    let mut products = HashMap::new();
+    for card in raw.products {
+        validate_card(&card, "Product").map_err(|e| format!("products.ron: {e}"))?;
+        products.insert(card.name.clone(), card);
+    }
+    info!("Loaded {} products", products.len());
+
+    // Load locations (no default merging - StoryComposer handles fallback)
+    let mut locations = HashMap::new();
+    for card in raw.locations {
+        validate_card(&card, "Location").map_err(|e| format!("locations.ron: {e}"))?;
+        locations.insert(card.name.clone(), card);
+    }
+    info!("Loaded {} locations", locations.len());
+
+    // Narc card definitions, by id - the compositions below name them
+    let evidence_defs: HashMap<String, Card> = raw.evidence.into_iter().map(|card| (card.id.clone(), card)).collect();
+    info!("Loaded {} evidence card definitions", evidence_defs.len());
+    let conviction_defs: HashMap<String, Card> =
+        raw.convictions.into_iter().map(|card| (card.id.clone(), card)).collect();
+    info!("Loaded {} conviction card definitions", conviction_defs.len());
+
+    let (cover, insurance, modifiers) = (raw.cover, raw.insurance, raw.modifiers);
+    info!("Loaded {} cover cards", cover.len());
+    info!("Loaded {} insurance cards", insurance.len());
+    info!("Loaded {} modifier cards", modifiers.len());
+
+    // SOW-057: evolution paths
+    let evolution_errors: Vec<String> = [
+        ("products.ron", products.values().collect::<Vec<_>>()),
+        ("locations.ron", locations.values().collect()),
+        ("cover.ron", cover.iter().collect()),
+        ("insurance.ron", insurance.iter().collect()),
+        ("modifiers.ron", modifiers.iter().collect()),
+    ]
+    .into_iter()
+    .flat_map(|(file, cards)| evolution_errors(file, &cards))
+    .collect();
+    if !evolution_errors.is_empty() {
+        return Err(evolution_errors.join("\n"));
+    }
+
+    // SOW-024: areas are validated before buyers so persona area references
+    // can be checked against real area ids
+    let shop_locations = raw.shop_locations;
+    crate::models::shop_location::validate_shop_locations(&shop_locations)
+        .map_err(|e| format!("Area validation failed: {e}"))?;
+    let area_ids: Vec<&str> = shop_locations.iter().map(|a| a.id.as_str()).collect();
+
+    // Every card's shop_location must be a real area id (same
+    // fail-loud-in-debug treatment as SOW-021 demand strings)
+    for card in products
+        .values()
+        .chain(locations.values())
+        .chain(cover.iter())
+        .chain(insurance.iter())
+        .chain(modifiers.iter())
+    {
+        if let Some(loc) = &card.shop_location {
+            if !area_ids.contains(&loc.as_str()) {
+                authoring_errors.push(format!("Card '{}' references unknown area '{}'", card.name, loc));
+            }
         }
     }
+    info!("Loaded {} areas", shop_locations.len());
+
+    let narc_compositions = resolve_narc_compositions(&raw.narc, &area_ids, &evidence_defs, &conviction_defs)
+        .map_err(|errors| errors.join("\n"))?;
+    for warning in unused_narc_card_warnings(&raw.narc, &evidence_defs, &conviction_defs) {
+        warn!("{}", warning);
+    }
 
-    // Load modifiers
-    match load_and_validate_cards("assets/cards/modifiers.ron", "Modifier") {
-        Ok(cards) => {
-            game_assets.modifiers = cards;
-            info!("Loaded {} modifier cards", game_assets.modifiers.len());
+    // Resolve the buyers' reaction deck IDs
+    let mut buyers = raw.buyers;
+    for buyer in &mut buyers {
+        // Locations first, then modifiers
+        buyer.reaction_deck = buyer
+            .reaction_deck_ids
+            .iter()
+            .map(|id| {
+                locations
+                    .values()
+                    .chain(modifiers.iter())
+                    .find(|c| &c.id == id)
+                    .cloned()
+                    .ok_or_else(|| {
+                        format!("Buyer {} reaction_deck references unknown card ID: {}", buyer.display_name, id)
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+    }
+    for buyer in &buyers {
+        validate_buyer(buyer).map_err(|e| format!("Buyer validation failed: {e}"))?;
+    }
+
+    // SOW-021: Every demand string must resolve to a real card name
+    let product_names: Vec<&str> = products.values().map(|c| c.name.as_str()).collect();
+    let location_names: Vec<&str> = locations.values().map(|c| c.name.as_str()).collect();
+    for buyer in &buyers {
+        if let Err(e) = validate_buyer_demand_strings(buyer, &product_names, &location_names) {
+            authoring_errors.push(format!("Demand string validation failed: {}", e));
         }
+    }
+
+    // SOW-024: persona areas must be real, and every area must have
+    // clientele (a run in an empty area would have no buyer to draw)
+    if let Err(e) = validate_persona_areas(&buyers, &shop_locations) {
+        authoring_errors.push(format!("Persona area validation failed: {}", e));
+    }
+    info!("Loaded {} buyer personas", buyers.len());
+
+    // SOW-059: relics sold in a zone name a real one
+    let relics = raw.relics;
+    crate::models::relic::validate_relics(&relics, &area_ids).map_err(|e| format!("relics.ron: {e}"))?;
+    info!("Loaded {} relics", relics.len());
+
+    // SOW-061: operations pay out real relics and bring their own faces
+    let bosses = raw.bosses;
+    let relic_ids: Vec<&str> = relics.iter().map(|r| r.id.as_str()).collect();
+    crate::models::boss::validate_bosses(&bosses, &relic_ids).map_err(|e| format!("bosses.ron: {e}"))?;
+    info!("Loaded {} task-force operations", bosses.len());
+
+    // SOW-062
+    let victories = raw.victories;
+    crate::models::victory::validate_victories(&victories).map_err(|e| format!("victories.ron: {e}"))?;
+    info!("Loaded {} victory conditions", victories.len());
+
+    let mut portraits = actor_portrait_files(root, &buyers, &shop_locations)?;
+    portraits.extend(boss_portrait_files(root, &bosses)?);
+
+    let mut content = GameContent {
+        products,
+        locations,
+        narc_compositions,
+        cover,
+        insurance,
+        modifiers,
+        buyers,
+        shop_locations,
+        narrative_defaults,
+        relics,
+        bosses,
+        victories,
+        portraits,
+        authoring_errors,
+    };
+
+    // SOW-026: the lean starting collection must still build a legal deck
+    // (>=1 Product, >=1 Location)
+    let all_player_cards = content.player_cards();
+    if let Err(e) = validate_fresh_collection(&all_player_cards) {
+        content.authoring_errors.push(format!("Fresh collection validation failed: {}", e));
+    }
+
+    // SOW-026: every scenario must demand at least one product attainable
+    // at-or-before its buyer's rung on the area ladder (warn-only - an
+    // OR-demand with one reachable product still pays out).
+    for warning in ladder_attainability_warnings(&content.buyers, &all_player_cards, &content.shop_locations) {
+        warn!("Shop ladder: {}", warning);
+    }
+
+    Ok(content)
+}
+
+/// SOW-055: every load-time check over `root` for validate-assets,
+/// collected rather than stopping at the first. A file that doesn't load
+/// skips only the checks that need it.
+pub fn validate_content(root: &str) -> super::validate::ContentReport {
+    let path = |file: &str| format!("{}/{}", root.trim_end_matches('/'), file);
+    let mut report = super::validate::ContentReport::default();
+    let loaded = |result: Result<Vec<Card>, String>, errors: &mut Vec<String>| match result {
+        Ok(cards) => Some(cards),
         Err(e) => {
-            error!("Failed to load modifiers.ron: {}", e);
-            panic!("Critical asset loading failure - fix modifiers.ron");
+            errors.push(e);
+            None
+        }
+    };
+
+    let products = loaded(load_and_validate_cards(&path("cards/products.ron"), "Product"), &mut report.errors);
+    let locations = loaded(load_and_validate_cards(&path("cards/locations.ron"), "Location"), &mut report.errors);
+    let evidence = loaded(load_and_validate_cards(&path("cards/evidence.ron"), "Evidence"), &mut report.errors);
+    let convictions = loaded(load_and_validate_cards(&path("cards/convictions.ron"), "Conviction"), &mut report.errors);
+    let cover = loaded(load_and_validate_cards(&path("cards/cover.ron"), "Cover"), &mut report.errors);
+    let insurance = loaded(load_and_validate_cards(&path("cards/insurance.ron"), "Insurance"), &mut report.errors);
+    let modifiers = loaded(load_and_validate_cards(&path("cards/modifiers.ron"), "Modifier"), &mut report.errors);
+
+    for (file, kind, cards) in [("products.ron", "Product", &products), ("locations.ron", "Location", &locations)] {
+        for card in cards.iter().flatten() {
+            if let Err(e) = validate_card(card, kind) {
+                report.errors.push(format!("{file}: {e}"));
+            }
         }
     }
 
-    // SOW-024: Load areas (shop locations) - loaded before buyers so persona
-    // area references can be validated against real area ids
-    match load_shop_locations("assets/data/shop_locations.ron") {
+    for (file, cards) in [
+        ("products.ron", &products),
+        ("locations.ron", &locations),
+        ("cover.ron", &cover),
+        ("insurance.ron", &insurance),
+        ("modifiers.ron", &modifiers),
+    ] {
+        let cards: Vec<&Card> = cards.iter().flatten().collect();
+        report.errors.extend(evolution_errors(file, &cards));
+    }
+
+    if let Err(e) = load_narrative_defaults(&crate::locale::asset_path(root, "narrative_defaults.ron")) {
+        report.warnings.push(format!("{e} - the game falls back to empty defaults"));
+    }
+
+    let areas = match load_shop_locations(&path("data/shop_locations.ron")) {
         Ok(areas) => {
-            crate::models::shop_location::validate_shop_locations(&areas)
-                .expect("Area validation failed");
-
-            // Every card's shop_location must be a real area id (same
-            // fail-loud-in-debug treatment as SOW-021 demand strings)
-            let area_ids: Vec<&str> = areas.iter().map(|a| a.id.as_str()).collect();
-            for card in game_assets
-                .products
-                .values()
-                .chain(game_assets.locations.values())
-                .chain(game_assets.cover.iter())
-                .chain(game_assets.insurance.iter())
-                .chain(game_assets.modifiers.iter())
-            {
-                if let Some(loc) = &card.shop_location {
-                    if !area_ids.contains(&loc.as_str()) {
-                        #[cfg(debug_assertions)]
-                        panic!("Card '{}' references unknown area '{}'", card.name, loc);
-                        #[cfg(not(debug_assertions))]
-                        error!("Card '{}' references unknown area '{}' (unpurchasable)", card.name, loc);
-                    }
-                }
+            if let Err(e) = crate::models::shop_location::validate_shop_locations(&areas) {
+                report.errors.push(format!("Area validation failed: {e}"));
             }
-
-            info!("Loaded {} areas", areas.len());
-            game_assets.shop_locations = areas;
+            Some(areas)
         }
         Err(e) => {
-            error!("Failed to load shop_locations.ron: {}", e);
-            panic!("Critical asset loading failure - fix shop_locations.ron");
+            report.errors.push(e);
+            None
         }
-    }
+    };
 
-    // SOW-027: Build per-area, per-tier narc deck compositions.
-    // Difficulty IS the composition (RFC-018 stat multipliers retired):
-    // the run's narc deck = effective[dealer's station][dealer's heat tier].
-    // The authored format is SPARSE: a `default` ladder plus per-area tier
-    // OVERRIDES - inheritance is resolved here so a new area ships with zero
-    // narc authoring and gets the baseline (Reed's authoring-burden concern).
-    match load_narc_compositions("assets/narc_deck.ron") {
-        Ok(raw) => {
-            const TIER_KEYS: [&str; 6] = ["Cold", "Warm", "Hot", "Blazing", "Scorching", "Inferno"];
-            let area_ids: Vec<&str> = game_assets.shop_locations.iter().map(|a| a.id.as_str()).collect();
-
-            // Resolve card ids -> cards, panicking with an authoring-friendly location
-            let resolve = |ids: &[String], whose: &str| -> Vec<crate::models::card::Card> {
-                ids.iter()
-                    .map(|id| {
-                        evidence_defs
-                            .get(id)
-                            .or_else(|| conviction_defs.get(id))
-                            .unwrap_or_else(|| {
-                                panic!("narc_deck.ron: unknown card id '{}' in {}", id, whose)
-                            })
-                            .clone()
-                    })
-                    .collect()
-            };
+    let player_cards: Vec<Card> = [&products, &locations, &cover, &insurance, &modifiers]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .cloned()
+        .collect();
 
-            // The default ladder must be complete - it's what new areas inherit
-            for tier in TIER_KEYS {
-                match raw.default.get(tier) {
-                    None => panic!("narc_deck.ron: default ladder is missing tier '{}'", tier),
-                    Some(ids) if ids.is_empty() => {
-                        panic!("narc_deck.ron: default ladder tier '{}' is empty", tier)
-                    }
-                    _ => {}
+    if let Some(areas) = &areas {
+        let area_ids: Vec<&str> = areas.iter().map(|a| a.id.as_str()).collect();
+        for card in &player_cards {
+            if let Some(loc) = &card.shop_location {
+                if !area_ids.contains(&loc.as_str()) {
+                    report.errors.push(format!("Card '{}' references unknown area '{}'", card.name, loc));
                 }
             }
+        }
 
-            // Overrides may only name real areas and real tiers
-            for (area, overrides) in &raw.areas {
-                if !area_ids.contains(&area.as_str()) {
-                    panic!("narc_deck.ron: unknown area '{}' (known: {:?})", area, area_ids);
+        // SOW-059
+        let relics = match load_relics(&path("relics.ron")) {
+            Some(Ok(relics)) => {
+                if let Err(e) = crate::models::relic::validate_relics(&relics, &area_ids) {
+                    report.errors.push(format!("relics.ron: {e}"));
                 }
-                for (tier, ids) in overrides {
-                    if !TIER_KEYS.contains(&tier.as_str()) {
-                        panic!("narc_deck.ron: area '{}' overrides unknown tier '{}'", area, tier);
-                    }
-                    if ids.is_empty() {
-                        panic!("narc_deck.ron: area '{}' tier '{}' override is empty", area, tier);
-                    }
+                relics
+            }
+            Some(Err(e)) => {
+                report.errors.push(e);
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+
+        // SOW-061
+        match load_bosses(&path("bosses.ron")) {
+            Some(Ok(bosses)) => {
+                let relic_ids: Vec<&str> = relics.iter().map(|r| r.id.as_str()).collect();
+                if let Err(e) = crate::models::boss::validate_bosses(&bosses, &relic_ids) {
+                    report.errors.push(format!("bosses.ron: {e}"));
+                }
+                if let Err(e) = boss_portrait_files(root, &bosses) {
+                    report.errors.push(e);
                 }
             }
+            Some(Err(e)) => report.errors.push(e),
+            None => {}
+        }
 
-            // Effective table: every purchasable area x tier, overrides beating default
-            let mut compositions: HashMap<String, HashMap<String, Vec<crate::models::card::Card>>> =
-                HashMap::new();
-            for area in &area_ids {
-                let overrides = raw.areas.get(*area);
-                let mut tier_map = HashMap::new();
-                for tier in TIER_KEYS {
-                    let (ids, source) = match overrides.and_then(|o| o.get(tier)) {
-                        Some(ids) => (ids, "override"),
-                        None => (raw.default.get(tier).expect("validated above"), "default"),
-                    };
-                    let deck = resolve(ids, &format!("{}/{}", area, tier));
-                    // Authors see what actually shipped (compact count summary)
-                    #[cfg(debug_assertions)]
-                    {
-                        let mut counts: std::collections::BTreeMap<&str, u32> =
-                            std::collections::BTreeMap::new();
-                        for card in &deck {
-                            *counts.entry(card.name.as_str()).or_insert(0) += 1;
-                        }
-                        let summary = counts
-                            .iter()
-                            .map(|(name, n)| format!("{n}x {name}"))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        debug!("narc effective {}/{} ({source}): {}", area, tier, summary);
-                    }
-                    tier_map.insert(tier.to_string(), deck);
+        // SOW-062
+        match load_victories(&path("victories.ron")) {
+            Some(Ok(victories)) => {
+                if let Err(e) = crate::models::victory::validate_victories(&victories) {
+                    report.errors.push(format!("victories.ron: {e}"));
                 }
-                compositions.insert(area.to_string(), tier_map);
             }
+            Some(Err(e)) => report.errors.push(e),
+            None => {}
+        }
 
-            // Authoring hygiene: a defined narc card that appears nowhere is
-            // probably a mistake (warn, don't fail - it may be staged content)
-            let used: std::collections::HashSet<&String> = raw
-                .default
-                .values()
-                .chain(raw.areas.values().flat_map(|o| o.values()))
-                .flatten()
-                .collect();
-            for id in evidence_defs.keys().chain(conviction_defs.keys()) {
-                if !used.contains(id) {
-                    warn!("narc card '{}' is defined but used in no composition", id);
+        match (load_narc_compositions(&path("narc_deck.ron")), &evidence, &convictions) {
+            (Err(e), _, _) => report.errors.push(e),
+            (Ok(raw), Some(evidence), Some(convictions)) => {
+                let by_id = |cards: &[Card]| -> HashMap<String, Card> {
+                    cards.iter().map(|c| (c.id.clone(), c.clone())).collect()
+                };
+                let (evidence_defs, conviction_defs) = (by_id(evidence), by_id(convictions));
+                if let Err(errors) = resolve_narc_compositions(&raw, &area_ids, &evidence_defs, &conviction_defs) {
+                    report.errors.extend(errors);
                 }
+                report.warnings.extend(unused_narc_card_warnings(&raw, &evidence_defs, &conviction_defs));
             }
+            // The narc card file's own error is already reported
+            (Ok(_), _, _) => {}
+        }
+    }
 
-            let deck_count: usize = compositions.values().map(|t| t.len()).sum();
-            info!("Built {} narc area/tier deck compositions", deck_count);
-            game_assets.narc_compositions = compositions;
+    let buyers = match load_and_validate_buyers(&path("buyers.ron")) {
+        Ok(mut buyers) => {
+            if let (Some(locations), Some(modifiers)) = (&locations, &modifiers) {
+                for buyer in &mut buyers {
+                    let mut deck = Vec::new();
+                    for id in &buyer.reaction_deck_ids {
+                        match locations.iter().chain(modifiers).find(|c| &c.id == id) {
+                            Some(card) => deck.push(card.clone()),
+                            None => report.errors.push(format!(
+                                "Buyer {} reaction_deck references unknown card ID: {}",
+                                buyer.display_name, id
+                            )),
+                        }
+                    }
+                    buyer.reaction_deck = deck;
+                    if deck_complete(buyer) {
+                        if let Err(e) = validate_buyer(buyer) {
+                            report.errors.push(format!("Buyer validation failed: {e}"));
+                        }
+                    }
+                }
+            }
+            if let (Some(products), Some(locations)) = (&products, &locations) {
+                let product_names: Vec<&str> = products.iter().map(|c| c.name.as_str()).collect();
+                let location_names: Vec<&str> = locations.iter().map(|c| c.name.as_str()).collect();
+                for buyer in &buyers {
+                    if let Err(e) = validate_buyer_demand_strings(buyer, &product_names, &location_names) {
+                        report.errors.push(format!("Demand string validation failed: {}", e));
+                    }
+                }
+            }
+            Some(buyers)
         }
         Err(e) => {
-            error!("Failed to load narc_deck.ron: {}", e);
-            panic!("Critical asset loading failure - fix narc_deck.ron");
+            report.errors.push(e);
+            None
         }
+    };
+
+    if let (Some(buyers), Some(areas)) = (&buyers, &areas) {
+        if let Err(e) = validate_persona_areas(buyers, areas) {
+            report.errors.push(format!("Persona area validation failed: {}", e));
+        }
+        if let Err(e) = actor_portrait_files(root, buyers, areas) {
+            report.errors.push(e);
+        }
+        report.warnings.extend(
+            ladder_attainability_warnings(buyers, &player_cards, areas)
+                .into_iter()
+                .map(|w| format!("Shop ladder: {w}")),
+        );
     }
 
-    // Load buyers and resolve their reaction deck IDs
-    match load_and_validate_buyers("assets/buyers.ron") {
-        Ok(mut buyers) => {
-            // Resolve reaction_deck_ids to actual cards
-            for buyer in &mut buyers {
-                buyer.reaction_deck = buyer.reaction_deck_ids.iter()
-                    .map(|id| {
-                        // Try to find card by ID in locations (search values)
-                        game_assets.locations.values()
-                            .find(|c| &c.id == id)
-                            .cloned()
-                            .or_else(|| {
-                                // Try modifiers
-                                game_assets.modifiers.iter()
-                                    .find(|c| &c.id == id)
-                                    .cloned()
-                            })
-                            .unwrap_or_else(|| panic!("Buyer {} reaction_deck references unknown card ID: {}", buyer.display_name, id))
-                    })
-                    .collect();
-            }
+    if products.is_some() && locations.is_some() {
+        if let Err(e) = validate_fresh_collection(&player_cards) {
+            report.errors.push(format!("Fresh collection validation failed: {}", e));
+        }
+    }
 
-            for buyer in &buyers {
-                validate_buyer(buyer).expect("Buyer validation failed");
-            }
+    report.summarize(
+        &player_cards,
+        areas.as_deref().unwrap_or_default(),
+        buyers.as_deref().unwrap_or_default(),
+    );
+    report
+}
 
-            // SOW-021: Every demand string must resolve to a real card name.
-            // Fail loudly in debug; log an error (but keep running) in release.
-            let product_names: Vec<&str> = game_assets.products.values().map(|c| c.name.as_str()).collect();
-            let location_names: Vec<&str> = game_assets.locations.values().map(|c| c.name.as_str()).collect();
-            for buyer in &buyers {
-                if let Err(e) = validate_buyer_demand_strings(buyer, &product_names, &location_names) {
-                    #[cfg(debug_assertions)]
-                    panic!("Demand string validation failed: {}", e);
-                    #[cfg(not(debug_assertions))]
-                    error!("Demand string validation failed (demand cannot pay out): {}", e);
-                }
-            }
+/// A reaction deck with an unknown id already has its error - don't pile a
+/// "has 6 cards" on top of it
+fn deck_complete(buyer: &BuyerPersona) -> bool {
+    buyer.reaction_deck.len() == buyer.reaction_deck_ids.len()
+}
 
-            // SOW-024: persona areas must be real, and every area must have
-            // clientele (a run in an empty area would have no buyer to draw).
-            // Same fail-loud-in-debug treatment as demand strings.
-            if let Err(e) = validate_persona_areas(&buyers, &game_assets.shop_locations) {
-                #[cfg(debug_assertions)]
-                panic!("Persona area validation failed: {}", e);
-                #[cfg(not(debug_assertions))]
-                error!("Persona area validation failed: {}", e);
+/// SOW-027: Build per-area, per-tier narc deck compositions.
+/// Difficulty IS the composition (RFC-018 stat multipliers retired):
+/// the run's narc deck = effective[dealer's station][dealer's heat tier].
+/// The authored format is SPARSE: a `default` ladder plus per-area tier
+/// OVERRIDES - inheritance is resolved here so a new area ships with zero
+/// narc authoring and gets the baseline (Reed's authoring-burden concern).
+/// SOW-055: every authoring problem is reported, not just the first.
+fn resolve_narc_compositions(
+    raw: &NarcCompositionsFile,
+    area_ids: &[&str],
+    evidence_defs: &HashMap<String, Card>,
+    conviction_defs: &HashMap<String, Card>,
+) -> Result<NarcCompositions, Vec<String>> {
+    const TIER_KEYS: [&str; 6] = ["Cold", "Warm", "Hot", "Blazing", "Scorching", "Inferno"];
+    let mut errors = Vec::new();
+
+    // The default ladder must be complete - it's what new areas inherit
+    for tier in TIER_KEYS {
+        match raw.default.get(tier) {
+            None => errors.push(format!("narc_deck.ron: default ladder is missing tier '{}'", tier)),
+            Some(ids) if ids.is_empty() => {
+                errors.push(format!("narc_deck.ron: default ladder tier '{}' is empty", tier))
             }
+            _ => {}
+        }
+    }
 
-            game_assets.buyers = buyers;
-            info!("Loaded {} buyer personas", game_assets.buyers.len());
+    // Overrides may only name real areas and real tiers
+    for (area, overrides) in &raw.areas {
+        if !area_ids.contains(&area.as_str()) {
+            errors.push(format!("narc_deck.ron: unknown area '{}' (known: {:?})", area, area_ids));
         }
-        Err(e) => {
-            error!("Failed to load buyers.ron: {}", e);
-            panic!("Critical asset loading failure - fix buyers.ron");
+        for (tier, ids) in overrides {
+            if !TIER_KEYS.contains(&tier.as_str()) {
+                errors.push(format!("narc_deck.ron: area '{}' overrides unknown tier '{}'", area, tier));
+            }
+            if ids.is_empty() {
+                errors.push(format!("narc_deck.ron: area '{}' tier '{}' override is empty", area, tier));
+            }
         }
     }
 
-    // SOW-033: portrait map is built from the loaded buyers' `portrait` and
-    // the areas' `narc_portrait` RON fields (plus the dealer pool). Runs here
-    // so both are populated; a missing mapped file panics loud.
-    load_actor_portraits(&asset_server, &mut game_assets);
+    // Every card id must be a real narc card, checked where it's authored
+    // (not once per inheriting area)
+    let authored = raw
+        .default
+        .iter()
+        .map(|(tier, ids)| (format!("default/{tier}"), ids))
+        .chain(raw.areas.iter().flat_map(|(area, overrides)| {
+            overrides.iter().map(move |(tier, ids)| (format!("{area}/{tier}"), ids))
+        }));
+    for (whose, ids) in authored {
+        for id in ids {
+            if !evidence_defs.contains_key(id) && !conviction_defs.contains_key(id) {
+                errors.push(format!("narc_deck.ron: unknown card id '{}' in {}", id, whose));
+            }
+        }
+    }
 
-    // SOW-026: the lean starting collection must still build a legal deck
-    // (>=1 Product, >=1 Location) - fail loudly in debug, error in release.
-    let all_player_cards = collect_player_cards(&game_assets);
-    if let Err(e) = validate_fresh_collection(&all_player_cards) {
-        #[cfg(debug_assertions)]
-        panic!("Fresh collection validation failed: {}", e);
-        #[cfg(not(debug_assertions))]
-        error!("Fresh collection validation failed: {}", e);
+    if !errors.is_empty() {
+        errors.sort();
+        return Err(errors);
     }
 
-    // SOW-026: every scenario must demand at least one product attainable
-    // at-or-before its buyer's rung on the area ladder (warn-only - an
-    // OR-demand with one reachable product still pays out).
-    for warning in ladder_attainability_warnings(
-        &game_assets.buyers,
-        &all_player_cards,
-        &game_assets.shop_locations,
-    ) {
-        warn!("Shop ladder: {}", warning);
+    // Effective table: every purchasable area x tier, overrides beating default
+    let mut compositions: NarcCompositions = HashMap::new();
+    for area in area_ids {
+        let overrides = raw.areas.get(*area);
+        let mut tier_map = HashMap::new();
+        for tier in TIER_KEYS {
+            let (ids, source) = match overrides.and_then(|o| o.get(tier)) {
+                Some(ids) => (ids, "override"),
+                None => (raw.default.get(tier).expect("validated above"), "default"),
+            };
+            let deck: Vec<Card> = ids
+                .iter()
+                .map(|id| {
+                    evidence_defs
+                        .get(id)
+                        .or_else(|| conviction_defs.get(id))
+                        .cloned()
+                        .expect("validated above")
+                })
+                .collect();
+            // Authors see what actually shipped (compact count summary)
+            #[cfg(debug_assertions)]
+            {
+                let mut counts: std::collections::BTreeMap<&str, u32> =
+                    std::collections::BTreeMap::new();
+                for card in &deck {
+                    *counts.entry(card.name.as_str()).or_insert(0) += 1;
+                }
+                let summary = counts
+                    .iter()
+                    .map(|(name, n)| format!("{n}x {name}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                debug!("narc effective {}/{} ({source}): {}", area, tier, summary);
+            }
+            tier_map.insert(tier.to_string(), deck);
+        }
+        compositions.insert(area.to_string(), tier_map);
     }
 
-    // Create StoryComposer resource with full narrative defaults (handles fallback internally)
-    let story_composer = crate::models::narrative::StoryComposer::new(game_assets.narrative_defaults.clone());
-    commands.insert_resource(story_composer);
-    info!("Created StoryComposer resource with defaults");
+    let deck_count: usize = compositions.values().map(|t| t.len()).sum();
+    info!("Built {} narc area/tier deck compositions", deck_count);
+    Ok(compositions)
+}
 
-    game_assets.assets_loaded = true;
-    info!("All game assets loaded successfully!");
+/// Authoring hygiene: a defined narc card that appears nowhere is probably
+/// a mistake (warn, don't fail - it may be staged content)
+fn unused_narc_card_warnings(
+    raw: &NarcCompositionsFile,
+    evidence_defs: &HashMap<String, Card>,
+    conviction_defs: &HashMap<String, Card>,
+) -> Vec<String> {
+    let used: std::collections::HashSet<&String> = raw
+        .default
+        .values()
+        .chain(raw.areas.values().flat_map(|o| o.values()))
+        .flatten()
+        .collect();
+    let mut warnings: Vec<String> = evidence_defs
+        .keys()
+        .chain(conviction_defs.keys())
+        .filter(|id| !used.contains(id))
+        .map(|id| format!("narc card '{}' is defined but used in no composition", id))
+        .collect();
+    warnings.sort();
+    warnings
 }
 
 /// Check if assets are loaded and transition to DeckBuilding state
@@ -431,8 +822,10 @@ fn load_and_validate_cards(path: &str, card_type_name: &str) -> Result<Vec<Card>
     let content = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path, e))?;
 
-    let cards: Vec<Card> = ron::from_str(&content)
+    let mut cards: Vec<Card> = ron::from_str(&content)
         .map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", path, e))?;
+    // SOW-053: the active locale's card_fragments.ron replaces story text
+    crate::locale::localize_cards(&mut cards);
 
     if cards.is_empty() {
         return Err(format!("{} is empty - must have at least one card", path));
@@ -467,19 +860,6 @@ fn validate_persona_areas(
     Ok(())
 }
 
-/// SOW-026: every player-ownable card across the loaded pools
-fn collect_player_cards(game_assets: &GameAssets) -> Vec<Card> {
-    game_assets
-        .products
-        .values()
-        .chain(game_assets.locations.values())
-        .chain(game_assets.cover.iter())
-        .chain(game_assets.insurance.iter())
-        .chain(game_assets.modifiers.iter())
-        .cloned()
-        .collect()
-}
-
 /// SOW-026: the fresh (starting-collection) pool must reference real cards
 /// and build a legal default deck - the lean start gates products hard, so
 /// this is the guard that keeps "lean" from becoming "unplayable"
@@ -548,7 +928,8 @@ fn ladder_attainability_warnings(
 }
 
 /// SOW-024: Load areas (shop locations) from RON file
-fn load_shop_locations(path: &str) -> Result<Vec<crate::models::shop_location::ShopLocationDef>, String> {
+/// SOW-051: pub for the chronicle export's zone names (no App there)
+pub fn load_shop_locations(path: &str) -> Result<Vec<crate::models::shop_location::ShopLocationDef>, String> {
     let content = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path, e))?;
 
@@ -578,6 +959,34 @@ fn load_and_validate_buyers(path: &str) -> Result<Vec<BuyerPersona>, String> {
     Ok(buyers)
 }
 
+/// SOW-057: every evolution must grow into a card from the same file that
+/// can't be bought, at an upgraded tier, along a path the card can take
+fn evolution_errors(file: &str, cards: &[&Card]) -> Vec<String> {
+    let mut errors = Vec::new();
+    for card in cards {
+        let paths = crate::save::UpgradeableStat::available_for(&card.card_type);
+        for evolution in &card.evolutions {
+            let problem = match cards.iter().find(|c| c.id == evolution.into) {
+                None => Some(format!("evolves into unknown card '{}'", evolution.into)),
+                Some(variant) if variant.shop_price.is_some() => {
+                    Some(format!("evolves into '{}', which is sold in a shop", evolution.into))
+                }
+                _ if evolution.tier == crate::save::UpgradeTier::Base => {
+                    Some("evolves at Base tier - it must be played first".to_string())
+                }
+                _ if !paths.contains(&evolution.path) => {
+                    Some(format!("evolves along {:?}, which this card can't upgrade", evolution.path))
+                }
+                _ => None,
+            };
+            if let Some(problem) = problem {
+                errors.push(format!("{file}: card '{}' {problem}", card.id));
+            }
+        }
+    }
+    errors
+}
+
 /// Validate a card's values
 fn validate_card(card: &Card, expected_type: &str) -> Result<(), String> {
     use crate::models::card::CardType;
@@ -675,6 +1084,103 @@ fn validate_buyer_demand_strings(
 }
 
 
+/// SOW-046: load and validate authored narrative patterns. None when the
+/// file doesn't exist (the shipped set applies).
+fn load_narrative_patterns(path: &str) -> Option<Result<Vec<crate::models::narrative::DynamicPattern>, String>> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(crate::models::narrative::patterns::parse_patterns(&content).and_then(|patterns| {
+        crate::models::narrative::patterns::validate_patterns(&patterns)?;
+        Ok(patterns)
+    }))
+}
+
+/// SOW-059: the authored relics (None = absent, the empire finds none)
+fn load_relics(path: &str) -> Option<Result<Vec<crate::models::relic::RelicDef>, String>> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(ron::from_str(&content).map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", path, e)))
+}
+
+/// SOW-061: the authored task-force operations (None = absent, no
+/// operation ever comes due)
+fn load_bosses(path: &str) -> Option<Result<Vec<crate::models::boss::BossDef>, String>> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(ron::from_str(&content).map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", path, e)))
+}
+
+/// SOW-062: victories.ron, parsed (None = absent)
+fn load_victories(path: &str) -> Option<Result<Vec<crate::models::victory::VictoryDef>, String>> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(ron::from_str(&content).map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", path, e)))
+}
+
+/// SOW-052: an authored headlines.ron, parsed and validated (None = absent)
+fn load_headlines(path: &str) -> Option<Result<crate::models::narrative::HeadlineDesk, String>> {
+    use crate::models::narrative::headlines::{parse_headlines, validate_headlines};
+    let content = fs::read_to_string(path).ok()?;
+    Some(parse_headlines(&content).and_then(|fragments| {
+        validate_headlines(&fragments)?;
+        Ok(crate::models::narrative::HeadlineDesk::new(fragments))
+    }))
+}
+
+/// SOW-054: the story patterns and headline fragments under `root`, for a
+/// hot reload - absent files mean the shipped sets, a broken one is the
+/// reload's error
+pub fn load_story_tables(
+    root: &str,
+) -> Result<(Vec<crate::models::narrative::DynamicPattern>, crate::models::narrative::HeadlineDesk), String> {
+    let patterns = match load_narrative_patterns(&crate::locale::asset_path(root, "narrative_patterns.ron")) {
+        Some(patterns) => patterns.map_err(|e| format!("narrative_patterns.ron: {e}"))?,
+        None => crate::models::narrative::DynamicPattern::shipped_patterns(),
+    };
+    let desk = match load_headlines(&crate::locale::asset_path(root, "headlines.ron")) {
+        Some(desk) => desk.map_err(|e| format!("headlines.ron: {e}"))?,
+        None => crate::models::narrative::HeadlineDesk::shipped(),
+    };
+    Ok((patterns, desk))
+}
+
+/// SOW-049: the story-relevant assets under `root`, read straight from disk
+/// (no App, no images) for narrative-lint. Reaction decks resolve the same
+/// way load_game_assets does, but an unknown id is an error, not a panic.
+pub fn load_story_assets(root: &str) -> Result<crate::models::narrative::lint::StoryAssets, String> {
+    let path = |file: &str| format!("{}/{}", root.trim_end_matches('/'), file);
+
+    let locations = load_and_validate_cards(&path("cards/locations.ron"), "Location")?;
+    let modifiers = load_and_validate_cards(&path("cards/modifiers.ron"), "Modifier")?;
+    let mut narc_cards = load_and_validate_cards(&path("cards/evidence.ron"), "Evidence")?;
+    narc_cards.extend(load_and_validate_cards(&path("cards/convictions.ron"), "Conviction")?);
+
+    let mut buyers = load_and_validate_buyers(&path("buyers.ron"))?;
+    for buyer in &mut buyers {
+        buyer.reaction_deck = buyer.reaction_deck_ids.iter()
+            .map(|id| {
+                locations.iter().chain(&modifiers)
+                    .find(|c| &c.id == id)
+                    .cloned()
+                    .ok_or_else(|| format!("Buyer {} reaction_deck references unknown card ID: {}", buyer.display_name, id))
+            })
+            .collect::<Result<_, _>>()?;
+    }
+
+    let patterns = match load_narrative_patterns(&crate::locale::asset_path(root, "narrative_patterns.ron")) {
+        Some(patterns) => patterns?,
+        None => crate::models::narrative::DynamicPattern::shipped_patterns(),
+    };
+
+    Ok(crate::models::narrative::lint::StoryAssets {
+        defaults: load_narrative_defaults(&crate::locale::asset_path(root, "narrative_defaults.ron"))?,
+        products: load_and_validate_cards(&path("cards/products.ron"), "Product")?,
+        locations,
+        narc_cards,
+        cover: load_and_validate_cards(&path("cards/cover.ron"), "Cover")?,
+        insurance: load_and_validate_cards(&path("cards/insurance.ron"), "Insurance")?,
+        modifiers,
+        buyers,
+        patterns,
+    })
+}
+
 /// Load narrative defaults from RON file
 fn load_narrative_defaults(path: &str) -> Result<crate::models::narrative::NarrativeFragments, String> {
     let content = fs::read_to_string(path)
@@ -718,6 +1224,16 @@ fn load_background_images(asset_server: &AssetServer, game_assets: &mut GameAsse
     info!("Initiated loading of {} background images", count);
 }
 
+/// SOW-051: the file under assets/art/actors/ a dealer's portrait key maps
+/// to - "dealer-<slug>.png", or the kingpin's code-owned silhouette. Shared
+/// with the chronicle export, which copies the faces alongside.
+pub fn dealer_portrait_file(portrait: &str) -> String {
+    if portrait == "Silhouette" {
+        return "silhouette.png".to_string();
+    }
+    format!("dealer-{}.png", portrait.to_lowercase().replace(' ', "-"))
+}
+
 /// SOW-033: build the actor portrait map from RON (buyers.ron `portrait`,
 /// shop_locations.ron `narc_portrait`, SOW-036 `signature_dealer.portrait`,
 /// SOW-038 `unlockable_dealers[].portrait`) plus the code-owned Silhouette
@@ -726,19 +1242,25 @@ fn load_background_images(asset_server: &AssetServer, game_assets: &mut GameAsse
 /// The hard-coded name->file HashMap is gone (art-backlog E3: new personas
 /// silently got no art). A mapped portrait whose file is missing on disk is a
 /// LOUD load error - Bevy's async asset_server.load would otherwise fail
-/// silently. Must be called AFTER buyers + shop_locations are loaded.
-fn load_actor_portraits(asset_server: &AssetServer, game_assets: &mut GameAssets) {
+/// silently. SOW-054: returns the (key, file) map for GameContent::install
+/// to load, so a hot reload checks the files too.
+fn actor_portrait_files(
+    root: &str,
+    buyers: &[BuyerPersona],
+    areas: &[crate::models::shop_location::ShopLocationDef],
+) -> Result<Vec<(String, String)>, String> {
     // (portrait-key, filename) pairs from every source; loaded through one
     // path with a loud file-existence check.
     let mut mapped: Vec<(String, String)> = Vec::new();
 
     // Buyers: keyed by display_name (the render lookup), file from RON
-    for buyer in &game_assets.buyers {
-        assert!(
-            !buyer.portrait.trim().is_empty(),
-            "buyer '{}' has no portrait in buyers.ron (SOW-033/E3 requires one)",
-            buyer.display_name
-        );
+    for buyer in buyers {
+        if buyer.portrait.trim().is_empty() {
+            return Err(format!(
+                "buyer '{}' has no portrait in buyers.ron (SOW-033/E3 requires one)",
+                buyer.display_name
+            ));
+        }
         mapped.push((buyer.display_name.clone(), buyer.portrait.clone()));
     }
 
@@ -746,7 +1268,7 @@ fn load_actor_portraits(asset_server: &AssetServer, game_assets: &mut GameAssets
     // file from RON narc_portrait, else the `narc-<area>.png` template
     // (so a new zone gets a loud "author its narc art" error, not a shared
     // fallback face).
-    for area in &game_assets.shop_locations {
+    for area in areas {
         let file = area.narc_portrait.clone().unwrap_or_else(|| {
             format!("narc-{}.png", area.id.replace('_', "-"))
         });
@@ -757,16 +1279,15 @@ fn load_actor_portraits(asset_server: &AssetServer, game_assets: &mut GameAssets
     // generic dealer pool is retired - every hired dealer's face is now authored
     // per zone in shop_locations.ron (loaded by the signature + unlockable loops
     // below), so this is the only code-owned portrait left.
-    mapped.push(("Silhouette".to_string(), "silhouette.png".to_string()));
+    mapped.push(("Silhouette".to_string(), dealer_portrait_file("Silhouette")));
 
     // SOW-036: signature-dealer faces, authored per zone in shop_locations.ron.
     // Keyed by portrait key -> "dealer-<slug>.png" so the map/roster render
     // resolves them and the loud disk-existence check below still covers every
     // dealer face. (This runs after shop_locations are loaded - see the doc note.)
-    for area in &game_assets.shop_locations {
+    for area in areas {
         if let Some(sig) = &area.signature_dealer {
-            let slug = sig.portrait.to_lowercase().replace(' ', "-");
-            mapped.push((sig.portrait.clone(), format!("dealer-{slug}.png")));
+            mapped.push((sig.portrait.clone(), dealer_portrait_file(&sig.portrait)));
         }
     }
 
@@ -774,28 +1295,40 @@ fn load_actor_portraits(asset_server: &AssetServer, game_assets: &mut GameAssets
     // alongside the signature (portrait key -> "dealer-<slug>.png"). Parallel to
     // the signature loop so the loud disk-existence check below covers the new
     // faces too (e.g. trailer_park's Gladys pilot -> dealer-gladys.png).
-    for area in &game_assets.shop_locations {
+    for area in areas {
         for dealer in &area.unlockable_dealers {
-            let slug = dealer.portrait.to_lowercase().replace(' ', "-");
-            mapped.push((dealer.portrait.clone(), format!("dealer-{slug}.png")));
+            mapped.push((dealer.portrait.clone(), dealer_portrait_file(&dealer.portrait)));
         }
     }
 
+    check_portrait_files(root, &mapped)?;
+    Ok(mapped)
+}
+
+/// SOW-061: operation faces, keyed by operation id (the narc portrait
+/// looks them up while an operation is on). An operation without one
+/// keeps the zone's narc.
+fn boss_portrait_files(root: &str, bosses: &[crate::models::boss::BossDef]) -> Result<Vec<(String, String)>, String> {
+    let mapped: Vec<(String, String)> = bosses
+        .iter()
+        .filter_map(|boss| boss.portrait.clone().map(|file| (boss.id.clone(), file)))
+        .collect();
+    check_portrait_files(root, &mapped)?;
+    Ok(mapped)
+}
+
+/// Every mapped portrait must be on disk
+fn check_portrait_files(root: &str, mapped: &[(String, String)]) -> Result<(), String> {
     for (key, filename) in mapped {
-        let disk_path = format!("assets/art/actors/{filename}");
-        assert!(
-            std::path::Path::new(&disk_path).exists(),
-            "actor portrait '{key}' -> '{filename}' not found at {disk_path} \
-             (SOW-033/E3: a mapped portrait must exist on disk)"
-        );
-        let handle = asset_server.load(format!("art/actors/{filename}"));
-        game_assets.actor_portraits.insert(key, handle);
+        let disk_path = format!("{}/art/actors/{filename}", root.trim_end_matches('/'));
+        if !std::path::Path::new(&disk_path).exists() {
+            return Err(format!(
+                "actor portrait '{key}' -> '{filename}' not found at {disk_path} \
+                 (SOW-033/E3: a mapped portrait must exist on disk)"
+            ));
+        }
     }
-
-    info!(
-        "Loaded {} actor portraits (RON-mapped buyers/narcs + dealer pool)",
-        game_assets.actor_portraits.len()
-    );
+    Ok(())
 }
 
 #[cfg(test)]
@@ -827,6 +1360,9 @@ mod tests {
                 narrative_fragments: None,
             }],
             active_scenario_index: None,
+            personality: Default::default(),
+            regular_names: Vec::new(),
+            regular: None,
         }
     }
 
@@ -878,6 +1414,8 @@ mod tests {
             unlockable_dealers: Vec::new(),
             narc_portrait: None,
             restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
         };
 
         // OK: one area, one persona living there (test_buyer defaults to trailer_park)
@@ -994,7 +1532,7 @@ mod tests {
         // SOW-038: the shipped content loads and validates with trailer_park's
         // ONE unlockable pilot (Gladys, cred 5) and empty lists on the other two
         // zones. Gladys' portrait key resolves to dealer-gladys.png by the same
-        // slug convention load_actor_portraits uses (the disk-existence assert
+        // slug convention actor_portrait_files uses (the disk-existence check
         // there is exercised by the full app; here we pin the authored shape).
         let areas = load_shop_locations("assets/data/shop_locations.ron").expect("areas load");
         crate::models::shop_location::validate_shop_locations(&areas).expect("areas valid");
@@ -1179,6 +1717,8 @@ mod tests {
                 unlockable_dealers: Vec::new(),
                 narc_portrait: None,
                 restock_margin: 0.5,
+                map_position: None,
+                links: Vec::new(),
             },
             crate::models::shop_location::ShopLocationDef {
                 id: "suburbia".to_string(),
@@ -1193,6 +1733,8 @@ mod tests {
                 unlockable_dealers: Vec::new(),
                 narc_portrait: None,
                 restock_margin: 0.5,
+                map_position: None,
+                links: Vec::new(),
             },
         ];
         // Corner buyer demanding a Block-gated product = dead payout -> warn
@@ -1352,4 +1894,58 @@ mod tests {
             "narc Cold-tier evidence must climb with the ladder: trailer {trailer} < suburbia {suburbia} < red_light {red_light}"
         );
     }
+
+    #[test]
+    fn narc_resolution_reports_every_problem() {
+        // SOW-055: validate-assets lists every authoring slip, not the first
+        let raw = NarcCompositionsFile {
+            default: HashMap::from([("Cold".to_string(), vec!["ghost".to_string()])]),
+            areas: HashMap::from([(
+                "atlantis".to_string(),
+                HashMap::from([("Tepid".to_string(), Vec::new())]),
+            )]),
+        };
+        let errors = resolve_narc_compositions(&raw, &["block"], &HashMap::new(), &HashMap::new()).unwrap_err();
+        for needle in [
+            "missing tier 'Warm'",
+            "unknown area 'atlantis'",
+            "unknown tier 'Tepid'",
+            "override is empty",
+            "unknown card id 'ghost' in default/Cold",
+        ] {
+            assert!(errors.iter().any(|e| e.contains(needle)), "{needle}: {errors:?}");
+        }
+    }
+
+    #[test]
+    fn evolutions_must_grow_into_unsold_cards_of_their_kind() {
+        use crate::models::card::CardEvolution;
+        use crate::models::test_helpers::create_cover;
+        use crate::save::{UpgradeTier, UpgradeableStat};
+        let evolve = |into: &str, tier, path| CardEvolution { into: into.to_string(), tier, path };
+        let mut alibi = create_cover("Alibi", 20, 0);
+        alibi.id = "alibi".to_string();
+        let mut ironclad = create_cover("Ironclad Alibi", 40, -5);
+        ironclad.id = "ironclad".to_string();
+        ironclad.shop_price = None;
+        alibi.evolutions = vec![evolve("ironclad", UpgradeTier::Tier5, UpgradeableStat::Cover)];
+        assert!(evolution_errors("cover.ron", &[&alibi, &ironclad]).is_empty());
+
+        alibi.evolutions = vec![
+            evolve("ghost", UpgradeTier::Tier5, UpgradeableStat::Cover),
+            evolve("ironclad", UpgradeTier::Base, UpgradeableStat::Cover),
+            evolve("ironclad", UpgradeTier::Tier4, UpgradeableStat::Price),
+        ];
+        let errors = evolution_errors("cover.ron", &[&alibi, &ironclad]);
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(errors[0].contains("unknown card 'ghost'"));
+        assert!(errors[1].contains("Base tier"));
+        assert!(errors[2].contains("Price"));
+        assert!(errors.iter().all(|e| e.starts_with("cover.ron: card 'alibi'")));
+
+        alibi.evolutions = vec![evolve("ironclad", UpgradeTier::Tier5, UpgradeableStat::Cover)];
+        ironclad.shop_price = Some(500);
+        let errors = evolution_errors("cover.ron", &[&alibi, &ironclad]);
+        assert!(errors[0].contains("sold in a shop"), "{errors:?}");
+    }
 }
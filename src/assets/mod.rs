@@ -1,7 +1,10 @@
 // SOW-013-A: Asset loading system using Bevy AssetServer
 
+pub mod hot_reload; // SOW-054
 pub mod loader;
+pub mod mods; // SOW-056
 pub mod registry;
+pub mod validate; // SOW-055
 
 pub use loader::AssetLoaderPlugin;
 pub use registry::GameAssets;
@@ -0,0 +1,543 @@
+// SOW-056: Mod packs - content layered over the shipped assets.
+//
+// A pack is a folder under mods/ holding a manifest (mod.ron) and any of the
+// base game's content files, at the same paths:
+//   cards/{products,locations,evidence,convictions,cover,insurance,modifiers}.ron
+//   buyers.ron, data/shop_locations.ron, narc_deck.ron, narrative_defaults.ron,
+//   relics.ron (SOW-059), bosses.ron (SOW-061), victories.ron (SOW-062)
+//
+// Packs layer in load order (ties by id). An entry whose id is already loaded
+// - shipped, or from an earlier pack - overrides it in place; any other id is
+// new and is namespaced "<pack>:<id>". References inside the pack (card and
+// persona areas, reaction decks, narc card ids and area keys, zone links,
+// relic shop slots, operation reward relics)
+// follow the renames, and may name an earlier pack's content by its
+// namespaced id. Buyers are keyed by display name (the portrait and render
+// key) rather than namespaced. narc_deck.ron overrides tier by tier and
+// narrative_defaults.ron list by list.
+//
+// Nothing here validates content: the merged files go through the loader's
+// validators exactly like the shipped set.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::Resource;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use super::loader::RawContent;
+use crate::models::card::Card;
+use crate::models::narrative::NarrativeFragments;
+use crate::save::ModStamp;
+
+/// Where packs live, next to assets/
+pub const MODS_DIR: &str = "mods";
+
+const MANIFEST: &str = "mod.ron";
+
+/// Joins a pack id to the ids it adds
+const NAMESPACE_SEP: char = ':';
+
+/// Card files, in RawContent pool order
+const CARD_FILES: [&str; 7] = [
+    "cards/products.ron",
+    "cards/locations.ron",
+    "cards/evidence.ron",
+    "cards/convictions.ron",
+    "cards/cover.ron",
+    "cards/insurance.ron",
+    "cards/modifiers.ron",
+];
+
+/// mod.ron
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ModManifest {
+    pub id: String,
+    pub version: String,
+    /// Lower loads first; ties load by id
+    #[serde(default)]
+    pub load_order: i32,
+    /// Pack ids that must be installed and load before this one
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModPack {
+    pub manifest: ModManifest,
+    pub dir: PathBuf,
+}
+
+/// The packs this session's content was built with, in load order
+#[derive(Resource, Debug, Default)]
+pub struct ModPacks(pub Vec<ModPack>);
+
+impl ModPacks {
+    /// What the save records
+    pub fn stamps(&self) -> Vec<ModStamp> {
+        self.0
+            .iter()
+            .map(|pack| ModStamp {
+                id: pack.manifest.id.clone(),
+                version: pack.manifest.version.clone(),
+            })
+            .collect()
+    }
+}
+
+/// A pack's narc_deck.ron: tiers for the default ladder and per area, each
+/// replacing just that tier
+#[derive(Debug, Default, Deserialize)]
+struct NarcPatch {
+    #[serde(default)]
+    default: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    areas: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+/// Every pack under `dir` (a folder with a mod.ron), in load order. No
+/// mods/ folder means no packs.
+pub fn discover_packs(dir: &Path) -> Result<Vec<ModPack>, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut packs = Vec::new();
+    for entry in entries.flatten() {
+        let manifest_path = entry.path().join(MANIFEST);
+        if !manifest_path.is_file() {
+            continue;
+        }
+        let content = fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?;
+        let manifest: ModManifest = ron::from_str(&content)
+            .map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", manifest_path.display(), e))?;
+        packs.push(ModPack { manifest, dir: entry.path() });
+    }
+    order_packs(packs)
+}
+
+/// Sort into load order and check the manifests: ids unique and free of
+/// the namespace separator, every dependency installed and loading earlier
+fn order_packs(mut packs: Vec<ModPack>) -> Result<Vec<ModPack>, String> {
+    packs.sort_by(|a, b| {
+        (a.manifest.load_order, &a.manifest.id).cmp(&(b.manifest.load_order, &b.manifest.id))
+    });
+    for (i, pack) in packs.iter().enumerate() {
+        let id = &pack.manifest.id;
+        if id.is_empty() || id.contains(NAMESPACE_SEP) {
+            return Err(format!("mod pack id '{id}' must be non-empty and contain no '{NAMESPACE_SEP}'"));
+        }
+        if packs[..i].iter().any(|p| &p.manifest.id == id) {
+            return Err(format!("two mod packs are called '{id}'"));
+        }
+        for dep in &pack.manifest.dependencies {
+            if packs[..i].iter().any(|p| &p.manifest.id == dep) {
+                continue;
+            }
+            return Err(if packs.iter().any(|p| &p.manifest.id == dep) {
+                format!("mod pack '{id}' depends on '{dep}', which loads after it (raise its load_order)")
+            } else {
+                format!("mod pack '{id}' depends on '{dep}', which isn't installed")
+            });
+        }
+    }
+    Ok(packs)
+}
+
+/// A pack file, parsed; None when the pack doesn't have it
+fn read_pack_file<T: DeserializeOwned>(pack: &ModPack, file: &str) -> Result<Option<T>, String> {
+    let path = pack.dir.join(file);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    ron::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse {} - Check RON syntax:\n{}", path.display(), e))
+}
+
+fn card_pools(raw: &mut RawContent) -> [&mut Vec<Card>; 7] {
+    [
+        &mut raw.products,
+        &mut raw.locations,
+        &mut raw.evidence,
+        &mut raw.convictions,
+        &mut raw.cover,
+        &mut raw.insurance,
+        &mut raw.modifiers,
+    ]
+}
+
+/// Replace the entry `same` picks out, or add it at the end
+fn upsert<T>(pool: &mut Vec<T>, item: T, same: impl Fn(&T, &T) -> bool) {
+    match pool.iter_mut().find(|existing| same(existing, &item)) {
+        Some(slot) => *slot = item,
+        None => pool.push(item),
+    }
+}
+
+/// The final id for each id this pack adds (ids it overrides keep theirs)
+fn new_ids<'a>(
+    pack_id: &str,
+    authored: impl Iterator<Item = &'a String>,
+    known: &HashSet<String>,
+    what: &str,
+) -> Result<HashMap<String, String>, String> {
+    let mut renames = HashMap::new();
+    for id in authored {
+        if known.contains(id) {
+            continue;
+        }
+        if id.contains(NAMESPACE_SEP) {
+            return Err(format!(
+                "mod pack '{pack_id}': {what} '{id}' is namespaced but names nothing loaded before it"
+            ));
+        }
+        renames.insert(id.clone(), format!("{pack_id}{NAMESPACE_SEP}{id}"));
+    }
+    Ok(renames)
+}
+
+fn rename(renames: &HashMap<String, String>, id: &mut String) {
+    if let Some(new) = renames.get(id) {
+        *id = new.clone();
+    }
+}
+
+/// Layer one pack over the content loaded so far
+pub fn layer_pack(raw: &mut RawContent, pack: &ModPack) -> Result<(), String> {
+    let pack_id = pack.manifest.id.as_str();
+
+    let mut card_files: Vec<Vec<Card>> = Vec::new();
+    for file in CARD_FILES {
+        card_files.push(read_pack_file(pack, file)?.unwrap_or_default());
+    }
+    // SOW-053: the locale's card text covers pack cards too
+    card_files.iter_mut().for_each(|cards| crate::locale::localize_cards(cards));
+    let mut areas: Vec<crate::models::shop_location::ShopLocationDef> =
+        read_pack_file(pack, "data/shop_locations.ron")?.unwrap_or_default();
+    let mut buyers: Vec<crate::models::buyer::BuyerPersona> =
+        read_pack_file(pack, "buyers.ron")?.unwrap_or_default();
+    let narc: NarcPatch = read_pack_file(pack, "narc_deck.ron")?.unwrap_or_default();
+    let mut relics: Vec<crate::models::relic::RelicDef> = read_pack_file(pack, "relics.ron")?.unwrap_or_default();
+    let mut bosses: Vec<crate::models::boss::BossDef> = read_pack_file(pack, "bosses.ron")?.unwrap_or_default();
+    let mut victories: Vec<crate::models::victory::VictoryDef> =
+        read_pack_file(pack, "victories.ron")?.unwrap_or_default();
+
+    // An override must stay the kind of card it replaces
+    for (file, cards) in CARD_FILES.iter().zip(&card_files) {
+        for card in cards {
+            let owner = CARD_FILES
+                .iter()
+                .zip(card_pools(raw))
+                .find(|(_, pool)| pool.iter().any(|c| c.id == card.id));
+            if let Some((owner_file, _)) = owner.filter(|(owner_file, _)| owner_file != &file) {
+                return Err(format!(
+                    "mod pack '{pack_id}': {file} overrides card '{}', which lives in {owner_file}",
+                    card.id
+                ));
+            }
+        }
+    }
+
+    let known_cards: HashSet<String> = card_pools(raw).iter().flat_map(|pool| pool.iter().map(|c| c.id.clone())).collect();
+    let card_renames = new_ids(pack_id, card_files.iter().flatten().map(|c| &c.id), &known_cards, "card")?;
+    let known_areas: HashSet<String> = raw.shop_locations.iter().map(|a| a.id.clone()).collect();
+    let area_renames = new_ids(pack_id, areas.iter().map(|a| &a.id), &known_areas, "zone")?;
+    let known_relics: HashSet<String> = raw.relics.iter().map(|r| r.id.clone()).collect();
+    let relic_renames = new_ids(pack_id, relics.iter().map(|r| &r.id), &known_relics, "relic")?;
+    let known_bosses: HashSet<String> = raw.bosses.iter().map(|b| b.id.clone()).collect();
+    let boss_renames = new_ids(pack_id, bosses.iter().map(|b| &b.id), &known_bosses, "operation")?;
+    let known_victories: HashSet<String> = raw.victories.iter().map(|v| v.id.clone()).collect();
+    let victory_renames = new_ids(pack_id, victories.iter().map(|v| &v.id), &known_victories, "victory")?;
+
+    // Follow the renames through everything the pack authored
+    for card in card_files.iter_mut().flatten() {
+        rename(&card_renames, &mut card.id);
+        if let Some(area) = &mut card.shop_location {
+            rename(&area_renames, area);
+        }
+    }
+    for area in &mut areas {
+        rename(&area_renames, &mut area.id);
+        for link in &mut area.links {
+            rename(&area_renames, &mut link.to);
+        }
+    }
+    for buyer in &mut buyers {
+        rename(&area_renames, &mut buyer.area);
+        for id in &mut buyer.reaction_deck_ids {
+            rename(&card_renames, id);
+        }
+    }
+    for relic in &mut relics {
+        rename(&relic_renames, &mut relic.id);
+        if let crate::models::relic::RelicSource::Shop { area, .. } = &mut relic.source {
+            rename(&area_renames, area);
+        }
+    }
+    for boss in &mut bosses {
+        rename(&boss_renames, &mut boss.id);
+        if let Some(relic) = &mut boss.relic {
+            rename(&relic_renames, relic);
+        }
+    }
+    for victory in &mut victories {
+        rename(&victory_renames, &mut victory.id);
+    }
+    let renamed_tiers = |tiers: HashMap<String, Vec<String>>| -> HashMap<String, Vec<String>> {
+        tiers
+            .into_iter()
+            .map(|(tier, mut ids)| {
+                ids.iter_mut().for_each(|id| rename(&card_renames, id));
+                (tier, ids)
+            })
+            .collect()
+    };
+
+    for (pool, cards) in card_pools(raw).into_iter().zip(card_files) {
+        for card in cards {
+            upsert(pool, card, |a, b| a.id == b.id);
+        }
+    }
+    for area in areas {
+        upsert(&mut raw.shop_locations, area, |a, b| a.id == b.id);
+    }
+    for buyer in buyers {
+        upsert(&mut raw.buyers, buyer, |a, b| a.display_name == b.display_name);
+    }
+    for relic in relics {
+        upsert(&mut raw.relics, relic, |a, b| a.id == b.id);
+    }
+    for boss in bosses {
+        upsert(&mut raw.bosses, boss, |a, b| a.id == b.id);
+    }
+    for victory in victories {
+        upsert(&mut raw.victories, victory, |a, b| a.id == b.id);
+    }
+    raw.narc.default.extend(renamed_tiers(narc.default));
+    for (mut area, tiers) in narc.areas {
+        rename(&area_renames, &mut area);
+        raw.narc.areas.entry(area).or_default().extend(renamed_tiers(tiers));
+    }
+
+    let fragments_path = pack.dir.join("narrative_defaults.ron");
+    if fragments_path.is_file() {
+        let text = fs::read_to_string(&fragments_path)
+            .map_err(|e| format!("Failed to read {}: {}", fragments_path.display(), e))?;
+        let mut defaults = raw.narrative_defaults.clone().unwrap_or_default();
+        layer_fragments(&mut defaults, &text)
+            .map_err(|e| format!("{}: {}", fragments_path.display(), e))?;
+        raw.narrative_defaults = Ok(defaults);
+    }
+    Ok(())
+}
+
+/// Replace each fragment list the pack's narrative_defaults.ron names; the
+/// lists it leaves out keep what's loaded
+fn layer_fragments(base: &mut NarrativeFragments, text: &str) -> Result<(), String> {
+    let patch: NarrativeFragments =
+        ron::from_str(text).map_err(|e| format!("Failed to parse - Check RON syntax:\n{e}"))?;
+    let ron::Value::Map(fields) = ron::from_str(text).map_err(|e| format!("{e}"))? else {
+        return Err("expected a (field: ...) struct".to_string());
+    };
+    for key in fields.keys() {
+        let ron::Value::String(key) = key else {
+            continue;
+        };
+        match key.as_str() {
+            "subject_clauses" => base.subject_clauses = patch.subject_clauses.clone(),
+            "need_clauses" => base.need_clauses = patch.need_clauses.clone(),
+            "product_clauses" => base.product_clauses = patch.product_clauses.clone(),
+            "location_clauses" => base.location_clauses = patch.location_clauses.clone(),
+            "evidence_clauses" => base.evidence_clauses = patch.evidence_clauses.clone(),
+            "cover_clauses" => base.cover_clauses = patch.cover_clauses.clone(),
+            "modifier_clauses" => base.modifier_clauses = patch.modifier_clauses.clone(),
+            "reaction_clauses" => base.reaction_clauses = patch.reaction_clauses.clone(),
+            "insurance_clauses" => base.insurance_clauses = patch.insurance_clauses.clone(),
+            "conviction_clauses" => base.conviction_clauses = patch.conviction_clauses.clone(),
+            "resolution_clauses" => base.resolution_clauses = patch.resolution_clauses.clone(),
+            "session_openers" => base.session_openers = patch.session_openers.clone(),
+            other => return Err(format!("unknown fragment list '{other}'")),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::narrative::fragments::TaggedFragment;
+    use crate::models::test_helpers::*;
+
+    fn manifest(id: &str, load_order: i32, dependencies: &[&str]) -> ModPack {
+        ModPack {
+            manifest: ModManifest {
+                id: id.to_string(),
+                version: "1.0".to_string(),
+                load_order,
+                dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            },
+            dir: PathBuf::from(id),
+        }
+    }
+
+    fn ids(packs: &[ModPack]) -> Vec<&str> {
+        packs.iter().map(|p| p.manifest.id.as_str()).collect()
+    }
+
+    fn raw_content() -> RawContent {
+        let mut weed = create_product("Weed", 30, 5);
+        weed.id = "weed".to_string();
+        weed.shop_location = Some("block".to_string());
+        let mut safe_house = create_location("Safe House", 10, 30, -5);
+        safe_house.id = "safe_house".to_string();
+        let block = crate::models::shop_location::ShopLocationDef {
+            id: "block".to_string(),
+            name: "The Block".to_string(),
+            description: String::new(),
+            unlocked: true,
+            price: 0,
+            identity: String::new(),
+            narc_hint: String::new(),
+            supplier: None,
+            signature_dealer: None,
+            unlockable_dealers: Vec::new(),
+            narc_portrait: None,
+            restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
+        };
+        RawContent {
+            products: vec![weed],
+            locations: vec![safe_house],
+            evidence: Vec::new(),
+            convictions: Vec::new(),
+            cover: Vec::new(),
+            insurance: Vec::new(),
+            modifiers: Vec::new(),
+            buyers: vec![create_mock_buyer_persona()],
+            shop_locations: vec![block],
+            narc: super::super::loader::NarcCompositionsFile {
+                default: HashMap::from([("Cold".to_string(), vec!["donut".to_string()])]),
+                areas: HashMap::new(),
+            },
+            narrative_defaults: Ok(NarrativeFragments::default()),
+            relics: Vec::new(),
+            bosses: Vec::new(),
+            victories: Vec::new(),
+        }
+    }
+
+    fn write(dir: &Path, file: &str, content: &str) {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn packs_sort_by_load_order_and_check_dependencies() {
+        let packs = order_packs(vec![manifest("b", 0, &[]), manifest("c", -1, &[]), manifest("a", 0, &["c"])]).unwrap();
+        assert_eq!(ids(&packs), ["c", "a", "b"]);
+
+        let err = order_packs(vec![manifest("a", 0, &["b"]), manifest("b", 5, &[])]).unwrap_err();
+        assert!(err.contains("loads after"), "{err}");
+        let err = order_packs(vec![manifest("a", 0, &["ghost"])]).unwrap_err();
+        assert!(err.contains("isn't installed"), "{err}");
+        assert!(order_packs(vec![manifest("a", 0, &[]), manifest("a", 1, &[])]).is_err());
+        assert!(order_packs(vec![manifest("x:y", 0, &[])]).is_err());
+    }
+
+    #[test]
+    fn discovery_reads_manifests_and_skips_other_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(discover_packs(&dir.path().join("missing")).unwrap().is_empty());
+        write(dir.path(), "night/mod.ron", r#"(id: "night", version: "0.2", dependencies: ["base"])"#);
+        write(dir.path(), "base/mod.ron", r#"(id: "base", version: "1.0", load_order: -10)"#);
+        write(dir.path(), "notes/readme.txt", "not a pack");
+        let packs = discover_packs(dir.path()).unwrap();
+        assert_eq!(ids(&packs), ["base", "night"]);
+        assert_eq!(packs[1].manifest.version, "0.2");
+    }
+
+    #[test]
+    fn packs_add_namespaced_content_and_override_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let pack = ModPack { dir: dir.path().to_path_buf(), ..manifest("night", 0, &[]) };
+        write(dir.path(), "cards/products.ron", r#"[
+            (id: "weed", name: "Weed", card_type: Product(price: 45, heat: 5), narrative_fragments: None),
+            (id: "molly", name: "Molly", card_type: Product(price: 80, heat: 10), narrative_fragments: None,
+             shop_location: Some("club")),
+        ]"#);
+        write(dir.path(), "data/shop_locations.ron", r#"[
+            (id: "club", name: "The Club", description: "", unlocked: false, price: 900,
+             links: [(to: "block", runs: 1, cost: 50)]),
+        ]"#);
+        write(dir.path(), "narc_deck.ron", r#"(areas: {"club": {"Hot": ["donut"]}})"#);
+        write(dir.path(), "relics.ron", r#"[
+            (id: "scanner", name: "Police Scanner", description: "", effects: [Cover(5)],
+             source: Shop(area: "club", price: 700)),
+        ]"#);
+        write(dir.path(), "bosses.ron", r#"[
+            (id: "vice", name: "Vice Squad", intro: "", win: "", loss: "", rules: [NoInsurance],
+             trigger: Tier(Hot), relic: Some("scanner")),
+        ]"#);
+        write(dir.path(), "victories.ron", r#"[
+            (id: "nightlife", name: "Own the Night", description: "", condition: AllZones, bonus: 500),
+        ]"#);
+
+        let mut raw = raw_content();
+        layer_pack(&mut raw, &pack).unwrap();
+
+        // weed existed: overridden where it stood; molly is new
+        assert_eq!(raw.products.len(), 2);
+        assert_eq!(raw.products[0].id, "weed");
+        assert!(matches!(raw.products[0].card_type, crate::models::card::CardType::Product { price: 45, .. }));
+        assert_eq!(raw.products[1].id, "night:molly");
+        assert_eq!(raw.products[1].shop_location.as_deref(), Some("night:club"));
+        assert_eq!(raw.shop_locations[1].id, "night:club");
+        assert_eq!(raw.shop_locations[1].links[0].to, "block");
+        assert!(raw.narc.areas.contains_key("night:club"));
+        assert_eq!(raw.narc.default["Cold"], ["donut"]);
+        assert_eq!(raw.relics[0].id, "night:scanner");
+        assert!(matches!(&raw.relics[0].source, crate::models::relic::RelicSource::Shop { area, .. } if area == "night:club"));
+        assert_eq!(raw.bosses[0].id, "night:vice");
+        assert_eq!(raw.bosses[0].relic.as_deref(), Some("night:scanner"));
+        assert_eq!(raw.victories[0].id, "night:nightlife");
+    }
+
+    #[test]
+    fn overrides_keep_their_card_kind() {
+        let dir = tempfile::tempdir().unwrap();
+        let pack = ModPack { dir: dir.path().to_path_buf(), ..manifest("odd", 0, &[]) };
+        write(dir.path(), "cards/locations.ron", r#"[
+            (id: "weed", name: "Weed Spot", card_type: Location(evidence: 5, cover: 5, heat: 0), narrative_fragments: None),
+        ]"#);
+        let err = layer_pack(&mut raw_content(), &pack).unwrap_err();
+        assert!(err.contains("cards/products.ron"), "{err}");
+
+        fs::remove_file(dir.path().join("cards/locations.ron")).unwrap();
+        write(dir.path(), "cards/cover.ron", r#"[
+            (id: "other:alibi", name: "Alibi", card_type: Cover(cover: 5, heat: 0), narrative_fragments: None),
+        ]"#);
+        let err = layer_pack(&mut raw_content(), &pack).unwrap_err();
+        assert!(err.contains("other:alibi"), "{err}");
+    }
+
+    #[test]
+    fn fragment_lists_override_one_by_one() {
+        let mut base = NarrativeFragments {
+            need_clauses: vec![TaggedFragment::any("needed a fix")],
+            product_clauses: vec![TaggedFragment::any("I had weed")],
+            ..Default::default()
+        };
+        layer_fragments(&mut base, r#"(product_clauses: [(text: "I had molly", relation: Some(And))])"#).unwrap();
+        assert_eq!(base.need_clauses[0].text, "needed a fix");
+        assert_eq!(base.product_clauses.len(), 1);
+        assert_eq!(base.product_clauses[0].text, "I had molly");
+
+        assert!(layer_fragments(&mut base, "(produce_clauses: [])").is_err());
+    }
+}
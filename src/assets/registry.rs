@@ -19,7 +19,12 @@ pub struct GameAssets {
     pub modifiers: Vec<Card>,          // Player Deal Modifiers
     pub buyers: Vec<BuyerPersona>,
     pub shop_locations: Vec<crate::models::shop_location::ShopLocationDef>, // SOW-024: unlockable areas
+    /// SOW-043: the roads between areas, built from shop_locations at load
+    pub zone_graph: crate::models::shop_location::ZoneGraph,
     pub narrative_defaults: NarrativeFragments, // Default fragments for cards without custom ones (includes resolution_clauses)
+    pub relics: Vec<crate::models::relic::RelicDef>, // SOW-059: authored relics
+    pub bosses: Vec<crate::models::boss::BossDef>, // SOW-061: task-force operations
+    pub victories: Vec<crate::models::victory::VictoryDef>, // SOW-062: win conditions
     pub background_images: HashMap<String, Handle<Image>>, // Location name -> background image
     pub actor_portraits: HashMap<String, Handle<Image>>, // Actor name -> portrait image
     pub card_template: Handle<Image>,  // POC: Card template for rendering
@@ -27,3 +32,36 @@ pub struct GameAssets {
     pub card_back: Handle<Image>,  // Card back for facedown cards
     pub assets_loaded: bool,
 }
+
+impl GameAssets {
+    /// SOW-057: every product, location, cover, insurance and modifier card
+    fn player_cards(&self) -> impl Iterator<Item = &Card> {
+        self.products
+            .values()
+            .chain(self.locations.values())
+            .chain(&self.cover)
+            .chain(&self.insurance)
+            .chain(&self.modifiers)
+    }
+
+    /// SOW-057: a player card by id
+    pub fn player_card(&self, id: &str) -> Option<&Card> {
+        self.player_cards().find(|card| card.id == id)
+    }
+
+    /// SOW-059: a relic by id
+    pub fn relic(&self, id: &str) -> Option<&crate::models::relic::RelicDef> {
+        self.relics.iter().find(|relic| relic.id == id)
+    }
+
+    /// SOW-061: an operation by id
+    pub fn boss(&self, id: &str) -> Option<&crate::models::boss::BossDef> {
+        self.bosses.iter().find(|boss| boss.id == id)
+    }
+
+    /// SOW-057: a player card by name (play counts and upgrades are keyed
+    /// by name)
+    pub fn player_card_named(&self, name: &str) -> Option<&Card> {
+        self.player_cards().find(|card| card.name == name)
+    }
+}
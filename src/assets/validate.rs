@@ -0,0 +1,280 @@
+// SOW-055: validate-assets - every content check, without launching the game.
+//
+// Runs the load-time validators (assets::loader::validate_content) over an
+// assets directory, collecting every error and warning instead of panicking
+// on the first, then summarises the content: cards and prices per zone up
+// the ladder, and how often the buyers demand each product and location.
+//
+// Usage (no Bevy App is built - it prints the report and exits):
+//   cargo run -- validate-assets [--assets <dir>]
+// Exit code 1 when there are errors, so CI can gate content PRs on it.
+
+use crate::models::buyer::BuyerPersona;
+use crate::models::card::{Card, CardType};
+use crate::models::shop_location::ShopLocationDef;
+use crate::ui::view::format_cash;
+
+/// Player card kinds, in the order the zone summary counts them
+const KINDS: [&str; 5] = ["product", "location", "cover", "insurance", "modifier"];
+
+#[derive(Debug, Default)]
+pub struct ContentReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    /// Ladder order (shop_locations.ron order)
+    pub zones: Vec<ZoneSummary>,
+    /// Cards a fresh empire already owns
+    pub starting_cards: usize,
+    /// Products first, then locations, each by name
+    pub demand: Vec<DemandCoverage>,
+}
+
+/// One rung of the ladder
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneSummary {
+    pub id: String,
+    pub name: String,
+    /// None for a zone a fresh empire starts with
+    pub price: Option<u32>,
+    pub restock_margin: f32,
+    /// Cards sold here, counted per KINDS entry
+    pub cards: [usize; 5],
+    /// Cheapest and dearest shop price here
+    pub shop_prices: Option<(u32, u32)>,
+    pub buyers: usize,
+}
+
+/// How many buyers ask for a product or location
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemandCoverage {
+    pub card: String,
+    pub is_product: bool,
+    /// Personas whose base demand names it
+    pub base: usize,
+    /// Scenarios that name it
+    pub scenarios: usize,
+}
+
+fn kind_index(card: &Card) -> Option<usize> {
+    match card.card_type {
+        CardType::Product { .. } => Some(0),
+        CardType::Location { .. } => Some(1),
+        CardType::Cover { .. } => Some(2),
+        CardType::Insurance { .. } => Some(3),
+        CardType::DealModifier { .. } => Some(4),
+        _ => None,
+    }
+}
+
+impl ContentReport {
+    /// Fill the summary from whatever loaded
+    pub fn summarize(&mut self, cards: &[Card], areas: &[ShopLocationDef], buyers: &[BuyerPersona]) {
+        self.zones = areas
+            .iter()
+            .map(|area| {
+                let sold: Vec<&Card> = cards
+                    .iter()
+                    .filter(|c| c.shop_location.as_deref() == Some(area.id.as_str()))
+                    .collect();
+                let mut counts = [0; KINDS.len()];
+                for index in sold.iter().filter_map(|c| kind_index(c)) {
+                    counts[index] += 1;
+                }
+                let prices = sold.iter().filter_map(|c| c.shop_price);
+                ZoneSummary {
+                    id: area.id.clone(),
+                    name: area.name.clone(),
+                    price: (!area.unlocked).then_some(area.price),
+                    restock_margin: area.restock_margin,
+                    cards: counts,
+                    shop_prices: prices.clone().min().zip(prices.max()),
+                    buyers: buyers.iter().filter(|b| b.area == area.id).count(),
+                }
+            })
+            .collect();
+
+        let starting = crate::save::AccountState::starting_collection();
+        self.starting_cards = cards.iter().filter(|c| starting.contains(&c.id)).count();
+
+        self.demand = cards
+            .iter()
+            .filter(|c| matches!(c.card_type, CardType::Product { .. } | CardType::Location { .. }))
+            .map(|card| {
+                let is_product = matches!(card.card_type, CardType::Product { .. });
+                let names = |products: &[String], locations: &[String]| {
+                    if is_product { products } else { locations }.contains(&card.name)
+                };
+                DemandCoverage {
+                    card: card.name.clone(),
+                    is_product,
+                    base: buyers.iter().filter(|b| names(&b.demand.products, &b.demand.locations)).count(),
+                    scenarios: buyers
+                        .iter()
+                        .flat_map(|b| &b.scenarios)
+                        .filter(|s| names(&s.products, &s.locations))
+                        .count(),
+                }
+            })
+            .collect();
+        self.demand.sort_by(|a, b| b.is_product.cmp(&a.is_product).then_with(|| a.card.cmp(&b.card)));
+    }
+
+    /// Plain-text report: problems first, then the content summary
+    pub fn render(&self, root: &str) -> String {
+        let mut out = format!("validate-assets: {root}\n");
+        for error in &self.errors {
+            out.push_str(&format!("error: {error}\n"));
+        }
+        for warning in &self.warnings {
+            out.push_str(&format!("warning: {warning}\n"));
+        }
+
+        out.push_str("\nZONES (ladder order)\n");
+        for (rung, zone) in self.zones.iter().enumerate() {
+            let price = zone.price.map_or_else(|| "start".to_string(), |p| format_cash(p as u64));
+            out.push_str(&format!(
+                "  {}. {} ({}) - {}, restock {:.0}%, {} buyer(s)\n",
+                rung + 1,
+                zone.name,
+                zone.id,
+                price,
+                zone.restock_margin * 100.0,
+                zone.buyers
+            ));
+            let counts: Vec<String> = KINDS
+                .iter()
+                .zip(zone.cards)
+                .filter(|(_, n)| *n > 0)
+                .map(|(kind, n)| format!("{n} {kind}"))
+                .collect();
+            let cards = if counts.is_empty() { "no cards".to_string() } else { counts.join(", ") };
+            match zone.shop_prices {
+                Some((low, high)) => out.push_str(&format!(
+                    "       {cards} - {} to {}\n",
+                    format_cash(low as u64),
+                    format_cash(high as u64)
+                )),
+                None => out.push_str(&format!("       {cards}\n")),
+            }
+        }
+        out.push_str(&format!("  starting collection: {} card(s)\n", self.starting_cards));
+
+        out.push_str("\nDEMAND COVERAGE\n");
+        for coverage in &self.demand {
+            let kind = if coverage.is_product { "product" } else { "location" };
+            let demanded = if coverage.base + coverage.scenarios == 0 {
+                "never demanded".to_string()
+            } else {
+                format!("{} base, {} scenario(s)", coverage.base, coverage.scenarios)
+            };
+            out.push_str(&format!("  {kind:<8} {:<24} {demanded}\n", coverage.card));
+        }
+
+        out.push_str(&format!("\n{} error(s), {} warning(s)\n", self.errors.len(), self.warnings.len()));
+        out
+    }
+}
+
+/// CLI entry: parse `[--assets <dir>]`, print the report
+pub fn run_cli(args: &[String]) {
+    let root = args
+        .iter()
+        .position(|a| a == "--assets")
+        .and_then(|i| args.get(i + 1))
+        .map_or("assets", String::as_str);
+
+    let report = super::loader::validate_content(root);
+    print!("{}", report.render(root));
+    if !report.errors.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::*;
+
+    fn area(id: &str, unlocked: bool, price: u32) -> ShopLocationDef {
+        ShopLocationDef {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            description: String::new(),
+            unlocked,
+            price,
+            identity: String::new(),
+            narc_hint: String::new(),
+            supplier: None,
+            signature_dealer: None,
+            unlockable_dealers: Vec::new(),
+            narc_portrait: None,
+            restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
+        }
+    }
+
+    fn sold_at(mut card: Card, area: &str, price: u32) -> Card {
+        card.shop_location = Some(area.to_string());
+        card.shop_price = Some(price);
+        card
+    }
+
+    #[test]
+    fn summary_counts_cards_prices_and_demand() {
+        let cards = vec![
+            sold_at(create_product("Weed", 30, 5), "block", 0),
+            sold_at(create_product("Coke", 120, 20), "strip", 900),
+            sold_at(create_location("Safe House", 10, 30, -5), "block", 0),
+            sold_at(create_cover("Alibi", 20, 0), "strip", 300),
+        ];
+        let areas = vec![area("block", true, 0), area("strip", false, 2000)];
+        let mut buyer = create_mock_buyer_persona();
+        buyer.area = "block".to_string();
+        buyer.demand.products = vec!["Weed".to_string()];
+        buyer.demand.locations = vec![];
+        for scenario in &mut buyer.scenarios {
+            scenario.products = vec!["Weed".to_string()];
+            scenario.locations = vec![];
+        }
+        let scenarios = buyer.scenarios.len();
+
+        let mut report = ContentReport::default();
+        report.summarize(&cards, &areas, &[buyer]);
+
+        assert_eq!(report.zones[0].cards, [1, 1, 0, 0, 0]);
+        assert_eq!(report.zones[0].price, None);
+        assert_eq!(report.zones[0].buyers, 1);
+        assert_eq!(report.zones[1].cards, [1, 0, 1, 0, 0]);
+        assert_eq!(report.zones[1].price, Some(2000));
+        assert_eq!(report.zones[1].shop_prices, Some((300, 900)));
+
+        let names: Vec<&str> = report.demand.iter().map(|d| d.card.as_str()).collect();
+        assert_eq!(names, ["Coke", "Weed", "Safe House"]);
+        assert_eq!((report.demand[1].base, report.demand[1].scenarios), (1, scenarios));
+        assert_eq!(report.demand[0].base + report.demand[0].scenarios, 0);
+
+        let text = report.render("assets");
+        assert!(text.contains("1. BLOCK (block) - start"), "{text}");
+        assert!(text.contains("1 product, 1 cover - $300 to $900"), "{text}");
+        assert!(text.contains("never demanded"), "{text}");
+        assert!(text.ends_with("0 error(s), 0 warning(s)\n"), "{text}");
+    }
+
+    #[test]
+    fn every_broken_file_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("cards")).unwrap();
+        std::fs::write(dir.path().join("cards/products.ron"), "[ (id: ").unwrap();
+        std::fs::write(dir.path().join("cards/locations.ron"), "[]").unwrap();
+
+        let report = super::super::loader::validate_content(dir.path().to_str().unwrap());
+        // Every card file, the areas, the narc deck and the buyers - not
+        // just the first one that failed
+        assert!(report.errors.iter().any(|e| e.contains("products.ron")), "{:?}", report.errors);
+        assert!(report.errors.iter().any(|e| e.contains("locations.ron")), "{:?}", report.errors);
+        assert!(report.errors.iter().any(|e| e.contains("shop_locations.ron")), "{:?}", report.errors);
+        assert!(report.errors.iter().any(|e| e.contains("buyers.ron")), "{:?}", report.errors);
+        assert!(report.render("x").contains(&format!("{} error(s)", report.errors.len())));
+    }
+}
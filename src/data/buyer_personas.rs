@@ -5,6 +5,7 @@
 
 use crate::models::buyer::BuyerPersona;
 use crate::assets::GameAssets;
+use crate::save::{Regular, REGULAR_VISIT_CHANCE};
 
 /// SOW-013-B: Get all available Buyer personas from loaded assets (3 personas)
 pub fn create_buyer_personas(assets: &GameAssets) -> Vec<BuyerPersona> {
@@ -17,6 +18,69 @@ pub fn personas_in_area<'a>(personas: &'a [BuyerPersona], area: &str) -> Vec<&'a
     personas.iter().filter(|p| p.area == area).collect()
 }
 
+/// SOW-042: Draw the buyer for a session in `area` - a random persona from
+/// that area's clientele with one of their scenarios picked at random
+/// (SOW-010). Load-time validation guarantees clientele per area; falls back
+/// to the full pool defensively rather than crash a run. None only when
+/// there are no personas at all.
+pub fn random_buyer_for_area(personas: &[BuyerPersona], area: &str) -> Option<BuyerPersona> {
+    use rand::prelude::*;
+
+    let area_personas = personas_in_area(personas, area);
+    let mut buyer = match area_personas.choose(&mut rand::rng()) {
+        Some(buyer) => (**buyer).clone(),
+        None => {
+            bevy::log::warn!("area '{area}' has no clientele at runtime - drawing from all personas");
+            personas.choose(&mut rand::rng())?.clone()
+        }
+    };
+    if !buyer.scenarios.is_empty() {
+        buyer.active_scenario_index = Some(rand::rng().random_range(0..buyer.scenarios.len()));
+    }
+    Some(buyer)
+}
+
+/// SOW-044: Draw the buyer for a session in `area`, regulars first: when
+/// the area has active regulars, one of them drops by with
+/// REGULAR_VISIT_CHANCE; otherwise a walk-in (random_buyer_for_area).
+pub fn buyer_for_session(
+    personas: &[BuyerPersona],
+    regulars: &[Regular],
+    area: &str,
+) -> Option<BuyerPersona> {
+    use rand::prelude::*;
+
+    let mut rng = rand::rng();
+    let visiting: Vec<&Regular> = regulars
+        .iter()
+        .filter(|r| r.is_active() && r.area == area)
+        .collect();
+    if !visiting.is_empty() && rng.random::<f32>() < REGULAR_VISIT_CHANCE {
+        let regular = visiting.choose(&mut rng)?;
+        // A regular whose persona left the content is just not coming by
+        if let Some(persona) = personas.iter().find(|p| p.display_name == regular.persona) {
+            let mut buyer = dress_as_regular(persona, regular);
+            if !buyer.scenarios.is_empty() {
+                buyer.active_scenario_index = Some(rng.random_range(0..buyer.scenarios.len()));
+            }
+            return Some(buyer);
+        }
+    }
+    random_buyer_for_area(personas, area)
+}
+
+/// SOW-044: `persona` wearing `regular`'s face - the relationship scales
+/// what they pay (Regular::loyalty_factor), and the snapshot rides along so
+/// the panel can name them and the resolution can find them again
+pub fn dress_as_regular(persona: &BuyerPersona, regular: &Regular) -> BuyerPersona {
+    let mut buyer = persona.clone();
+    let factor = regular.loyalty_factor();
+    buyer.base_multiplier *= factor;
+    buyer.reduced_multiplier *= factor;
+    buyer.regular = Some(regular.clone());
+    buyer
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -43,6 +107,9 @@ mod tests {
             reaction_deck: vec![],
             scenarios: vec![],
             active_scenario_index: None,
+            personality: Default::default(),
+            regular_names: Vec::new(),
+            regular: None,
         }
     }
 
@@ -68,4 +135,90 @@ mod tests {
 
         assert!(personas_in_area(&personas, "downtown").is_empty());
     }
+
+    #[test]
+    fn random_buyer_draws_from_the_area_and_picks_a_scenario() {
+        use crate::models::buyer::BuyerScenario;
+
+        let scenario = |name: &str| BuyerScenario {
+            display_name: name.to_string(),
+            products: vec![],
+            locations: vec![],
+            heat_threshold: None,
+            description: String::new(),
+            narrative_fragments: None,
+        };
+        let mut wolf = persona("Wall Street Wolf", "suburbia");
+        wolf.scenarios = vec![scenario("Closing Bell"), scenario("Bonus Season")];
+        let personas = vec![persona("Frat Bro", "trailer_park"), wolf];
+
+        for _ in 0..10 {
+            let buyer = random_buyer_for_area(&personas, "suburbia").unwrap();
+            assert_eq!(buyer.display_name, "Wall Street Wolf");
+            assert!(buyer.active_scenario_index.is_some_and(|i| i < 2));
+        }
+        // No scenarios authored - none picked
+        let bro = random_buyer_for_area(&personas, "trailer_park").unwrap();
+        assert_eq!(bro.active_scenario_index, None);
+
+        // An area without clientele falls back to the full pool
+        assert!(random_buyer_for_area(&personas, "downtown").is_some());
+        assert!(random_buyer_for_area(&[], "suburbia").is_none());
+    }
+
+    fn regular(name: &str, persona: &str, area: &str, relationship: u32) -> Regular {
+        Regular {
+            name: name.to_string(),
+            persona: persona.to_string(),
+            area: area.to_string(),
+            relationship,
+            favourite_product: None,
+            favourite_location: None,
+            deals: 0,
+            referred_by: None,
+            informant: false,
+        }
+    }
+
+    #[test]
+    fn dressed_regular_pays_by_relationship() {
+        let bro = persona("Frat Bro", "trailer_park");
+
+        let loyal = dress_as_regular(&bro, &regular("Chad", "Frat Bro", "trailer_park", 90));
+        assert!((loyal.base_multiplier - 1.2).abs() < 1e-6);
+        assert!((loyal.reduced_multiplier - 1.2).abs() < 1e-6);
+        assert_eq!(loyal.regular.as_ref().map(|r| r.name.as_str()), Some("Chad"));
+        // The persona itself is untouched
+        assert_eq!(bro.base_multiplier, 1.0);
+        assert!(bro.regular.is_none());
+
+        let sour = dress_as_regular(&bro, &regular("Chad", "Frat Bro", "trailer_park", 10));
+        assert!((sour.base_multiplier - 0.85).abs() < 1e-6);
+    }
+
+    #[test]
+    fn session_buyer_only_brings_active_regulars_of_the_area() {
+        let personas = vec![persona("Frat Bro", "trailer_park"), persona("Wall Street Wolf", "suburbia")];
+        let mut snitch = regular("Tyler", "Frat Bro", "trailer_park", 5);
+        snitch.informant = true;
+        let regulars = vec![
+            snitch,
+            regular("Gordon", "Wall Street Wolf", "suburbia", 60),
+            // Persona no longer authored - never shows up
+            regular("Ghost", "Retired Persona", "trailer_park", 60),
+        ];
+
+        for _ in 0..30 {
+            let buyer = buyer_for_session(&personas, &regulars, "trailer_park").unwrap();
+            assert_eq!(buyer.display_name, "Frat Bro");
+            assert!(buyer.regular.is_none());
+
+            let buyer = buyer_for_session(&personas, &regulars, "suburbia").unwrap();
+            if let Some(regular) = &buyer.regular {
+                assert_eq!(regular.name, "Gordon");
+            }
+        }
+        // Nobody at all: no session
+        assert!(buyer_for_session(&[], &regulars, "suburbia").is_none());
+    }
 }
@@ -3,7 +3,7 @@
 
 use crate::models::card::Card;
 use crate::assets::GameAssets;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// SOW-020: Create Player available cards filtered by unlocked card IDs
 /// Only includes cards that:
@@ -49,3 +49,13 @@ pub fn create_player_deck_filtered(assets: &GameAssets, unlocked_cards: &HashSet
 
     deck
 }
+
+/// SOW-057: swap each card a dealer has evolved (card id -> variant id) for
+/// its variant
+pub fn evolve_cards(cards: &mut [Card], assets: &GameAssets, evolved: &HashMap<String, String>) {
+    for card in cards {
+        if let Some(variant) = evolved.get(&card.id).and_then(|id| assets.player_card(id)) {
+            *card = variant.clone();
+        }
+    }
+}
@@ -0,0 +1,452 @@
+// SOW-053: Locale layer - UI string tables and the story engine's grammar.
+//
+// UI text is looked up by id (`tr("hub.start_run")`) in the active locale's
+// string table, falling back to the shipped English table (en.ron, compiled
+// in) and then to the id itself, so a half-translated locale still runs.
+// The same locale carries the grammar the narrative engine used to hard-code
+// for English: the word for each clause relation, whether sentences open
+// capitalised, the sentence terminator and the words that keep their case
+// mid-sentence ("I").
+//
+// Locales other than English live in assets/locales/<id>/:
+//
+//   locale.ron            (grammar: Some((...)), strings: { id: text })
+//   narrative_defaults.ron, narrative_patterns.ron, headlines.ron
+//                         replace the top-level files when present
+//   card_fragments.ron    { card id: NarrativeFragments } replacing a card's
+//                         authored fragments
+//
+// "pseudo" is English run through pseudo_text: accented, ~30% longer and
+// bracketed, so the pure view tests catch labels that would overflow their
+// layout once translated.
+//
+// The active locale is process-wide (install, once, at startup); tests pick
+// one per thread with `scoped` so they never race each other.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use serde::Deserialize;
+
+use crate::models::card::Card;
+use crate::models::narrative::fragments::ClauseRelation;
+use crate::models::narrative::NarrativeFragments;
+
+/// The shipped English table - every other locale falls back to it
+const SHIPPED_ENGLISH: &str = include_str!("en.ron");
+
+/// The locale ids that need no files on disk
+pub const BUILT_IN_LOCALES: [&str; 2] = ["en", "pseudo"];
+
+/// How much longer pseudo_text makes a line, in percent (translations into
+/// German or Finnish commonly run 30% over the English)
+const PSEUDO_STRETCH_PERCENT: usize = 30;
+
+/// The sentence rules the narrative engine follows
+#[derive(Debug, Clone, Deserialize)]
+pub struct Grammar {
+    /// The word(s) for each clause relation; one is picked at random when
+    /// there are several ("although" / "even though")
+    pub conjunctions: HashMap<ClauseRelation, Vec<String>>,
+    /// Upper-case a sentence's first letter (and lower-case a clause that
+    /// moves mid-sentence)
+    #[serde(default = "default_capitalize")]
+    pub capitalize: bool,
+    #[serde(default = "default_sentence_end")]
+    pub sentence_end: String,
+    /// Words that keep their case wherever they fall ("I")
+    #[serde(default)]
+    pub keep_case: Vec<String>,
+}
+
+fn default_capitalize() -> bool {
+    true
+}
+
+fn default_sentence_end() -> String {
+    ".".to_string()
+}
+
+/// One locale file (en.ron, or assets/locales/<id>/locale.ron)
+#[derive(Debug, Deserialize)]
+struct LocaleFile {
+    #[serde(default)]
+    grammar: Option<Grammar>,
+    #[serde(default)]
+    strings: HashMap<String, String>,
+}
+
+/// A loaded locale
+#[derive(Debug, Clone)]
+pub struct Locale {
+    pub id: String,
+    strings: HashMap<String, String>,
+    pub grammar: Grammar,
+    pseudo: bool,
+    card_fragments: HashMap<String, NarrativeFragments>,
+}
+
+static ENGLISH: OnceLock<Arc<Locale>> = OnceLock::new();
+static INSTALLED: RwLock<Option<Arc<Locale>>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED: RefCell<Option<Arc<Locale>>> = const { RefCell::new(None) };
+}
+
+fn parse_locale_file(content: &str) -> Result<LocaleFile, String> {
+    ron::from_str(content).map_err(|e| format!("RON parse error: {e}"))
+}
+
+fn english_arc() -> Arc<Locale> {
+    ENGLISH.get_or_init(|| Arc::new(Locale::english())).clone()
+}
+
+impl Locale {
+    /// The shipped English locale
+    pub fn english() -> Self {
+        let file = parse_locale_file(SHIPPED_ENGLISH).expect("shipped en.ron must parse");
+        Self {
+            id: "en".to_string(),
+            strings: file.strings,
+            grammar: file.grammar.expect("shipped en.ron must define the grammar"),
+            pseudo: false,
+            card_fragments: HashMap::new(),
+        }
+    }
+
+    /// English, stretched for layout testing
+    pub fn pseudo() -> Self {
+        Self { id: "pseudo".to_string(), pseudo: true, ..Self::english() }
+    }
+
+    /// A locale by id: the built-ins, or assets/locales/<id>/ under `root`.
+    /// Strings and grammar the locale leaves out come from English.
+    pub fn load(id: &str, root: &str) -> Result<Self, String> {
+        match id {
+            "en" => return Ok(Self::english()),
+            "pseudo" => return Ok(Self::pseudo()),
+            _ => {}
+        }
+        let dir = locale_dir(root, id);
+        let path = dir.join("locale.ron");
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let file = parse_locale_file(&content).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let english = Self::english();
+        let mut strings = english.strings;
+        strings.extend(file.strings);
+        let mut grammar = file.grammar.unwrap_or_else(|| english.grammar.clone());
+        for (relation, words) in english.grammar.conjunctions {
+            grammar.conjunctions.entry(relation).or_insert(words);
+        }
+
+        let fragments_path = dir.join("card_fragments.ron");
+        let card_fragments = match std::fs::read_to_string(&fragments_path) {
+            Ok(content) => ron::from_str(&content)
+                .map_err(|e| format!("Failed to parse {}: {}", fragments_path.display(), e))?,
+            Err(_) => HashMap::new(),
+        };
+
+        Ok(Self { id: id.to_string(), strings, grammar, pseudo: false, card_fragments })
+    }
+
+    fn lookup(&self, key: &str) -> String {
+        let text = self
+            .strings
+            .get(key)
+            .cloned()
+            .or_else(|| english_arc().strings.get(key).cloned())
+            .unwrap_or_else(|| key.to_string());
+        if self.pseudo {
+            pseudo_text(&text)
+        } else {
+            text
+        }
+    }
+}
+
+fn locale_dir(root: &str, id: &str) -> std::path::PathBuf {
+    Path::new(root).join("locales").join(id)
+}
+
+/// Make `locale` the process-wide locale (main, once, before the App runs)
+pub fn install(locale: Locale) {
+    *INSTALLED.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(locale));
+}
+
+/// Run `f` with `locale` active on this thread only (tests)
+#[cfg(test)]
+pub fn scoped<R>(locale: Locale, f: impl FnOnce() -> R) -> R {
+    let previous = SCOPED.with(|s| s.replace(Some(Arc::new(locale))));
+    let result = f();
+    SCOPED.with(|s| *s.borrow_mut() = previous);
+    result
+}
+
+/// The active locale: this thread's scoped one, else the installed one,
+/// else English
+pub fn current() -> Arc<Locale> {
+    if let Some(locale) = SCOPED.with(|s| s.borrow().clone()) {
+        return locale;
+    }
+    INSTALLED
+        .read()
+        .ok()
+        .and_then(|installed| installed.clone())
+        .unwrap_or_else(english_arc)
+}
+
+/// The UI text for `key`
+pub fn tr(key: &str) -> String {
+    current().lookup(key)
+}
+
+/// The UI text for `key` with its `{name}` placeholders filled
+pub fn tr_with(key: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(tr(key), |text, (name, value)| text.replace(&format!("{{{name}}}"), value))
+}
+
+/// The word joining a clause with `relation` ("because", "even though")
+pub fn conjunction(relation: ClauseRelation) -> String {
+    use rand::prelude::*;
+    let locale = current();
+    locale
+        .grammar
+        .conjunctions
+        .get(&relation)
+        .and_then(|words| words.choose(&mut rand::rng()))
+        .cloned()
+        .unwrap_or_else(|| format!("{relation:?}").to_lowercase())
+}
+
+/// Does `text` open with a word that keeps its case ("I ...", "I'd ...")
+pub fn keeps_case(text: &str) -> bool {
+    current().grammar.keep_case.iter().any(|word| {
+        text.strip_prefix(word.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\'']))
+    })
+}
+
+/// A clause moving mid-sentence: its first letter lower-cased, unless the
+/// locale doesn't capitalise or the clause opens with a keep-case word
+pub fn lowercase_first(text: String) -> String {
+    if !current().grammar.capitalize || keeps_case(&text) {
+        return text;
+    }
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+/// A finished sentence: capitalised (where the locale does) and terminated
+pub fn finalize_sentence(sentence: String) -> String {
+    let locale = current();
+    let mut sentence = if locale.grammar.capitalize {
+        let mut chars = sentence.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => sentence,
+        }
+    } else {
+        sentence
+    };
+    if !sentence.ends_with(locale.grammar.sentence_end.as_str()) {
+        sentence.push_str(&locale.grammar.sentence_end);
+    }
+    sentence
+}
+
+/// The path of `file` for the active locale: assets/locales/<id>/<file> when
+/// the locale ships one, else `<root>/<file>`
+pub fn asset_path(root: &str, file: &str) -> String {
+    let localized = locale_dir(root, &current().id).join(file);
+    if localized.is_file() {
+        localized.to_string_lossy().into_owned()
+    } else {
+        format!("{}/{}", root.trim_end_matches('/'), file)
+    }
+}
+
+/// Swap in the active locale's fragments for any card it translates
+pub fn localize_cards(cards: &mut [Card]) {
+    let locale = current();
+    for card in cards {
+        if let Some(fragments) = locale.card_fragments.get(&card.id) {
+            card.narrative_fragments = Some(fragments.clone());
+        }
+    }
+}
+
+/// English made visibly foreign: letters accented, each line padded ~30%
+/// with '~' and bracketed. `{placeholders}` are left alone so they still
+/// fill.
+pub fn pseudo_text(text: &str) -> String {
+    text.split('\n').map(pseudo_line).collect::<Vec<_>>().join("\n")
+}
+
+fn pseudo_line(line: &str) -> String {
+    let mut out = String::from("[");
+    let mut visible = 0usize;
+    let mut in_placeholder = false;
+    for c in line.chars() {
+        match c {
+            '{' => in_placeholder = true,
+            '}' => in_placeholder = false,
+            _ => {}
+        }
+        if in_placeholder || c == '}' {
+            out.push(c);
+            continue;
+        }
+        visible += 1;
+        out.push(match c {
+            'a' => 'á',
+            'e' => 'é',
+            'i' => 'í',
+            'o' => 'ö',
+            'u' => 'ü',
+            'c' => 'ç',
+            'n' => 'ñ',
+            'A' => 'Å',
+            'E' => 'É',
+            'I' => 'Î',
+            'O' => 'Ö',
+            'U' => 'Ü',
+            'C' => 'Ç',
+            'N' => 'Ñ',
+            other => other,
+        });
+    }
+    let pad = (visible * PSEUDO_STRETCH_PERCENT).div_ceil(100);
+    out.extend(std::iter::repeat_n('~', pad));
+    out.push(']');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups_fall_back_to_english_then_the_key() {
+        assert_eq!(tr("hub.start_run"), "START RUN");
+        assert_eq!(tr("hub.no_such_label"), "hub.no_such_label");
+        assert_eq!(tr_with("ledger.stories_of", &[("name", "SLIM")]), "STORIES — SLIM");
+    }
+
+    #[test]
+    fn pseudo_stretches_and_keeps_placeholders() {
+        assert_eq!(pseudo_text("PASS"), "[PÅSS~~]");
+        assert_eq!(pseudo_text("{n} LEFT"), "[{n} LÉFT~~]");
+        assert_eq!(pseudo_text("CUT OFF\nsettle"), "[ÇÜT ÖFF~~~]\n[séttlé~~]");
+        scoped(Locale::pseudo(), || {
+            assert_eq!(tr("hub.pass"), "[PÅSS~~]");
+            assert_eq!(tr_with("ledger.stories_of", &[("name", "SLIM")]), "[STÖRÎÉS — SLIM~~~]");
+        });
+        assert_eq!(tr("hub.pass"), "PASS", "scoped locales end with the closure");
+    }
+
+    #[test]
+    fn fits_checks_every_line() {
+        use crate::models::test_helpers::fits;
+        assert!(fits("ORDER\nREST", 5));
+        assert!(!fits("ORDER\nLAY LOW", 5));
+    }
+
+    #[test]
+    fn english_grammar_keeps_its_rules() {
+        assert_eq!(finalize_sentence("we got out".to_string()), "We got out.");
+        assert_eq!(finalize_sentence("done.".to_string()), "Done.");
+        assert_eq!(lowercase_first("The cops left".to_string()), "the cops left");
+        assert_eq!(lowercase_first("I ran".to_string()), "I ran");
+        assert_eq!(lowercase_first("I'd run".to_string()), "I'd run");
+        assert_eq!(lowercase_first("Ice was cold".to_string()), "ice was cold");
+        assert_eq!(conjunction(ClauseRelation::Because), "because");
+        assert!(["although", "even though"].contains(&conjunction(ClauseRelation::Although).as_str()));
+    }
+
+    #[test]
+    fn shipped_english_covers_every_relation() {
+        let english = Locale::english();
+        for relation in ClauseRelation::ALL {
+            assert!(
+                english.grammar.conjunctions.get(&relation).is_some_and(|w| !w.is_empty()),
+                "no English word for {relation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn every_key_in_the_source_is_in_the_english_table() {
+        let english = Locale::english();
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut missing = Vec::new();
+        let mut stack = vec![src];
+        while let Some(dir) = stack.pop() {
+            for entry in std::fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    stack.push(path);
+                    continue;
+                }
+                if path.extension().is_none_or(|e| e != "rs") || path.ends_with("locale/mod.rs") {
+                    continue;
+                }
+                let code = std::fs::read_to_string(&path).unwrap();
+                for call in ["tr(", "tr_with("] {
+                    for (at, _) in code.match_indices(call) {
+                        // skip from_str(" and friends
+                        if code[..at].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                            continue;
+                        }
+                        // a literal key, possibly on the next line
+                        let Some(rest) = code[at + call.len()..].trim_start().strip_prefix('"') else {
+                            continue;
+                        };
+                        let key = &rest[..rest.find('"').unwrap()];
+                        if !english.strings.contains_key(key) {
+                            missing.push(format!("{}: {key}", path.display()));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(missing.is_empty(), "keys missing from en.ron: {missing:?}");
+    }
+
+    #[test]
+    fn a_locale_on_disk_overrides_strings_grammar_and_fragments() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("locales").join("xx");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("locale.ron"),
+            r#"(
+                grammar: Some((conjunctions: { Because: ["weil"] }, capitalize: false, sentence_end: "。")),
+                strings: { "hub.pass": "PASSEN" },
+            )"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("narrative_defaults.ron"), "()").unwrap();
+        let root_str = root.path().to_str().unwrap();
+        let locale = Locale::load("xx", root_str).unwrap();
+
+        scoped(locale, || {
+            assert_eq!(tr("hub.pass"), "PASSEN");
+            assert_eq!(tr("hub.start_run"), "START RUN", "untranslated ids fall back to English");
+            assert_eq!(conjunction(ClauseRelation::Because), "weil");
+            assert_eq!(conjunction(ClauseRelation::After), "after", "missing relations fall back too");
+            assert_eq!(finalize_sentence("wir liefen".to_string()), "wir liefen。");
+            assert_eq!(lowercase_first("Wir".to_string()), "Wir");
+            assert!(asset_path(root_str, "narrative_defaults.ron").ends_with("locales/xx/narrative_defaults.ron"));
+            assert!(asset_path(root_str, "headlines.ron").ends_with(&format!("{root_str}/headlines.ron")));
+        });
+        assert!(Locale::load("zz", root_str).is_err());
+    }
+}
@@ -5,6 +5,7 @@ mod models;
 mod systems;
 mod game_state;
 mod save;
+mod locale;
 
 use bevy::prelude::*;
 use bevy::asset::load_internal_binary_asset;
@@ -21,10 +22,33 @@ fn main() {
     // SOW-023: dev subcommand - `cargo run -- forge <scenario> [--dir <path>]`
     // writes a crafted, signed save for e2e playtests and exits (no App)
     let cli_args: Vec<String> = std::env::args().collect();
+
+    // SOW-053: the locale comes first so subcommands read localized assets
+    // too. `--locale <id>` picks one and remembers it in settings.ron.
+    install_locale(&cli_args);
+
     if cli_args.get(1).map(String::as_str) == Some("forge") {
         save::forge::run_cli(&cli_args[2..]);
         return;
     }
+    // SOW-049: `cargo run -- narrative-lint [--assets <dir>] [--samples <n>]`
+    // checks the story assets compose cleanly and exits (no App)
+    if cli_args.get(1).map(String::as_str) == Some("narrative-lint") {
+        models::narrative::lint::run_cli(&cli_args[2..]);
+        return;
+    }
+    // SOW-051: `cargo run -- chronicle [current|<n>] [--format md|html] [--out <dir>]`
+    // writes an empire's chronicle from the save and exits (no App)
+    if cli_args.get(1).map(String::as_str) == Some("chronicle") {
+        save::chronicle::run_cli(&cli_args[2..]);
+        return;
+    }
+    // SOW-055: `cargo run -- validate-assets [--assets <dir>]` runs every
+    // content check and prints the report (no App)
+    if cli_args.get(1).map(String::as_str) == Some("validate-assets") {
+        assets::validate::run_cli(&cli_args[2..]);
+        return;
+    }
 
     let mut app = App::new();
 
@@ -45,6 +69,12 @@ fn main() {
         |bytes: &[u8], _path: String| { Font::try_from_bytes(bytes.to_vec()).unwrap() }
     );
 
+    // SOW-054: `cargo run -- --hot-reload` - content authors edit the RON
+    // while the game runs
+    if cli_args.iter().any(|a| a == "--hot-reload") {
+        app.add_plugins(assets::hot_reload::HotReloadPlugin);
+    }
+
     app
         .add_plugins(assets::AssetLoaderPlugin)
         .add_plugins(SavePlugin)
@@ -65,6 +95,7 @@ fn main() {
         .add_systems(OnEnter(GameState::DeckBuilding), (
             load_character_system,
             apply_decay_system,
+            offer_evolutions_system, // SOW-057: before the upgrade screen check
             check_pending_upgrades_system, // RFC-019: Redirect to UpgradeChoice if pending
             initialize_deck_builder_from_assets,
             setup_deck_builder,
@@ -91,10 +122,11 @@ fn main() {
             ai_betting_system,
             auto_flip_system,
             betting_button_system,
-            restart_button_system,
+            route_choice_system,              // SOW-060: replaced NEW DEAL
             go_home_button_system,
             update_betting_button_states,
-            update_restart_button_states,
+            update_go_home_button_state,
+            populate_run_route_system,        // SOW-060
         ).chain())
         .add_systems(Update, (
             update_actor_portraits_system,
@@ -152,6 +184,13 @@ fn main() {
         .add_systems(Update, (
             ledger_toggle_system,
             ledger_story_click_system,
+            ledger_story_filter_system, // SOW-050
+            ledger_story_search_system,
+            ledger_export_system, // SOW-051
+            ledger_retire_system, // SOW-062
+            ledger_succession_system, // SOW-063
+            ledger_legacy_system, // SOW-064
+            ledger_ascension_system, // SOW-065
             populate_ledger_system,
             // SOW-031: fronts ride the same group - self-contained too
             // (SaveData mutations + the ShopState reinsert refresh trick,
@@ -169,6 +208,42 @@ fn main() {
             tutorial_progress_system,
             populate_goal_strip_system,
         ).chain().run_if(in_state(GameState::DeckBuilding)))
+        // SOW-041: per-dealer decks - the builder's edits land on the active
+        // dealer, and the loadout shelf reads/writes that dealer's presets.
+        // Own group (hub chain is full); dealer switches bank the outgoing
+        // selection inside roster_button_system, so cross-group order with
+        // the hub chain can't leak one dealer's edit into another's deck.
+        .add_systems(Update, (
+            sync_dealer_deck_system,
+            loadout_button_system,
+            populate_loadout_row_system,
+        ).chain().run_if(in_state(GameState::DeckBuilding)))
+        // SOW-042: day planning - roster ORDER buttons and the end-of-day
+        // summary. Own group (hub chain is full); DaySummary is only ever read
+        // as an Option (GO HOME inserts it, DISMISS removes it), so no
+        // init_resource is needed.
+        .add_systems(Update, (
+            order_button_system,
+            day_summary_dismiss_system,
+            populate_day_summary_system,
+        ).chain().run_if(in_state(GameState::DeckBuilding)))
+        // SOW-052: the morning paper - own group like the day summary;
+        // MorningPaper is only ever read as an Option (the press inserts it,
+        // PUT IT DOWN removes it)
+        .add_systems(Update, (
+            press_headlines_system,
+            morning_paper_dismiss_system,
+            populate_morning_paper_system,
+        ).chain().run_if(in_state(GameState::DeckBuilding)))
+        // SOW-059: relics - own group like the paper; self-contained (the
+        // shop purchase reuses the ShopState reinsert refresh trick, and the
+        // shelf redraws on any SaveData change, so cross-group order doesn't
+        // matter)
+        .add_systems(Update, (
+            award_relics_system,
+            relic_purchase_system,
+            update_relic_shelf_system,
+        ).chain().run_if(in_state(GameState::DeckBuilding)))
         .run();
 }
 
@@ -199,7 +274,18 @@ fn initialize_deck_builder_from_assets(
             .map(|data| data.account.unlocked_cards.clone())
             .unwrap_or_else(|| save::AccountState::starting_collection());
 
-        let deck_builder = DeckBuilder::from_assets_filtered(&game_assets, &unlocked_cards);
+        // SOW-041: the active dealer's own deck (a fresh save has none yet,
+        // which seeds the default selection)
+        let deck = save_data
+            .as_ref()
+            .map(|data| data.active_dealer_state().deck.clone())
+            .unwrap_or_default();
+        // SOW-057: and the cards they've evolved
+        let evolved = save_data
+            .as_ref()
+            .map(|data| data.active_character().evolved_cards.clone())
+            .unwrap_or_default();
+        let deck_builder = DeckBuilder::for_dealer(&game_assets, &unlocked_cards, &deck, &evolved);
         let card_count = deck_builder.available_cards.len();
         commands.insert_resource(deck_builder);
         info!("DeckBuilder initialized from assets with {} unlocked cards", card_count);
@@ -221,3 +307,24 @@ fn cleanup_deck_builder_ui(
         commands.entity(entity).despawn();
     }
 }
+
+/// SOW-053: load the chosen locale (the flag, else settings.ron) and make it
+/// current. An unknown or broken locale falls back to English.
+fn install_locale(cli_args: &[String]) {
+    let dir = save::settings::settings_dir();
+    let mut settings = save::settings::Settings::load(&dir);
+    if let Some(id) = cli_args.iter().position(|a| a == "--locale").and_then(|i| cli_args.get(i + 1)) {
+        settings.locale = id.clone();
+        if let Err(e) = settings.store(&dir) {
+            eprintln!("locale: {e}");
+        }
+    }
+    match locale::Locale::load(&settings.locale, "assets") {
+        Ok(chosen) => locale::install(chosen),
+        Err(e) => eprintln!(
+            "locale '{}': {e} - using English (built in: {})",
+            settings.locale,
+            locale::BUILT_IN_LOCALES.join(", ")
+        ),
+    }
+}
@@ -0,0 +1,156 @@
+// SOW-065: Ascension - difficulty levels chosen when an empire starts.
+// Level 0 is the game as tuned. Each level above it stacks one more
+// modifier on top of the ones below, and a level is unlocked by retiring
+// (or beating a task-force operation) at the level before it. The rule
+// points read the level the same way they read relics.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::buyer::BuyerPersona;
+use crate::save::HeatTier;
+
+/// What one ascension level adds, in the order they stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AscensionModifier {
+    /// Narc decks are built one heat tier hotter
+    HotterNarcs,
+    /// Fronts carry ASCENSION_VIG_PCT vig instead of FRONT_VIG_PCT
+    HeavyVig,
+    /// Heat fades with time at half the rate
+    SlowDecay,
+    /// Buyers bail at ASCENSION_BAIL_PCT of their thresholds
+    NervousBuyers,
+}
+
+/// Level n plays with the first n modifiers
+pub const ASCENSION_MODIFIERS: [AscensionModifier; 4] = [
+    AscensionModifier::HotterNarcs,
+    AscensionModifier::HeavyVig,
+    AscensionModifier::SlowDecay,
+    AscensionModifier::NervousBuyers,
+];
+
+/// The highest level there is
+pub const MAX_ASCENSION: u32 = ASCENSION_MODIFIERS.len() as u32;
+
+/// Front vig with HeavyVig on, in percent
+pub const ASCENSION_VIG_PCT: u64 = 40;
+
+/// Buyer thresholds with NervousBuyers on, in percent of the authored ones
+pub const ASCENSION_BAIL_PCT: u32 = 75;
+
+/// An empire's difficulty level
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ascension(pub u32);
+
+impl Ascension {
+    /// The modifiers in play, lowest level first
+    pub fn modifiers(self) -> &'static [AscensionModifier] {
+        &ASCENSION_MODIFIERS[..self.0.min(MAX_ASCENSION) as usize]
+    }
+
+    pub fn has(self, modifier: AscensionModifier) -> bool {
+        self.modifiers().contains(&modifier)
+    }
+
+    /// The heat tier narc decks are built at
+    pub fn narc_tier(self, tier: HeatTier) -> HeatTier {
+        if self.has(AscensionModifier::HotterNarcs) {
+            tier.shifted(1)
+        } else {
+            tier
+        }
+    }
+
+    /// The vig a front carries before any relic's cut, in percent
+    pub fn vig_pct(self) -> u64 {
+        if self.has(AscensionModifier::HeavyVig) {
+            ASCENSION_VIG_PCT
+        } else {
+            crate::save::FRONT_VIG_PCT
+        }
+    }
+
+    /// Heat that fades with time
+    pub fn decay(self, decay: u32) -> u32 {
+        if self.has(AscensionModifier::SlowDecay) {
+            decay / 2
+        } else {
+            decay
+        }
+    }
+
+    /// Tighten a buyer's bail thresholds before the hand is dealt, so the
+    /// bail check, the buyer's AI and the HUD all read the same line
+    pub fn apply_to_buyer(self, persona: &mut BuyerPersona) {
+        if !self.has(AscensionModifier::NervousBuyers) {
+            return;
+        }
+        let tighten = |threshold: &mut Option<u32>| {
+            if let Some(t) = threshold {
+                *t = *t * ASCENSION_BAIL_PCT / 100;
+            }
+        };
+        tighten(&mut persona.evidence_threshold);
+        for scenario in &mut persona.scenarios {
+            tighten(&mut scenario.heat_threshold);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::relic::{RelicEffect, RelicEffects};
+
+    #[test]
+    fn modifiers_stack_by_level() {
+        assert!(Ascension(0).modifiers().is_empty());
+        assert_eq!(Ascension(2).modifiers(), &[AscensionModifier::HotterNarcs, AscensionModifier::HeavyVig]);
+        assert_eq!(Ascension(99).modifiers().len(), MAX_ASCENSION as usize);
+        assert!(Ascension(4).has(AscensionModifier::HotterNarcs), "levels keep what's below them");
+    }
+
+    #[test]
+    fn hotter_narcs_build_a_tier_up() {
+        assert_eq!(Ascension(0).narc_tier(HeatTier::Cold), HeatTier::Cold);
+        assert_eq!(Ascension(1).narc_tier(HeatTier::Cold), HeatTier::Warm);
+    }
+
+    #[test]
+    fn heavy_vig_sits_under_the_relic_cut() {
+        assert_eq!((Ascension(1).vig_pct(), Ascension(2).vig_pct()), (crate::save::FRONT_VIG_PCT, ASCENSION_VIG_PCT));
+        let cut = RelicEffects::sum(&[RelicEffect::Vig(10)]);
+        assert_eq!(cut.vig_pct(Ascension(2)), ASCENSION_VIG_PCT - 10);
+    }
+
+    #[test]
+    fn slow_decay_halves_what_fades() {
+        assert_eq!((Ascension(2).decay(9), Ascension(3).decay(9)), (9, 4));
+        let mut state = crate::save::CharacterState::new();
+        state.heat = 100;
+        state.last_played = crate::save::current_timestamp().saturating_sub(10 * 3600);
+        let relics = RelicEffects::sum(&[RelicEffect::Decay(50)]);
+        assert_eq!(state.apply_decay_with(&relics, Ascension(3)), 7, "the relic bonus comes on top");
+    }
+
+    #[test]
+    fn nervous_buyers_bail_sooner() {
+        let mut persona = crate::models::test_helpers::create_mock_game_assets()
+            .buyers
+            .into_iter()
+            .next()
+            .expect("a mock buyer");
+        persona.evidence_threshold = Some(40);
+        let untouched = persona.clone();
+        Ascension(3).apply_to_buyer(&mut persona);
+        assert_eq!(persona.evidence_threshold, Some(40));
+
+        Ascension(4).apply_to_buyer(&mut persona);
+        assert_eq!(persona.evidence_threshold, Some(30));
+        for (scenario, before) in persona.scenarios.iter().zip(&untouched.scenarios) {
+            assert_eq!(scenario.heat_threshold, before.heat_threshold.map(|t| t * 3 / 4));
+        }
+    }
+}
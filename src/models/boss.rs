@@ -0,0 +1,221 @@
+// SOW-061: Task-force operations - authored boss narcs, loaded from
+// assets/bosses.ron. Ordinary narc difficulty is only the deck built for
+// area x heat tier; an operation is a named narc with rules of its own
+// (hidden evidence, no insurance, a buyer wearing a wire, extra rounds).
+// One comes due when a dealer crosses a heat tier or the empire reaches a
+// milestone, waits at the last call of the next run, and is faced once per
+// empire: beat it for the bounty, get busted for the extra time.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::hand_state::HandOutcome;
+use crate::save::HeatTier;
+
+/// An authored task-force operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BossDef {
+    pub id: String,
+    /// Who runs it ("Agent Reyes, DEA")
+    pub name: String,
+    /// The line the stop opens with
+    pub intro: String,
+    /// Appended to the hand's story when the runner walks away Safe
+    pub win: String,
+    /// Appended to the hand's story on a bust
+    pub loss: String,
+    /// File under art/actors/ for the narc portrait while the operation is
+    /// on (None: the zone's own narc)
+    #[serde(default)]
+    pub portrait: Option<String>,
+    pub rules: Vec<BossRule>,
+    pub trigger: BossTrigger,
+    /// Cash paid to the empire for beating it
+    #[serde(default)]
+    pub bounty: u32,
+    /// A relic (by id) the empire takes for beating it
+    #[serde(default)]
+    pub relic: Option<String>,
+    /// Runs added to the runner's sentence on a bust
+    #[serde(default)]
+    pub sentence: u32,
+}
+
+/// A rule the operation plays by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BossRule {
+    /// Evidence nobody saw on the table, revealed at resolution
+    HiddenEvidence(u32),
+    /// Insurance won't pay out
+    NoInsurance,
+    /// The buyer is wearing a wire: each buyer card adds this much evidence
+    /// at resolution
+    UndercoverBuyer(u32),
+    /// Rounds on top of the usual three
+    ExtraRounds(u8),
+}
+
+/// When an operation comes due
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BossTrigger {
+    /// Any dealer on the roster reaches this heat tier
+    Tier(HeatTier),
+    /// Lifetime revenue reaches this
+    Revenue(u64),
+    /// Zones owned reach this
+    Zones(u32),
+}
+
+/// An operation's rules added up - what the hand reads
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BossRules {
+    pub hidden_evidence: u32,
+    pub no_insurance: bool,
+    pub wire_evidence: u32,
+    pub extra_rounds: u8,
+}
+
+impl BossRules {
+    pub fn sum<'a>(rules: impl IntoIterator<Item = &'a BossRule>) -> Self {
+        let mut sum = Self::default();
+        for rule in rules {
+            match *rule {
+                BossRule::HiddenEvidence(n) => sum.hidden_evidence += n,
+                BossRule::NoInsurance => sum.no_insurance = true,
+                BossRule::UndercoverBuyer(n) => sum.wire_evidence += n,
+                BossRule::ExtraRounds(n) => sum.extra_rounds = sum.extra_rounds.saturating_add(n),
+            }
+        }
+        sum
+    }
+
+    /// Evidence revealed at resolution, given how many cards the buyer
+    /// played
+    pub fn revealed_evidence(&self, buyer_cards: usize) -> u32 {
+        self.hidden_evidence + self.wire_evidence * buyer_cards as u32
+    }
+}
+
+/// The operation a hand is played against - the def's rules and story
+/// lines, copied in at the stop so the hand needs no assets
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub id: String,
+    pub name: String,
+    pub rules: BossRules,
+    pub intro: String,
+    pub win: String,
+    pub loss: String,
+}
+
+impl From<&BossDef> for Operation {
+    fn from(def: &BossDef) -> Self {
+        Self {
+            id: def.id.clone(),
+            name: def.name.clone(),
+            rules: BossRules::sum(&def.rules),
+            intro: def.intro.clone(),
+            win: def.win.clone(),
+            loss: def.loss.clone(),
+        }
+    }
+}
+
+impl Operation {
+    /// A hand's story told as the operation: its intro, the story, and the
+    /// win or loss line (a fold or a bail leaves the ending open)
+    pub fn frame(&self, story: &str, outcome: Option<HandOutcome>) -> String {
+        let ending = match outcome {
+            Some(HandOutcome::Safe) => self.win.as_str(),
+            Some(HandOutcome::Busted) => self.loss.as_str(),
+            _ => "",
+        };
+        [self.intro.as_str(), story, ending]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Most rounds an operation can add - a hand is still a hand
+const MAX_EXTRA_ROUNDS: u8 = 3;
+
+/// Load-time checks: unique ids, a name and intro, at least one rule, a
+/// sane round count, and reward relics that exist
+pub fn validate_bosses(bosses: &[BossDef], relic_ids: &[&str]) -> Result<(), String> {
+    let mut ids = std::collections::HashSet::new();
+    for boss in bosses {
+        if boss.id.trim().is_empty() || boss.name.trim().is_empty() || boss.intro.trim().is_empty() {
+            return Err(format!("operation '{}' needs an id, a name and an intro", boss.id));
+        }
+        if !ids.insert(boss.id.as_str()) {
+            return Err(format!("duplicate operation id '{}'", boss.id));
+        }
+        if boss.rules.is_empty() {
+            return Err(format!("operation '{}' has no rules", boss.id));
+        }
+        if BossRules::sum(&boss.rules).extra_rounds > MAX_EXTRA_ROUNDS {
+            return Err(format!("operation '{}' adds more than {MAX_EXTRA_ROUNDS} rounds", boss.id));
+        }
+        if let Some(relic) = &boss.relic {
+            if !relic_ids.contains(&relic.as_str()) {
+                return Err(format!("operation '{}' pays out unknown relic '{}'", boss.id, relic));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::create_boss as boss;
+
+    #[test]
+    fn rules_add_up_and_reveal() {
+        let rules = BossRules::sum(&[
+            BossRule::HiddenEvidence(20),
+            BossRule::UndercoverBuyer(5),
+            BossRule::HiddenEvidence(10),
+            BossRule::ExtraRounds(1),
+        ]);
+        assert_eq!(rules.revealed_evidence(0), 30);
+        assert_eq!(rules.revealed_evidence(2), 40);
+        assert_eq!(rules.extra_rounds, 1);
+        assert!(!rules.no_insurance);
+        assert!(BossRules::sum(&[BossRule::NoInsurance]).no_insurance);
+        assert_eq!(BossRules::default().revealed_evidence(3), 0, "no operation reveals nothing");
+    }
+
+    #[test]
+    fn the_story_opens_and_closes_on_the_operation() {
+        let op = Operation::from(&BossDef {
+            win: "Reyes lost the trail.".to_string(),
+            loss: "Reyes had the tapes.".to_string(),
+            ..boss("dea", vec![BossRule::NoInsurance], BossTrigger::Zones(1))
+        });
+        assert_eq!(
+            op.frame("The deal went down.", Some(HandOutcome::Safe)),
+            "dea is on you. The deal went down. Reyes lost the trail."
+        );
+        assert!(op.frame("x", Some(HandOutcome::Busted)).ends_with("Reyes had the tapes."));
+        assert_eq!(op.frame("x", Some(HandOutcome::Folded)), "dea is on you. x");
+    }
+
+    #[test]
+    fn validation_catches_broken_operations() {
+        let dea = boss("dea", vec![BossRule::NoInsurance], BossTrigger::Tier(HeatTier::Hot));
+        assert!(validate_bosses(std::slice::from_ref(&dea), &[]).is_ok());
+        assert!(validate_bosses(&[dea.clone(), dea.clone()], &[]).unwrap_err().contains("duplicate"));
+
+        let toothless = boss("ia", vec![], BossTrigger::Zones(2));
+        assert!(validate_bosses(&[toothless], &[]).unwrap_err().contains("no rules"));
+
+        let endless = boss("ia", vec![BossRule::ExtraRounds(9)], BossTrigger::Zones(2));
+        assert!(validate_bosses(&[endless], &[]).unwrap_err().contains("rounds"));
+
+        let paying = BossDef { relic: Some("badge".to_string()), ..dea };
+        assert!(validate_bosses(std::slice::from_ref(&paying), &["badge"]).is_ok());
+        assert!(validate_bosses(&[paying], &["scanner"]).unwrap_err().contains("unknown relic"));
+    }
+}
@@ -29,6 +29,53 @@ fn default_persona_area() -> String {
     "trailer_park".to_string()
 }
 
+/// SOW-045: how a persona leans when the deal is under pressure - weights on
+/// the reaction policy's intent scores (hand_state/buyer_ai.rs). 1.0 is
+/// neutral; a jumpy persona authors a high `walk`, a party animal a low
+/// `calm`. Authored in buyers.ron; omitted = neutral.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BuyerPersonality {
+    /// Cool a hot deal down (scales heat pressure)
+    pub calm: f32,
+    /// Push for a location they actually like
+    pub push: f32,
+    /// Threaten to walk when the evidence piles up (scales evidence pressure)
+    pub walk: f32,
+}
+
+impl Default for BuyerPersonality {
+    fn default() -> Self {
+        Self { calm: 1.0, push: 1.0, walk: 1.0 }
+    }
+}
+
+/// SOW-045: what the buyer means to do with their next reaction card -
+/// decided from the deal state, telegraphed in the buyer bubble
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuyerIntent {
+    /// The deal is fine: sweeten it (best price card on hand)
+    GoAlong,
+    /// Heat is closing on their line: play the coolest card
+    CalmDown,
+    /// Demand unmet on location: put a location down, a preferred one if held
+    PushLocation,
+    /// Evidence is closing on their line: the safest card, or they're gone
+    ThreatenToWalk,
+}
+
+impl BuyerIntent {
+    /// Bubble title verb ("CALMING DOWN · ICE COLD")
+    pub fn label(self) -> String {
+        crate::locale::tr(match self {
+            BuyerIntent::GoAlong => "hub.going_along",
+            BuyerIntent::CalmDown => "hub.calming_down",
+            BuyerIntent::PushLocation => "hub.wants_another_spot",
+            BuyerIntent::ThreatenToWalk => "hub.threatens_to_walk",
+        })
+    }
+}
+
 /// Buyer persona - merges Dealer scenario deck + Customer modifiers into one entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuyerPersona {
@@ -52,4 +99,15 @@ pub struct BuyerPersona {
     pub reaction_deck: Vec<Card>,            // 7 cards built from reaction_deck_ids during loading
     pub scenarios: Vec<BuyerScenario>,       // SOW-010: 2 scenarios per Buyer
     pub active_scenario_index: Option<usize>, // Which scenario is active (set during Buyer selection)
+    /// SOW-045: reaction policy weights (neutral when not authored)
+    #[serde(default)]
+    pub personality: BuyerPersonality,
+    /// SOW-044: names this persona's regulars go by ("Chad", "Tyler"),
+    /// handed out in order; "<Persona> #n" once they run out
+    #[serde(default)]
+    pub regular_names: Vec<String>,
+    /// SOW-044: set when this session's buyer is one of the empire's
+    /// regulars - a snapshot of their memory as the session started
+    #[serde(skip)]
+    pub regular: Option<crate::save::Regular>,
 }
@@ -51,6 +51,20 @@ pub struct Card {
     // roster's best cred for that area; None = cash only.
     #[serde(default)]
     pub shop_cred_required: Option<u32>,
+    // SOW-057: what this card can grow into once it's been played enough
+    #[serde(default)]
+    pub evolutions: Vec<CardEvolution>,
+}
+
+/// SOW-057: an authored evolution path. A card that has reached `tier` with
+/// `path` as its most-chosen upgrade can turn into `into` - a card of the
+/// same kind in the same file, with no shop price (it can only be grown).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardEvolution {
+    /// Card id of the evolved variant
+    pub into: String,
+    pub tier: crate::save::UpgradeTier,
+    pub path: crate::save::UpgradeableStat,
 }
 
 /// Totals calculated from all played cards
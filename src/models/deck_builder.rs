@@ -1,11 +1,12 @@
 // SOW-AAA Phase 2/4: Deck builder model
 // Extracted from main.rs (originally lines 48-85)
 // SOW-020: Filter by unlocked cards from AccountState
+// SOW-041: Selections are per dealer (DealerState.deck holds card ids)
 
 use bevy::prelude::Resource;
 use super::card::Card;
-use crate::data::{validate_deck, create_player_deck_filtered, create_default_deck_from_available};
-use std::collections::HashSet;
+use crate::data::{validate_deck, create_player_deck_filtered, create_default_deck_from_available, evolve_cards};
+use std::collections::{HashMap, HashSet};
 
 /// Deck builder resource for managing card selection
 #[derive(Resource)]
@@ -15,16 +16,69 @@ pub struct DeckBuilder {
 }
 
 impl DeckBuilder {
-    /// SOW-020: Initialize from loaded assets filtered by unlocked cards
-    pub fn from_assets_filtered(assets: &crate::assets::GameAssets, unlocked_cards: &HashSet<String>) -> Self {
-        let available = create_player_deck_filtered(assets, unlocked_cards);
-        let selected = create_default_deck_from_available(&available);
+    /// SOW-041: Build the deck builder for one dealer's saved deck. Ids are
+    /// resolved against the owned pool, so cards the empire no longer has
+    /// drop out; a never-built (or fully stale) deck falls back to the
+    /// default selection.
+    /// SOW-057: the dealer's evolved cards stand in for their originals, in
+    /// the pool and in the saved deck.
+    pub fn for_dealer(
+        assets: &crate::assets::GameAssets,
+        unlocked_cards: &HashSet<String>,
+        deck: &[String],
+        evolved: &HashMap<String, String>,
+    ) -> Self {
+        let mut available = create_player_deck_filtered(assets, unlocked_cards);
+        evolve_cards(&mut available, assets, evolved);
+        let mut selected: Vec<Card> = deck
+            .iter()
+            .map(|id| evolved.get(id).unwrap_or(id))
+            .filter_map(|id| available.iter().find(|c| &c.id == id).cloned())
+            .collect();
+        if selected.is_empty() {
+            selected = create_default_deck_from_available(&available);
+        }
         Self {
             available_cards: available,
             selected_cards: selected,
         }
     }
 
+    /// SOW-041: The selection as card ids - what DealerState.deck stores
+    pub fn selected_ids(&self) -> Vec<String> {
+        self.selected_cards.iter().map(|c| c.id.clone()).collect()
+    }
+
+    /// SOW-041: Display name for a loadout of the current selection - its
+    /// products in selection order ("Weed + Shrooms"), capped at two with a
+    /// "+N" tail. A product-less deck can't run, but still gets a name.
+    pub fn loadout_name(&self) -> String {
+        let products: Vec<&str> = self
+            .selected_cards
+            .iter()
+            .filter(|c| matches!(c.card_type, super::card::CardType::Product { .. }))
+            .map(|c| c.name.as_str())
+            .collect();
+        match products.len() {
+            0 => "No Product".to_string(),
+            1 | 2 => products.join(" + "),
+            n => format!("{} +{}", products[..2].join(" + "), n - 2),
+        }
+    }
+
+    /// SOW-041: How many cards of a saved loadout are upgraded (tier 1+)
+    /// for this character - the shelf shows it so a build around upgraded
+    /// cards reads as one. Ids resolve through the character's evolutions
+    /// against the pool; cards no longer owned don't count.
+    pub fn upgraded_in(&self, cards: &[String], character: &crate::save::CharacterState) -> usize {
+        cards
+            .iter()
+            .map(|id| character.evolved_cards.get(id).unwrap_or(id))
+            .filter_map(|id| self.available_cards.iter().find(|c| &c.id == id))
+            .filter(|c| character.get_card_tier(&c.name) > crate::save::UpgradeTier::Base)
+            .count()
+    }
+
     pub fn is_valid(&self) -> bool {
         validate_deck(&self.selected_cards).is_ok()
     }
@@ -39,9 +93,12 @@ impl DeckBuilder {
         &mut self,
         assets: &crate::assets::GameAssets,
         unlocked_cards: &HashSet<String>,
+        evolved: &HashMap<String, String>,
     ) {
         self.available_cards = create_player_deck_filtered(assets, unlocked_cards);
-        self.selected_cards.retain(|c| unlocked_cards.contains(&c.id));
+        evolve_cards(&mut self.available_cards, assets, evolved);
+        let available = &self.available_cards;
+        self.selected_cards.retain(|c| available.iter().any(|a| a.id == c.id));
     }
 }
 
@@ -55,13 +112,13 @@ mod tests {
     use crate::save::AccountState;
 
     #[test]
-    fn test_deck_builder_from_assets_filtered() {
+    fn test_deck_builder_for_a_fresh_dealer() {
         use crate::models::test_helpers::create_mock_game_assets;
         let assets = create_mock_game_assets();
 
         // Use starting collection (11 cards unlocked by default)
         let unlocked = AccountState::starting_collection();
-        let builder = DeckBuilder::from_assets_filtered(&assets, &unlocked);
+        let builder = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
 
         // Starting collection has 11 cards (3 products + 3 locations + 2 cover + 1 insurance + 2 modifiers)
         // But mock assets may not have all these, so just check it's not empty and valid
@@ -76,13 +133,13 @@ mod tests {
         let assets = create_mock_game_assets();
 
         let unlocked = AccountState::starting_collection();
-        let mut builder = DeckBuilder::from_assets_filtered(&assets, &unlocked);
+        let mut builder = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
         let full: HashSet<String> = builder_all_ids(&assets);
 
         // Grow: a mid-hub unlock (buy or front) appears in the pool NOW,
         // and the existing selection is untouched
         let selected_before = builder.selected_cards.len();
-        builder.resync_available(&assets, &full);
+        builder.resync_available(&assets, &full, &HashMap::new());
         assert!(builder.available_cards.len() > selected_before);
         assert_eq!(builder.selected_cards.len(), selected_before);
 
@@ -90,13 +147,105 @@ mod tests {
         let victim = builder.selected_cards[0].id.clone();
         let mut without: HashSet<String> = full.clone();
         without.remove(&victim);
-        builder.resync_available(&assets, &without);
+        builder.resync_available(&assets, &without, &HashMap::new());
         assert!(builder.available_cards.iter().all(|c| c.id != victim));
         assert!(builder.selected_cards.iter().all(|c| c.id != victim));
     }
 
+    #[test]
+    fn for_dealer_loads_saved_deck_in_order() {
+        use crate::models::test_helpers::create_mock_game_assets;
+        let assets = create_mock_game_assets();
+        let unlocked = AccountState::starting_collection();
+        let default = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
+
+        // A hand-picked two-card deck comes back exactly, in saved order
+        let ids: Vec<String> = default.selected_ids().into_iter().rev().take(2).collect();
+        let builder = DeckBuilder::for_dealer(&assets, &unlocked, &ids, &HashMap::new());
+        assert_eq!(builder.selected_ids(), ids);
+        assert_eq!(builder.available_cards.len(), default.available_cards.len());
+    }
+
+    #[test]
+    fn for_dealer_drops_unowned_ids_and_seeds_empty_decks() {
+        use crate::models::test_helpers::create_mock_game_assets;
+        let assets = create_mock_game_assets();
+        let unlocked = AccountState::starting_collection();
+        let default = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
+
+        // A never-built deck gets the default selection
+        let fresh = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
+        assert_eq!(fresh.selected_ids(), default.selected_ids());
+
+        // Stale ids are dropped; a fully stale deck falls back to the default
+        let kept = default.selected_ids()[0].clone();
+        let mixed = DeckBuilder::for_dealer(&assets, &unlocked, &[kept.clone(), "gone".to_string()], &HashMap::new());
+        assert_eq!(mixed.selected_ids(), vec![kept]);
+        let stale = DeckBuilder::for_dealer(&assets, &unlocked, &["gone".to_string()], &HashMap::new());
+        assert_eq!(stale.selected_ids(), default.selected_ids());
+    }
+
+    #[test]
+    fn for_dealer_swaps_in_evolved_cards() {
+        use crate::models::test_helpers::{create_cover, create_mock_game_assets};
+        let mut assets = create_mock_game_assets();
+        let unlocked = AccountState::starting_collection();
+        let default = DeckBuilder::for_dealer(&assets, &unlocked, &[], &HashMap::new());
+        let original = default.selected_ids()[0].clone();
+        let mut variant = create_cover("Evolved", 40, -5);
+        variant.id = "evolved".to_string();
+        assets.cover.push(variant);
+        let evolved = HashMap::from([(original.clone(), "evolved".to_string())]);
+
+        let builder = DeckBuilder::for_dealer(&assets, &unlocked, std::slice::from_ref(&original), &evolved);
+        assert_eq!(builder.selected_ids(), ["evolved"]);
+        assert!(builder.available_cards.iter().any(|c| c.id == "evolved"));
+        assert!(builder.available_cards.iter().all(|c| c.id != original));
+
+        // Losing the original loses the variant with it
+        let mut resynced = builder;
+        let mut without = unlocked.clone();
+        without.remove(&original);
+        resynced.resync_available(&assets, &without, &evolved);
+        assert!(resynced.selected_cards.is_empty());
+    }
+
+    #[test]
+    fn loadout_name_lists_products_with_overflow_tail() {
+        use crate::models::test_helpers::create_product;
+        let builder = |names: &[&str]| DeckBuilder {
+            available_cards: Vec::new(),
+            selected_cards: names.iter().map(|n| create_product(n, 10, 5)).collect(),
+        };
+        assert_eq!(builder(&[]).loadout_name(), "No Product");
+        assert_eq!(builder(&["Weed"]).loadout_name(), "Weed");
+        assert_eq!(builder(&["Weed", "Acid"]).loadout_name(), "Weed + Acid");
+        assert_eq!(builder(&["Weed", "Acid", "Meth", "Coke"]).loadout_name(), "Weed + Acid +2");
+    }
+
+    #[test]
+    fn upgraded_in_counts_tiered_cards_through_evolutions() {
+        use crate::models::test_helpers::create_product;
+        use crate::save::CharacterState;
+        let mut weed = create_product("Weed", 10, 5);
+        weed.id = "weed".to_string();
+        let mut kush = create_product("Kush", 20, 5);
+        kush.id = "kush".to_string();
+        let builder = DeckBuilder { available_cards: vec![weed, kush], selected_cards: Vec::new() };
+        let loadout = ["weed".to_string(), "acid".to_string()];
+
+        let mut character = CharacterState::new();
+        assert_eq!(builder.upgraded_in(&loadout, &character), 0);
+        // Tier 1 at 3 plays
+        character.card_play_counts.insert("Weed".to_string(), 3);
+        assert_eq!(builder.upgraded_in(&loadout, &character), 1);
+        // Weed evolved into an unplayed Kush: the loadout carries the variant
+        character.evolved_cards.insert("weed".to_string(), "kush".to_string());
+        assert_eq!(builder.upgraded_in(&loadout, &character), 0);
+    }
+
     fn builder_all_ids(assets: &crate::assets::GameAssets) -> HashSet<String> {
-        DeckBuilder::from_assets_filtered(
+        DeckBuilder::for_dealer(
             assets,
             &assets
                 .products
@@ -107,6 +256,8 @@ mod tests {
                 .chain(assets.insurance.iter().map(|c| c.id.clone()))
                 .chain(assets.modifiers.iter().map(|c| c.id.clone()))
                 .collect(),
+            &[],
+            &HashMap::new(),
         )
         .available_cards
         .iter()
@@ -147,7 +298,7 @@ mod tests {
             }
         }
 
-        let builder = DeckBuilder::from_assets_filtered(&assets, &all_unlocked);
+        let builder = DeckBuilder::for_dealer(&assets, &all_unlocked, &[], &HashMap::new());
 
         // With all unlocked, we should have all player-purchasable cards
         assert!(!builder.available_cards.is_empty());
@@ -0,0 +1,432 @@
+// SOW-042: Off-screen runs - the hand engine driven without a player.
+//
+// Dealers given a RUN order for the day work their station while the
+// active dealer plays in person. There is no UI and no timers here: the
+// same HandState rules (draw, narc play, buyer reaction, resolution) run to
+// completion in a loop, with a RunPolicy standing in for the player's
+// clicks. choose_action is pure so each policy's judgment is unit-tested;
+// auto_play_session is the whole session, and the caller banks the result
+// through the same SaveData paths the played hand uses.
+
+use super::*;
+use crate::models::narrative::StoryComposer;
+use crate::save::{AccountState, RunPolicy, StoryRecord};
+
+/// What the runner does on their turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoAction {
+    Play(usize),
+    Check,
+    Fold,
+}
+
+/// One auto-resolved hand
+#[derive(Debug, Clone)]
+pub struct AutoHand {
+    pub outcome: HandOutcome,
+    pub profit: u32,
+    /// Everything on the table at resolution (what bank_safe_hand counts)
+    pub cards_played: Vec<Card>,
+}
+
+/// A whole off-screen session, ready to bank
+#[derive(Debug, Clone, Default)]
+pub struct AutoSession {
+    pub hands: Vec<AutoHand>,
+    /// Signed session heat, same ledger as HandState.current_heat
+    pub session_heat: i32,
+    /// SOW-050: one record per told hand, as HandState.session_stories
+    pub stories: Vec<StoryRecord>,
+    /// SOW-048: the closing paragraph, same as GO HOME writes
+    pub summary: Option<StoryRecord>,
+}
+
+impl AutoSession {
+    pub fn busted(&self) -> bool {
+        self.hands.last().is_some_and(|h| h.outcome == HandOutcome::Busted)
+    }
+
+    /// Same rule as GO HOME: a session counts as a completed deck when it
+    /// ended on a Safe hand
+    pub fn finished_safe(&self) -> bool {
+        self.hands.last().is_some_and(|h| h.outcome == HandOutcome::Safe)
+    }
+
+    pub fn profit(&self) -> u32 {
+        self.hands
+            .iter()
+            .filter(|h| h.outcome == HandOutcome::Safe)
+            .map(|h| h.profit)
+            .sum()
+    }
+
+    pub fn deals_closed(&self) -> usize {
+        self.hands.iter().filter(|h| h.outcome == HandOutcome::Safe).count()
+    }
+}
+
+/// Safety valve for the phase loop - a hand is 3 rounds of at most four
+/// steps each, so this is never reached by a well-formed hand
+const MAX_STEPS_PER_HAND: usize = 64;
+
+/// Decide the runner's turn. Priorities, in order: get a product (in stock)
+/// and a location on the table; cover up when evidence beats cover; then
+/// per policy - Greedy chases multipliers, Balanced takes a multiplier that
+/// keeps the deal covered, Cautious stacks cover. Cautious folds an exposed
+/// hand from round 2, Balanced only in the last round, Greedy never.
+pub fn choose_action(hand: &HandState, policy: RunPolicy, in_stock: impl Fn(&Card) -> bool) -> AutoAction {
+    let slots = &hand.cards(Owner::Player).hand;
+    let playable = |pred: &dyn Fn(&Card) -> bool| -> Vec<(usize, &Card)> {
+        slots
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slot)| slot.as_ref().map(|card| (i, card)))
+            .filter(|(_, card)| pred(card))
+            .collect()
+    };
+
+    // 1. Product first (an out-of-stock product is inert, same as a click)
+    if hand.active_product(true).is_none() {
+        let best = playable(&|c| matches!(c.card_type, CardType::Product { .. }) && in_stock(c))
+            .into_iter()
+            .max_by_key(|(_, c)| match c.card_type {
+                CardType::Product { price, .. } => price,
+                _ => 0,
+            });
+        if let Some((i, _)) = best {
+            return AutoAction::Play(i);
+        }
+    }
+
+    // 2. Then somewhere to deal - the location with the widest cover margin
+    if hand.active_location(true).is_none() {
+        let best = playable(&|c| matches!(c.card_type, CardType::Location { .. }))
+            .into_iter()
+            .max_by_key(|(_, c)| match c.card_type {
+                CardType::Location { evidence, cover, .. } => cover as i64 - evidence as i64,
+                _ => i64::MIN,
+            });
+        if let Some((i, _)) = best {
+            return AutoAction::Play(i);
+        }
+    }
+
+    let totals = hand.calculate_totals(true);
+    let exposed = totals.evidence > totals.cover;
+    let best_cover = playable(&|c| matches!(c.card_type, CardType::Cover { .. }))
+        .into_iter()
+        .max_by_key(|(_, c)| match c.card_type {
+            CardType::Cover { cover, .. } => cover,
+            _ => 0,
+        })
+        .map(|(i, _)| i);
+
+    // 3. Exposed: cover up, or buy insurance the runner can afford
+    if exposed {
+        if let Some(i) = best_cover {
+            return AutoAction::Play(i);
+        }
+        if hand.active_insurance(true).is_none() {
+            let affordable = playable(&|c| match c.card_type {
+                CardType::Insurance { cost, .. } => hand.cash >= cost,
+                _ => false,
+            });
+            if let Some((i, _)) = affordable.first() {
+                return AutoAction::Play(*i);
+            }
+        }
+        let fold_from_round = match policy {
+            RunPolicy::Cautious => 2,
+            RunPolicy::Balanced => 3,
+            RunPolicy::Greedy => u8::MAX,
+        };
+        if hand.current_round >= fold_from_round && hand.active_insurance(true).is_none() {
+            return AutoAction::Fold;
+        }
+        return AutoAction::Check;
+    }
+
+    // 4. Covered: the policy decides how hard to push
+    match policy {
+        RunPolicy::Cautious => best_cover.map(AutoAction::Play).unwrap_or(AutoAction::Check),
+        RunPolicy::Balanced | RunPolicy::Greedy => {
+            let slack = totals.cover as i64 - totals.evidence as i64;
+            let modifier = playable(&|c| match c.card_type {
+                CardType::DealModifier { price_multiplier, evidence, cover, .. } => {
+                    price_multiplier > 1.0
+                        && (policy == RunPolicy::Greedy || slack + cover as i64 - evidence as i64 >= 0)
+                }
+                _ => false,
+            });
+            modifier
+                .first()
+                .map(|(i, _)| AutoAction::Play(*i))
+                .unwrap_or(AutoAction::Check)
+        }
+    }
+}
+
+/// Drive one hand from wherever it stands to its terminal state. The narc
+/// plays its first card (ai_betting_system's rule), the buyer reacts as in
+/// auto_flip_system, and a committed product burns a charge from `account`
+/// at the same edge as card_click_system. Returns the hand's outcome.
+pub fn auto_play_hand(
+    hand: &mut HandState,
+    policy: RunPolicy,
+    account: &mut AccountState,
+    composer: &StoryComposer,
+) -> AutoHand {
+    for _ in 0..MAX_STEPS_PER_HAND {
+        match hand.current_state {
+            HandPhase::Draw => hand.draw_cards(),
+            HandPhase::PlayerPhase => match hand.current_player() {
+                Owner::Player => {
+                    let action = choose_action(hand, policy, |c| account.has_stock(&c.id));
+                    match action {
+                        AutoAction::Play(i) => {
+                            let burn = hand.cards(Owner::Player).hand[i]
+                                .as_ref()
+                                .filter(|c| matches!(c.card_type, CardType::Product { .. }))
+                                .map(|c| c.id.clone());
+                            if hand.play_card(Owner::Player, i).is_ok() {
+                                if let Some(id) = burn {
+                                    account.burn_charge(&id);
+                                }
+                            } else {
+                                skip_turn(hand);
+                            }
+                        }
+                        AutoAction::Check => {
+                            let round = hand.current_round;
+                            hand.checks_this_hand.push((Owner::Player, round));
+                            skip_turn(hand);
+                        }
+                        AutoAction::Fold => {
+                            // SOW-058: FoldRefund perks hand their charge back
+                            for id in hand.fold_refunds() {
+                                account.add_stock(&id, 1);
+                            }
+                            hand.outcome = Some(HandOutcome::Folded);
+                            hand.current_state = HandPhase::Bust;
+                        }
+                    }
+                }
+                _ => {
+                    let slot = hand.cards(Owner::Narc).hand.iter().position(|s| s.is_some());
+                    match slot {
+                        Some(i) => {
+                            let _ = hand.play_card(Owner::Narc, i);
+                        }
+                        None => skip_turn(hand),
+                    }
+                }
+            },
+            HandPhase::DealerReveal => {
+                hand.buyer_plays_card();
+                hand.transition_state();
+            }
+            HandPhase::Resolve => {
+                hand.resolve_hand();
+            }
+            HandPhase::Bust => break,
+        }
+    }
+
+    let outcome = hand.outcome.unwrap_or(HandOutcome::InvalidDeal);
+    hand.outcome = Some(outcome);
+    composer.narrate_hand(hand);
+
+    AutoHand {
+        outcome,
+        profit: if outcome == HandOutcome::Safe { hand.last_profit } else { 0 },
+        cards_played: hand.cards_played.clone(),
+    }
+}
+
+/// Work a whole session: hands until a bust, an exhausted deck, or the
+/// policy's hand limit - whichever comes first.
+pub fn auto_play_session(
+    mut hand: HandState,
+    policy: RunPolicy,
+    account: &mut AccountState,
+    composer: &StoryComposer,
+) -> AutoSession {
+    let mut session = AutoSession::default();
+    loop {
+        let played = auto_play_hand(&mut hand, policy, account, composer);
+        session.hands.push(played);
+        if session.busted() || session.hands.len() >= policy.hand_limit() || !hand.start_next_hand() {
+            break;
+        }
+    }
+    session.session_heat = hand.current_heat;
+    session.summary = composer.compose_session_record(&hand);
+    session.stories = std::mem::take(&mut hand.session_stories);
+    session
+}
+
+/// Pass the turn without playing (a check, or nothing left to play)
+fn skip_turn(hand: &mut HandState) {
+    hand.current_player_index += 1;
+    if hand.all_players_acted() {
+        hand.transition_state();
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::narrative::{DynamicPattern, NarrativeFragments};
+    use crate::models::test_helpers::*;
+
+    /// A hand at the player's turn with the given cards on the table and in
+    /// the player's slots
+    fn at_player_turn(table: Vec<Card>, slots: Vec<Card>) -> HandState {
+        let mut hand = HandState {
+            current_state: HandPhase::PlayerPhase,
+            current_player_index: 1, // narc already acted
+            cards_played: table,
+            ..Default::default()
+        };
+        let cards = hand.cards_mut(Owner::Player);
+        cards.deck.clear();
+        cards.hand = [None, None, None];
+        for (i, card) in slots.into_iter().enumerate() {
+            cards.hand[i] = Some(card);
+        }
+        hand
+    }
+
+    fn always(_: &Card) -> bool {
+        true
+    }
+
+    #[test]
+    fn test_product_then_location_first() {
+        let hand = at_player_turn(
+            vec![],
+            vec![
+                create_location("Alley", 10, 20, 0),
+                create_product("Weed", 30, 5),
+                create_product("Coke", 120, 20),
+            ],
+        );
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Play(2));
+
+        let hand = at_player_turn(
+            vec![create_product("Weed", 30, 5)],
+            vec![
+                create_location("Alley", 10, 20, 0),
+                create_location("Park", 30, 5, 0),
+                create_cover("Alibi", 10, 0),
+            ],
+        );
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Play(0));
+    }
+
+    #[test]
+    fn test_out_of_stock_product_is_never_played() {
+        let hand = at_player_turn(vec![], vec![create_product("Coke", 120, 20)]);
+        let action = choose_action(&hand, RunPolicy::Greedy, |c| c.id != "coke");
+        assert_eq!(action, AutoAction::Check);
+    }
+
+    #[test]
+    fn test_exposed_hand_covers_up_then_folds_by_policy() {
+        let table = vec![
+            create_product("Weed", 30, 5),
+            create_location("Park", 20, 10, 0),
+            create_evidence("Patrol", 10, 0),
+        ];
+        let hand = at_player_turn(table.clone(), vec![create_cover("Alibi", 15, 0)]);
+        assert_eq!(choose_action(&hand, RunPolicy::Greedy, always), AutoAction::Play(0));
+
+        // Nothing to cover with: round 2 splits Cautious from Balanced
+        let mut hand = at_player_turn(table.clone(), vec![]);
+        hand.current_round = 2;
+        assert_eq!(choose_action(&hand, RunPolicy::Cautious, always), AutoAction::Fold);
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Check);
+
+        hand.current_round = 3;
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Fold);
+        assert_eq!(choose_action(&hand, RunPolicy::Greedy, always), AutoAction::Check);
+    }
+
+    #[test]
+    fn test_insurance_only_when_affordable() {
+        let table = vec![create_product("Weed", 30, 5), create_location("Park", 30, 10, 0)];
+        let mut hand = at_player_turn(table, vec![create_insurance("Lawyer", 5, 100, 10)]);
+        hand.current_round = 3;
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Fold);
+
+        hand.cash = 100;
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Play(0));
+    }
+
+    #[test]
+    fn test_covered_hand_pushes_by_policy() {
+        let table = vec![create_product("Weed", 30, 5), create_location("Alley", 10, 15, 0)];
+        let slots = vec![
+            create_deal_modifier("Bulk Order", 1.5, 10, 0, 5),
+            create_cover("Alibi", 10, 0),
+        ];
+        let hand = at_player_turn(table, slots);
+
+        // Slack is 5; the modifier's +10 evidence would expose the deal
+        assert_eq!(choose_action(&hand, RunPolicy::Greedy, always), AutoAction::Play(0));
+        assert_eq!(choose_action(&hand, RunPolicy::Balanced, always), AutoAction::Check);
+        assert_eq!(choose_action(&hand, RunPolicy::Cautious, always), AutoAction::Play(1));
+    }
+
+    #[test]
+    fn test_session_plays_to_a_terminal_outcome() {
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        let deck = vec![
+            create_product("Weed", 30, 5),
+            create_location("Alley", 5, 50, 0),
+            create_cover("Alibi", 10, 0),
+        ];
+        let mut hand = HandState::default();
+        *hand.cards_mut(Owner::Player) = Cards::new(deck);
+        let mut account = AccountState::new();
+        account.stock.clear(); // drop the seeded batch
+        account.add_stock("weed", 1);
+
+        let session = auto_play_session(hand, RunPolicy::Greedy, &mut account, &composer);
+
+        // Three cards is one hand: the deck is exhausted afterwards
+        assert_eq!(session.hands.len(), 1);
+        assert_eq!(session.stories.len(), 1);
+        assert!(session.summary.as_ref().is_some_and(|s| s.text.starts_with("One deal")));
+        let played = &session.hands[0];
+        assert!(played.cards_played.iter().any(|c| c.name == "Weed"));
+        // The product play burned the only charge
+        assert_eq!(account.charges_in("weed"), 0);
+        if played.outcome == HandOutcome::Safe {
+            assert_eq!(session.profit(), played.profit);
+            assert!(session.finished_safe());
+        }
+    }
+
+    #[test]
+    fn test_session_stops_at_the_policy_hand_limit() {
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        // Nothing but locations: every hand is an invalid deal, never a bust
+        let deck: Vec<Card> = (0..30)
+            .map(|i| create_location(&format!("Spot {i}"), 0, 50, 0))
+            .collect();
+        let mut hand = HandState::default();
+        *hand.cards_mut(Owner::Player) = Cards::new(deck);
+        let mut account = AccountState::new();
+
+        let session = auto_play_session(hand, RunPolicy::Cautious, &mut account, &composer);
+        assert_eq!(session.hands.len(), RunPolicy::Cautious.hand_limit());
+        assert!(session.hands.iter().all(|h| h.outcome == HandOutcome::InvalidDeal));
+        assert_eq!(session.profit(), 0);
+        assert!(!session.busted() && !session.finished_safe());
+    }
+}
@@ -0,0 +1,260 @@
+// SOW-045: Buyer reaction policy - the buyer plays with intent instead of a
+// uniformly random slot.
+//
+// The policy reads the same deal state the bail check does (session heat vs
+// the scenario's heat_threshold, evidence vs the persona's
+// evidence_threshold) plus is_demand_satisfied, weighs it by the persona's
+// authored personality, and settles on one BuyerIntent. The intent then
+// picks the card. Everything here is deterministic on HandState, so the
+// bubble can telegraph exactly what the reaction will be while the player
+// still has a move to make.
+
+use super::*;
+
+/// Score of just going along with the deal - pressure has to beat this
+/// before the buyer changes their tune
+const GO_ALONG_SCORE: f32 = 0.5;
+/// Score of pushing for a location when theirs isn't on the table
+const PUSH_SCORE: f32 = 0.6;
+
+/// Printed heat of a buyer reaction card (buyer cards carry no upgrade
+/// tiers - get_card_heat applies them verbatim)
+fn reaction_heat(card: &Card) -> i32 {
+    match card.card_type {
+        CardType::Location { heat, .. }
+        | CardType::Cover { heat, .. }
+        | CardType::DealModifier { heat, .. } => heat,
+        _ => 0,
+    }
+}
+
+/// Cover minus evidence: how much safer the card leaves the deal
+fn reaction_safety(card: &Card) -> i32 {
+    match card.card_type {
+        CardType::Location { evidence, cover, .. } => cover as i32 - evidence as i32,
+        CardType::Cover { cover, .. } => cover as i32,
+        CardType::DealModifier { evidence, cover, .. } => cover - evidence,
+        _ => 0,
+    }
+}
+
+impl HandState {
+    /// Locations the buyer wants: the active scenario's, else the persona's
+    /// generic demand (same fallback as is_demand_satisfied)
+    fn preferred_locations(&self) -> &[String] {
+        let Some(persona) = &self.buyer_persona else {
+            return &[];
+        };
+        match persona.active_scenario_index.and_then(|i| persona.scenarios.get(i)) {
+            Some(scenario) => &scenario.locations,
+            None => &persona.demand.locations,
+        }
+    }
+
+    /// What the buyer means to do with their next reaction. Heat and
+    /// evidence pressure are fractions of the way to the bail line, scaled
+    /// by personality; a location push needs the demand unmet on location
+    /// specifically. Ties go to the safer intent (walk, calm, push, along).
+    pub fn buyer_intent(&self) -> BuyerIntent {
+        let Some(persona) = &self.buyer_persona else {
+            return BuyerIntent::GoAlong;
+        };
+        let weights = persona.personality;
+
+        let heat_pressure = persona
+            .active_scenario_index
+            .and_then(|i| persona.scenarios.get(i))
+            .and_then(|s| s.heat_threshold)
+            .filter(|t| *t > 0)
+            .map_or(0.0, |t| self.current_heat.max(0) as f32 / t as f32);
+        let evidence_pressure = persona
+            .evidence_threshold
+            .filter(|t| *t > 0)
+            .map_or(0.0, |t| self.calculate_totals(true).evidence as f32 / t as f32);
+
+        let preferred = self.preferred_locations();
+        let location_unmet = !self.is_demand_satisfied()
+            && !preferred.is_empty()
+            && !self
+                .active_location(true)
+                .is_some_and(|card| preferred.contains(&card.name));
+
+        let mut best = (BuyerIntent::GoAlong, GO_ALONG_SCORE);
+        for (intent, score) in [
+            (BuyerIntent::PushLocation, if location_unmet { PUSH_SCORE * weights.push } else { 0.0 }),
+            (BuyerIntent::CalmDown, heat_pressure * weights.calm),
+            (BuyerIntent::ThreatenToWalk, evidence_pressure * weights.walk),
+        ] {
+            if score >= best.1 {
+                best = (intent, score);
+            }
+        }
+        best.0
+    }
+
+    /// The hand slot the buyer reacts with: the card that best serves their
+    /// intent, or - when nothing on hand fits it - the best card for the deal
+    /// (GoAlong). Ties go to the lower slot. None with an empty hand.
+    pub fn buyer_reaction_slot(&self) -> Option<usize> {
+        let intent = self.buyer_intent();
+        let preferred = self.preferred_locations();
+        let hand: Vec<(usize, &Card)> = self
+            .cards(Owner::Buyer)
+            .hand
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slot)| slot.as_ref().map(|card| (i, card)))
+            .collect();
+
+        let fit = |intent: BuyerIntent, card: &Card| -> Option<f32> {
+            match intent {
+                BuyerIntent::CalmDown => {
+                    let heat = reaction_heat(card);
+                    (heat < 0).then_some(-heat as f32)
+                }
+                BuyerIntent::ThreatenToWalk => {
+                    let safety = reaction_safety(card);
+                    (safety > 0).then_some(safety as f32)
+                }
+                BuyerIntent::PushLocation => match card.card_type {
+                    CardType::Location { .. } if preferred.contains(&card.name) => Some(2.0),
+                    CardType::Location { .. } => Some(1.0),
+                    _ => None,
+                },
+                // Best price first, cooler breaks the tie
+                BuyerIntent::GoAlong => {
+                    let price = match card.card_type {
+                        CardType::DealModifier { price_multiplier, .. } => price_multiplier,
+                        _ => 1.0,
+                    };
+                    Some(price * 100.0 - reaction_heat(card) as f32)
+                }
+            }
+        };
+        let pick = |intent: BuyerIntent| {
+            let mut best: Option<(usize, f32)> = None;
+            for (slot, card) in &hand {
+                if let Some(score) = fit(intent, card) {
+                    if best.is_none_or(|(_, top)| score > top) {
+                        best = Some((*slot, score));
+                    }
+                }
+            }
+            best.map(|(slot, _)| slot)
+        };
+        pick(intent).or_else(|| pick(BuyerIntent::GoAlong))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::*;
+
+    /// A hand mid-deal with the mock buyer (Test Scenario: Weed @ Safe
+    /// House, bails past 40 heat) holding `reactions`
+    fn deal_with(reactions: Vec<Card>) -> HandState {
+        let mut hs = HandState {
+            buyer_persona: Some(create_mock_buyer_persona()),
+            ..Default::default()
+        };
+        let buyer = hs.cards_mut(Owner::Buyer);
+        for (slot, card) in reactions.into_iter().enumerate() {
+            buyer.hand[slot] = Some(card);
+        }
+        hs
+    }
+
+    fn weed_at_safe_house(hs: &mut HandState) {
+        hs.cards_played.push(create_product("Weed", 30, 0));
+        hs.cards_played.push(create_location("Safe House", 0, 10, 0));
+    }
+
+    #[test]
+    fn happy_buyer_goes_along_with_the_best_price() {
+        let mut hs = deal_with(vec![
+            create_buyer_modifier("Haggle", 0.8, 0, 0, 0),
+            create_buyer_modifier("Big Spender", 1.5, 0, 0, 5),
+            create_buyer_location("Back Room", 0, 5, 0),
+        ]);
+        weed_at_safe_house(&mut hs);
+        assert!(hs.is_demand_satisfied());
+        assert_eq!(hs.buyer_intent(), BuyerIntent::GoAlong);
+        assert_eq!(hs.buyer_reaction_slot(), Some(1));
+    }
+
+    #[test]
+    fn hot_deal_gets_calmed_down() {
+        let mut hs = deal_with(vec![
+            create_buyer_modifier("Big Spender", 1.5, 0, 0, 5),
+            create_buyer_modifier("Chill Out", 1.0, 0, 0, -5),
+            create_buyer_modifier("Ice Cold", 1.0, 0, 0, -15),
+        ]);
+        weed_at_safe_house(&mut hs);
+        hs.current_heat = 18; // under half way to 40: not yet
+        assert_eq!(hs.buyer_intent(), BuyerIntent::GoAlong);
+        hs.current_heat = 30; // 0.75 of the line
+        assert_eq!(hs.buyer_intent(), BuyerIntent::CalmDown);
+        assert_eq!(hs.buyer_reaction_slot(), Some(2));
+    }
+
+    #[test]
+    fn unmet_location_gets_pushed_preferred_first() {
+        let mut hs = deal_with(vec![
+            create_buyer_modifier("Big Spender", 1.5, 0, 0, 0),
+            create_buyer_location("Alley", 5, 5, 0),
+            create_buyer_location("Safe House", 0, 20, 0),
+        ]);
+        hs.cards_played.push(create_product("Weed", 30, 0));
+        hs.cards_played.push(create_location("Parking Lot", 5, 5, 0));
+        assert_eq!(hs.buyer_intent(), BuyerIntent::PushLocation);
+        assert_eq!(hs.buyer_reaction_slot(), Some(2));
+
+        // No location on hand: falls back to going along
+        hs.cards_mut(Owner::Buyer).hand[2] = None;
+        hs.cards_mut(Owner::Buyer).hand[1] = None;
+        assert_eq!(hs.buyer_reaction_slot(), Some(0));
+    }
+
+    #[test]
+    fn piling_evidence_makes_them_threaten_to_walk() {
+        let mut hs = deal_with(vec![
+            create_buyer_modifier("Big Spender", 1.5, 10, 0, 0),
+            create_buyer_location("Back Room", 0, 15, 0),
+            create_buyer_modifier("Lookout", 1.0, -5, 5, 0),
+        ]);
+        hs.buyer_persona.as_mut().unwrap().evidence_threshold = Some(20);
+        weed_at_safe_house(&mut hs);
+        hs.cards_played.push(create_evidence("Stakeout", 16, 0));
+        assert_eq!(hs.buyer_intent(), BuyerIntent::ThreatenToWalk);
+        assert_eq!(hs.buyer_reaction_slot(), Some(1));
+    }
+
+    #[test]
+    fn personality_weights_the_intent() {
+        let mut hs = deal_with(vec![create_buyer_modifier("Chill Out", 1.0, 0, 0, -5)]);
+        weed_at_safe_house(&mut hs);
+        hs.current_heat = 30;
+        // A party animal doesn't care how hot it gets
+        hs.buyer_persona.as_mut().unwrap().personality.calm = 0.5;
+        assert_eq!(hs.buyer_intent(), BuyerIntent::GoAlong);
+        // A jumpy one calms down early
+        hs.buyer_persona.as_mut().unwrap().personality.calm = 2.0;
+        hs.current_heat = 12;
+        assert_eq!(hs.buyer_intent(), BuyerIntent::CalmDown);
+    }
+
+    #[test]
+    fn buyer_plays_the_intended_card() {
+        let mut hs = deal_with(vec![
+            create_buyer_modifier("Big Spender", 1.5, 0, 0, 5),
+            create_buyer_modifier("Ice Cold", 1.0, 0, 0, -15),
+        ]);
+        weed_at_safe_house(&mut hs);
+        hs.current_heat = 35;
+        let played = hs.buyer_plays_card().unwrap();
+        assert_eq!(played.name, "Ice Cold");
+        assert_eq!(hs.current_heat, 20);
+        assert!(hs.cards(Owner::Buyer).hand[1].is_none());
+    }
+}
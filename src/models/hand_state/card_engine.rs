@@ -71,6 +71,9 @@ impl HandState {
     /// - Each upgrade adds +10% to the chosen stat (additive stacking)
     /// - "Good" stats increase (Price, Cover, PriceMultiplier)
     /// - "Bad" stats decrease (Evidence, Heat, HeatPenalty)
+    ///
+    /// SOW-059: the empire's relics add their cover and profit last
+    /// SOW-060: then the run-map stop scales the profit
     pub fn calculate_totals(&self, include_current_round: bool) -> Totals {
         use crate::save::UpgradeableStat;
 
@@ -144,6 +147,14 @@ impl HandState {
             }
         }
 
+        // SOW-059: relics - flat cover and a cut of the profit
+        totals.cover += self.relics.cover;
+        totals.profit = self.relics.profit(totals.profit);
+        // SOW-060: the stop's stakes
+        if let Some(params) = self.node.params() {
+            totals.profit = params.profit(totals.profit);
+        }
+
         totals
     }
 }
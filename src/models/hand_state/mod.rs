@@ -12,6 +12,8 @@ use crate::data::*;
 pub mod state_machine;
 pub mod resolution;
 pub mod card_engine;
+pub mod autoplay; // SOW-042: off-screen runs (policy-driven, no UI)
+pub mod buyer_ai; // SOW-045: buyer reaction policy
 
 // ============================================================================
 // CORE DEFINITIONS
@@ -30,7 +32,8 @@ pub enum HandPhase {
 }
 
 /// Outcome of hand resolution
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// (SOW-046: serde so narrative_patterns.ron can require one)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum HandOutcome {
     Safe,
     Busted,
@@ -39,6 +42,31 @@ pub enum HandOutcome {
     BuyerBailed,
 }
 
+/// SOW-047: how the bust check went when evidence beat cover - recorded at
+/// resolution so the story can tell it (the cards alone can't say whether
+/// the insurance paid out or a conviction overrode it)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BustCheck {
+    /// Insurance activated: paid its cost, took its heat, saved the hand
+    InsurancePaid,
+    /// A conviction over its heat threshold overrode any insurance
+    ConvictionOverrode,
+    /// Insurance on the table, but not enough cash to activate it
+    InsuranceUnaffordable,
+    /// SOW-061: insurance on the table, and the operation wouldn't honour it
+    InsuranceRefused,
+}
+
+/// SOW-063: what a kingpin bust did to the empire - recorded at resolution
+/// so GO HOME knows the runner is gone, and whether anyone is left to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KingpinFall {
+    /// A lieutenant took the chair; the empire carries on
+    Succeeded,
+    /// Nobody could take over - the empire was reset
+    EmpireFell,
+}
+
 /// Hand state tracking
 #[derive(Component)]
 pub struct HandState {
@@ -58,7 +86,7 @@ pub struct HandState {
     pub checks_this_hand: Vec<(Owner, u8)>,
     pub buyer_persona: Option<BuyerPersona>,
     pub hand_story: Option<String>, // SOW-012: Generated narrative for this hand
-    pub session_stories: Vec<String>, // Story history for this session
+    pub session_stories: Vec<crate::save::StoryRecord>, // Story history for this session (SOW-050: records)
     pub last_profit: u32, // RFC-016: Profit from most recent hand resolution
     pub card_play_counts: HashMap<String, u32>, // RFC-017: Play counts for upgrade tiers
     pub card_upgrades: HashMap<String, crate::save::CardUpgrades>, // RFC-019: Per-card upgrade choices
@@ -67,6 +95,22 @@ pub struct HandState {
     /// SOW-025: the area this run happens in (the active dealer's station).
     /// Safe hands here earn the runner street cred in this area.
     pub run_area: String,
+    /// SOW-047: set at resolution when evidence beat cover and a conviction
+    /// or insurance decided the hand
+    pub bust_check: Option<BustCheck>,
+    /// SOW-048: one beat per told hand this session - survives
+    /// start_next_hand like session_stories, feeds openers and the summary
+    pub session_arc: crate::models::narrative::SessionArc,
+    /// SOW-058: a ConvictionImmunity perk has been spent this session
+    pub conviction_shrugged: bool,
+    /// SOW-059: the empire's relics, copied in with the upgrades
+    pub relics: crate::models::relic::RelicEffects,
+    /// SOW-060: the run-map stop this hand is played at
+    pub node: crate::models::run_map::RunNodeKind,
+    /// SOW-061: the task-force operation this hand is played against
+    pub operation: Option<crate::models::boss::Operation>,
+    /// SOW-063: set at resolution when the kingpin busted
+    pub kingpin_fall: Option<KingpinFall>,
 }
 
 // SOW-027: from_assets removed (dead since custom decks shipped;
@@ -111,6 +155,13 @@ impl Default for HandState {
             card_play_counts: HashMap::new(), // RFC-017: Initialize empty
             card_upgrades: HashMap::new(), // RFC-019: Initialize empty
             run_area: crate::save::DEFAULT_STATION.to_string(), // SOW-025
+            bust_check: None, // SOW-047
+            session_arc: Default::default(), // SOW-048
+            conviction_shrugged: false, // SOW-058
+            relics: Default::default(), // SOW-059
+            node: crate::models::run_map::RunNodeKind::Deal, // SOW-060
+            operation: None, // SOW-061
+            kingpin_fall: None, // SOW-063
         }
     }
 }
@@ -140,4 +191,21 @@ impl HandState {
             .map(|u| u.stat_multiplier(stat))
             .unwrap_or(1.0)
     }
+
+    /// SOW-058: whether a card has taken a perk
+    pub fn has_perk(&self, card_name: &str, perk: crate::save::UpgradePerk) -> bool {
+        self.card_upgrades.get(card_name).is_some_and(|u| u.has_perk(perk))
+    }
+
+    /// SOW-058: product ids whose burned charge a fold hands back (played
+    /// this hand with the FoldRefund perk). Call before the played cards
+    /// are cleared.
+    pub fn fold_refunds(&self) -> Vec<String> {
+        self.cards_played
+            .iter()
+            .filter(|c| matches!(c.card_type, CardType::Product { .. }))
+            .filter(|c| self.has_perk(&c.name, crate::save::UpgradePerk::FoldRefund))
+            .map(|c| c.id.clone())
+            .collect()
+    }
 }
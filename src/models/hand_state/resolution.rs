@@ -36,10 +36,11 @@ impl HandState {
             return HandOutcome::BuyerBailed;
         }
 
-        let totals = self.calculate_totals(true); // Always include all cards at resolution
+        let totals = self.resolution_totals(); // Always include all cards at resolution
 
-        // Step 3: Evidence ≤ Cover → Safe (tie goes to player)
-        let outcome = if totals.evidence <= totals.cover {
+        // Step 3: Evidence ≤ Cover → Safe (tie goes to player; SOW-059:
+        // a Margin relic stretches the tie)
+        let outcome = if totals.evidence <= totals.cover + self.relics.margin {
             HandOutcome::Safe
         } else {
             // Evidence > Cover → Potential bust, check insurance/conviction
@@ -47,8 +48,13 @@ impl HandState {
             // Step 2: Check Conviction override (using current cumulative deck heat)
             if let Some(conviction) = self.active_conviction(true) {
                 if let CardType::Conviction { heat_threshold } = conviction.card_type {
-                    if self.current_heat >= heat_threshold as i32 {
+                    if self.current_heat >= heat_threshold as i32 && self.shrug_conviction() {
+                        // SOW-058: a ConvictionImmunity perk spent - insurance
+                        // decides the hand as if no conviction were active
+                        self.try_insurance_activation()
+                    } else if self.current_heat >= heat_threshold as i32 {
                         // Conviction overrides insurance - run ends
+                        self.bust_check = Some(BustCheck::ConvictionOverrode);
                         HandOutcome::Busted
                     } else {
                         // Heat below threshold, conviction doesn't activate
@@ -91,6 +97,31 @@ impl HandState {
         outcome
     }
 
+    /// The totals a hand resolves on: calculate_totals, plus the evidence an
+    /// operation kept off the table (SOW-061) - its hidden evidence and the
+    /// wire on the buyer. The resolution overlay shows these too.
+    pub fn resolution_totals(&self) -> Totals {
+        let mut totals = self.calculate_totals(true);
+        if let Some(op) = &self.operation {
+            totals.evidence += op.rules.revealed_evidence(self.cards(Owner::Buyer).played.len());
+        }
+        totals
+    }
+
+    /// SOW-058: spend the session's ConvictionImmunity, if a card in play
+    /// has the perk and it hasn't been spent. True when it was.
+    fn shrug_conviction(&mut self) -> bool {
+        if self.conviction_shrugged {
+            return false;
+        }
+        let immune = self
+            .cards_played
+            .iter()
+            .any(|c| self.has_perk(&c.name, crate::save::UpgradePerk::ConvictionImmunity));
+        self.conviction_shrugged = immune;
+        immune
+    }
+
     /// Try to activate insurance (Step 3 of resolution order)
     ///
     /// Returns:
@@ -106,7 +137,19 @@ impl HandState {
             }
         });
 
+        // SOW-061: a NoInsurance operation doesn't honour the policy
+        if insurance_info.is_some() && self.operation.as_ref().is_some_and(|op| op.rules.no_insurance) {
+            self.bust_check = Some(BustCheck::InsuranceRefused);
+            return HandOutcome::Busted;
+        }
+
         if let Some((insurance_name, cost, heat_penalty)) = insurance_info {
+            // SOW-058: the CheapInsurance perk takes its cut off the cost
+            let cost = if self.has_perk(&insurance_name, crate::save::UpgradePerk::CheapInsurance) {
+                cost * (100 - crate::save::CHEAP_INSURANCE_DISCOUNT_PCT) / 100
+            } else {
+                cost
+            };
             // Check affordability
             if self.cash >= cost {
                 // Activate insurance: pay cost, gain heat penalty
@@ -122,9 +165,11 @@ impl HandState {
                 // Burn insurance card (remove from deck permanently)
                 self.cards_mut(Owner::Player).deck.retain(|card| card.name != insurance_name);
 
+                self.bust_check = Some(BustCheck::InsurancePaid);
                 HandOutcome::Safe
             } else {
                 // Can't afford insurance
+                self.bust_check = Some(BustCheck::InsuranceUnaffordable);
                 HandOutcome::Busted
             }
         } else {
@@ -142,6 +187,7 @@ impl HandState {
 mod tests {
     use super::*;
     use crate::models::test_helpers::*; // SOW-012: Use shared test helpers
+    use crate::models::relic::RelicEffects;
 
     // ========================================================================
     // Bust Check & Resolution Tests
@@ -217,6 +263,30 @@ mod tests {
         // In this test we push directly, so only insurance heat_penalty is added during activation
         let insurance_heat = if let CardType::Insurance { heat_penalty, .. } = insurance.card_type { heat_penalty } else { 0 };
         assert_eq!(hand_state.current_heat, insurance_heat);
+        assert_eq!(hand_state.bust_check, Some(BustCheck::InsurancePaid));
+    }
+
+    #[test]
+    fn test_unaffordable_insurance_is_recorded() {
+        // SOW-047: the story needs to know the lawyer was there but unpaid
+        let mut hand_state = HandState {
+            cash: 500,
+            ..Default::default()
+        };
+
+        hand_state.cards_played.push(create_product("Weed", 30, 5));
+        hand_state.cards_played.push(create_location("Location", 30, 20, 0));
+        hand_state.cards_played.push(create_insurance("Plea Bargain", 5, 1000, 20));
+
+        assert_eq!(hand_state.resolve_hand(), HandOutcome::Busted);
+        assert_eq!(hand_state.bust_check, Some(BustCheck::InsuranceUnaffordable));
+
+        // A clean safe hand never reaches the bust check
+        let mut safe = HandState::default();
+        safe.cards_played.push(create_product("Weed", 30, 5));
+        safe.cards_played.push(create_location("Location", 20, 30, 0));
+        assert_eq!(safe.resolve_hand(), HandOutcome::Safe);
+        assert_eq!(safe.bust_check, None);
     }
 
     #[test]
@@ -257,6 +327,130 @@ mod tests {
         let outcome = hand_state.resolve_hand();
         assert_eq!(outcome, HandOutcome::Busted);
         assert_eq!(hand_state.cash, 2000); // Cash unchanged (conviction blocked insurance)
+        assert_eq!(hand_state.bust_check, Some(BustCheck::ConvictionOverrode));
+    }
+
+    fn with_perk(hand_state: &mut HandState, card: &str, perk: crate::save::UpgradePerk) {
+        hand_state
+            .card_upgrades
+            .entry(card.to_string())
+            .or_default()
+            .add_choice(crate::save::UpgradeChoice::Perk(perk));
+    }
+
+    #[test]
+    fn test_conviction_immunity_shrugs_once_per_session() {
+        // SOW-058: the perk lets insurance decide the hand - once
+        let warrant_hand = || {
+            let mut hand_state = HandState {
+                cash: 2000,
+                current_heat: 50,
+                ..Default::default()
+            };
+            hand_state.cards_played.push(create_product("Weed", 30, 5));
+            hand_state.cards_played.push(create_location("Location", 30, 20, 0));
+            hand_state.cards_played.push(create_insurance("Plea Bargain", 5, 1000, 20));
+            hand_state.cards_played.push(create_conviction("Warrant", 40));
+            with_perk(&mut hand_state, "Plea Bargain", crate::save::UpgradePerk::ConvictionImmunity);
+            hand_state
+        };
+
+        let mut hand_state = warrant_hand();
+        assert_eq!(hand_state.resolve_hand(), HandOutcome::Safe);
+        assert!(hand_state.conviction_shrugged);
+
+        let mut spent = warrant_hand();
+        spent.conviction_shrugged = true;
+        assert_eq!(spent.resolve_hand(), HandOutcome::Busted);
+        assert_eq!(spent.bust_check, Some(BustCheck::ConvictionOverrode));
+    }
+
+    #[test]
+    fn test_cheap_insurance_perk_discounts_activation() {
+        let mut hand_state = HandState {
+            cash: 1500,
+            ..Default::default()
+        };
+        hand_state.cards_played.push(create_product("Weed", 30, 5));
+        hand_state.cards_played.push(create_location("Location", 30, 20, 0));
+        hand_state.cards_played.push(create_insurance("Plea Bargain", 5, 1000, 20));
+        with_perk(&mut hand_state, "Plea Bargain", crate::save::UpgradePerk::CheapInsurance);
+
+        // 25% off the $1000 activation, then the Weed's $30 banked
+        assert_eq!(hand_state.resolve_hand(), HandOutcome::Safe);
+        assert_eq!(hand_state.cash, 1500 - 750 + 30);
+    }
+
+    #[test]
+    fn test_fold_refunds_only_perked_products() {
+        let mut hand_state = HandState::default();
+        hand_state.cards_played.push(create_product("Weed", 30, 5));
+        hand_state.cards_played.push(create_product("Coke", 120, 20));
+        hand_state.cards_played.push(create_location("Alley", 5, 20, 0));
+        with_perk(&mut hand_state, "Coke", crate::save::UpgradePerk::FoldRefund);
+        with_perk(&mut hand_state, "Alley", crate::save::UpgradePerk::FoldRefund);
+
+        let coke = hand_state.cards_played[1].id.clone();
+        assert_eq!(hand_state.fold_refunds(), vec![coke]);
+    }
+
+    #[test]
+    fn test_relics_lend_cover_and_margin() {
+        // SOW-059: evidence 30 against cover 20 busts without relics
+        let hand = |relics: RelicEffects| {
+            let mut hand_state = HandState { relics, ..Default::default() };
+            hand_state.cards_played.push(create_product("Weed", 30, 5));
+            hand_state.cards_played.push(create_location("Alley", 30, 20, 0));
+            hand_state
+        };
+        assert_eq!(hand(RelicEffects::default()).resolve_hand(), HandOutcome::Busted);
+        assert_eq!(hand(RelicEffects { margin: 10, ..Default::default() }).resolve_hand(), HandOutcome::Safe);
+        assert_eq!(hand(RelicEffects { margin: 9, ..Default::default() }).resolve_hand(), HandOutcome::Busted);
+
+        let covered = hand(RelicEffects { cover: 10, profit_pct: 50, ..Default::default() });
+        let totals = covered.calculate_totals(true);
+        assert_eq!((totals.cover, totals.profit), (30, 45));
+    }
+
+    #[test]
+    fn test_operations_reveal_evidence_and_refuse_insurance() {
+        use crate::models::boss::{BossRules, Operation};
+        // SOW-061: evidence 20 against cover 30 is Safe on an ordinary hand
+        let hand = |rules: BossRules| {
+            let operation = Operation::from(&create_boss("dea", vec![], crate::models::boss::BossTrigger::Zones(1)));
+            let mut hand_state = HandState {
+                operation: Some(Operation { rules, ..operation }),
+                ..Default::default()
+            };
+            hand_state.cards_played.push(create_product("Weed", 30, 5));
+            hand_state.cards_played.push(create_location("Alley", 20, 30, 0));
+            hand_state
+        };
+        assert_eq!(hand(BossRules::default()).resolve_hand(), HandOutcome::Safe);
+
+        let mut hidden = hand(BossRules { hidden_evidence: 15, ..Default::default() });
+        assert_eq!(hidden.calculate_totals(true).evidence, 20, "hidden until resolution");
+        assert_eq!(hidden.resolution_totals().evidence, 35);
+        assert_eq!(hidden.resolve_hand(), HandOutcome::Busted);
+
+        // The wire counts every card the buyer played
+        let mut wired = hand(BossRules { wire_evidence: 6, ..Default::default() });
+        wired.cards_mut(Owner::Buyer).played = vec![create_evidence("Nod", 0, 0), create_evidence("Wink", 0, 0)];
+        assert_eq!(wired.resolve_hand(), HandOutcome::Busted);
+
+        // Insurance that would have paid out is refused
+        let insured = |rules: BossRules| {
+            let mut hand_state = hand(rules);
+            hand_state.cash = 1500;
+            hand_state.cards_played.push(create_location("Location", 40, 20, 0));
+            hand_state.cards_played.push(create_insurance("Plea Bargain", 5, 1000, 20));
+            hand_state
+        };
+        assert_eq!(insured(BossRules::default()).resolve_hand(), HandOutcome::Safe);
+        let mut refused = insured(BossRules { no_insurance: true, ..Default::default() });
+        assert_eq!(refused.resolve_hand(), HandOutcome::Busted);
+        assert_eq!(refused.bust_check, Some(BustCheck::InsuranceRefused));
+        assert_eq!(refused.cash, 1500, "nothing was paid");
     }
 
     #[test]
@@ -42,6 +42,13 @@ impl HandState {
             card_play_counts: std::collections::HashMap::new(), // RFC-017: Initialize empty, set from SaveData
             card_upgrades: std::collections::HashMap::new(), // RFC-019: Initialize empty, set from SaveData
             run_area: run_area.to_string(), // SOW-025/027: where this run happens
+            bust_check: None, // SOW-047
+            session_arc: Default::default(), // SOW-048
+            conviction_shrugged: false, // SOW-058
+            relics: Default::default(), // SOW-059: set from SaveData
+            node: crate::models::run_map::RunNodeKind::Deal, // SOW-060: the opening deal
+            operation: None, // SOW-061: only ever set at an operation stop
+            kingpin_fall: None, // SOW-063: set at resolution
         }
     }
 
@@ -93,8 +100,14 @@ impl HandState {
         let preserved_play_counts = self.card_play_counts.clone(); // RFC-017: Preserve play counts
         let preserved_upgrades = self.card_upgrades.clone(); // RFC-019: Preserve card upgrades
         let preserved_run_area = self.run_area.clone(); // SOW-025: the whole session happens in one area
-
-        // Reset state but preserve cash/heat/cards/buyer/play_counts/upgrades/run_area
+        // SOW-048: the session's stories so far (GO HOME carries them all,
+        // not just the last hand's) and the arc the next story builds on
+        let preserved_stories = std::mem::take(&mut self.session_stories);
+        let preserved_arc = std::mem::take(&mut self.session_arc);
+        let preserved_shrug = self.conviction_shrugged; // SOW-058: once per session
+        let preserved_relics = self.relics; // SOW-059
+
+        // Reset state but preserve cash/heat/cards/buyer/play_counts/upgrades/run_area/session story
         // (SOW-027: the narc deck itself carries difficulty now - it's in owner_cards)
         *self = Self::default();
         self.cash = preserved_cash;
@@ -104,6 +117,10 @@ impl HandState {
         self.card_play_counts = preserved_play_counts; // RFC-017: Restore play counts
         self.card_upgrades = preserved_upgrades; // RFC-019: Restore card upgrades
         self.run_area = preserved_run_area; // SOW-025: Restore run area
+        self.session_stories = preserved_stories; // SOW-048
+        self.session_arc = preserved_arc; // SOW-048
+        self.conviction_shrugged = preserved_shrug; // SOW-058
+        self.relics = preserved_relics; // SOW-059
 
         bevy::log::info!(
             "start_next_hand: after restore heat={}, cash={}",
@@ -113,6 +130,38 @@ impl HandState {
         true
     }
 
+    /// SOW-060: the next hand, at a run-map stop - start_next_hand, then
+    /// the stop's stakes: its narc deck (built by the caller at the runner's
+    /// tier shifted by the stop's narc_shift) and its opening heat. False,
+    /// and nothing changes, when the deck is exhausted or the stop isn't a
+    /// hand.
+    pub fn start_node_hand(&mut self, node: crate::models::run_map::RunNodeKind, narc_deck: Vec<Card>) -> bool {
+        let Some(params) = node.params() else {
+            return false;
+        };
+        if self.playable_cards_remaining() < 3 || !self.start_next_hand() {
+            return false;
+        }
+        self.node = node;
+        self.current_heat += params.opening_heat;
+        let narc = self.cards_mut(Owner::Narc);
+        *narc = Cards::new(narc_deck);
+        narc.shuffle_deck();
+        true
+    }
+
+    /// SOW-060: a rest stop - the played cards go back into the deck
+    /// (insurance stays spent) and the session cools off
+    pub fn rest(&mut self) {
+        let cards = self.cards_mut(Owner::Player);
+        let played = std::mem::take(&mut cards.played);
+        cards
+            .deck
+            .extend(played.into_iter().filter(|c| !matches!(c.card_type, CardType::Insurance { .. })));
+        cards.shuffle_deck();
+        self.current_heat -= crate::models::run_map::REST_STOP_COOLING;
+    }
+
     /// Draw cards from decks to hands (initial draw phase)
     pub fn draw_cards(&mut self) {
         self.initialize_buyer_hand();
@@ -135,11 +184,11 @@ impl HandState {
             HandPhase::DealerReveal => {
                 // After Dealer reveals, check if customer folds, then advance
                 // Player can fold during their turn in PlayerPhase (not here)
-                if self.current_round >= 3 {
-                    // Round 3: Go to Resolution
+                if self.current_round >= self.rounds() {
+                    // Last round: Go to Resolution
                     HandPhase::Resolve
                 } else {
-                    // Rounds 1-2: Advance to next round
+                    // Earlier rounds: Advance to next round
                     self.current_round += 1;
                     self.reset_turn_tracking();
                     // Don't clear checks_this_hand - persist for entire hand
@@ -151,6 +200,11 @@ impl HandState {
         };
     }
 
+    /// Rounds in this hand: three, plus any an operation adds (SOW-061)
+    pub fn rounds(&self) -> u8 {
+        3 + self.operation.as_ref().map_or(0, |op| op.rules.extra_rounds)
+    }
+
     /// Play a card from hand during PlayerPhase
     pub fn play_card(&mut self, owner: Owner, card_index: usize) -> Result<(), String> {
         // Verify we're in PlayerPhase and it's the correct player's turn
@@ -331,28 +385,13 @@ impl HandState {
         }
     }
 
-    /// Buyer plays 1 random card from visible hand
+    /// Buyer plays 1 card from visible hand - SOW-045: the one their intent
+    /// calls for (buyer_ai.rs), no longer a random slot
     /// Returns the card that was played, or None if no cards available
     pub fn buyer_plays_card(&mut self) -> Option<Card> {
+        let slot = self.buyer_reaction_slot()?;
         let buyer_cards = self.cards_mut(Owner::Buyer);
-        let hand_vec: Vec<Card> = buyer_cards.hand.iter().filter_map(|s| s.clone()).collect();
-
-        if hand_vec.is_empty() {
-            return None;
-        }
-
-        // Find a random non-empty slot
-        let filled_indices: Vec<usize> = buyer_cards.hand.iter()
-            .enumerate()
-            .filter_map(|(i, slot)| if slot.is_some() { Some(i) } else { None })
-            .collect();
-
-        if filled_indices.is_empty() {
-            return None;
-        }
-
-        let random_idx = filled_indices[rand::rng().random_range(0..filled_indices.len())];
-        let card = buyer_cards.hand[random_idx].take().unwrap();
+        let card = buyer_cards.hand[slot].take()?;
 
         buyer_cards.played.push(card.clone());
         self.cards_played.push(card.clone());
@@ -584,6 +623,80 @@ mod tests {
         assert_eq!(hand_state.run_area, "suburbia");
     }
 
+    #[test]
+    fn test_node_hand_plays_at_the_stops_stakes() {
+        // SOW-060: the stop sets the narc deck, the opening heat and the
+        // profit; the run carries on as start_next_hand would
+        use crate::models::run_map::RunNodeKind;
+        let mut hand_state = HandState { cash: 300, current_heat: 20, ..Default::default() };
+        let player_cards = hand_state.cards_mut(Owner::Player);
+        for i in 0..10 {
+            player_cards.deck.push(create_product(&format!("P{i}"), 50, 5));
+        }
+
+        let sting_deck = vec![create_evidence("Wire", 40, 10)];
+        assert!(!hand_state.start_node_hand(RunNodeKind::RestStop, sting_deck.clone()), "a rest isn't a hand");
+        assert!(hand_state.start_node_hand(RunNodeKind::Tip, sting_deck));
+        assert_eq!(hand_state.node, RunNodeKind::Tip);
+        assert_eq!((hand_state.cash, hand_state.current_heat), (300, 35));
+        assert_eq!(hand_state.cards(Owner::Narc).deck.len(), 1);
+        assert_eq!(hand_state.cards(Owner::Narc).deck[0].name, "Wire");
+
+        hand_state.cards_played.push(create_product("Weed", 40, 0));
+        hand_state.cards_played.push(create_location("Alley", 0, 10, 0));
+        assert_eq!(hand_state.calculate_totals(true).profit, 80, "a tip doubles the money");
+
+        // Nothing left to deal: the stop refuses and the hand is untouched
+        let mut dry = HandState::default();
+        dry.cards_mut(Owner::Player).deck.truncate(1);
+        assert!(!dry.start_node_hand(RunNodeKind::Deal, vec![]));
+        assert_eq!(dry.node, RunNodeKind::Deal);
+        assert_eq!(dry.cards(Owner::Narc).deck.len(), 2);
+    }
+
+    #[test]
+    fn test_rest_stop_reshuffles_the_played_cards_but_not_insurance() {
+        let mut hand_state = HandState::default();
+        let player_cards = hand_state.cards_mut(Owner::Player);
+        player_cards.deck.clear();
+        player_cards.played = vec![
+            create_product("Weed", 30, 5),
+            create_location("Alley", 10, 20, 0),
+            create_insurance("Plea Bargain", 5, 1000, 20),
+        ];
+        hand_state.current_heat = 4;
+
+        assert!(hand_state.playable_cards_remaining() < 3);
+        hand_state.rest();
+        let cards = hand_state.cards(Owner::Player);
+        assert_eq!(cards.deck.len(), 2);
+        assert!(cards.played.is_empty());
+        assert!(cards.deck.iter().all(|c| c.name != "Plea Bargain"), "insurance stays spent");
+        assert_eq!(hand_state.current_heat, 4 - crate::models::run_map::REST_STOP_COOLING);
+    }
+
+    #[test]
+    fn test_start_next_hand_preserves_the_session_story() {
+        // SOW-048: GO HOME carries every hand's story, and the next story
+        // builds on the arc so far
+        use crate::models::test_helpers::*;
+        let mut hand_state = HandState::default();
+        let player_cards = hand_state.cards_mut(Owner::Player);
+        for i in 0..10 {
+            player_cards.deck.push(create_product(&format!("P{i}"), 50, 5));
+        }
+        hand_state.outcome = Some(HandOutcome::Folded);
+        use crate::models::narrative::{DynamicPattern, NarrativeFragments, StoryComposer};
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        let story = composer.narrate_hand(&mut hand_state);
+
+        hand_state.start_next_hand();
+        assert_eq!(hand_state.session_stories.len(), 1);
+        assert_eq!(hand_state.session_stories[0].text, story);
+        assert_eq!(hand_state.session_arc.beats.len(), 1);
+        assert!(hand_state.hand_story.is_none());
+    }
+
     #[test]
     fn test_buyer_deck_resets_between_hands() {
         use crate::models::test_helpers::create_mock_game_assets;
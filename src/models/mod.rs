@@ -8,6 +8,11 @@ pub mod hand_state;
 pub mod narrative; // SOW-012: Narrative generation system
 pub mod fonts;
 pub mod shop_location; // SOW-024: unlockable areas
+pub mod ascension; // SOW-065: difficulty levels
+pub mod relic; // SOW-059: passive empire items
+pub mod boss; // SOW-061: task-force operations
+pub mod victory; // SOW-062: win conditions
+pub mod run_map; // SOW-060: the night as a branching path
 
 #[cfg(test)]
 pub mod test_helpers; // SOW-012: Shared test card creation helpers
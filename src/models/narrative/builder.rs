@@ -1,6 +1,7 @@
 use super::fragments::{SentenceStructure, FragmentSlot, ClauseRelation, GrammaticalStructure};
 use super::patterns::NarrativeRole;
 use rand::prelude::*;
+use serde::{Deserialize, Serialize}; // SOW-046: satellites are authored in RON
 
 /// A builder for dynamically constructing sentence structures.
 pub struct SentenceBuilder {
@@ -8,19 +9,30 @@ pub struct SentenceBuilder {
     product_conjunction: ClauseRelation,
 }
 
-#[derive(Clone)]
+fn always() -> f32 {
+    1.0
+}
+
+/// SOW-046: RON names are the authoring vocabulary - `chance` (omitted =
+/// always included) and `conjunction` (omitted = prepositional attach)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Satellite {
     pub role: NarrativeRole,
+    #[serde(default)]
     pub placements: Vec<(Placement, f32)>, // Placement and weight
+    #[serde(rename = "chance", default = "always")]
     pub inclusion_chance: f32,
+    #[serde(rename = "conjunction", default)]
     pub relation: Option<ClauseRelation>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Placement {
     Start,
     End,
     BeforeResolution,
+    /// SOW-047: a closing sentence of its own, after the core pair
+    Epilogue,
 }
 
 impl SentenceBuilder {
@@ -85,6 +97,7 @@ impl SentenceBuilder {
         // Attach satellites to appropriate sentences
         let mut satellites = self.satellites;
         satellites.shuffle(&mut rng);
+        let mut epilogue = Vec::new();
 
         for sat in satellites {
             if rng.random::<f32>() > sat.inclusion_chance {
@@ -137,42 +150,24 @@ impl SentenceBuilder {
                         }
                     };
                 },
+                Placement::Epilogue => {
+                    // Standalone closer: "I sweetened the deal."
+                    epilogue.push(Box::new(sat_structure));
+                },
             }
         }
 
         // Return multi-sentence structure
-        SentenceStructure::MultiSentence {
-            sentences: vec![Box::new(sentence1), Box::new(sentence2)],
-        }
+        let mut sentences = vec![Box::new(sentence1), Box::new(sentence2)];
+        sentences.extend(epilogue);
+        SentenceStructure::MultiSentence { sentences }
     }
 }
 
 impl Satellite {
-    pub fn new(role: NarrativeRole) -> Self {
-        Self {
-            role,
-            placements: Vec::new(),
-            inclusion_chance: 1.0,
-            relation: None,
-        }
-    }
-    
-    pub fn with_placement(mut self, placement: Placement, weight: f32) -> Self {
-        self.placements.push((placement, weight));
-        self
-    }
-    
-    pub fn optional(mut self, chance: f32) -> Self {
-        self.inclusion_chance = chance;
-        self
-    }
-    
-    /// Set the conjunction for sentence structure (e.g., "because", "although")
-    /// This does NOT filter fragments - all fragments of this role will be considered
-    pub fn with_conjunction(mut self, relation: ClauseRelation) -> Self {
-        self.relation = Some(relation);
-        self
-    }
+    // SOW-046: new / with_placement / optional / with_conjunction pruned -
+    // authored satellites (placements, chance, relation) are deserialized
+    // from narrative_patterns.ron, and tests parse RON the same way
     
     fn pick_placement(&self, rng: &mut impl Rng) -> Placement {
         if self.placements.is_empty() {
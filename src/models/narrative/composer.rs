@@ -2,10 +2,14 @@
 // Assembles narratives from patterns and card fragments
 
 use super::fragments::{SentenceStructure, FragmentSlot, NarrativeFragments, TaggedFragment, ClauseRelation, GrammaticalStructure};
-use super::patterns::{DynamicPattern, NarrativeRole};
+use super::patterns::{sort_by_priority, DynamicPattern, HandFacts, NarrativeRole};
+use super::session::{hand_record, session_buyer, Connective, HandBeat, SessionArc};
 use crate::models::card::{Card, CardType};
 use crate::models::buyer::BuyerScenario;
-use crate::models::hand_state::HandOutcome;
+use crate::models::card::Owner;
+use crate::models::hand_state::{HandOutcome, HandState};
+use crate::save::{Retirement, SaveData, StoryRecord};
+use crate::locale::{tr, tr_with};
 use rand::prelude::*;
 use bevy::prelude::Resource;
 
@@ -25,15 +29,22 @@ struct FragmentContext<'a> {
     product_card: Option<&'a Card>,
     location_card: Option<&'a Card>,
     evidence_cards: Vec<&'a Card>,
+    // SOW-047: the dramatic beats
+    cover_cards: Vec<&'a Card>,
+    modifier_cards: Vec<&'a Card>,
+    reaction_cards: &'a [Card],
+    insurance_card: Option<&'a Card>,
+    conviction_card: Option<&'a Card>,
 }
 
 impl<'a> FragmentContext<'a> {
     fn new(
         buyer_scenario: Option<&'a BuyerScenario>,
-        played_cards: &'a [Card],
+        facts: &HandFacts<'a>,
         outcome: HandOutcome,
         defaults: &'a NarrativeFragments
     ) -> Self {
+        let played_cards = facts.played_cards;
         // Find relevant cards once
         // For override card types (Product, Location), find the LAST one played (active after overrides)
         let product_card = played_cards.iter().rev().find(|c| matches!(c.card_type, CardType::Product { .. }));
@@ -42,6 +53,12 @@ impl<'a> FragmentContext<'a> {
         let evidence_cards: Vec<&Card> = played_cards.iter()
             .filter(|c| matches!(c.card_type, CardType::Evidence { .. }))
             .collect();
+        let cover_cards: Vec<&Card> = played_cards.iter()
+            .filter(|c| matches!(c.card_type, CardType::Cover { .. }))
+            .collect();
+        // Insurance and Conviction override like Product: the active one is the last
+        let insurance_card = played_cards.iter().rev().find(|c| matches!(c.card_type, CardType::Insurance { .. }));
+        let conviction_card = played_cards.iter().rev().find(|c| matches!(c.card_type, CardType::Conviction { .. }));
 
         Self {
             buyer_scenario,
@@ -50,6 +67,11 @@ impl<'a> FragmentContext<'a> {
             product_card,
             location_card,
             evidence_cards,
+            cover_cards,
+            modifier_cards: facts.player_modifiers().collect(),
+            reaction_cards: facts.buyer_reactions,
+            insurance_card,
+            conviction_card,
         }
     }
 
@@ -60,14 +82,168 @@ impl<'a> FragmentContext<'a> {
 }
 
 impl StoryComposer {
-    pub fn new(defaults: NarrativeFragments) -> Self {
-        Self {
-            patterns: DynamicPattern::create_all_patterns(),
-            defaults,
+    /// SOW-046: composer over authored patterns (validated by the loader),
+    /// tried in priority order
+    pub fn with_patterns(defaults: NarrativeFragments, mut patterns: Vec<DynamicPattern>) -> Self {
+        sort_by_priority(&mut patterns);
+        Self { patterns, defaults }
+    }
+
+    /// SOW-048: tell a resolved hand as part of its session - a connective
+    /// opener from the hands before it, then the hand's own story - and
+    /// record it (hand_story, session_stories, session_arc)
+    pub fn narrate_hand(&self, hand_state: &mut HandState) -> String {
+        let story = self.compose_story_from_hand(hand_state);
+        let story = match self.session_opener(hand_state) {
+            Some(opener) => format!("{} {}", opener, story),
+            None => story,
+        };
+        // SOW-061: an operation opens the story and has the last word
+        let story = match &hand_state.operation {
+            Some(op) => op.frame(&story, hand_state.outcome),
+            None => story,
+        };
+        // SOW-050: the record reads the arc before this hand's beat joins it
+        let record = hand_record(hand_state, story.clone());
+        hand_state.session_arc.record(HandBeat::from_hand(hand_state));
+        hand_state.hand_story = Some(story.clone());
+        hand_state.session_stories.push(record);
+        story
+    }
+
+    /// SOW-050: the session's closing summary as a record for the dealer's
+    /// history (None for a session with no resolved hands)
+    pub fn compose_session_record(&self, hand_state: &HandState) -> Option<StoryRecord> {
+        let text = self.compose_session_summary(&hand_state.session_arc)?;
+        Some(hand_state.session_arc.summary_record(text, &hand_state.run_area))
+    }
+
+    /// SOW-048: the lead-in for this hand given the session so far (None for
+    /// a hand with nothing to connect to)
+    pub fn session_opener(&self, hand_state: &HandState) -> Option<String> {
+        let buyer = session_buyer(hand_state);
+        let regular_deals = hand_state.buyer_persona.as_ref()
+            .and_then(|persona| persona.regular.as_ref())
+            .map_or(0, |regular| regular.deals);
+        let connective = hand_state.session_arc.connective(buyer.as_deref(), regular_deals)?;
+
+        let openers = &self.defaults.session_openers;
+        let (list, fallback, buyer, count) = match &connective {
+            Connective::RegularBack { buyer } => (&openers.regular_back, "{buyer} was back again.", buyer.as_str(), 0),
+            Connective::ReturningBuyer { buyer } => (&openers.returning_buyer, "{buyer} came back for more.", buyer.as_str(), 0),
+            Connective::NearMissTwoAgo => (&openers.near_miss, "Two deals on from that close call, I was still looking over my shoulder.", "", 0),
+            Connective::SafeStreak { count } => (&openers.safe_streak, "After {count} clean deals in a row, I was feeling lucky.", "", *count),
+            Connective::RisingHeat => (&openers.rising_heat, "The heat kept climbing.", "", 0),
+        };
+        let template = list.choose(&mut rand::rng()).map_or(fallback, |s| s.as_str());
+        Some(Self::finalize_sentence(
+            template.replace("{buyer}", buyer).replace("{count}", &count_word(count as u32)),
+        ))
+    }
+
+    /// SOW-048: the closing paragraph for a session - how many deals, how
+    /// they went, where the heat ended up, the close calls and the take.
+    /// None for a session with no resolved hands.
+    pub fn compose_session_summary(&self, arc: &SessionArc) -> Option<String> {
+        let last = arc.beats.last()?;
+        let hands = arc.beats.len();
+        let count = |outcome: HandOutcome| arc.beats.iter().filter(|b| b.outcome == outcome).count();
+        let safe = count(HandOutcome::Safe);
+        let busted = count(HandOutcome::Busted);
+        let fell_through = hands - safe - busted;
+
+        let mut sentences = Vec::new();
+        let deals = if hands == 1 { "one deal".to_string() } else { format!("{} deals", count_word(hands as u32)) };
+        sentences.push(match &last.buyer {
+            Some(buyer) => format!("{} with {} that night", deals, buyer),
+            None => format!("{} that night", deals),
+        });
+
+        let mut tally = vec![match safe {
+            0 => "none went clean".to_string(),
+            n => format!("{} went clean", count_word(n as u32)),
+        }];
+        if fell_through > 0 {
+            tally.push(format!("{} fell through", count_word(fell_through as u32)));
         }
+        if busted > 0 {
+            tally.push(format!("{} ended in cuffs", count_word(busted as u32)));
+        }
+        sentences.push(match tally.len() {
+            1 => tally.remove(0),
+            _ => {
+                let tail = tally.pop().unwrap_or_default();
+                format!("{} and {}", tally.join(", "), tail)
+            }
+        });
+
+        sentences.push(if last.heat <= 0 {
+            "I kept it cool".to_string()
+        } else if last.heat >= super::session::HEAT_CLIMB_MIN * 2 {
+            format!("The heat climbed to {}", last.heat)
+        } else {
+            format!("The heat crept up to {}", last.heat)
+        });
+
+        match arc.near_misses() {
+            0 => {}
+            1 => sentences.push("There was one close call".to_string()),
+            n => sentences.push(format!("There were {} close calls", count_word(n as u32))),
+        }
+
+        let profit = arc.total_profit();
+        sentences.push(if last.outcome == HandOutcome::Busted {
+            "It ended with me in the back of a squad car".to_string()
+        } else if profit > 0 {
+            format!("I went home ${} richer", profit)
+        } else {
+            "I went home with nothing to show for it".to_string()
+        });
+
+        Some(sentences.into_iter().map(Self::finalize_sentence).collect::<Vec<_>>().join(" "))
     }
 
-    pub fn compose_story_from_hand(&self, hand_state: &crate::models::hand_state::HandState) -> String {
+    /// SOW-062: the kingpin's last word on an empire that cashed out - the
+    /// take, the reach, the time the crew did, and what they'll be
+    /// remembered for. Every sentence is a `retirement.*` locale string.
+    pub fn compose_retirement(&self, save: &SaveData, retirement: &Retirement) -> String {
+        let mut sentences = vec![if retirement.clean_cash > 0 {
+            tr_with("retirement.take", &[("cash", &retirement.clean_cash.to_string())])
+        } else {
+            tr("retirement.broke")
+        }];
+
+        let zones = save.account.unlocked_locations.len() as u32;
+        let crew = save.dealers.iter().filter(|d| !d.is_kingpin).count() as u32;
+        let zones = if zones == 1 {
+            tr("retirement.one_zone")
+        } else {
+            tr_with("retirement.zones", &[("count", &count_word(zones))])
+        };
+        sentences.push(match crew {
+            0 => tr_with("retirement.alone", &[("zones", &zones)]),
+            1 => tr_with("retirement.one_dealer", &[("zones", &zones)]),
+            n => tr_with("retirement.dealers", &[("zones", &zones), ("count", &count_word(n))]),
+        });
+
+        let convictions: u32 = save.dealers.iter().map(|d| d.prior_convictions).sum();
+        sentences.push(match convictions {
+            0 => tr("retirement.never_inside"),
+            1 => tr("retirement.one_conviction"),
+            n => tr_with("retirement.convictions", &[("count", &count_word(n))]),
+        });
+
+        sentences.push(match retirement.victories.as_slice() {
+            [] => tr("retirement.no_legend"),
+            [one] => tr_with("retirement.legend", &[("victory", one)]),
+            [rest @ .., last] => tr_with("retirement.legends", &[("victories", &rest.join(", ")), ("last", last)]),
+        });
+        sentences.push(tr("retirement.never_cuffed"));
+
+        sentences.into_iter().map(Self::finalize_sentence).collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn compose_story_from_hand(&self, hand_state: &HandState) -> String {
         let buyer_scenario = hand_state.buyer_persona.as_ref()
             .and_then(|persona| persona.active_scenario_index)
             .and_then(|index| hand_state.buyer_persona.as_ref()
@@ -75,18 +251,31 @@ impl StoryComposer {
 
         let outcome = hand_state.outcome.expect("HandState must have outcome set before composing story");
 
-        self.compose_story(buyer_scenario, &hand_state.cards_played, outcome)
+        // SOW-047: the whole hand - who played what, and how the bust check went
+        let facts = HandFacts {
+            has_buyer: buyer_scenario.is_some(),
+            played_cards: &hand_state.cards_played,
+            buyer_reactions: &hand_state.cards(Owner::Buyer).played,
+            bust_check: hand_state.bust_check,
+        };
+        self.compose_story_with(buyer_scenario, &facts, outcome)
     }
 
-    pub fn compose_story(&self, buyer_scenario: Option<&BuyerScenario>, played_cards: &[Card], outcome: HandOutcome) -> String {
+    pub fn compose_story_with(&self, buyer_scenario: Option<&BuyerScenario>, facts: &HandFacts, outcome: HandOutcome) -> String {
         // 1. Find best matching pattern
-        let pattern = self.match_pattern(buyer_scenario, played_cards, outcome);
+        let pattern = self.match_pattern(facts, outcome);
+        self.compose_with_pattern(pattern, buyer_scenario, facts, outcome)
+    }
+
+    /// SOW-049: tell the hand in a given pattern's shape, matched or not
+    /// (narrative-lint walks every pattern this way)
+    pub fn compose_with_pattern(&self, pattern: &DynamicPattern, buyer_scenario: Option<&BuyerScenario>, facts: &HandFacts, outcome: HandOutcome) -> String {
 
         // 2. Build dynamic structure
-        let structure = pattern.build_structure();
+        let structure = pattern.build_structure(facts);
 
         // 3. Build fragment context
-        let context = FragmentContext::new(buyer_scenario, played_cards, outcome, &self.defaults);
+        let context = FragmentContext::new(buyer_scenario, facts, outcome, &self.defaults);
 
         // 4. Recursively assemble sentence
         let sentence = self.assemble_structure(&structure, &context);
@@ -100,9 +289,12 @@ impl StoryComposer {
         }
     }
 
-    fn match_pattern(&self, buyer_scenario: Option<&BuyerScenario>, played_cards: &[Card], outcome: HandOutcome) -> &DynamicPattern {
-        let has_buyer = buyer_scenario.is_some();
+    /// The patterns this composer tells stories with, in priority order
+    pub fn patterns(&self) -> &[DynamicPattern] {
+        &self.patterns
+    }
 
+    fn match_pattern(&self, facts: &HandFacts, outcome: HandOutcome) -> &DynamicPattern {
         for pattern in &self.patterns {
             // Check if pattern matches this outcome
             if let Some(required_outcome) = pattern.required_outcome {
@@ -112,7 +304,7 @@ impl StoryComposer {
             }
 
             // Check if pattern matches cards
-            if pattern.matches(has_buyer, played_cards) {
+            if pattern.matches(facts) {
                 return pattern;
             }
         }
@@ -136,7 +328,7 @@ impl StoryComposer {
                 let c2 = self.assemble_structure(clause2, context);
                 // No comma for short, direct conjunctions (but, because, when)
                 // These flow better without interruption
-                format!("{} {} {}", c1, conjunction.as_str(), c2)
+                format!("{} {} {}", c1, conjunction.word(), c2)
             },
             SentenceStructure::ReversedComplex { subordinator, subordinate_clause, main_clause } => {
                 let sub = self.assemble_structure(subordinate_clause, context);
@@ -144,12 +336,9 @@ impl StoryComposer {
                 // Lowercase the main clause since it comes after comma
                 // EXCEPT for "I" (pronoun) which must stay capitalized
                 // "Although [sub], [main]" where main should start lowercase
-                let main_formatted = if main.starts_with("I ") {
-                    main
-                } else {
-                    Self::lowercase_first(main)
-                };
-                format!("{} {}, {}", subordinator.as_str(), sub, main_formatted)
+                // SOW-053: both rules come from the locale's grammar
+                let main_formatted = crate::locale::lowercase_first(main);
+                format!("{} {}, {}", subordinator.word(), sub, main_formatted)
             },
             SentenceStructure::Concatenated { clause1, clause2 } => {
                 let c1 = self.assemble_structure(clause1, context);
@@ -159,14 +348,8 @@ impl StoryComposer {
                 // Special case: "I" stays capitalized but still gets comma
                 let first_char = c2.chars().next();
                 if first_char.map_or(false, |c| c.is_uppercase()) {
-                    if c2.starts_with("I ") {
-                        // Keep "I" capitalized but add comma
-                        format!("{}, {}", c1, c2)
-                    } else {
-                        // Lowercase other uppercase starts
-                        let c2_lower = Self::lowercase_first(c2);
-                        format!("{}, {}", c1, c2_lower)
-                    }
+                    // Lowercase uppercase starts (a keep-case "I" stays)
+                    format!("{}, {}", c1, crate::locale::lowercase_first(c2))
                 } else {
                     // Lowercase start - just append with space (true prepositional)
                     format!("{} {}", c1, c2)
@@ -199,7 +382,7 @@ impl StoryComposer {
                     .and_then(|frags| Self::random_from_tagged_list(&frags.subject_clauses, relation_filter, structure_filter))
                     .or_else(|| context.buyer_scenario.map(|b| b.display_name.clone()))
                     .or_else(|| Self::random_from_tagged_list(&context.defaults.subject_clauses, relation_filter, structure_filter))
-                    .unwrap_or_else(|| "A mysterious buyer".to_string())
+                    .unwrap_or_else(|| role.fallback_line().to_string())
             },
             NarrativeRole::BuyerNeed => {
                 // Try buyer-specific need fragments first, then defaults
@@ -207,7 +390,7 @@ impl StoryComposer {
                     .and_then(|scenario| scenario.narrative_fragments.as_ref())
                     .and_then(|frags| Self::random_from_tagged_list(&frags.need_clauses, relation_filter, structure_filter))
                     .or_else(|| Self::random_from_tagged_list(&context.defaults.need_clauses, relation_filter, structure_filter))
-                    .unwrap_or_else(|| "needed something".to_string())
+                    .unwrap_or_else(|| role.fallback_line().to_string())
             },
             NarrativeRole::Product => {
                 // Try product card fragments first, then defaults
@@ -215,7 +398,7 @@ impl StoryComposer {
                     .and_then(|c| c.narrative_fragments.as_ref())
                     .and_then(|f| Self::random_from_tagged_list(&f.product_clauses, relation_filter, structure_filter))
                     .or_else(|| Self::random_from_tagged_list(&context.defaults.product_clauses, relation_filter, structure_filter))
-                    .unwrap_or_else(|| "I had the goods".to_string())
+                    .unwrap_or_else(|| role.fallback_line().to_string())
             },
             NarrativeRole::Location => {
                 // Try location card fragments first, then defaults
@@ -223,7 +406,7 @@ impl StoryComposer {
                     .and_then(|c| c.narrative_fragments.as_ref())
                     .and_then(|f| Self::random_from_tagged_list(&f.location_clauses, relation_filter, structure_filter))
                     .or_else(|| Self::random_from_tagged_list(&context.defaults.location_clauses, relation_filter, structure_filter))
-                    .unwrap_or_else(|| "at the spot".to_string())
+                    .unwrap_or_else(|| role.fallback_line().to_string())
             },
             NarrativeRole::Evidence => {
                 // Try evidence card fragments first, then defaults
@@ -231,11 +414,47 @@ impl StoryComposer {
                     .and_then(|c| c.narrative_fragments.as_ref())
                     .and_then(|f| Self::random_from_tagged_list(&f.evidence_clauses, relation_filter, structure_filter))
                     .or_else(|| Self::random_from_tagged_list(&context.defaults.evidence_clauses, relation_filter, structure_filter))
-                    .unwrap_or_else(|| "things got heated".to_string())
+                    .unwrap_or_else(|| role.fallback_line().to_string())
             },
             NarrativeRole::Resolution => {
                 context.get_resolution_clause()
             },
+            // SOW-047: card fragments first, then defaults, then a plain line
+            NarrativeRole::Cover => {
+                context.cover_cards.choose(&mut rand::rng())
+                    .and_then(|c| c.narrative_fragments.as_ref())
+                    .and_then(|f| Self::random_from_tagged_list(&f.cover_clauses, relation_filter, structure_filter))
+                    .or_else(|| Self::random_from_tagged_list(&context.defaults.cover_clauses, relation_filter, structure_filter))
+                    .unwrap_or_else(|| role.fallback_line().to_string())
+            },
+            NarrativeRole::Modifier => {
+                context.modifier_cards.choose(&mut rand::rng())
+                    .and_then(|c| c.narrative_fragments.as_ref())
+                    .and_then(|f| Self::random_from_tagged_list(&f.modifier_clauses, relation_filter, structure_filter))
+                    .or_else(|| Self::random_from_tagged_list(&context.defaults.modifier_clauses, relation_filter, structure_filter))
+                    .unwrap_or_else(|| role.fallback_line().to_string())
+            },
+            NarrativeRole::BuyerReaction => {
+                context.reaction_cards.choose(&mut rand::rng())
+                    .and_then(|c| c.narrative_fragments.as_ref())
+                    .and_then(|f| Self::random_from_tagged_list(&f.reaction_clauses, relation_filter, structure_filter))
+                    .or_else(|| Self::random_from_tagged_list(&context.defaults.reaction_clauses, relation_filter, structure_filter))
+                    .unwrap_or_else(|| role.fallback_line().to_string())
+            },
+            NarrativeRole::InsurancePayout => {
+                context.insurance_card
+                    .and_then(|c| c.narrative_fragments.as_ref())
+                    .and_then(|f| Self::random_from_tagged_list(&f.insurance_clauses, relation_filter, structure_filter))
+                    .or_else(|| Self::random_from_tagged_list(&context.defaults.insurance_clauses, relation_filter, structure_filter))
+                    .unwrap_or_else(|| role.fallback_line().to_string())
+            },
+            NarrativeRole::ConvictionOverride => {
+                context.conviction_card
+                    .and_then(|c| c.narrative_fragments.as_ref())
+                    .and_then(|f| Self::random_from_tagged_list(&f.conviction_clauses, relation_filter, structure_filter))
+                    .or_else(|| Self::random_from_tagged_list(&context.defaults.conviction_clauses, relation_filter, structure_filter))
+                    .unwrap_or_else(|| role.fallback_line().to_string())
+            },
         }
     }
 
@@ -274,24 +493,260 @@ impl StoryComposer {
         list.choose(&mut rand::rng()).map(|f| f.text.clone())
     }
 
-    fn finalize_sentence(mut sentence: String) -> String {
-        if let Some(first_char) = sentence.chars().next() {
-            let capitalized = first_char.to_uppercase().to_string();
-            sentence.replace_range(..1, &capitalized);
-        }
-        if !sentence.ends_with('.') {
-            sentence.push('.');
-        }
-        sentence
+    /// SOW-053: capitalisation and the terminator follow the locale
+    pub(super) fn finalize_sentence(sentence: String) -> String {
+        crate::locale::finalize_sentence(sentence)
     }
+}
 
-    fn lowercase_first(s: String) -> String {
-        if let Some(first_char) = s.chars().next() {
-            let mut result = first_char.to_lowercase().to_string();
-            result.push_str(&s[first_char.len_utf8()..]);
-            result
-        } else {
-            s
+/// Small counts read as words in prose ("two deals"), bigger ones as digits
+pub(super) fn count_word(n: u32) -> String {
+    const WORDS: [&str; 11] = [
+        "count.0", "count.1", "count.2", "count.3", "count.4", "count.5",
+        "count.6", "count.7", "count.8", "count.9", "count.10",
+    ];
+    WORDS.get(n as usize).map_or_else(|| n.to_string(), |key| tr(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::hand_state::BustCheck;
+    use crate::models::narrative::patterns::parse_patterns;
+    use crate::models::test_helpers::*;
+
+    /// The composer the loader builds when no narrative_patterns.ron ships
+    fn shipped(defaults: NarrativeFragments) -> StoryComposer {
+        StoryComposer::with_patterns(defaults, DynamicPattern::shipped_patterns())
+    }
+
+    /// A resolved hand with the mock buyer (active scenario) at the table
+    fn resolved(cards: Vec<Card>, outcome: HandOutcome, bust_check: Option<BustCheck>) -> HandState {
+        HandState {
+            buyer_persona: Some(create_mock_buyer_persona()),
+            cards_played: cards,
+            outcome: Some(outcome),
+            bust_check,
+            ..Default::default()
         }
     }
+
+    fn with_fragments(mut card: Card, fragments: NarrativeFragments) -> Card {
+        card.narrative_fragments = Some(fragments);
+        card
+    }
+
+    #[test]
+    fn insurance_payout_closes_the_story() {
+        let composer = shipped(NarrativeFragments::default());
+        let hs = resolved(
+            vec![
+                create_product("Weed", 30, 0),
+                create_location("Alley", 30, 10, 0),
+                create_insurance("Plea Bargain", 5, 1000, 20),
+            ],
+            HandOutcome::Safe,
+            Some(BustCheck::InsurancePaid),
+        );
+        let story = composer.compose_story_from_hand(&hs);
+        assert!(story.ends_with("The lawyer's plea deal held, but it cost me."), "{story}");
+
+        // The same table without the payout is just a deal
+        let plain = resolved(hs.cards_played.clone(), HandOutcome::Safe, None);
+        assert!(!composer.compose_story_from_hand(&plain).contains("lawyer"));
+    }
+
+    #[test]
+    fn conviction_override_uses_the_card_fragment() {
+        let fragments = NarrativeFragments {
+            conviction_clauses: vec![TaggedFragment::any("the DA had already signed off")],
+            ..Default::default()
+        };
+        let composer = shipped(NarrativeFragments::default());
+        let hs = resolved(
+            vec![
+                create_product("Weed", 30, 0),
+                create_location("Alley", 30, 10, 0),
+                with_fragments(create_conviction("DA Approval", 60), fragments),
+            ],
+            HandOutcome::Busted,
+            Some(BustCheck::ConvictionOverrode),
+        );
+        let story = composer.compose_story_from_hand(&hs);
+        assert!(story.ends_with("The DA had already signed off."), "{story}");
+    }
+
+    #[test]
+    fn cover_explains_a_deal_the_narc_was_on() {
+        let fragments = NarrativeFragments {
+            cover_clauses: vec![TaggedFragment::any("my alibi held up")],
+            ..Default::default()
+        };
+        let composer = shipped(NarrativeFragments::default());
+        let hs = resolved(
+            vec![
+                create_product("Weed", 30, 0),
+                create_location("Alley", 10, 10, 0),
+                create_evidence("Stakeout", 15, 0),
+                with_fragments(create_cover("Alibi", 10, 0), fragments),
+            ],
+            HandOutcome::Safe,
+            None,
+        );
+        let story = composer.compose_story_from_hand(&hs);
+        assert!(story.contains("because my alibi held up"), "{story}");
+    }
+
+    #[test]
+    fn buyer_reactions_are_told_apart_from_the_dealers_modifiers() {
+        let patterns = parse_patterns(
+            r#"[
+                (
+                    id: "told",
+                    priority: 10,
+                    required_cards: [BuyerSubject],
+                    satellites: [
+                        (role: BuyerReaction, placements: [(Epilogue, 1.0)]),
+                        (role: Modifier, placements: [(Epilogue, 1.0)]),
+                    ],
+                ),
+            ]"#,
+        )
+        .unwrap();
+        let defaults = NarrativeFragments {
+            reaction_clauses: vec![TaggedFragment::any("she haggled me down")],
+            modifier_clauses: vec![TaggedFragment::any("I cut the batch")],
+            ..Default::default()
+        };
+        let composer = StoryComposer::with_patterns(defaults, patterns);
+
+        let haggle = create_buyer_modifier("Haggle", 0.8, 0, 0, 0);
+        let mut hs = resolved(
+            vec![create_product("Weed", 30, 0), create_location("Alley", 0, 0, 0), haggle.clone()],
+            HandOutcome::Safe,
+            None,
+        );
+        hs.cards_mut(Owner::Buyer).played.push(haggle);
+
+        // Only the buyer played a modifier: no dealer modifier line
+        let story = composer.compose_story_from_hand(&hs);
+        assert!(story.contains("She haggled me down."), "{story}");
+        assert!(!story.contains("I cut the batch"), "{story}");
+        // No reaction this hand: the beat is left out, not made up
+        let quiet = resolved(hs.cards_played.clone(), HandOutcome::Safe, None);
+        assert!(!composer.compose_story_from_hand(&quiet).contains("haggled"));
+
+        hs.cards_played.push(create_deal_modifier("Cut It", 1.2, 0, 0, 5));
+        let story = composer.compose_story_from_hand(&hs);
+        assert!(story.contains("She haggled me down.") && story.contains("I cut the batch."), "{story}");
+    }
+
+    #[test]
+    fn narrated_hands_lead_in_from_the_session() {
+        let composer = shipped(NarrativeFragments::default());
+        let table = vec![create_product("Weed", 30, 0), create_location("Alley", 0, 10, 0)];
+        let mut hs = resolved(table, HandOutcome::Safe, None);
+
+        // Nothing to connect the first hand to
+        let first = composer.narrate_hand(&mut hs);
+        assert_eq!(hs.hand_story.as_deref(), Some(first.as_str()));
+        assert_eq!(hs.session_arc.beats.len(), 1);
+
+        // Same buyer, second hand: they came back
+        let second = composer.narrate_hand(&mut hs);
+        assert!(second.starts_with("Test Buyer came back for more. "), "{second}");
+        let told: Vec<&str> = hs.session_stories.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(told, vec![first.as_str(), second.as_str()]);
+
+        // Authored openers win over the fallback, placeholders filled
+        let mut defaults = NarrativeFragments::default();
+        defaults.session_openers.safe_streak = vec!["{count} clean ones down, I pushed my luck.".to_string()];
+        let authored = shipped(defaults);
+        let third = authored.narrate_hand(&mut hs);
+        assert!(third.starts_with("Two clean ones down, I pushed my luck. "), "{third}");
+    }
+
+    #[test]
+    fn session_summary_tallies_the_night() {
+        let composer = shipped(NarrativeFragments::default());
+        let mut arc = SessionArc::default();
+        assert_eq!(composer.compose_session_summary(&arc), None);
+
+        let karen = Some("Karen".to_string());
+        arc.record(HandBeat { buyer: karen.clone(), outcome: HandOutcome::Safe, heat: 10, profit: 30, near_miss: true });
+        arc.record(HandBeat { buyer: karen.clone(), outcome: HandOutcome::Folded, heat: 25, profit: 0, near_miss: false });
+        assert_eq!(
+            composer.compose_session_summary(&arc).unwrap(),
+            "Two deals with Karen that night. One went clean and one fell through. \
+             The heat climbed to 25. There was one close call. I went home $30 richer."
+        );
+
+        arc.record(HandBeat { buyer: karen, outcome: HandOutcome::Busted, heat: 40, profit: 0, near_miss: false });
+        let busted = composer.compose_session_summary(&arc).unwrap();
+        assert!(busted.contains("One went clean, one fell through and one ended in cuffs."), "{busted}");
+        assert!(busted.ends_with("It ended with me in the back of a squad car."), "{busted}");
+    }
+
+    #[test]
+    fn session_record_carries_the_take_and_the_heat() {
+        let composer = shipped(NarrativeFragments::default());
+        let mut hs = HandState::default();
+        assert!(composer.compose_session_record(&hs).is_none());
+
+        hs.session_arc.record(HandBeat { buyer: None, outcome: HandOutcome::Safe, heat: 12, profit: 45, near_miss: false });
+        let record = composer.compose_session_record(&hs).unwrap();
+        assert_eq!(record.outcome, None, "a summary isn't a hand");
+        assert_eq!(record.area, hs.run_area);
+        assert_eq!(record.profit, 45);
+        assert_eq!(record.heat_delta, 12);
+        assert!(record.text.starts_with("One deal that night."), "{}", record.text);
+    }
+
+    #[test]
+    fn retirement_tells_the_take_the_reach_and_the_legend() {
+        let composer = shipped(NarrativeFragments::default());
+        let mut save = SaveData::new();
+        save.account.unlocked_locations.insert("suburbia".to_string());
+        save.dealers.push(crate::save::DealerState::zone_dealer("suburbia", "Slim", "Gladys"));
+        save.dealers[1].prior_convictions = 2;
+        let retirement = Retirement {
+            score: 9_000,
+            clean_cash: 4_000,
+            victories: vec!["Own the City".to_string(), "Clean Money".to_string()],
+        };
+        let told = |sentences: Vec<String>| {
+            sentences.into_iter().map(StoryComposer::finalize_sentence).collect::<Vec<_>>().join(" ")
+        };
+        let zones = tr_with("retirement.zones", &[("count", &count_word(2))]);
+        assert_eq!(
+            composer.compose_retirement(&save, &retirement),
+            told(vec![
+                tr_with("retirement.take", &[("cash", "4000")]),
+                tr_with("retirement.one_dealer", &[("zones", &zones)]),
+                tr_with("retirement.convictions", &[("count", &count_word(2))]),
+                tr_with("retirement.legends", &[("victories", "Own the City"), ("last", "Clean Money")]),
+                tr("retirement.never_cuffed"),
+            ])
+        );
+
+        assert_eq!(
+            composer.compose_retirement(&SaveData::new(), &Retirement::default()),
+            told(vec![
+                tr("retirement.broke"),
+                tr_with("retirement.alone", &[("zones", &tr("retirement.one_zone"))]),
+                tr("retirement.never_inside"),
+                tr("retirement.no_legend"),
+                tr("retirement.never_cuffed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn retirement_is_told_in_the_active_locale() {
+        use crate::locale::{scoped, Locale};
+        let composer = shipped(NarrativeFragments::default());
+        let text = scoped(Locale::pseudo(), || composer.compose_retirement(&SaveData::new(), &Retirement::default()));
+        assert!(text.starts_with("[Î wálkéd"), "{text}");
+        assert_eq!(text.matches("]. [").count(), 4, "every sentence is a table string: {text}");
+    }
 }
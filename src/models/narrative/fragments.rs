@@ -14,7 +14,8 @@ pub enum GrammaticalStructure {
 }
 
 /// Clause relation - semantic relationship (embedded in text)
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// SOW-053: Eq + Hash so a locale's grammar can key its words by relation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ClauseRelation {
     // Coordinating (connect equal clauses)
     And,          // Addition/sequence
@@ -34,21 +35,25 @@ pub enum ClauseRelation {
 }
 
 impl ClauseRelation {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::And => "and",
-            Self::But => "but",
-            Self::So => "so",
-            Self::Still => "still",
-            Self::Although => ["although", "even though"].choose(&mut rand::rng()).unwrap(),
-            Self::Because => "because",
-            Self::When => "when",
-            Self::While => "while",
-            Self::Since => "since",
-            Self::After => "after",
-            Self::Before => "before",
-            Self::If => "if",
-        }
+    pub const ALL: [Self; 12] = [
+        Self::And,
+        Self::But,
+        Self::So,
+        Self::Still,
+        Self::Although,
+        Self::Because,
+        Self::When,
+        Self::While,
+        Self::Since,
+        Self::After,
+        Self::Before,
+        Self::If,
+    ];
+
+    /// SOW-053: the joining word comes from the active locale's grammar
+    /// (English: "although" picks between "although" and "even though")
+    pub fn word(&self) -> String {
+        crate::locale::conjunction(*self)
     }
 }
 
@@ -96,12 +101,69 @@ pub struct NarrativeFragments {
     pub location_clauses: Vec<TaggedFragment>,     // "at the park", "in my safe house"
     #[serde(default)]
     pub evidence_clauses: Vec<TaggedFragment>,     // "the cops tapped my lines", "someone dropped a dime" (from Evidence cards)
+    // SOW-047: the dramatic beats
+    #[serde(default)]
+    pub cover_clauses: Vec<TaggedFragment>,        // "my alibi held up" (from Cover cards)
+    #[serde(default)]
+    pub modifier_clauses: Vec<TaggedFragment>,     // "I cut it to stretch the batch" (from DealModifier cards)
+    #[serde(default)]
+    pub reaction_clauses: Vec<TaggedFragment>,     // "she haggled me down" (from the buyer's reaction cards)
+    #[serde(default)]
+    pub insurance_clauses: Vec<TaggedFragment>,    // "the lawyer got me off, for a price" (from Insurance cards)
+    #[serde(default)]
+    pub conviction_clauses: Vec<TaggedFragment>,   // "the warrant had my name on it" (from Conviction cards)
 
     // RESOLUTION CLAUSES (outcome-specific endings)
     #[serde(default)]
     pub resolution_clauses: ResolutionClauses,
+
+    // SOW-048: SESSION OPENERS (lead a hand's story in from the ones before it)
+    #[serde(default)]
+    pub session_openers: SessionOpeners,
 }
 
+impl NarrativeFragments {
+    /// SOW-049: the tagged list a role draws from (Resolution has its own
+    /// per-outcome lists - see ResolutionClauses)
+    pub fn clauses_for(&self, role: super::patterns::NarrativeRole) -> &[TaggedFragment] {
+        use super::patterns::NarrativeRole;
+        match role {
+            NarrativeRole::BuyerSubject => &self.subject_clauses,
+            NarrativeRole::BuyerNeed => &self.need_clauses,
+            NarrativeRole::Product => &self.product_clauses,
+            NarrativeRole::Location => &self.location_clauses,
+            NarrativeRole::Evidence => &self.evidence_clauses,
+            NarrativeRole::Resolution => &[],
+            NarrativeRole::Cover => &self.cover_clauses,
+            NarrativeRole::Modifier => &self.modifier_clauses,
+            NarrativeRole::BuyerReaction => &self.reaction_clauses,
+            NarrativeRole::InsurancePayout => &self.insurance_clauses,
+            NarrativeRole::ConvictionOverride => &self.conviction_clauses,
+        }
+    }
+}
+
+/// SOW-048: connective openers, one list per session arc beat. `{buyer}`
+/// is replaced with the buyer's name, `{count}` with the streak length
+/// ("two", "three", ...).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionOpeners {
+    #[serde(default)]
+    pub regular_back: Vec<String>,     // "{buyer} was back again."
+    #[serde(default)]
+    pub returning_buyer: Vec<String>,  // "{buyer} came back for more."
+    #[serde(default)]
+    pub near_miss: Vec<String>,        // "Two deals on from that close call, I was still jumpy."
+    #[serde(default)]
+    pub safe_streak: Vec<String>,      // "After {count} clean deals in a row, I got cocky."
+    #[serde(default)]
+    pub rising_heat: Vec<String>,      // "The heat kept climbing."
+}
+
+
+/// What a story resolves with when the outcome's clause list is empty
+/// (narrative-lint reports those lists)
+pub const RESOLUTION_FALLBACK: &str = "something happened";
 
 /// Resolution clauses for each outcome type (externalized from hard-coded strings)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -119,19 +181,23 @@ pub struct ResolutionClauses {
 }
 
 impl ResolutionClauses {
-    /// Get random resolution clause for the given outcome
-    pub fn get_random(&self, outcome: HandOutcome) -> String {
-        let list = match outcome {
+    /// The clause list for an outcome
+    pub fn for_outcome(&self, outcome: HandOutcome) -> &[String] {
+        match outcome {
             HandOutcome::Safe => &self.safe,
             HandOutcome::Busted => &self.busted,
             HandOutcome::Folded => &self.folded,
             HandOutcome::BuyerBailed => &self.buyer_bailed,
             HandOutcome::InvalidDeal => &self.invalid_deal,
-        };
+        }
+    }
 
-        list.choose(&mut rand::rng())
-            .map(|s| s.clone())
-            .unwrap_or_else(|| "something happened".to_string())
+    /// Get random resolution clause for the given outcome
+    pub fn get_random(&self, outcome: HandOutcome) -> String {
+        self.for_outcome(outcome)
+            .choose(&mut rand::rng())
+            .cloned()
+            .unwrap_or_else(|| RESOLUTION_FALLBACK.to_string())
     }
 }
 
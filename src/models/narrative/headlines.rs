@@ -0,0 +1,277 @@
+// SOW-052: Headlines - the empire's big moments, as the morning paper
+// tells them.
+//
+// The save layer queues newsworthy chronicle entries (SaveData::press_queue,
+// filtered by HeadlineKind::of); the hub's press system hands each one to
+// the HeadlineDesk, which writes a banner and a short article from the
+// headlines.ron fragments. Articles use the story engine's pieces: ledes and
+// details are TaggedFragments, and a detail's relation tag joins it onto
+// the lede the way a satellite joins the core sentence.
+
+use std::collections::HashMap;
+
+use bevy::prelude::Resource;
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::composer::{count_word, StoryComposer};
+use super::fragments::TaggedFragment;
+use crate::models::shop_location::ShopLocationDef;
+use crate::save::{ChronicleEntry, ChronicleEvent, Headline, HeadlineKind};
+
+/// The shipped headline set - used when assets/headlines.ron is absent
+const SHIPPED_HEADLINES: &str = include_str!("headlines.ron");
+
+/// One kind of news's fragments (see headlines.ron for the authoring notes)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeadlineFragments {
+    pub headlines: Vec<String>,
+    pub ledes: Vec<TaggedFragment>,
+    #[serde(default)]
+    pub details: Vec<TaggedFragment>,
+    #[serde(default)]
+    pub quotes: Vec<String>,
+}
+
+/// Writes front pages from chronicle entries
+#[derive(Resource, Debug, Clone)]
+pub struct HeadlineDesk {
+    fragments: HashMap<HeadlineKind, HeadlineFragments>,
+}
+
+/// The facts a front page can name, filled into `{placeholder}`s
+#[derive(Debug, Clone, Default, PartialEq)]
+struct NewsFacts {
+    dealer: String,
+    zone: String,
+    amount: String,
+    heat: String,
+    runs: String,
+    card: String,
+    revenue: String,
+}
+
+impl HeadlineKind {
+    /// The placeholders this kind's fragments may use
+    pub fn placeholders(self) -> &'static [&'static str] {
+        match self {
+            Self::KingpinFell => &["revenue"],
+            Self::InfernoBust | Self::DealerJailed => &["dealer", "runs", "heat"],
+            Self::MuscleSeized => &["zone", "amount"],
+            Self::MuscleBenched => &["zone", "dealer"],
+            Self::SupplierCutOff => &["zone"],
+            Self::SupplierSoured => &["zone", "card"],
+            Self::ZoneOpened => &["zone", "amount"],
+        }
+    }
+}
+
+impl NewsFacts {
+    fn of(event: &ChronicleEvent, areas: &[ShopLocationDef]) -> Self {
+        let zone = |id: &str| {
+            areas.iter().find(|a| a.id == id).map_or_else(|| id.to_string(), |a| a.name.clone())
+        };
+        let cash = |n: u64| crate::ui::view::format_cash(n);
+        let mut facts = Self::default();
+        match event {
+            ChronicleEvent::Fell { lifetime_revenue } => facts.revenue = cash(*lifetime_revenue),
+            ChronicleEvent::Jailed { dealer, runs, heat } => {
+                facts.dealer = dealer.clone();
+                facts.runs = format!("{} run{}", count_word(*runs), if *runs == 1 { "" } else { "s" });
+                facts.heat = heat.to_string();
+            }
+            ChronicleEvent::MuscleSeized { area_id, amount } => {
+                facts.zone = zone(area_id);
+                facts.amount = cash(*amount);
+            }
+            ChronicleEvent::MuscleBenched { area_id, dealer } => {
+                facts.zone = zone(area_id);
+                facts.dealer = dealer.clone();
+            }
+            ChronicleEvent::CutOff { area_id } => facts.zone = zone(area_id),
+            ChronicleEvent::Soured { area_id, card_id } => {
+                facts.zone = zone(area_id);
+                facts.card = card_id.clone();
+            }
+            ChronicleEvent::ZoneBought { area_id, price } => {
+                facts.zone = zone(area_id);
+                facts.amount = cash(*price);
+            }
+            ChronicleEvent::Hired { .. }
+            | ChronicleEvent::FrontTaken { .. }
+            | ChronicleEvent::FrontPaid { .. }
+            | ChronicleEvent::BailedOut { .. }
+            | ChronicleEvent::Operation { .. }
+            | ChronicleEvent::Retired { .. }
+            | ChronicleEvent::Succeeded { .. } => {}
+        }
+        facts
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{dealer}", &self.dealer)
+            .replace("{zone}", &self.zone)
+            .replace("{amount}", &self.amount)
+            .replace("{heat}", &self.heat)
+            .replace("{runs}", &self.runs)
+            .replace("{card}", &self.card)
+            .replace("{revenue}", &self.revenue)
+    }
+}
+
+/// Every `{name}` a fragment uses
+fn placeholders_in(text: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        found.push(&rest[open + 1..open + close]);
+        rest = &rest[open + close + 1..];
+    }
+    found
+}
+
+/// Parse a headlines.ron document
+pub fn parse_headlines(content: &str) -> Result<HashMap<HeadlineKind, HeadlineFragments>, String> {
+    ron::from_str(content).map_err(|e| format!("RON parse error: {e}"))
+}
+
+/// Load-time checks: every kind covered with a headline and a lede, and
+/// every placeholder one that kind can fill (an unfilled one would print
+/// as "{zone}" on the front page)
+pub fn validate_headlines(fragments: &HashMap<HeadlineKind, HeadlineFragments>) -> Result<(), String> {
+    for kind in HeadlineKind::ALL {
+        let Some(set) = fragments.get(&kind) else {
+            return Err(format!("no headlines for {kind:?}"));
+        };
+        if set.headlines.is_empty() || set.ledes.is_empty() {
+            return Err(format!("{kind:?} needs at least one headline and one lede"));
+        }
+        let texts = set
+            .headlines
+            .iter()
+            .chain(&set.quotes)
+            .map(String::as_str)
+            .chain(set.ledes.iter().chain(&set.details).map(|f| f.text.as_str()));
+        for text in texts {
+            if let Some(name) = placeholders_in(text).into_iter().find(|n| !kind.placeholders().contains(n)) {
+                return Err(format!("{kind:?}: '{{{name}}}' can't be filled in \"{text}\""));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl HeadlineDesk {
+    pub fn new(fragments: HashMap<HeadlineKind, HeadlineFragments>) -> Self {
+        Self { fragments }
+    }
+
+    /// The shipped set (headlines.ron compiled in)
+    pub fn shipped() -> Self {
+        Self::new(parse_headlines(SHIPPED_HEADLINES).expect("shipped headlines.ron must parse"))
+    }
+
+    /// Write up a chronicle entry. None for entries that aren't news.
+    pub fn compose(&self, entry: &ChronicleEntry, areas: &[ShopLocationDef]) -> Option<Headline> {
+        let kind = HeadlineKind::of(&entry.event)?;
+        let set = self.fragments.get(&kind)?;
+        let facts = NewsFacts::of(&entry.event, areas);
+        let mut rng = rand::rng();
+
+        let headline = set.headlines.choose(&mut rng).map(|h| facts.fill(h))?;
+        let lede = set.ledes.choose(&mut rng).map(|f| facts.fill(&f.text))?;
+        let mut sentences = Vec::new();
+        match set.details.choose(&mut rng) {
+            Some(detail) => match detail.relation {
+                Some(relation) => {
+                    sentences.push(format!("{}, {} {}", lede, relation.word(), facts.fill(&detail.text)));
+                }
+                None => {
+                    sentences.push(lede);
+                    sentences.push(facts.fill(&detail.text));
+                }
+            },
+            None => sentences.push(lede),
+        }
+        sentences.extend(set.quotes.choose(&mut rng).map(|q| facts.fill(q)));
+
+        Some(Headline {
+            at: entry.at,
+            kind,
+            headline,
+            article: sentences
+                .into_iter()
+                .map(StoryComposer::finalize_sentence)
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(event: ChronicleEvent) -> ChronicleEntry {
+        ChronicleEntry { at: 42, event }
+    }
+
+    #[test]
+    fn shipped_headlines_cover_every_kind() {
+        let fragments = parse_headlines(SHIPPED_HEADLINES).unwrap();
+        validate_headlines(&fragments).unwrap();
+    }
+
+    #[test]
+    fn desk_writes_up_news_and_skips_the_books() {
+        let desk = HeadlineDesk::shipped();
+        let jailed = entry(ChronicleEvent::Jailed { dealer: "Slim".to_string(), runs: 3, heat: 180 });
+        for _ in 0..20 {
+            let page = desk.compose(&jailed, &[]).unwrap();
+            assert_eq!(page.kind, HeadlineKind::InfernoBust);
+            assert_eq!(page.at, 42);
+            assert!(!page.headline.contains('{') && !page.article.contains('{'), "{page:?}");
+            assert!(page.article.ends_with('.'), "{}", page.article);
+        }
+        let hired = entry(ChronicleEvent::Hired { dealer: "Slim".to_string(), area_id: "x".to_string(), cost: 5 });
+        assert!(desk.compose(&hired, &[]).is_none());
+    }
+
+    #[test]
+    fn related_details_join_the_lede() {
+        let mut set = HeadlineFragments {
+            headlines: vec!["{zone} Opens".to_string()],
+            ledes: vec![TaggedFragment::any("a crew moved into {zone}")],
+            details: vec![TaggedFragment::full_clause(
+                "it cost {amount}",
+                Some(super::super::fragments::ClauseRelation::And),
+            )],
+            quotes: vec![],
+        };
+        let desk = HeadlineDesk::new(HashMap::from([(HeadlineKind::ZoneOpened, set.clone())]));
+        let bought = entry(ChronicleEvent::ZoneBought { area_id: "suburbia".to_string(), price: 1500 });
+        let page = desk.compose(&bought, &[]).unwrap();
+        assert_eq!(page.headline, "suburbia Opens");
+        assert_eq!(page.article, "A crew moved into suburbia, and it cost $1,500.");
+
+        set.details = vec![TaggedFragment::any("police are watching")];
+        let desk = HeadlineDesk::new(HashMap::from([(HeadlineKind::ZoneOpened, set)]));
+        let page = desk.compose(&bought, &[]).unwrap();
+        assert_eq!(page.article, "A crew moved into suburbia. Police are watching.");
+    }
+
+    #[test]
+    fn validation_catches_gaps_and_stray_placeholders() {
+        let mut fragments = parse_headlines(SHIPPED_HEADLINES).unwrap();
+        fragments.get_mut(&HeadlineKind::SupplierCutOff).unwrap().headlines.push("{dealer} Cut Off".to_string());
+        let err = validate_headlines(&fragments).unwrap_err();
+        assert!(err.contains("{dealer}"), "{err}");
+
+        fragments.remove(&HeadlineKind::ZoneOpened);
+        assert!(validate_headlines(&fragments).is_err());
+    }
+}
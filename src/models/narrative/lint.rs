@@ -0,0 +1,727 @@
+// SOW-049: Narrative lint - do the shipped fragments combine into
+// well-formed stories?
+//
+// Three passes over the story assets (see assets::loader::load_story_assets):
+//   1. coverage - every role a pattern can tell has something to say for
+//      every card/persona that can fill it; otherwise the composer falls
+//      back to a placeholder ("something happened", "at the spot", ...)
+//   2. tags - every fragment's relation/structure tag is selectable by some
+//      pattern's use of its role
+//   3. text - every pattern x outcome x persona scenario x product x
+//      location (x each card for the pattern's other roles) is composed and
+//      the story checked for double spaces, stray punctuation and words
+//      wrongly lowercased after a ReversedComplex opener
+//
+// Usage (no Bevy App is built - it prints the report and exits):
+//   cargo run -- narrative-lint [--assets <dir>] [--samples <n>]
+// Exit code 1 when there are errors, so CI can gate on it.
+
+use std::collections::BTreeMap;
+
+use super::builder::Placement;
+use super::fragments::{ClauseRelation, GrammaticalStructure, NarrativeFragments, TaggedFragment};
+use super::patterns::{DynamicPattern, HandFacts, NarrativeRole};
+use super::StoryComposer;
+use crate::models::buyer::{BuyerPersona, BuyerScenario};
+use crate::models::card::{Card, CardType};
+use crate::models::hand_state::{BustCheck, HandOutcome};
+
+/// Stories composed per combination by default - patterns pick templates,
+/// placements and fragments at random, so one sample misses shapes
+pub const DEFAULT_SAMPLES: usize = 3;
+
+const OUTCOMES: [HandOutcome; 5] = [
+    HandOutcome::Safe,
+    HandOutcome::Busted,
+    HandOutcome::Folded,
+    HandOutcome::BuyerBailed,
+    HandOutcome::InvalidDeal,
+];
+
+/// Roles every story's core sentence tells
+const CORE_ROLES: [NarrativeRole; 3] = [NarrativeRole::BuyerSubject, NarrativeRole::BuyerNeed, NarrativeRole::Product];
+
+/// Sentence-initial words that are fine lowercased after "although ..., "
+const COMMON_OPENERS: [&str; 16] = [
+    "A", "An", "The", "My", "Our", "Your", "His", "Her", "Their", "This", "That", "Some", "One", "Every",
+    "No", "We",
+];
+
+/// Everything stories are made of, loaded without a Bevy App
+#[derive(Debug, Clone, Default)]
+pub struct StoryAssets {
+    pub defaults: NarrativeFragments,
+    pub products: Vec<Card>,
+    pub locations: Vec<Card>,
+    /// Evidence and Conviction cards
+    pub narc_cards: Vec<Card>,
+    pub cover: Vec<Card>,
+    pub insurance: Vec<Card>,
+    pub modifiers: Vec<Card>,
+    /// With reaction decks resolved
+    pub buyers: Vec<BuyerPersona>,
+    pub patterns: Vec<DynamicPattern>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintKind {
+    /// A story would fall back to the composer's placeholder line
+    EmptyFallback,
+    DoubleSpace,
+    /// "i" or a name lowercased mid-sentence, or a sentence starting lowercase
+    BadCapitalisation,
+    /// " ,", "..", ",." and friends
+    StrayPunctuation,
+    /// A relation/structure tag no pattern ever asks for
+    UnselectableTag,
+    /// A card or scenario with no fragments of its own (uses the defaults)
+    MissingFragments,
+}
+
+impl LintKind {
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::UnselectableTag | Self::MissingFragments => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::EmptyFallback => "empty-fallback",
+            Self::DoubleSpace => "double-space",
+            Self::BadCapitalisation => "capitalisation",
+            Self::StrayPunctuation => "punctuation",
+            Self::UnselectableTag => "unselectable-tag",
+            Self::MissingFragments => "missing-fragments",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LintIssue {
+    pub kind: LintKind,
+    /// Where: a fragment list, card, scenario or pattern
+    pub subject: String,
+    pub message: String,
+}
+
+/// How often an issue came up, and the first story that showed it
+#[derive(Debug, Clone, Default)]
+pub struct Finding {
+    pub count: usize,
+    pub example: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct LintReport {
+    /// Deduplicated, errors first
+    pub issues: BTreeMap<LintIssue, Finding>,
+    pub stories_checked: usize,
+}
+
+impl LintReport {
+    fn push(&mut self, kind: LintKind, subject: impl Into<String>, message: impl Into<String>, example: Option<&str>) {
+        let finding = self
+            .issues
+            .entry(LintIssue { kind, subject: subject.into(), message: message.into() })
+            .or_default();
+        finding.count += 1;
+        if finding.example.is_none() {
+            finding.example = example.map(str::to_string);
+        }
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.issues.keys().filter(|i| i.kind.severity() == severity).count()
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    /// Plain-text report, errors before warnings
+    pub fn render(&self) -> String {
+        let mut out = format!("narrative-lint: {} stories checked\n", self.stories_checked);
+        // LintKind orders errors before warnings
+        for (issue, finding) in &self.issues {
+            let level = match issue.kind.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            out.push_str(&format!("{level}[{}] {}: {}", issue.kind.label(), issue.subject, issue.message));
+            if finding.count > 1 {
+                out.push_str(&format!(" (x{})", finding.count));
+            }
+            out.push('\n');
+            if let Some(example) = &finding.example {
+                out.push_str(&format!("    e.g. {example}\n"));
+            }
+        }
+        out.push_str(&format!("{} error(s), {} warning(s)\n", self.errors(), self.warnings()));
+        out
+    }
+}
+
+/// The RON field a role's fragments live in
+fn list_name(role: NarrativeRole) -> &'static str {
+    match role {
+        NarrativeRole::BuyerSubject => "subject_clauses",
+        NarrativeRole::BuyerNeed => "need_clauses",
+        NarrativeRole::Product => "product_clauses",
+        NarrativeRole::Location => "location_clauses",
+        NarrativeRole::Evidence => "evidence_clauses",
+        NarrativeRole::Resolution => "resolution_clauses",
+        NarrativeRole::Cover => "cover_clauses",
+        NarrativeRole::Modifier => "modifier_clauses",
+        NarrativeRole::BuyerReaction => "reaction_clauses",
+        NarrativeRole::InsurancePayout => "insurance_clauses",
+        NarrativeRole::ConvictionOverride => "conviction_clauses",
+    }
+}
+
+/// Roles the patterns actually tell: the core, plus every satellite
+fn told_roles(patterns: &[DynamicPattern]) -> Vec<NarrativeRole> {
+    let mut roles = CORE_ROLES.to_vec();
+    for satellite in patterns.iter().flat_map(|p| &p.satellites) {
+        if !roles.contains(&satellite.role) {
+            roles.push(satellite.role);
+        }
+    }
+    roles
+}
+
+/// The cards a role's fragments come from (BuyerNeed/BuyerSubject come from
+/// scenarios, not cards)
+fn filler_cards(story: &StoryAssets, role: NarrativeRole) -> Vec<&Card> {
+    let narc = |conviction: bool| {
+        story
+            .narc_cards
+            .iter()
+            .filter(move |c| matches!(c.card_type, CardType::Conviction { .. }) == conviction)
+    };
+    match role {
+        NarrativeRole::Product => story.products.iter().collect(),
+        NarrativeRole::Location => story.locations.iter().collect(),
+        NarrativeRole::Evidence => narc(false).collect(),
+        NarrativeRole::ConvictionOverride => narc(true).collect(),
+        NarrativeRole::Cover => story.cover.iter().collect(),
+        NarrativeRole::Modifier => story.modifiers.iter().collect(),
+        NarrativeRole::InsurancePayout => story.insurance.iter().collect(),
+        NarrativeRole::BuyerReaction => {
+            let mut cards: Vec<&Card> = Vec::new();
+            for card in story.buyers.iter().flat_map(|b| &b.reaction_deck) {
+                if !cards.iter().any(|c| c.id == card.id) {
+                    cards.push(card);
+                }
+            }
+            cards
+        }
+        NarrativeRole::BuyerSubject | NarrativeRole::BuyerNeed | NarrativeRole::Resolution => Vec::new(),
+    }
+}
+
+fn scenarios<'a>(story: &'a StoryAssets) -> impl Iterator<Item = (&'a BuyerPersona, &'a BuyerScenario)> + 'a {
+    story.buyers.iter().flat_map(|b| b.scenarios.iter().map(move |s| (b, s)))
+}
+
+fn has_own(fragments: Option<&NarrativeFragments>, role: NarrativeRole) -> bool {
+    fragments.is_some_and(|f| !f.clauses_for(role).is_empty())
+}
+
+/// Pass 1: placeholders and cards without fragments of their own
+fn lint_coverage(story: &StoryAssets, report: &mut LintReport) {
+    for outcome in OUTCOMES {
+        if story.defaults.resolution_clauses.for_outcome(outcome).is_empty() {
+            report.push(
+                LintKind::EmptyFallback,
+                format!("narrative_defaults.resolution_clauses ({outcome:?})"),
+                format!("empty - every {outcome:?} story resolves \"{}\"", NarrativeRole::Resolution.fallback_line()),
+                None,
+            );
+        }
+    }
+
+    for role in told_roles(&story.patterns) {
+        // A scenario's display name always stands in for the subject
+        if role == NarrativeRole::BuyerSubject {
+            continue;
+        }
+        let defaults_empty = story.defaults.clauses_for(role).is_empty();
+        let fillers: Vec<(String, bool)> = if role == NarrativeRole::BuyerNeed {
+            scenarios(story)
+                .map(|(b, s)| {
+                    (format!("scenario '{} / {}'", b.display_name, s.display_name), has_own(s.narrative_fragments.as_ref(), role))
+                })
+                .collect()
+        } else {
+            filler_cards(story, role)
+                .into_iter()
+                .map(|c| (format!("card '{}'", c.name), has_own(c.narrative_fragments.as_ref(), role)))
+                .collect()
+        };
+
+        if defaults_empty && fillers.is_empty() {
+            report.push(
+                LintKind::EmptyFallback,
+                format!("narrative_defaults.{}", list_name(role)),
+                format!("empty and nothing else fills {role:?} - stories say \"{}\"", role.fallback_line()),
+                None,
+            );
+        }
+        for (subject, _) in fillers.into_iter().filter(|(_, own)| !own) {
+            if defaults_empty {
+                report.push(
+                    LintKind::EmptyFallback,
+                    subject,
+                    format!(
+                        "no {} and narrative_defaults has none - stories say \"{}\"",
+                        list_name(role),
+                        role.fallback_line()
+                    ),
+                    None,
+                );
+            } else {
+                report.push(
+                    LintKind::MissingFragments,
+                    subject,
+                    format!("no {} of its own (uses narrative_defaults)", list_name(role)),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// How a pattern uses a role's fragment slot
+#[derive(Debug, Clone, Copy)]
+struct Usage {
+    relation: Option<ClauseRelation>,
+    structure: Option<GrammaticalStructure>,
+    /// Core slots join with whatever the pattern's product_conjunction is
+    core: bool,
+}
+
+fn usages(patterns: &[DynamicPattern], role: NarrativeRole) -> Vec<Usage> {
+    let mut usages = Vec::new();
+    if CORE_ROLES.contains(&role) {
+        usages.push(Usage { relation: None, structure: None, core: true });
+    }
+    for satellite in patterns.iter().flat_map(|p| &p.satellites).filter(|s| s.role == role) {
+        // No placements means End (Satellite::pick_placement)
+        let placements: Vec<Placement> = if satellite.placements.is_empty() {
+            vec![Placement::End]
+        } else {
+            satellite.placements.iter().map(|(p, _)| *p).collect()
+        };
+        for placement in placements {
+            // Mirrors SentenceBuilder::build: only a conjunction-less,
+            // in-sentence satellite asks for a prepositional fragment
+            let structure = (satellite.relation.is_none() && placement != Placement::Epilogue)
+                .then_some(GrammaticalStructure::Prepositional);
+            usages.push(Usage { relation: satellite.relation, structure, core: false });
+        }
+    }
+    usages
+}
+
+fn selectable(fragment: &TaggedFragment, usages: &[Usage]) -> bool {
+    usages.iter().any(|u| {
+        let structure_ok = u.structure.is_none() || fragment.structure.is_none() || fragment.structure == u.structure;
+        let relation_ok = fragment.relation.is_none() || u.core || fragment.relation == u.relation;
+        structure_ok && relation_ok
+    })
+}
+
+/// Pass 2: tags nothing ever asks for
+fn lint_tags(story: &StoryAssets, report: &mut LintReport) {
+    let mut lists: Vec<(String, &NarrativeFragments)> = vec![("narrative_defaults".to_string(), &story.defaults)];
+    let cards = story
+        .products
+        .iter()
+        .chain(&story.locations)
+        .chain(&story.narc_cards)
+        .chain(&story.cover)
+        .chain(&story.insurance)
+        .chain(&story.modifiers);
+    for card in cards {
+        if let Some(fragments) = &card.narrative_fragments {
+            lists.push((format!("card '{}'", card.name), fragments));
+        }
+    }
+    for (buyer, scenario) in scenarios(story) {
+        if let Some(fragments) = &scenario.narrative_fragments {
+            lists.push((format!("scenario '{} / {}'", buyer.display_name, scenario.display_name), fragments));
+        }
+    }
+
+    let roles = [
+        NarrativeRole::BuyerSubject,
+        NarrativeRole::BuyerNeed,
+        NarrativeRole::Product,
+        NarrativeRole::Location,
+        NarrativeRole::Evidence,
+        NarrativeRole::Cover,
+        NarrativeRole::Modifier,
+        NarrativeRole::BuyerReaction,
+        NarrativeRole::InsurancePayout,
+        NarrativeRole::ConvictionOverride,
+    ];
+    for role in roles {
+        let usages = usages(&story.patterns, role);
+        for (owner, fragments) in &lists {
+            let list = fragments.clauses_for(role);
+            if list.is_empty() {
+                continue;
+            }
+            let subject = format!("{owner}.{}", list_name(role));
+            if usages.is_empty() {
+                report.push(
+                    LintKind::UnselectableTag,
+                    subject,
+                    format!("no pattern tells {role:?} - {} fragment(s) never used", list.len()),
+                    None,
+                );
+                continue;
+            }
+            for fragment in list.iter().filter(|f| !selectable(f, &usages)) {
+                report.push(
+                    LintKind::UnselectableTag,
+                    subject.clone(),
+                    format!(
+                        "\"{}\" (relation {:?}, structure {:?}) matches no pattern's use of {role:?}",
+                        fragment.text, fragment.relation, fragment.structure
+                    ),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// Name-like subjects that must keep their capital after "although ..., "
+fn proper_starts(story: &StoryAssets) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut add = |text: &str| {
+        let first = text.split_whitespace().next().unwrap_or("");
+        let capitalised = first.chars().next().is_some_and(char::is_uppercase);
+        if capitalised && first != "I" && !COMMON_OPENERS.contains(&first) && !names.iter().any(|n| n == text) {
+            names.push(text.to_string());
+        }
+    };
+    for (_, scenario) in scenarios(story) {
+        add(&scenario.display_name);
+        if let Some(fragments) = &scenario.narrative_fragments {
+            fragments.subject_clauses.iter().for_each(|f| add(&f.text));
+        }
+    }
+    story.defaults.subject_clauses.iter().for_each(|f| add(&f.text));
+    names
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Text checks on one composed story
+fn check_story(story: &str, names: &[String], subject: &str, report: &mut LintReport) {
+    if story.contains("  ") {
+        report.push(LintKind::DoubleSpace, subject, "double space in a composed story", Some(story));
+    }
+    for stray in [" ,", " .", ",,", "..", ",.", ".,"] {
+        if story.contains(stray) {
+            report.push(LintKind::StrayPunctuation, subject, format!("\"{stray}\" in a composed story"), Some(story));
+        }
+    }
+    if story.split(". ").any(|s| s.starts_with(char::is_lowercase)) {
+        report.push(LintKind::BadCapitalisation, subject, "sentence starts lowercase", Some(story));
+    }
+    if story.contains(" i ") || story.contains(" i'") {
+        report.push(LintKind::BadCapitalisation, subject, "\"I\" lowercased mid-sentence", Some(story));
+    }
+    for name in names {
+        if story.contains(&format!(", {}", lowercase_first(name))) {
+            report.push(
+                LintKind::BadCapitalisation,
+                subject,
+                format!("\"{name}\" lowercased after a ReversedComplex opener"),
+                Some(story),
+            );
+        }
+    }
+}
+
+/// The cards that can fill a non-core role on the table
+fn pool<'a>(story: &'a StoryAssets, buyer: &'a BuyerPersona, role: NarrativeRole) -> Vec<&'a Card> {
+    match role {
+        NarrativeRole::BuyerReaction => buyer.reaction_deck.iter().collect(),
+        _ => filler_cards(story, role),
+    }
+}
+
+/// Pass 3: compose every combination and check the text
+fn lint_stories(story: &StoryAssets, samples: usize, report: &mut LintReport) {
+    let composer = StoryComposer::with_patterns(story.defaults.clone(), story.patterns.clone());
+    let names = proper_starts(story);
+
+    for pattern in composer.patterns() {
+        let outcomes: Vec<HandOutcome> = match pattern.required_outcome {
+            Some(outcome) => vec![outcome],
+            None => OUTCOMES.to_vec(),
+        };
+        // The roles beyond product + location this pattern can tell
+        let mut extra: Vec<NarrativeRole> = Vec::new();
+        for role in pattern.required_cards.iter().copied().chain(pattern.satellites.iter().map(|s| s.role)) {
+            if !CORE_ROLES.contains(&role) && role != NarrativeRole::Location && !extra.contains(&role) {
+                extra.push(role);
+            }
+        }
+
+        for (buyer, scenario) in scenarios(story) {
+            let pools: Vec<Vec<&Card>> = extra.iter().map(|role| pool(story, buyer, *role)).collect();
+            // Every card of every extra role at least once: the first card of
+            // each pool, then each pool's other cards in turn
+            let firsts: Vec<Option<&Card>> = pools.iter().map(|p| p.first().copied()).collect();
+            let mut variants: Vec<Vec<&Card>> = vec![firsts.iter().flatten().copied().collect()];
+            for (i, p) in pools.iter().enumerate() {
+                for card in p.iter().skip(1) {
+                    let mut variant = firsts.clone();
+                    variant[i] = Some(*card);
+                    variants.push(variant.into_iter().flatten().collect());
+                }
+            }
+            let bust_check = if extra.contains(&NarrativeRole::ConvictionOverride) {
+                Some(BustCheck::ConvictionOverrode)
+            } else if extra.contains(&NarrativeRole::InsurancePayout) {
+                Some(BustCheck::InsurancePaid)
+            } else {
+                None
+            };
+
+            for product in &story.products {
+                for location in &story.locations {
+                    for variant in &variants {
+                        let mut played = vec![product.clone(), location.clone()];
+                        played.extend(variant.iter().map(|c| (*c).clone()));
+                        let reactions: Vec<Card> = variant
+                            .iter()
+                            .filter(|c| buyer.reaction_deck.iter().any(|r| r.id == c.id))
+                            .map(|c| (*c).clone())
+                            .collect();
+                        let facts = HandFacts {
+                            has_buyer: true,
+                            played_cards: &played,
+                            buyer_reactions: &reactions,
+                            bust_check,
+                        };
+                        for &outcome in &outcomes {
+                            let subject = format!("pattern '{}' ({outcome:?})", pattern.id);
+                            for _ in 0..samples {
+                                let text = composer.compose_with_pattern(pattern, Some(scenario), &facts, outcome);
+                                check_story(&text, &names, &subject, report);
+                                report.stories_checked += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Run every pass
+pub fn lint_narrative(story: &StoryAssets, samples: usize) -> LintReport {
+    let mut report = LintReport::default();
+    lint_coverage(story, &mut report);
+    lint_tags(story, &mut report);
+    lint_stories(story, samples, &mut report);
+    report
+}
+
+/// CLI entry: parse `[--assets <dir>] [--samples <n>]`, print the report
+pub fn run_cli(args: &[String]) {
+    let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1));
+    let root = flag("--assets").map_or("assets", String::as_str);
+    let samples = match flag("--samples").map(|n| n.parse::<usize>()) {
+        None => DEFAULT_SAMPLES,
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            eprintln!("usage: narrative-lint [--assets <dir>] [--samples <n>=1..]");
+            std::process::exit(2);
+        }
+    };
+
+    let story = match crate::assets::loader::load_story_assets(root) {
+        Ok(story) => story,
+        Err(e) => {
+            eprintln!("narrative-lint: {e}");
+            std::process::exit(2);
+        }
+    };
+    let report = lint_narrative(&story, samples);
+    print!("{}", report.render());
+    if report.errors() > 0 {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::*;
+
+    fn fragments(set: impl FnOnce(&mut NarrativeFragments)) -> Option<NarrativeFragments> {
+        let mut f = NarrativeFragments::default();
+        set(&mut f);
+        Some(f)
+    }
+
+    /// A tiny, clean world: one buyer, one of each card, every list filled
+    fn clean_world() -> StoryAssets {
+        let mut defaults = NarrativeFragments {
+            need_clauses: vec![TaggedFragment::any("needed a fix")],
+            product_clauses: vec![TaggedFragment::any("I had weed")],
+            location_clauses: vec![TaggedFragment::prep("at the park")],
+            evidence_clauses: vec![TaggedFragment::any("the cops were watching")],
+            cover_clauses: vec![TaggedFragment::any("my alibi held")],
+            modifier_clauses: vec![TaggedFragment::any("I cut the batch")],
+            reaction_clauses: vec![TaggedFragment::any("she haggled")],
+            insurance_clauses: vec![TaggedFragment::any("the lawyer came through")],
+            conviction_clauses: vec![TaggedFragment::any("the warrant stuck")],
+            ..Default::default()
+        };
+        for outcome in OUTCOMES {
+            let list = match outcome {
+                HandOutcome::Safe => &mut defaults.resolution_clauses.safe,
+                HandOutcome::Busted => &mut defaults.resolution_clauses.busted,
+                HandOutcome::Folded => &mut defaults.resolution_clauses.folded,
+                HandOutcome::BuyerBailed => &mut defaults.resolution_clauses.buyer_bailed,
+                HandOutcome::InvalidDeal => &mut defaults.resolution_clauses.invalid_deal,
+            };
+            list.push("it was over".to_string());
+        }
+
+        let own = |role: NarrativeRole, text: &str| {
+            fragments(|f| match role {
+                NarrativeRole::Product => f.product_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::Location => f.location_clauses = vec![TaggedFragment::prep(text)],
+                NarrativeRole::Evidence => f.evidence_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::ConvictionOverride => f.conviction_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::Cover => f.cover_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::Modifier => f.modifier_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::InsurancePayout => f.insurance_clauses = vec![TaggedFragment::any(text)],
+                NarrativeRole::BuyerReaction => f.reaction_clauses = vec![TaggedFragment::any(text)],
+                _ => {}
+            })
+        };
+        let with = |mut card: Card, role: NarrativeRole, text: &str| {
+            card.narrative_fragments = own(role, text);
+            card
+        };
+
+        let mut buyer = create_mock_buyer_persona();
+        let mut reaction = with(create_buyer_modifier("Haggle", 0.8, 0, 0, 0), NarrativeRole::BuyerReaction, "she haggled hard");
+        reaction.narrative_fragments.as_mut().unwrap().modifier_clauses = vec![TaggedFragment::any("she haggled")];
+        buyer.reaction_deck = vec![reaction];
+        buyer.scenarios[0].display_name = "The party girl".to_string();
+        buyer.scenarios[0].narrative_fragments = fragments(|f| f.need_clauses = vec![TaggedFragment::any("wanted to party")]);
+
+        StoryAssets {
+            defaults,
+            products: vec![with(create_product("Weed", 30, 0), NarrativeRole::Product, "I was holding weed")],
+            locations: vec![with(create_location("Park", 5, 5, 0), NarrativeRole::Location, "in the park")],
+            narc_cards: vec![
+                with(create_evidence("Stakeout", 10, 0), NarrativeRole::Evidence, "a van sat outside"),
+                with(create_conviction("Warrant", 40), NarrativeRole::ConvictionOverride, "the warrant had my name"),
+            ],
+            cover: vec![with(create_cover("Alibi", 10, 0), NarrativeRole::Cover, "my alibi held up")],
+            insurance: vec![with(create_insurance("Lawyer", 10, 100, 5), NarrativeRole::InsurancePayout, "the lawyer earned it")],
+            modifiers: vec![with(create_deal_modifier("Cut It", 1.2, 0, 0, 0), NarrativeRole::Modifier, "I stepped on it")],
+            buyers: vec![buyer],
+            patterns: DynamicPattern::shipped_patterns(),
+        }
+    }
+
+    #[test]
+    fn clean_world_lints_clean() {
+        let report = lint_narrative(&clean_world(), 2);
+        assert_eq!(report.errors(), 0, "{}", report.render());
+        assert_eq!(report.warnings(), 0, "{}", report.render());
+        assert!(report.stories_checked > 0);
+    }
+
+    #[test]
+    fn empty_lists_are_fallbacks() {
+        let mut world = clean_world();
+        world.defaults.resolution_clauses.busted.clear();
+        world.defaults.cover_clauses.clear();
+        world.cover[0].narrative_fragments = None;
+        let report = lint_narrative(&world, 1);
+
+        let subjects: Vec<&str> = report
+            .issues
+            .keys()
+            .filter(|i| i.kind == LintKind::EmptyFallback)
+            .map(|i| i.subject.as_str())
+            .collect();
+        assert!(subjects.contains(&"narrative_defaults.resolution_clauses (Busted)"), "{subjects:?}");
+        assert!(subjects.contains(&"card 'Alibi'"), "{subjects:?}");
+    }
+
+    #[test]
+    fn cards_without_their_own_fragments_warn() {
+        let mut world = clean_world();
+        world.products[0].narrative_fragments = None;
+        let report = lint_narrative(&world, 1);
+        assert_eq!(report.errors(), 0, "{}", report.render());
+        assert!(report.issues.keys().any(|i| i.kind == LintKind::MissingFragments && i.subject == "card 'Weed'"));
+    }
+
+    #[test]
+    fn text_checks_catch_spacing_and_capitals() {
+        let mut world = clean_world();
+        world.products[0].narrative_fragments = own_product("I'm  holding");
+        world.buyers[0].scenarios[0].display_name = "Karen".to_string();
+        let report = lint_narrative(&world, 8);
+        assert!(report.issues.keys().any(|i| i.kind == LintKind::DoubleSpace), "{}", report.render());
+
+        // complicated_deal opens "although ..., " in front of the subject
+        let mut report = LintReport::default();
+        check_story("Although the cops were watching, karen needed a fix. I had weed and it was over.", &["Karen".to_string()], "t", &mut report);
+        check_story("I had weed, and i'm out.", &[], "t", &mut report);
+        check_story("I had weed ,so. it was over..", &[], "t", &mut report);
+        assert!(report.issues.keys().filter(|i| i.kind == LintKind::BadCapitalisation).count() >= 3, "{}", report.render());
+        assert!(report.issues.keys().any(|i| i.kind == LintKind::StrayPunctuation));
+    }
+
+    fn own_product(text: &str) -> Option<NarrativeFragments> {
+        fragments(|f| f.product_clauses = vec![TaggedFragment::any(text)])
+    }
+
+    #[test]
+    fn tags_no_pattern_asks_for_are_flagged() {
+        let mut world = clean_world();
+        // Location is only ever a conjunction-less satellite (prepositional)
+        world.defaults.location_clauses.push(TaggedFragment::full_clause("we met at the park", None));
+        // No shipped pattern joins Location with "while"
+        world.defaults.location_clauses.push(TaggedFragment::new("at the park", Some(ClauseRelation::While), None));
+        let report = lint_narrative(&world, 1);
+        let tags: Vec<&LintIssue> = report.issues.keys().filter(|i| i.kind == LintKind::UnselectableTag).collect();
+        assert_eq!(tags.len(), 2, "{}", report.render());
+        assert!(tags.iter().all(|i| i.subject == "narrative_defaults.location_clauses"));
+    }
+}
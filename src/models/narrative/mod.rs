@@ -5,6 +5,9 @@ pub mod fragments;
 pub mod patterns;
 pub mod composer;
 pub mod builder;
+pub mod session; // SOW-048: session arcs
+pub mod lint; // SOW-049: narrative-lint subcommand
+pub mod headlines; // SOW-052: the morning paper
 
 #[cfg(test)]
 mod story_test; // Comprehensive story generation test
@@ -12,3 +15,6 @@ mod story_test; // Comprehensive story generation test
 // Re-export key types for external use
 pub use fragments::NarrativeFragments;
 pub use composer::StoryComposer;
+pub use patterns::DynamicPattern; // SOW-046: authored in narrative_patterns.ron
+pub use session::SessionArc;
+pub use headlines::HeadlineDesk; // SOW-052: authored in headlines.ron
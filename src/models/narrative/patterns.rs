@@ -1,261 +1,410 @@
 // SOW-014: Dynamic Narrative Construction
 // Defines dynamic patterns that build sentences at runtime
+//
+// SOW-046: patterns are DATA now - authored in narrative_patterns.ron (the
+// shipped defaults below are the former Rust closures, ported one-for-one),
+// loaded and validated by the asset loader. A pattern is its match rules
+// (outcome, cards on the table, priority) plus the satellites the
+// SentenceBuilder hangs off the core sentence.
 
-use super::builder::{SentenceBuilder, Satellite, Placement};
-use super::fragments::{SentenceStructure, ClauseRelation};
-use crate::models::{card::{Card, CardType}, hand_state::HandOutcome};
+use serde::{Deserialize, Serialize};
+
+use super::builder::{Satellite, SentenceBuilder};
+use super::fragments::{ClauseRelation, SentenceStructure};
+use crate::models::{card::{Card, CardType}, hand_state::{BustCheck, HandOutcome}};
+
+/// The shipped pattern set - used when assets/narrative_patterns.ron is
+/// absent, and the reference for writers adding new shapes
+const SHIPPED_PATTERNS: &str = include_str!("narrative_patterns.ron");
+
+fn default_product_conjunction() -> ClauseRelation {
+    ClauseRelation::And
+}
 
 /// Dynamic story pattern that builds structure at runtime
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DynamicPattern {
-    pub pattern_id: &'static str,
+    pub id: String,
+    /// Higher is tried first; the first pattern that matches tells the story
     pub priority: u32,
+    /// None = any outcome
+    #[serde(default)]
     pub required_outcome: Option<HandOutcome>,
-    pub required_cards: Vec<CardRequirement>,
-    // Closure that takes a builder and configures it
-    pub builder_factory: Box<dyn Fn() -> SentenceBuilder + Send + Sync>,
+    /// Roles that must be on the table (NarrativeRole::is_present)
+    #[serde(default)]
+    pub required_cards: Vec<NarrativeRole>,
+    /// Joins the product clause to the resolution ("and" / "but")
+    #[serde(default = "default_product_conjunction")]
+    pub product_conjunction: ClauseRelation,
+    #[serde(default)]
+    pub satellites: Vec<Satellite>,
 }
 
-impl std::fmt::Debug for DynamicPattern {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DynamicPattern")
-            .field("pattern_id", &self.pattern_id)
-            .field("priority", &self.priority)
-            .field("required_outcome", &self.required_outcome)
-            .finish()
-    }
+/// Narrative role that a card plays in the story
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NarrativeRole {
+    BuyerSubject,   // "A desperate housewife"
+    BuyerNeed,      // "needed her fix"
+    Product,        // "I had the stuff"
+    Location,       // "at the park"
+    Evidence,       // "the cops tapped my lines" (from Evidence cards)
+    Resolution,     // "and we made the deal" / "but I got pinched"
+    // SOW-047: the dramatic beats
+    Cover,              // "my alibi held up" (player Cover cards)
+    Modifier,           // "I cut it to stretch the batch" (player DealModifier cards)
+    BuyerReaction,      // "she wanted it done by the pool" (buyer's reaction cards)
+    InsurancePayout,    // "the lawyer's plea deal held, but it cost me"
+    ConvictionOverride, // "the warrant had my name on it - no lawyer could fix that"
 }
 
-/// Card requirement for pattern matching
-#[derive(Debug, Clone)]
-pub struct CardRequirement {
-    pub role: NarrativeRole,
-    pub card_type_filter: Option<CardTypeFilter>,
+/// SOW-047: what a resolved hand puts in front of the story - the table, who
+/// played the buyer's cards, and how the bust check went
+#[derive(Debug, Clone, Copy)]
+pub struct HandFacts<'a> {
+    pub has_buyer: bool,
+    pub played_cards: &'a [Card],
+    /// The buyer's reaction cards (also in played_cards)
+    pub buyer_reactions: &'a [Card],
+    pub bust_check: Option<BustCheck>,
 }
 
-impl CardRequirement {
-    pub fn buyer() -> Self {
-        Self {
-            role: NarrativeRole::BuyerSubject,
-            card_type_filter: None,
-        }
+impl<'a> HandFacts<'a> {
+    /// Just the table: no reactions, no bust check
+    pub fn table(has_buyer: bool, played_cards: &'a [Card]) -> Self {
+        Self { has_buyer, played_cards, buyer_reactions: &[], bust_check: None }
     }
 
-    pub fn product() -> Self {
-        Self {
-            role: NarrativeRole::Product,
-            card_type_filter: Some(CardTypeFilter::Product),
-        }
+    /// The PLAYER's deal modifiers (buyer reactions share the card type)
+    pub fn player_modifiers(&self) -> impl Iterator<Item = &'a Card> + '_ {
+        self.played_cards.iter().filter(|card| {
+            matches!(card.card_type, CardType::DealModifier { .. })
+                && !self.buyer_reactions.iter().any(|r| r.id == card.id)
+        })
     }
+}
 
-    pub fn evidence() -> Self {
-        Self {
-            role: NarrativeRole::Evidence,
-            card_type_filter: Some(CardTypeFilter::Evidence),
+impl NarrativeRole {
+    /// Roles every story's core sentence already carries - patterns can't
+    /// hang them as satellites (SentenceBuilder::build)
+    pub fn is_core(self) -> bool {
+        matches!(
+            self,
+            Self::BuyerSubject | Self::BuyerNeed | Self::Product | Self::Resolution
+        )
+    }
+
+    /// What the composer says when neither the cards nor the defaults have
+    /// a fragment for the role (narrative-lint flags every way to get here)
+    pub fn fallback_line(self) -> &'static str {
+        match self {
+            Self::BuyerSubject => "A mysterious buyer",
+            Self::BuyerNeed => "needed something",
+            Self::Product => "I had the goods",
+            Self::Location => "at the spot",
+            Self::Evidence => "things got heated",
+            Self::Resolution => super::fragments::RESOLUTION_FALLBACK,
+            Self::Cover => "my cover story held",
+            Self::Modifier => "I sweetened the deal",
+            Self::BuyerReaction => "the buyer had their own ideas",
+            Self::InsurancePayout => "the lawyer's plea deal held, but it cost me",
+            Self::ConvictionOverride => "the warrant stuck and no deal could save me",
         }
     }
-}
 
-/// Filter for card types
-#[derive(Debug, Clone, Copy)]
-pub enum CardTypeFilter {
-    Product,
-    Evidence,
-}
+    /// SOW-047: beats that only get told when they happened - unlike
+    /// Location/Evidence flavour, a satellite of these is dropped when the
+    /// hand has nothing to fill it with
+    pub fn is_beat(self) -> bool {
+        matches!(
+            self,
+            Self::Cover | Self::Modifier | Self::BuyerReaction | Self::InsurancePayout | Self::ConvictionOverride
+        )
+    }
 
-impl CardTypeFilter {
-    pub fn matches(&self, card_type: &CardType) -> bool {
-        match (self, card_type) {
-            (Self::Product, CardType::Product { .. }) => true,
-            (Self::Evidence, CardType::Evidence { .. }) | (Self::Evidence, CardType::Conviction { .. }) => true,
-            _ => false,
+    /// Whether this hand has something to fill the role with
+    pub fn is_present(self, facts: &HandFacts) -> bool {
+        let on_table =
+            |is_kind: fn(&CardType) -> bool| facts.played_cards.iter().any(|c| is_kind(&c.card_type));
+        match self {
+            Self::BuyerSubject | Self::BuyerNeed => facts.has_buyer,
+            Self::Product => on_table(|t| matches!(t, CardType::Product { .. })),
+            Self::Location => on_table(|t| matches!(t, CardType::Location { .. })),
+            Self::Evidence => {
+                on_table(|t| matches!(t, CardType::Evidence { .. } | CardType::Conviction { .. }))
+            }
+            // Every resolved hand has one
+            Self::Resolution => true,
+            Self::Cover => on_table(|t| matches!(t, CardType::Cover { .. })),
+            Self::Modifier => facts.player_modifiers().next().is_some(),
+            Self::BuyerReaction => !facts.buyer_reactions.is_empty(),
+            Self::InsurancePayout => facts.bust_check == Some(BustCheck::InsurancePaid),
+            Self::ConvictionOverride => facts.bust_check == Some(BustCheck::ConvictionOverrode),
         }
     }
 }
 
-/// Narrative role that a card plays in the story
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum NarrativeRole {
-    BuyerSubject,   // "A desperate housewife"
-    BuyerNeed,      // "needed her fix"
-    Product,        // "I had the stuff"
-    Location,       // "at the park"
-    Evidence,       // "the cops tapped my lines" (from Evidence cards)
-    Resolution,     // "and we made the deal" / "but I got pinched"
-}
-
 impl DynamicPattern {
-    /// Check if this pattern matches the given cards
-    pub fn matches(&self, has_buyer: bool, played_cards: &[Card]) -> bool {
-        for requirement in &self.required_cards {
-            let has_match = match requirement.role {
-                NarrativeRole::BuyerSubject | NarrativeRole::BuyerNeed => {
-                    has_buyer
-                },
-                _ => {
-                    if let Some(filter) = &requirement.card_type_filter {
-                        played_cards.iter().any(|card| filter.matches(&card.card_type))
-                    } else {
-                        false
-                    }
-                }
-            };
-
-            if !has_match {
-                return false;
-            }
-        }
-        true
+    /// Check if this pattern matches the given hand
+    pub fn matches(&self, facts: &HandFacts) -> bool {
+        self.required_cards.iter().all(|role| role.is_present(facts))
     }
 
-    /// Build a sentence structure using this pattern's factory
-    pub fn build_structure(&self) -> SentenceStructure {
-        let builder = (self.builder_factory)();
+    /// Build a sentence structure from this pattern's satellites
+    pub fn build_structure(&self, facts: &HandFacts) -> SentenceStructure {
+        let builder = self
+            .satellites
+            .iter()
+            .filter(|satellite| !satellite.role.is_beat() || satellite.role.is_present(facts))
+            .cloned()
+            .fold(
+                SentenceBuilder::new().with_product_conjunction(self.product_conjunction),
+                SentenceBuilder::with_satellite,
+            );
         builder.build()
     }
 
-    /// Create all dynamic patterns
-    pub fn create_all_patterns() -> Vec<DynamicPattern> {
-        vec![
-            Self::pattern_complicated_deal(),
-            Self::pattern_simple_bust(),
-            Self::pattern_simple_buyer_bail(),
-            Self::pattern_simple_dealer_bail(),
-            Self::pattern_simple_invalid_deal(),
-            Self::pattern_simple_deal(),
-        ]
+    /// The shipped pattern set (narrative_patterns.ron compiled in).
+    /// A unit test pins that it parses and validates.
+    pub fn shipped_patterns() -> Vec<DynamicPattern> {
+        parse_patterns(SHIPPED_PATTERNS).expect("shipped narrative_patterns.ron must parse")
     }
+}
 
-    // --- Pattern Definitions ---
+/// Parse a narrative_patterns.ron document
+pub fn parse_patterns(content: &str) -> Result<Vec<DynamicPattern>, String> {
+    ron::from_str::<Vec<DynamicPattern>>(content).map_err(|e| format!("RON parse error: {e}"))
+}
 
-    fn pattern_simple_deal() -> DynamicPattern {
-        DynamicPattern {
-            pattern_id: "simple_deal",
-            priority: 50,
-            required_outcome: Some(HandOutcome::Safe),
-            required_cards: vec![
-                CardRequirement::buyer(),
-                CardRequirement::product(),
-            ],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (Safe outcome)
-                // Optional: Location (no conjunction - prepositional phrase)
-                SentenceBuilder::new()
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 0.3)
-                        .with_placement(Placement::End, 0.7)
-                        .optional(0.4))
-            }),
+/// Load-time checks for authored patterns (fail loud - a broken pattern
+/// would only surface as a garbled story mid-run)
+pub fn validate_patterns(patterns: &[DynamicPattern]) -> Result<(), String> {
+    if patterns.is_empty() {
+        return Err("no narrative patterns defined".to_string());
+    }
+    let mut seen = std::collections::HashSet::new();
+    for pattern in patterns {
+        if pattern.id.trim().is_empty() {
+            return Err("narrative pattern with an empty id".to_string());
+        }
+        if !seen.insert(pattern.id.as_str()) {
+            return Err(format!("duplicate narrative pattern id '{}'", pattern.id));
+        }
+        if pattern.required_cards.contains(&NarrativeRole::Resolution) {
+            return Err(format!(
+                "pattern '{}': Resolution is always present - don't require it",
+                pattern.id
+            ));
+        }
+        let mut roles = std::collections::HashSet::new();
+        for satellite in &pattern.satellites {
+            if satellite.role.is_core() {
+                return Err(format!(
+                    "pattern '{}': {:?} is part of every core sentence, not a satellite",
+                    pattern.id, satellite.role
+                ));
+            }
+            if !roles.insert(satellite.role) {
+                return Err(format!(
+                    "pattern '{}': {:?} satellite defined twice",
+                    pattern.id, satellite.role
+                ));
+            }
+            if !(0.0..=1.0).contains(&satellite.inclusion_chance) {
+                return Err(format!(
+                    "pattern '{}': {:?} chance {} is outside 0.0..=1.0",
+                    pattern.id, satellite.role, satellite.inclusion_chance
+                ));
+            }
+            if let Some((placement, weight)) = satellite
+                .placements
+                .iter()
+                .find(|(_, weight)| !weight.is_finite() || *weight <= 0.0)
+            {
+                return Err(format!(
+                    "pattern '{}': {:?} placement {:?} has weight {} (must be > 0)",
+                    pattern.id, satellite.role, placement, weight
+                ));
+            }
         }
     }
+    Ok(())
+}
+
+/// Order patterns for matching: priority high to low, authoring order
+/// breaking ties (the composer falls back to the last one)
+pub fn sort_by_priority(patterns: &mut [DynamicPattern]) {
+    patterns.sort_by_key(|p| std::cmp::Reverse(p.priority));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::*;
+    use crate::models::narrative::builder::Placement;
 
-    fn pattern_complicated_deal() -> DynamicPattern {
+    fn pattern(id: &str) -> DynamicPattern {
         DynamicPattern {
-            pattern_id: "complicated_deal",
-            priority: 90,
-            required_outcome: Some(HandOutcome::Safe),
-            required_cards: vec![
-                CardRequirement::buyer(),
-                CardRequirement::product(),
-                CardRequirement::evidence(), // Requires evidence/complication
-            ],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (Safe outcome)
-                // Required: Evidence (complications from evidence cards)
-                // Optional: Location
-                SentenceBuilder::new()
-                    .with_satellite(Satellite::new(NarrativeRole::Evidence)
-                        .with_placement(Placement::Start, 0.4)
-                        .with_placement(Placement::BeforeResolution, 0.6)
-                        .with_conjunction(ClauseRelation::Although))  // Use "although" conjunction
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 1.0)
-                        .optional(0.2))  // Only at start to avoid collision with complication
-            }),
+            id: id.to_string(),
+            priority: 50,
+            required_outcome: None,
+            required_cards: vec![],
+            product_conjunction: ClauseRelation::And,
+            satellites: vec![],
         }
     }
 
-    fn pattern_simple_bust() -> DynamicPattern {
-        DynamicPattern {
-            pattern_id: "simple_bust",
-            priority: 60,
-            required_outcome: Some(HandOutcome::Busted),
-            required_cards: vec![CardRequirement::buyer()],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (Busted)
-                // Optional: Evidence (what led to bust), Location
-                SentenceBuilder::new()
-                    .with_product_conjunction(ClauseRelation::But)  // Negative outcome
-                    .with_satellite(Satellite::new(NarrativeRole::Evidence)
-                        .with_placement(Placement::BeforeResolution, 1.0)
-                        .with_conjunction(ClauseRelation::Because)  // "because" for bust explanations
-                        .optional(0.6))
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 1.0)
-                        .optional(0.2))  // Only at start to avoid collision
-            }),
-        }
+    /// One authored pattern with the given satellites, parsed the way the
+    /// loader reads narrative_patterns.ron
+    fn authored(satellites: &str) -> Vec<DynamicPattern> {
+        parse_patterns(&format!(r#"[(id: "a", priority: 50, satellites: {satellites})]"#)).unwrap()
     }
 
-    fn pattern_simple_buyer_bail() -> DynamicPattern {
-        DynamicPattern {
-            pattern_id: "simple_buyer_bail",
-            priority: 60,
-            required_outcome: Some(HandOutcome::BuyerBailed),
-            required_cards: vec![CardRequirement::buyer()],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (Buyer bailed)
-                // Optional: Location, Evidence (why they bailed)
-                SentenceBuilder::new()
-                    .with_product_conjunction(ClauseRelation::But)  // Negative outcome
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 1.0)
-                        .optional(0.2))
-                    .with_satellite(Satellite::new(NarrativeRole::Evidence)
-                        .with_placement(Placement::BeforeResolution, 1.0)
-                        .with_conjunction(ClauseRelation::Because)  // "because they bailed"
-                        .optional(0.5))
-            }),
+    #[test]
+    fn shipped_patterns_parse_and_validate() {
+        let patterns = DynamicPattern::shipped_patterns();
+        assert!(validate_patterns(&patterns).is_ok());
+        let ids: Vec<&str> = patterns.iter().map(|p| p.id.as_str()).collect();
+        // The former Rust patterns, one-for-one
+        for id in [
+            "complicated_deal",
+            "simple_bust",
+            "simple_buyer_bail",
+            "simple_dealer_bail",
+            "simple_invalid_deal",
+            "simple_deal",
+            // SOW-047
+            "conviction_override",
+            "insurance_payout",
+            "covered_deal",
+        ] {
+            assert!(ids.contains(&id), "missing shipped pattern {id}");
         }
     }
 
-    fn pattern_simple_dealer_bail() -> DynamicPattern {
-        DynamicPattern {
-            pattern_id: "simple_dealer_bail",
-            priority: 60,
-            required_outcome: Some(HandOutcome::Folded),
-            required_cards: vec![CardRequirement::buyer()],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (I walked away)
-                // Optional: Location, Evidence (why I walked)
-                SentenceBuilder::new()
-                    .with_product_conjunction(ClauseRelation::But)  // Negative outcome
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 1.0)
-                        .optional(0.2))
-                    .with_satellite(Satellite::new(NarrativeRole::Evidence)
-                        .with_placement(Placement::BeforeResolution, 1.0)
-                        .with_conjunction(ClauseRelation::Because)  // "because I walked"
-                        .optional(0.6))
-            }),
-        }
+    #[test]
+    fn shipped_complicated_deal_is_the_port() {
+        let patterns = DynamicPattern::shipped_patterns();
+        let p = patterns.iter().find(|p| p.id == "complicated_deal").unwrap();
+        assert_eq!(p.priority, 90);
+        assert_eq!(p.required_outcome, Some(HandOutcome::Safe));
+        assert_eq!(
+            p.required_cards,
+            vec![NarrativeRole::BuyerSubject, NarrativeRole::Product, NarrativeRole::Evidence]
+        );
+        let evidence = &p.satellites[0];
+        assert_eq!(evidence.role, NarrativeRole::Evidence);
+        assert_eq!(evidence.placements, vec![(Placement::Start, 0.4), (Placement::BeforeResolution, 0.6)]);
+        assert_eq!(evidence.relation, Some(ClauseRelation::Although));
+        assert_eq!(evidence.inclusion_chance, 1.0);
+        assert_eq!(p.satellites[1].inclusion_chance, 0.2);
+
+        let bust = patterns.iter().find(|p| p.id == "simple_bust").unwrap();
+        assert_eq!(bust.product_conjunction, ClauseRelation::But);
     }
 
-    fn pattern_simple_invalid_deal() -> DynamicPattern {
-        DynamicPattern {
-            pattern_id: "simple_invalid_deal",
-            priority: 60,
-            required_outcome: Some(HandOutcome::InvalidDeal),
-            required_cards: vec![CardRequirement::buyer()],
-            builder_factory: Box::new(|| {
-                // Core: Subject + Need + Product + Resolution (Deal fell through)
-                // Optional: Location
-                SentenceBuilder::new()
-                    .with_product_conjunction(ClauseRelation::But)  // Negative outcome
-                    .with_satellite(Satellite::new(NarrativeRole::Location)
-                        .with_placement(Placement::Start, 0.4)
-                        .with_placement(Placement::End, 0.6)
-                        .optional(0.3))
-            }),
-        }
+    #[test]
+    fn authored_pattern_parses_with_defaults() {
+        let patterns = parse_patterns(
+            r#"[
+                (
+                    id: "quiet_night",
+                    priority: 10,
+                    satellites: [
+                        (role: Location, placements: [(End, 1.0)]),
+                    ],
+                ),
+            ]"#,
+        )
+        .unwrap();
+        let p = &patterns[0];
+        assert_eq!(p.required_outcome, None);
+        assert!(p.required_cards.is_empty());
+        assert_eq!(p.product_conjunction, ClauseRelation::And);
+        assert_eq!(p.satellites[0].inclusion_chance, 1.0);
+        assert_eq!(p.satellites[0].relation, None);
+
+        assert!(parse_patterns("[(priority: 1)]").is_err(), "id is required");
+    }
+
+    #[test]
+    fn validation_rejects_broken_patterns() {
+        assert!(validate_patterns(&[]).is_err());
+
+        let dup = [pattern("a"), pattern("a")];
+        assert!(validate_patterns(&dup).unwrap_err().contains("duplicate"));
+
+        let mut needs_resolution = pattern("a");
+        needs_resolution.required_cards = vec![NarrativeRole::Resolution];
+        assert!(validate_patterns(&[needs_resolution]).is_err());
+
+        let core_satellite = authored("[(role: Product)]");
+        assert!(validate_patterns(&core_satellite).unwrap_err().contains("core"));
+
+        let twice = authored("[(role: Location), (role: Location)]");
+        assert!(validate_patterns(&twice).unwrap_err().contains("twice"));
+
+        let bad_chance = authored("[(role: Location, chance: 1.5)]");
+        assert!(validate_patterns(&bad_chance).is_err());
+
+        let bad_weight = authored("[(role: Location, placements: [(End, 0.0)])]");
+        assert!(validate_patterns(&bad_weight).unwrap_err().contains("weight"));
+    }
+
+    #[test]
+    fn roles_match_the_cards_on_the_table() {
+        let mut p = pattern("a");
+        p.required_cards = vec![NarrativeRole::BuyerSubject, NarrativeRole::Location];
+        let location = [create_location("Alley", 0, 0, 0)];
+        assert!(p.matches(&HandFacts::table(true, &location)));
+        assert!(!p.matches(&HandFacts::table(false, &location)), "no buyer");
+        let weed = [create_product("Weed", 30, 0)];
+        assert!(!p.matches(&HandFacts::table(true, &weed)), "no location");
+
+        // Evidence is filled by narc Evidence and Conviction cards alike
+        let warrant = [create_conviction("Warrant", 30)];
+        assert!(NarrativeRole::Evidence.is_present(&HandFacts::table(true, &warrant)));
+    }
+
+    #[test]
+    fn dramatic_beats_read_the_whole_hand() {
+        let reaction = create_buyer_modifier("Haggle", 0.8, 0, 0, 0);
+        let mut own = create_deal_modifier("Cut It", 1.2, 0, 0, 5);
+        own.id = "cut_it".to_string();
+        let mut buyer_card = reaction.clone();
+        buyer_card.id = "haggle".to_string();
+        let played = [create_cover("Alibi", 10, 0), buyer_card.clone()];
+        let reactions = [buyer_card];
+
+        let mut facts = HandFacts { buyer_reactions: &reactions, ..HandFacts::table(true, &played) };
+        assert!(NarrativeRole::Cover.is_present(&facts));
+        assert!(NarrativeRole::BuyerReaction.is_present(&facts));
+        // The buyer's modifier is theirs, not the player's
+        assert!(!NarrativeRole::Modifier.is_present(&facts));
+        let with_own = [own, played[1].clone()];
+        facts.played_cards = &with_own;
+        assert!(NarrativeRole::Modifier.is_present(&facts));
+
+        // Insurance and convictions are about how the bust check went, not
+        // just what's on the table
+        assert!(!NarrativeRole::InsurancePayout.is_present(&facts));
+        facts.bust_check = Some(BustCheck::InsurancePaid);
+        assert!(NarrativeRole::InsurancePayout.is_present(&facts));
+        assert!(!NarrativeRole::ConvictionOverride.is_present(&facts));
+        facts.bust_check = Some(BustCheck::ConvictionOverrode);
+        assert!(NarrativeRole::ConvictionOverride.is_present(&facts));
+    }
+
+    #[test]
+    fn priority_orders_matching_with_ties_kept() {
+        let mut a = pattern("a");
+        a.priority = 10;
+        let mut b = pattern("b");
+        b.priority = 90;
+        let c = pattern("c");
+        let d = pattern("d");
+        let mut patterns = vec![a, b, c, d];
+        sort_by_priority(&mut patterns);
+        let ids: Vec<&str> = patterns.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "d", "a"]);
     }
 }
@@ -0,0 +1,254 @@
+// SOW-048: Session arcs - each hand's story knows the hands before it.
+//
+// A SessionArc is one beat per resolved hand (who the buyer was, how it
+// went, where the heat stood, whether it was a close call). The composer
+// reads it for a connective opener before the next hand's story ("After
+// two clean deals in a row, ...") and for the closing summary paragraph
+// when the dealer goes home. Everything here is plain data over HandState
+// so the arc rides along with the session through start_next_hand.
+
+use crate::models::hand_state::{BustCheck, HandOutcome, HandState};
+use crate::save::{current_timestamp, StoryRecord};
+
+/// A Safe hand whose cover beat evidence by at most this much was a close call
+pub const NEAR_MISS_MARGIN: i32 = 5;
+/// Safe hands in a row before the streak gets a mention
+pub const SAFE_STREAK_MIN: usize = 2;
+/// Heat a hand has to add to count towards a climb
+pub const HEAT_CLIMB_MIN: i32 = 10;
+
+/// One resolved hand, as the rest of the session remembers it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandBeat {
+    /// Regular's name, else the persona's display name
+    pub buyer: Option<String>,
+    pub outcome: HandOutcome,
+    /// Session heat after the hand
+    pub heat: i32,
+    pub profit: u32,
+    pub near_miss: bool,
+}
+
+impl HandBeat {
+    /// Read the beat off a resolved hand
+    pub fn from_hand(hand: &HandState) -> Self {
+        let outcome = hand.outcome.unwrap_or(HandOutcome::InvalidDeal);
+        let totals = hand.calculate_totals(true);
+        let near_miss = outcome == HandOutcome::Safe
+            && (hand.bust_check == Some(BustCheck::InsurancePaid)
+                || (totals.evidence > 0 && totals.cover as i32 - (totals.evidence as i32) <= NEAR_MISS_MARGIN));
+        Self {
+            buyer: session_buyer(hand),
+            outcome,
+            heat: hand.current_heat,
+            profit: if outcome == HandOutcome::Safe { hand.last_profit } else { 0 },
+            near_miss,
+        }
+    }
+}
+
+/// Who the session is dealing with: a regular by name, else the persona
+pub fn session_buyer(hand: &HandState) -> Option<String> {
+    hand.buyer_persona.as_ref().map(|persona| match &persona.regular {
+        Some(regular) => regular.name.clone(),
+        None => persona.display_name.clone(),
+    })
+}
+
+/// SOW-050: the record of a told hand, written at resolution - before its
+/// beat joins the arc, so the heat delta is over the hand before it. The
+/// dealer and run are stamped when the session closes.
+pub fn hand_record(hand: &HandState, text: String) -> StoryRecord {
+    let persona = hand.buyer_persona.as_ref();
+    let outcome = hand.outcome.unwrap_or(HandOutcome::InvalidDeal);
+    StoryRecord {
+        text,
+        outcome: Some(outcome),
+        area: hand.run_area.clone(),
+        buyer: persona.map(|p| p.display_name.clone()),
+        scenario: persona
+            .and_then(|p| p.active_scenario_index.and_then(|i| p.scenarios.get(i)))
+            .map(|s| s.display_name.clone()),
+        product: hand.active_product(true).map(|card| card.name.clone()),
+        profit: if outcome == HandOutcome::Safe { hand.last_profit } else { 0 },
+        heat_delta: hand.current_heat - hand.session_arc.beats.last().map_or(0, |b| b.heat),
+        timestamp: current_timestamp(),
+        ..Default::default()
+    }
+}
+
+/// What the story of the next hand should lead in with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Connective {
+    /// A regular with history opens the session
+    RegularBack { buyer: String },
+    /// Second hand with the same buyer
+    ReturningBuyer { buyer: String },
+    /// The hand two back was a close call
+    NearMissTwoAgo,
+    /// This many Safe hands in a row just before this one
+    SafeStreak { count: usize },
+    /// The last two hands each piled on heat
+    RisingHeat,
+}
+
+/// The session so far
+#[derive(Debug, Clone, Default)]
+pub struct SessionArc {
+    pub beats: Vec<HandBeat>,
+}
+
+impl SessionArc {
+    pub fn record(&mut self, beat: HandBeat) {
+        self.beats.push(beat);
+    }
+
+    /// Safe hands in a row at the end of the arc
+    pub fn safe_streak(&self) -> usize {
+        self.beats.iter().rev().take_while(|b| b.outcome == HandOutcome::Safe).count()
+    }
+
+    /// The opener for the hand about to be told, dealt with `buyer` (and a
+    /// regular's prior deals, for the first hand). Most telling first:
+    /// a close call two hands back, a streak, the buyer, then the heat.
+    pub fn connective(&self, buyer: Option<&str>, regular_deals: u32) -> Option<Connective> {
+        let n = self.beats.len();
+        if n >= 2 && self.beats[n - 2].near_miss {
+            return Some(Connective::NearMissTwoAgo);
+        }
+        let streak = self.safe_streak();
+        if streak >= SAFE_STREAK_MIN {
+            return Some(Connective::SafeStreak { count: streak });
+        }
+        if let Some(buyer) = buyer {
+            if n == 0 && regular_deals > 0 {
+                return Some(Connective::RegularBack { buyer: buyer.to_string() });
+            }
+            if n == 1 && self.beats[0].buyer.as_deref() == Some(buyer) {
+                return Some(Connective::ReturningBuyer { buyer: buyer.to_string() });
+            }
+        }
+        let added = |i: usize| self.beats[i].heat - if i == 0 { 0 } else { self.beats[i - 1].heat };
+        if n >= 2 && added(n - 1) >= HEAT_CLIMB_MIN && added(n - 2) >= HEAT_CLIMB_MIN {
+            return Some(Connective::RisingHeat);
+        }
+        None
+    }
+
+    pub fn total_profit(&self) -> u32 {
+        self.beats.iter().map(|b| b.profit).sum()
+    }
+
+    pub fn near_misses(&self) -> usize {
+        self.beats.iter().filter(|b| b.near_miss).count()
+    }
+
+    /// SOW-050: the record of the session's closing summary - the night's
+    /// take and the heat it ended on, no outcome of its own
+    pub fn summary_record(&self, text: String, area: &str) -> StoryRecord {
+        StoryRecord {
+            text,
+            area: area.to_string(),
+            profit: self.total_profit(),
+            heat_delta: self.beats.last().map_or(0, |b| b.heat),
+            timestamp: current_timestamp(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn beat(outcome: HandOutcome, heat: i32) -> HandBeat {
+        HandBeat { buyer: Some("Karen".to_string()), outcome, heat, profit: 0, near_miss: false }
+    }
+
+    #[test]
+    fn first_hand_has_no_opener_unless_a_regular_is_back() {
+        let arc = SessionArc::default();
+        assert_eq!(arc.connective(Some("Karen"), 0), None);
+        assert_eq!(
+            arc.connective(Some("Karen"), 3),
+            Some(Connective::RegularBack { buyer: "Karen".to_string() })
+        );
+    }
+
+    #[test]
+    fn second_hand_with_the_same_buyer_is_a_return() {
+        let mut arc = SessionArc::default();
+        arc.record(beat(HandOutcome::Folded, 5));
+        assert_eq!(
+            arc.connective(Some("Karen"), 0),
+            Some(Connective::ReturningBuyer { buyer: "Karen".to_string() })
+        );
+        assert_eq!(arc.connective(Some("Someone Else"), 0), None);
+    }
+
+    #[test]
+    fn close_call_two_hands_back_outranks_a_streak() {
+        let mut arc = SessionArc::default();
+        arc.record(HandBeat { near_miss: true, ..beat(HandOutcome::Safe, 5) });
+        arc.record(beat(HandOutcome::Safe, 10));
+        assert_eq!(arc.connective(None, 0), Some(Connective::NearMissTwoAgo));
+
+        arc.record(beat(HandOutcome::Safe, 15));
+        assert_eq!(arc.connective(None, 0), Some(Connective::SafeStreak { count: 3 }));
+        assert_eq!(arc.near_misses(), 1);
+    }
+
+    #[test]
+    fn climbing_heat_needs_two_hot_hands_in_a_row() {
+        let mut arc = SessionArc::default();
+        arc.record(beat(HandOutcome::Folded, 12));
+        arc.record(beat(HandOutcome::Folded, 20));
+        assert_eq!(arc.connective(None, 0), None, "second hand only added 8");
+        arc.record(beat(HandOutcome::Folded, 35));
+        assert_eq!(arc.connective(None, 0), None, "only one hot hand in the last two");
+        arc.record(beat(HandOutcome::Folded, 50));
+        assert_eq!(arc.connective(None, 0), Some(Connective::RisingHeat));
+    }
+
+    #[test]
+    fn beat_reads_close_calls_off_the_hand() {
+        use crate::models::test_helpers::*;
+        let mut hand = HandState::default();
+        hand.cards_played.push(create_product("Weed", 30, 0));
+        hand.cards_played.push(create_location("Alley", 20, 22, 0));
+        hand.resolve_hand();
+        let b = HandBeat::from_hand(&hand);
+        assert_eq!(b.outcome, HandOutcome::Safe);
+        assert!(b.near_miss, "cover 22 vs evidence 20");
+        assert_eq!(b.profit, 30);
+
+        let mut easy = HandState::default();
+        easy.cards_played.push(create_product("Weed", 30, 0));
+        easy.cards_played.push(create_location("Safe House", 0, 30, 0));
+        easy.resolve_hand();
+        assert!(!HandBeat::from_hand(&easy).near_miss);
+    }
+
+    #[test]
+    fn hand_record_reads_the_deal_and_its_heat() {
+        use crate::models::test_helpers::*;
+        let mut hand = HandState {
+            buyer_persona: Some(create_mock_buyer_persona()),
+            ..Default::default()
+        };
+        hand.cards_played.push(create_product("Weed", 30, 0));
+        hand.cards_played.push(create_location("Safe House", 0, 30, 0));
+        hand.resolve_hand();
+        hand.current_heat = 25;
+        hand.session_arc.record(beat(HandOutcome::Safe, 10));
+
+        let record = hand_record(&hand, "Sold it.".to_string());
+        assert_eq!(record.outcome, Some(HandOutcome::Safe));
+        assert_eq!(record.product.as_deref(), Some("Weed"));
+        assert_eq!(record.scenario.as_deref(), Some("Test Scenario"));
+        assert_eq!(record.buyer, hand.buyer_persona.as_ref().map(|p| p.display_name.clone()));
+        assert_eq!(record.area, hand.run_area);
+        assert_eq!(record.profit, hand.last_profit);
+        assert_eq!(record.heat_delta, 15, "over the hand before it");
+    }
+}
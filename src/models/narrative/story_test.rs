@@ -3,7 +3,8 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::models::narrative::StoryComposer;
+    use crate::models::narrative::patterns::HandFacts;
+    use crate::models::narrative::{DynamicPattern, StoryComposer};
     use crate::models::hand_state::HandOutcome;
     use crate::models::card::Card;
     use crate::assets::GameAssets;
@@ -21,7 +22,7 @@ mod tests {
         let assets = load_test_assets();
 
         let mut all_stories = Vec::new();
-        let composer = StoryComposer::new(assets.narrative_defaults.clone());
+        let composer = StoryComposer::with_patterns(assets.narrative_defaults.clone(), DynamicPattern::shipped_patterns());
 
         // Test each buyer scenario
         for buyer in &assets.buyers {
@@ -62,7 +63,7 @@ mod tests {
                                 // Generate multiple iterations to capture all fragment variations
                                 // Run 50 times to ensure we hit random fragment combinations
                                 for _ in 0..50 {
-                                    let story = composer.compose_story(Some(scenario), &cards_played, outcome);
+                                    let story = composer.compose_story_with(Some(scenario), &HandFacts::table(true, &cards_played), outcome);
 
                                     let evidence_desc = if evidence_indices.is_empty() {
                                         "None".to_string()
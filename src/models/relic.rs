@@ -0,0 +1,235 @@
+// SOW-059: Relics - passive empire items that bend the rules, loaded from
+// assets/relics.ron. A relic is found (a chronicle event), bought (a zone
+// shop's rare slot) or earned (an empire milestone), and from then on its
+// effects apply at fixed rule points until the empire falls.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::ascension::Ascension;
+
+/// An authored relic
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelicDef {
+    pub id: String,
+    pub name: String,
+    /// One line of flavour for the hub and the shop slot
+    pub description: String,
+    pub effects: Vec<RelicEffect>,
+    /// How the empire comes by it
+    pub source: RelicSource,
+}
+
+/// What a relic does, and at which rule point
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RelicEffect {
+    /// calculate_totals: flat cover on every hand
+    Cover(u32),
+    /// calculate_totals: percent more profit on every hand
+    Profit(u32),
+    /// resolve_hand: evidence may beat cover by this much and the hand
+    /// still goes Safe
+    Margin(u32),
+    /// Jail sentencing: runs off every sentence (never below one)
+    Sentence(u32),
+    /// Front vig: percentage points off FRONT_VIG_PCT
+    Vig(u32),
+    /// Restock cost: percent off a zone's restock margin
+    Restock(u32),
+    /// Heat decay: percent more heat fades with time
+    Decay(u32),
+}
+
+/// Where a relic comes from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RelicSource {
+    /// Found the first time the chronicle records this
+    Event(RelicEvent),
+    /// For sale in a zone shop's rare slot
+    Shop { area: String, price: u32 },
+    /// Earned when the empire reaches this
+    Milestone(RelicMilestone),
+}
+
+/// The chronicle entries a relic can turn up after
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RelicEvent {
+    Hired,
+    ZoneBought,
+    FrontTaken,
+    FrontPaid,
+    CutOff,
+    Soured,
+    Jailed,
+    BailedOut,
+}
+
+impl RelicEvent {
+    pub fn matches(self, event: &crate::save::ChronicleEvent) -> bool {
+        use crate::save::ChronicleEvent as E;
+        matches!(
+            (self, event),
+            (Self::Hired, E::Hired { .. })
+                | (Self::ZoneBought, E::ZoneBought { .. })
+                | (Self::FrontTaken, E::FrontTaken { .. })
+                | (Self::FrontPaid, E::FrontPaid { .. })
+                | (Self::CutOff, E::CutOff { .. })
+                | (Self::Soured, E::Soured { .. })
+                | (Self::Jailed, E::Jailed { .. })
+                | (Self::BailedOut, E::BailedOut { .. })
+        )
+    }
+}
+
+/// Empire milestones, checked against the books
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RelicMilestone {
+    /// Lifetime revenue reaches this
+    Revenue(u64),
+    /// Hands completed reach this
+    Hands(u32),
+    /// Zones owned reach this
+    Zones(u32),
+    /// The roster (kingpin included) reaches this
+    Roster(u32),
+}
+
+/// Most a relic set can take off the restock margin, in percent - restock
+/// is never free
+const RESTOCK_DISCOUNT_CAP: u32 = 90;
+
+/// Every owned relic's effects added up - what the rule points read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RelicEffects {
+    pub cover: u32,
+    pub profit_pct: u32,
+    pub margin: u32,
+    pub sentence_cut: u32,
+    pub vig_cut: u32,
+    pub restock_pct: u32,
+    pub decay_pct: u32,
+}
+
+impl RelicEffects {
+    pub fn sum<'a>(effects: impl IntoIterator<Item = &'a RelicEffect>) -> Self {
+        let mut sum = Self::default();
+        for effect in effects {
+            match *effect {
+                RelicEffect::Cover(n) => sum.cover += n,
+                RelicEffect::Profit(n) => sum.profit_pct += n,
+                RelicEffect::Margin(n) => sum.margin += n,
+                RelicEffect::Sentence(n) => sum.sentence_cut += n,
+                RelicEffect::Vig(n) => sum.vig_cut += n,
+                RelicEffect::Restock(n) => sum.restock_pct += n,
+                RelicEffect::Decay(n) => sum.decay_pct += n,
+            }
+        }
+        sum
+    }
+
+    /// A hand's profit with the profit bonus
+    pub fn profit(&self, profit: u32) -> u32 {
+        (profit as u64 * (100 + self.profit_pct as u64) / 100) as u32
+    }
+
+    /// A sentence after the cut (one run stays mandatory)
+    pub fn sentence(&self, runs: u32) -> u32 {
+        runs.saturating_sub(self.sentence_cut).max(1)
+    }
+
+    /// The vig a front carries, in percent - the cut comes off the
+    /// ascension level's vig (SOW-065)
+    pub fn vig_pct(&self, ascension: Ascension) -> u64 {
+        ascension.vig_pct().saturating_sub(self.vig_cut as u64)
+    }
+
+    /// A zone's restock margin after the discount
+    pub fn restock_margin(&self, margin: f32) -> f32 {
+        margin * (100 - self.restock_pct.min(RESTOCK_DISCOUNT_CAP)) as f32 / 100.0
+    }
+
+    /// Heat that fades with time, with the decay bonus
+    pub fn decay(&self, decay: u32) -> u32 {
+        (decay as u64 * (100 + self.decay_pct as u64) / 100) as u32
+    }
+}
+
+/// Load-time checks: unique ids, a name, at least one effect, and shop
+/// slots in real zones at a real price
+pub fn validate_relics(relics: &[RelicDef], area_ids: &[&str]) -> Result<(), String> {
+    let mut ids = std::collections::HashSet::new();
+    for relic in relics {
+        if relic.id.trim().is_empty() || relic.name.trim().is_empty() {
+            return Err(format!("relic '{}' needs an id and a name", relic.id));
+        }
+        if !ids.insert(relic.id.as_str()) {
+            return Err(format!("duplicate relic id '{}'", relic.id));
+        }
+        if relic.effects.is_empty() {
+            return Err(format!("relic '{}' has no effects", relic.id));
+        }
+        if let RelicSource::Shop { area, price } = &relic.source {
+            if !area_ids.contains(&area.as_str()) {
+                return Err(format!("relic '{}' is sold in unknown area '{}'", relic.id, area));
+            }
+            if *price == 0 {
+                return Err(format!("relic '{}' needs a shop price", relic.id));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::test_helpers::create_relic as relic;
+
+    #[test]
+    fn effects_add_up_and_apply() {
+        let effects = RelicEffects::sum(&[
+            RelicEffect::Sentence(1),
+            RelicEffect::Sentence(1),
+            RelicEffect::Vig(10),
+            RelicEffect::Restock(20),
+            RelicEffect::Decay(50),
+            RelicEffect::Profit(10),
+        ]);
+        assert_eq!(effects.sentence(5), 3);
+        assert_eq!(effects.sentence(2), 1, "one run stays mandatory");
+        assert_eq!(effects.vig_pct(Ascension::default()), crate::save::FRONT_VIG_PCT - 10);
+        assert!((effects.restock_margin(0.5) - 0.4).abs() < 1e-6);
+        assert_eq!(effects.decay(10), 15);
+        assert_eq!(effects.profit(100), 110);
+
+        // Nothing owned changes nothing
+        let none = RelicEffects::default();
+        assert_eq!((none.sentence(4), none.vig_pct(Ascension::default()), none.decay(7)), (4, crate::save::FRONT_VIG_PCT, 7));
+        assert_eq!(RelicEffects::sum(&[RelicEffect::Restock(150)]).restock_margin(1.0), 0.1);
+    }
+
+    #[test]
+    fn events_match_their_chronicle_entries() {
+        let bail = crate::save::ChronicleEvent::BailedOut { dealer: "Ray".to_string(), cost: 500 };
+        assert!(RelicEvent::BailedOut.matches(&bail));
+        assert!(!RelicEvent::Jailed.matches(&bail));
+    }
+
+    #[test]
+    fn validation_catches_broken_relics() {
+        let scanner = relic("scanner", vec![RelicEffect::Cover(5)], RelicSource::Event(RelicEvent::Hired));
+        assert!(validate_relics(std::slice::from_ref(&scanner), &["block"]).is_ok());
+        assert!(validate_relics(&[scanner.clone(), scanner.clone()], &["block"]).unwrap_err().contains("duplicate"));
+
+        let inert = relic("dice", vec![], RelicSource::Milestone(RelicMilestone::Hands(10)));
+        assert!(validate_relics(&[inert], &["block"]).unwrap_err().contains("no effects"));
+
+        let nowhere = relic(
+            "judge",
+            vec![RelicEffect::Sentence(1)],
+            RelicSource::Shop { area: "moon".to_string(), price: 500 },
+        );
+        assert!(validate_relics(&[nowhere], &["block"]).unwrap_err().contains("unknown area"));
+        let free = relic("judge", vec![RelicEffect::Sentence(1)], RelicSource::Shop { area: "block".to_string(), price: 0 });
+        assert!(validate_relics(&[free], &["block"]).unwrap_err().contains("price"));
+    }
+}
@@ -0,0 +1,259 @@
+// SOW-060: Run map - the night laid out as a short branching path.
+//
+// A run used to be the same deal repeated until the deck ran dry. Now START
+// RUN rolls a map: an opening deal, a few rows of stops to pick a route
+// through, and a last call. Every stop but the rest stop is a hand on the
+// existing HandState engine, played with the stop's stakes (NodeParams);
+// the rest stop reshuffles the deck instead. Pure and seeded, so the shape
+// rules are unit-tested.
+
+use rand::prelude::*;
+
+/// Rows on a night's map, the opening deal and the last call included
+pub const RUN_MAP_ROWS: usize = 6;
+
+/// Heat a rest stop takes off the session
+pub const REST_STOP_COOLING: i32 = 10;
+
+/// What waits at a stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunNodeKind {
+    /// A regular deal - the old run, one hand of it
+    Deal,
+    /// Bigger money, a harder narc deck
+    HighRoller,
+    /// Double the money, but the tip came with eyes on it
+    Tip,
+    /// On the supplier's turf: cooler narcs, thinner margins
+    SupplierMeet,
+    /// The elite stop - the narcs were waiting
+    Sting,
+    /// No deal: the played cards go back in the deck and the night cools
+    RestStop,
+    /// SOW-061: a task-force operation at last call - never rolled, only
+    /// staged when one is due
+    Operation,
+}
+
+/// The stakes a hand is played at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeParams {
+    /// Profit as a percentage of what the cards make
+    pub profit_pct: u32,
+    /// Narc heat tiers above (or below) the runner's own
+    pub narc_shift: i8,
+    /// Session heat the hand opens with
+    pub opening_heat: i32,
+}
+
+impl NodeParams {
+    pub fn profit(&self, profit: u32) -> u32 {
+        (profit as u64 * self.profit_pct as u64 / 100) as u32
+    }
+}
+
+impl RunNodeKind {
+    /// The hand's stakes; None for the rest stop, which isn't a hand
+    pub fn params(self) -> Option<NodeParams> {
+        let (profit_pct, narc_shift, opening_heat) = match self {
+            Self::Deal => (100, 0, 0),
+            Self::HighRoller => (150, 1, 0),
+            Self::Tip => (200, 0, 15),
+            Self::SupplierMeet => (75, -1, 0),
+            Self::Sting => (250, 2, 0),
+            Self::Operation => (200, 1, 0),
+            Self::RestStop => return None,
+        };
+        Some(NodeParams { profit_pct, narc_shift, opening_heat })
+    }
+
+    /// Draw weight for a middle row. Stings wait until the night is under
+    /// way (row 2 on).
+    fn weight(self, row: usize) -> u32 {
+        match self {
+            Self::Deal => 4,
+            Self::HighRoller | Self::Tip => 2,
+            Self::SupplierMeet | Self::RestStop => 1,
+            Self::Sting if row >= 2 => 1,
+            Self::Sting | Self::Operation => 0,
+        }
+    }
+}
+
+const KINDS: [RunNodeKind; 6] = [
+    RunNodeKind::Deal,
+    RunNodeKind::HighRoller,
+    RunNodeKind::Tip,
+    RunNodeKind::SupplierMeet,
+    RunNodeKind::Sting,
+    RunNodeKind::RestStop,
+];
+
+/// A stop and the stops it leads to (indices into the next row)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunNode {
+    pub kind: RunNodeKind,
+    pub next: Vec<usize>,
+}
+
+/// A night's map and where the runner stands on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunMap {
+    pub rows: Vec<Vec<RunNode>>,
+    pub row: usize,
+    pub col: usize,
+}
+
+impl RunMap {
+    /// Roll a night: one opening deal, RUN_MAP_ROWS - 2 rows of two or three
+    /// stops, one last-call deal. Links never cross, and every stop can be
+    /// reached.
+    pub fn generate(rng: &mut impl Rng) -> Self {
+        let widths: Vec<usize> = (0..RUN_MAP_ROWS)
+            .map(|row| if row == 0 || row == RUN_MAP_ROWS - 1 { 1 } else { rng.random_range(2..=3) })
+            .collect();
+
+        let rows = widths
+            .iter()
+            .enumerate()
+            .map(|(row, &width)| {
+                (0..width)
+                    .map(|col| {
+                        let kind = if width == 1 { RunNodeKind::Deal } else { roll_kind(rng, row) };
+                        let next = widths.get(row + 1).map_or_else(Vec::new, |&to| links(col, width, to));
+                        RunNode { kind, next }
+                    })
+                    .collect()
+            })
+            .collect();
+        Self { rows, row: 0, col: 0 }
+    }
+
+    /// The stop the runner is at
+    pub fn current(&self) -> &RunNode {
+        &self.rows[self.row][self.col]
+    }
+
+    /// The stops reachable from here, as (column, kind); empty at last call
+    pub fn choices(&self) -> Vec<(usize, RunNodeKind)> {
+        self.current()
+            .next
+            .iter()
+            .map(|&col| (col, self.rows[self.row + 1][col].kind))
+            .collect()
+    }
+
+    /// Move on to a stop in the next row. None (and no move) when it isn't
+    /// linked from here.
+    pub fn advance(&mut self, col: usize) -> Option<RunNodeKind> {
+        if !self.current().next.contains(&col) {
+            return None;
+        }
+        self.row += 1;
+        self.col = col;
+        Some(self.current().kind)
+    }
+
+    /// SOW-061: a task force is waiting - the last call becomes its
+    /// operation
+    pub fn stage_operation(&mut self) {
+        if let Some(last) = self.rows.last_mut().and_then(|row| row.first_mut()) {
+            last.kind = RunNodeKind::Operation;
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.row + 1 >= self.rows.len()
+    }
+}
+
+/// Columns of a `to`-wide row that column `col` of a `from`-wide row links
+/// to: its proportional share of the next row, so links never cross and
+/// every stop has a way in
+fn links(col: usize, from: usize, to: usize) -> Vec<usize> {
+    let first = col * to / from;
+    let last = ((col + 1) * to).div_ceil(from) - 1;
+    (first..=last).collect()
+}
+
+fn roll_kind(rng: &mut impl Rng, row: usize) -> RunNodeKind {
+    KINDS
+        .choose_weighted(rng, |kind| kind.weight(row))
+        .copied()
+        .unwrap_or(RunNodeKind::Deal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn maps_open_and_close_on_a_deal_and_every_stop_is_reachable() {
+        for seed in 0..50 {
+            let map = RunMap::generate(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(map.rows.len(), RUN_MAP_ROWS);
+            assert_eq!(map.rows[0].len(), 1);
+            assert_eq!(map.rows[0][0].kind, RunNodeKind::Deal);
+            assert_eq!(map.rows[RUN_MAP_ROWS - 1].len(), 1);
+            assert_eq!(map.rows[RUN_MAP_ROWS - 1][0].kind, RunNodeKind::Deal);
+            assert!(map.rows[1].iter().all(|n| n.kind != RunNodeKind::Sting), "no sting on the first pick");
+
+            for row in 1..RUN_MAP_ROWS {
+                for col in 0..map.rows[row].len() {
+                    assert!(
+                        map.rows[row - 1].iter().any(|n| n.next.contains(&col)),
+                        "seed {seed}: row {row} col {col} unreachable"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn links_never_cross() {
+        assert_eq!(links(0, 2, 3), [0, 1]);
+        assert_eq!(links(1, 2, 3), [1, 2]);
+        assert_eq!(links(0, 3, 2), [0]);
+        assert_eq!(links(1, 3, 2), [0, 1]);
+        assert_eq!(links(2, 3, 2), [1]);
+        assert_eq!(links(0, 1, 3), [0, 1, 2]);
+        assert_eq!(links(2, 3, 1), [0]);
+    }
+
+    #[test]
+    fn advancing_follows_the_links_to_last_call() {
+        let mut map = RunMap::generate(&mut StdRng::seed_from_u64(7));
+        let unlinked = (0..3).find(|c| !map.current().next.contains(c));
+        if let Some(col) = unlinked {
+            assert_eq!(map.advance(col), None);
+            assert_eq!(map.row, 0);
+        }
+        while !map.is_finished() {
+            let (col, kind) = map.choices()[0];
+            assert_eq!(map.advance(col), Some(kind));
+        }
+        assert_eq!(map.row, RUN_MAP_ROWS - 1);
+        assert!(map.choices().is_empty());
+    }
+
+    #[test]
+    fn a_staged_operation_waits_at_last_call() {
+        for seed in 0..20 {
+            let mut map = RunMap::generate(&mut StdRng::seed_from_u64(seed));
+            assert!(map.rows.iter().flatten().all(|n| n.kind != RunNodeKind::Operation), "never rolled");
+            map.stage_operation();
+            assert_eq!(map.rows[RUN_MAP_ROWS - 1][0].kind, RunNodeKind::Operation);
+            assert_eq!(map.rows.iter().flatten().filter(|n| n.kind == RunNodeKind::Operation).count(), 1);
+        }
+    }
+
+    #[test]
+    fn stops_set_the_stakes() {
+        assert_eq!(RunNodeKind::Deal.params().unwrap().profit(80), 80);
+        assert_eq!(RunNodeKind::HighRoller.params().unwrap().profit(80), 120);
+        assert_eq!(RunNodeKind::SupplierMeet.params().unwrap().narc_shift, -1);
+        assert!(RunNodeKind::Sting.params().unwrap().narc_shift > RunNodeKind::HighRoller.params().unwrap().narc_shift);
+        assert_eq!(RunNodeKind::RestStop.params(), None);
+    }
+}
@@ -42,6 +42,27 @@ pub struct AreaDealerDef {
     pub cred_required: u32,
 }
 
+/// SOW-043: where a zone sits on the city map, as fractions of the map
+/// canvas (0.0 = left/top edge, 1.0 = right/bottom edge). The node is
+/// centred on this point.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MapPosition {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// SOW-043: one authored road out of a zone. Links are symmetric - authoring
+/// it on either end is enough (ZoneGraph mirrors it).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ZoneLink {
+    /// Area id at the other end
+    pub to: String,
+    /// Runs of downtime a dealer spends on this leg
+    pub runs: u32,
+    /// Cash charged up front for this leg
+    pub cost: u64,
+}
+
 /// An unlockable area: gates a card shop and (RFC-024) its buyer personas
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShopLocationDef {
@@ -85,6 +106,14 @@ pub struct ShopLocationDef {
     /// (a missing/out-of-range margin fails loud - the authorability rule).
     #[serde(default)]
     pub restock_margin: f32,
+    /// SOW-043: the zone's spot on the city map. Optional - the map lays
+    /// zones out on a grid unless EVERY zone carries one.
+    #[serde(default)]
+    pub map_position: Option<MapPosition>,
+    /// SOW-043: roads to neighbouring zones. When no zone authors any, every
+    /// pair of zones is one flat-fee hop apart (the SOW-025 move).
+    #[serde(default)]
+    pub links: Vec<ZoneLink>,
 }
 
 /// Load-time validation for the area list:
@@ -96,6 +125,9 @@ pub struct ShopLocationDef {
 ///   these unconditionally, so missing content fails loud here
 /// - SOW-036: every area carries a signature dealer with a name and a
 ///   portrait - the map offers it as a hire, so missing content fails loud
+/// - SOW-043: map positions sit on the canvas; links point at real, other
+///   zones and take at least one run; once any link is authored the graph
+///   must reach every zone (a stranded zone could never be moved to)
 pub fn validate_shop_locations(areas: &[ShopLocationDef]) -> Result<(), String> {
     let mut seen = std::collections::HashSet::new();
     for area in areas {
@@ -169,9 +201,187 @@ pub fn validate_shop_locations(areas: &[ShopLocationDef]) -> Result<(), String>
     if !areas.iter().any(|a| a.unlocked) {
         return Err("no area starts unlocked - a fresh empire would have nowhere to operate".to_string());
     }
+    validate_zone_graph(areas)
+}
+
+/// SOW-043: the map half of validate_shop_locations (positions + links)
+fn validate_zone_graph(areas: &[ShopLocationDef]) -> Result<(), String> {
+    for area in areas {
+        if let Some(pos) = area.map_position {
+            if !(0.0..=1.0).contains(&pos.x) || !(0.0..=1.0).contains(&pos.y) {
+                return Err(format!(
+                    "area '{}' map_position ({}, {}) must be within 0.0..=1.0",
+                    area.id, pos.x, pos.y
+                ));
+            }
+        }
+        let mut targets = std::collections::HashSet::new();
+        for link in &area.links {
+            if link.to == area.id {
+                return Err(format!("area '{}' links to itself", area.id));
+            }
+            if !areas.iter().any(|a| a.id == link.to) {
+                return Err(format!("area '{}' links to unknown area '{}'", area.id, link.to));
+            }
+            if link.runs == 0 {
+                return Err(format!(
+                    "area '{}' link to '{}' takes 0 runs - every road costs time",
+                    area.id, link.to
+                ));
+            }
+            if !targets.insert(link.to.as_str()) {
+                return Err(format!("area '{}' links to '{}' twice", area.id, link.to));
+            }
+        }
+    }
+    let graph = ZoneGraph::from_areas(areas);
+    if graph.is_authored() {
+        if let Some(first) = areas.first() {
+            for area in areas {
+                if graph.route(&first.id, &area.id).is_none() && area.id != first.id {
+                    return Err(format!(
+                        "area '{}' can't be reached from '{}' - the zone graph must be connected",
+                        area.id, first.id
+                    ));
+                }
+            }
+        }
+    }
     Ok(())
 }
 
+/// SOW-025's flat relocation fee, now the price of one hop on an unauthored
+/// map (every zone one run apart). [TUNING]
+pub const MOVE_FEE: u64 = 250;
+
+/// SOW-043: a planned move - the zones walked (origin first, destination
+/// last) and what the whole trip costs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneRoute {
+    pub path: Vec<String>,
+    pub runs: u32,
+    pub cost: u64,
+}
+
+/// SOW-043: the city's roads, built once from shop_locations.ron at load
+/// (GameAssets.zone_graph). Symmetric: a link authored on either end is a
+/// two-way road. With NO links authored anywhere the graph is unauthored
+/// and every pair of zones is one direct hop (1 run, MOVE_FEE) - the old
+/// flat move - so a content set without roads keeps working.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ZoneGraph {
+    /// (a, b, runs, cost) with a < b, one entry per road
+    edges: Vec<(String, String, u32, u64)>,
+}
+
+impl ZoneGraph {
+    pub fn from_areas(areas: &[ShopLocationDef]) -> Self {
+        let mut edges: Vec<(String, String, u32, u64)> = Vec::new();
+        for area in areas {
+            for link in &area.links {
+                let (a, b) = if area.id < link.to {
+                    (area.id.clone(), link.to.clone())
+                } else {
+                    (link.to.clone(), area.id.clone())
+                };
+                // Authored on both ends: the first one read wins
+                if !edges.iter().any(|(x, y, _, _)| *x == a && *y == b) {
+                    edges.push((a, b, link.runs, link.cost));
+                }
+            }
+        }
+        Self { edges }
+    }
+
+    /// Whether any road was authored (false: every zone is one hop apart)
+    pub fn is_authored(&self) -> bool {
+        !self.edges.is_empty()
+    }
+
+    /// Every road once, as (a, b, runs, cost)
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str, u32, u64)> {
+        self.edges
+            .iter()
+            .map(|(a, b, runs, cost)| (a.as_str(), b.as_str(), *runs, *cost))
+    }
+
+    /// The direct road between two zones as (runs, cost), if there is one
+    pub fn leg(&self, from: &str, to: &str) -> Option<(u32, u64)> {
+        if from == to {
+            return None;
+        }
+        if !self.is_authored() {
+            return Some((1, MOVE_FEE));
+        }
+        self.edges
+            .iter()
+            .find(|(a, b, _, _)| (a == from && b == to) || (a == to && b == from))
+            .map(|(_, _, runs, cost)| (*runs, *cost))
+    }
+
+    fn neighbours<'a>(&'a self, zone: &'a str) -> impl Iterator<Item = (&'a str, u32, u64)> + 'a {
+        self.edges.iter().filter_map(move |(a, b, runs, cost)| {
+            if a == zone {
+                Some((b.as_str(), *runs, *cost))
+            } else if b == zone {
+                Some((a.as_str(), *runs, *cost))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The best way from one zone to another: fewest runs on the road, then
+    /// cheapest. Routes may pass THROUGH zones you haven't unlocked - you
+    /// drive past, you don't set up shop. None when `from == to` or no road
+    /// connects them.
+    pub fn route(&self, from: &str, to: &str) -> Option<ZoneRoute> {
+        if from == to {
+            return None;
+        }
+        if !self.is_authored() {
+            return Some(ZoneRoute {
+                path: vec![from.to_string(), to.to_string()],
+                runs: 1,
+                cost: MOVE_FEE,
+            });
+        }
+        // Dijkstra over (runs, cost) - a handful of zones, so a linear scan
+        // for the next-closest beats a heap
+        let mut best: std::collections::HashMap<&str, ((u32, u64), Option<&str>)> =
+            std::collections::HashMap::from([(from, ((0, 0), None))]);
+        let mut done: std::collections::HashSet<&str> = std::collections::HashSet::new();
+        loop {
+            let next = best
+                .iter()
+                .filter(|(zone, _)| !done.contains(*zone))
+                .min_by_key(|(zone, (dist, _))| (*dist, **zone))
+                .map(|(zone, (dist, _))| (*zone, *dist));
+            let (zone, (runs, cost)) = next?;
+            if zone == to {
+                break;
+            }
+            done.insert(zone);
+            for (neighbour, leg_runs, leg_cost) in self.neighbours(zone) {
+                let candidate = (runs + leg_runs, cost + leg_cost);
+                if best.get(neighbour).is_none_or(|(dist, _)| candidate < *dist) {
+                    best.insert(neighbour, (candidate, Some(zone)));
+                }
+            }
+        }
+
+        let ((runs, cost), _) = best[to];
+        let mut path = vec![to.to_string()];
+        let mut at = to;
+        while let Some(prev) = best[at].1 {
+            path.push(prev.to_string());
+            at = prev;
+        }
+        path.reverse();
+        Some(ZoneRoute { path, runs, cost })
+    }
+}
+
 /// SOW-024: The areas a run may take place in, in definition order.
 /// (INTERIM: run area is picked randomly from these until dealer stationing
 /// lands - see the stationing design update.)
@@ -264,9 +474,25 @@ mod tests {
             unlockable_dealers: Vec::new(),
             narc_portrait: None,
             restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
         }
     }
 
+    fn link(to: &str, runs: u32, cost: u64) -> ZoneLink {
+        ZoneLink { to: to.to_string(), runs, cost }
+    }
+
+    /// trailer_park - suburbia - downtown in a line, plus a slow, cheap
+    /// back road straight from trailer_park to downtown
+    fn linked_city() -> Vec<ShopLocationDef> {
+        let mut park = area("trailer_park", true, 0);
+        park.links = vec![link("suburbia", 1, 200), link("downtown", 3, 100)];
+        let mut burbs = area("suburbia", false, 800);
+        burbs.links = vec![link("downtown", 1, 300)];
+        vec![park, burbs, area("downtown", false, 1500)]
+    }
+
     #[test]
     fn restock_margin_out_of_range_rejected() {
         let mut zero = area("trailer_park", true, 0);
@@ -550,4 +776,98 @@ mod tests {
         unlocked.insert("downtown".to_string());
         assert_eq!(unlocked_area_ids(&areas, &unlocked), vec!["trailer_park", "downtown"]);
     }
+
+    // -- SOW-043: zone graph --
+
+    #[test]
+    fn unauthored_graph_is_one_flat_hop_between_any_zones() {
+        let graph = ZoneGraph::from_areas(&[area("a", true, 0), area("b", false, 10)]);
+        assert!(!graph.is_authored());
+        assert_eq!(
+            graph.route("a", "b"),
+            Some(ZoneRoute { path: vec!["a".into(), "b".into()], runs: 1, cost: MOVE_FEE })
+        );
+        assert_eq!(graph.leg("a", "b"), Some((1, MOVE_FEE)));
+        assert_eq!(graph.route("a", "a"), None);
+    }
+
+    #[test]
+    fn links_are_symmetric_and_deduplicated() {
+        let mut areas = linked_city();
+        // The same road authored from the far end too
+        areas[2].links = vec![link("suburbia", 1, 300)];
+        let graph = ZoneGraph::from_areas(&areas);
+        assert_eq!(graph.edges().count(), 3);
+        assert_eq!(graph.leg("downtown", "suburbia"), Some((1, 300)));
+        assert_eq!(graph.leg("suburbia", "trailer_park"), Some((1, 200)));
+    }
+
+    #[test]
+    fn route_prefers_fewest_runs_then_cheapest() {
+        let graph = ZoneGraph::from_areas(&linked_city());
+        // Via suburbia: 2 runs, $500 - beats the 3-run $100 back road
+        let route = graph.route("trailer_park", "downtown").expect("connected");
+        assert_eq!(route.path, vec!["trailer_park", "suburbia", "downtown"]);
+        assert_eq!((route.runs, route.cost), (2, 500));
+
+        // Tie on runs: the cheaper road wins
+        let mut areas = linked_city();
+        areas[0].links[1] = link("downtown", 2, 100);
+        let route = ZoneGraph::from_areas(&areas).route("trailer_park", "downtown").unwrap();
+        assert_eq!(route.path, vec!["trailer_park", "downtown"]);
+        assert_eq!((route.runs, route.cost), (2, 100));
+
+        // Reversed direction walks the same roads
+        let back = graph.route("downtown", "trailer_park").unwrap();
+        assert_eq!(back.path, vec!["downtown", "suburbia", "trailer_park"]);
+    }
+
+    #[test]
+    fn route_to_an_unconnected_zone_is_none() {
+        let mut areas = linked_city();
+        areas.push(area("harbor", false, 2000));
+        let graph = ZoneGraph::from_areas(&areas);
+        assert_eq!(graph.route("trailer_park", "harbor"), None);
+        assert_eq!(graph.leg("trailer_park", "harbor"), None);
+    }
+
+    #[test]
+    fn linked_city_validates() {
+        assert!(validate_shop_locations(&linked_city()).is_ok());
+    }
+
+    #[test]
+    fn bad_links_rejected() {
+        let mut areas = linked_city();
+        areas[1].links.push(link("suburbia", 1, 0));
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("itself"));
+
+        let mut areas = linked_city();
+        areas[1].links.push(link("atlantis", 1, 0));
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("unknown area"));
+
+        let mut areas = linked_city();
+        areas[1].links[0].runs = 0;
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("0 runs"));
+
+        let mut areas = linked_city();
+        areas[0].links.push(link("suburbia", 2, 50));
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("twice"));
+    }
+
+    #[test]
+    fn stranded_zone_rejected_once_roads_exist() {
+        let mut areas = linked_city();
+        areas.push(area("harbor", false, 2000));
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("harbor"));
+    }
+
+    #[test]
+    fn off_canvas_map_position_rejected() {
+        let mut areas = linked_city();
+        areas[0].map_position = Some(MapPosition { x: 0.5, y: 1.2 });
+        assert!(validate_shop_locations(&areas).unwrap_err().contains("map_position"));
+        areas[0].map_position = Some(MapPosition { x: 0.5, y: 1.0 });
+        assert!(validate_shop_locations(&areas).is_ok());
+    }
 }
@@ -6,6 +6,8 @@
 
 use super::card::{Card, CardType};
 use super::buyer::BuyerPersona;
+use super::relic::{RelicDef, RelicEffect, RelicSource};
+use super::boss::{BossDef, BossRule, BossTrigger};
 use crate::assets::GameAssets;
 
 /// Create a Product card for testing (with optional shop data)
@@ -18,6 +20,7 @@ pub fn create_product(name: &str, price: u32, heat: i32) -> Card {
         shop_location: Some("trailer_park".to_string()),
         shop_price: Some(0),
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -31,6 +34,7 @@ pub fn create_location(name: &str, evidence: u32, cover: u32, heat: i32) -> Card
         shop_location: Some("trailer_park".to_string()),
         shop_price: Some(0),
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -44,6 +48,7 @@ pub fn create_evidence(name: &str, evidence: u32, heat: i32) -> Card {
         shop_location: None,
         shop_price: None,
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -57,6 +62,7 @@ pub fn create_cover(name: &str, cover: u32, heat: i32) -> Card {
         shop_location: Some("trailer_park".to_string()),
         shop_price: Some(0),
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -70,6 +76,7 @@ pub fn create_deal_modifier(name: &str, price_multiplier: f32, evidence: i32, co
         shop_location: Some("trailer_park".to_string()),
         shop_price: Some(0),
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -83,6 +90,7 @@ pub fn create_insurance(name: &str, cover: u32, cost: u32, heat_penalty: i32) ->
         shop_location: Some("trailer_park".to_string()),
         shop_price: Some(0),
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -96,6 +104,7 @@ pub fn create_conviction(name: &str, heat_threshold: u32) -> Card {
         shop_location: None,
         shop_price: None,
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -109,6 +118,7 @@ pub fn create_buyer_location(name: &str, evidence: u32, cover: u32, heat: i32) -
         shop_location: None,
         shop_price: None,
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -122,6 +132,7 @@ pub fn create_buyer_modifier(name: &str, price_multiplier: f32, evidence: i32, c
         shop_location: None,
         shop_price: None,
         shop_cred_required: None,
+        evolutions: Vec::new(),
     }
 }
 
@@ -203,7 +214,7 @@ pub fn create_mock_game_assets() -> GameAssets {
 }
 
 /// Create a mock buyer persona for testing
-fn create_mock_buyer_persona() -> BuyerPersona {
+pub fn create_mock_buyer_persona() -> BuyerPersona {
     use super::buyer::{BuyerDemand, BuyerScenario};
 
     BuyerPersona {
@@ -239,5 +250,42 @@ fn create_mock_buyer_persona() -> BuyerPersona {
             },
         ],
         active_scenario_index: Some(0),
+        personality: Default::default(),
+        regular_names: Vec::new(),
+        regular: None,
     }
 }
+
+/// SOW-059: a relic named after its id
+pub fn create_relic(id: &str, effects: Vec<RelicEffect>, source: RelicSource) -> RelicDef {
+    RelicDef {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        description: String::new(),
+        effects,
+        source,
+    }
+}
+
+/// SOW-061: an operation with empty story lines and no rewards
+pub fn create_boss(id: &str, rules: Vec<BossRule>, trigger: BossTrigger) -> BossDef {
+    BossDef {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        intro: format!("{id} is on you."),
+        win: String::new(),
+        loss: String::new(),
+        portrait: None,
+        rules,
+        trigger,
+        bounty: 0,
+        relic: None,
+        sentence: 0,
+    }
+}
+
+/// SOW-053: does every line of `label` fit in `budget` characters (the
+/// layout checks the pure views make under the pseudo-locale)
+pub fn fits(label: &str, budget: usize) -> bool {
+    label.split('\n').all(|line| line.chars().count() <= budget)
+}
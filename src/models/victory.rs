@@ -0,0 +1,96 @@
+// SOW-062: Victory conditions - authored win states, loaded from
+// assets/victories.ron. Until now the only way an empire ended was the
+// kingpin's bust, so the arcade board only ever held failures. A victory is
+// a goal the empire can meet (control every zone, bank cash clean, keep a
+// crew on the street); any met victory adds its bonus to the score the
+// empire retires with when the kingpin cashes out.
+
+use serde::{Deserialize, Serialize};
+
+/// An authored win condition
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VictoryDef {
+    pub id: String,
+    /// Board name ("Own the City")
+    pub name: String,
+    /// One line on how to meet it
+    pub description: String,
+    pub condition: VictoryCondition,
+    /// Added to the retirement score when met
+    #[serde(default)]
+    pub bonus: u64,
+}
+
+/// What the empire has to hold when it retires
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VictoryCondition {
+    /// Every zone on the map owned
+    AllZones,
+    /// This much cash on hand once every front is paid off
+    CleanCash(u64),
+    /// This many hired dealers out of jail
+    FreeDealers(u32),
+    /// Lifetime revenue reaches this
+    Revenue(u64),
+}
+
+/// Load-time checks: unique ids, a name, and a condition that can't be met
+/// by an empire on day one
+pub fn validate_victories(victories: &[VictoryDef]) -> Result<(), String> {
+    let mut ids = std::collections::HashSet::new();
+    for victory in victories {
+        if victory.id.trim().is_empty() || victory.name.trim().is_empty() {
+            return Err(format!("victory '{}' needs an id and a name", victory.id));
+        }
+        if !ids.insert(victory.id.as_str()) {
+            return Err(format!("duplicate victory id '{}'", victory.id));
+        }
+        let trivial = match victory.condition {
+            VictoryCondition::AllZones => false,
+            VictoryCondition::CleanCash(n) | VictoryCondition::Revenue(n) => n == 0,
+            VictoryCondition::FreeDealers(n) => n == 0,
+        };
+        if trivial {
+            return Err(format!("victory '{}' is met before the first deal", victory.id));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn victory(id: &str, condition: VictoryCondition) -> VictoryDef {
+        VictoryDef {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            description: String::new(),
+            condition,
+            bonus: 1_000,
+        }
+    }
+
+    #[test]
+    fn validation_catches_broken_victories() {
+        let city = victory("city", VictoryCondition::AllZones);
+        assert!(validate_victories(std::slice::from_ref(&city)).is_ok());
+        assert!(validate_victories(&[city.clone(), city]).unwrap_err().contains("duplicate"));
+
+        let free = victory("free", VictoryCondition::CleanCash(0));
+        assert!(validate_victories(&[free]).unwrap_err().contains("before the first deal"));
+
+        let nameless = VictoryDef { name: " ".to_string(), ..victory("crew", VictoryCondition::FreeDealers(3)) };
+        assert!(validate_victories(&[nameless]).unwrap_err().contains("needs an id"));
+    }
+
+    #[test]
+    fn victories_read_from_ron() {
+        let parsed: Vec<VictoryDef> = ron::from_str(
+            r#"[(id: "clean", name: "Clean Money", description: "Bank it.", condition: CleanCash(50000))]"#,
+        )
+        .unwrap();
+        assert_eq!(parsed[0].condition, VictoryCondition::CleanCash(50_000));
+        assert_eq!(parsed[0].bonus, 0, "bonus defaults to nothing");
+    }
+}
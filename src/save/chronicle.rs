@@ -0,0 +1,154 @@
+// SOW-051: Empire chronicle export - writes ui::chronicle_view's document to
+// disk with the roster's portraits copied alongside, so the folder opens
+// anywhere (the Markdown and HTML reference `portraits/<file>` relatively).
+//
+// Two entry points: the Kingpin Ledger's EXPORT button, and
+//   cargo run -- chronicle [current|<n>] [--format md|html] [--out <dir>]
+//                          [--dir <save dir>] [--assets <dir>]
+// which reads the signed save directly (no Bevy App is built). `<n>` is the
+// fallen empire's number as the ledger shows it (1 = the first to fall).
+
+use std::path::{Path, PathBuf};
+
+use super::io;
+use super::types::SaveData;
+use crate::models::shop_location::ShopLocationDef;
+use crate::ui::chronicle_view::{self, Chronicle, ChronicleFormat, ChronicleSubject};
+
+/// Where the game's portraits live, relative to the asset root
+const ACTORS_DIR: &str = "art/actors";
+
+/// Write `chronicle.<ext>` into `out_dir` and copy each roster portrait
+/// found under `actors_dir` into `out_dir/portraits/`. A missing portrait
+/// file is skipped (the document just shows a broken image), not an error.
+/// Returns the written document's path.
+pub fn export(
+    save: &SaveData,
+    subject: ChronicleSubject,
+    format: ChronicleFormat,
+    areas: &[ShopLocationDef],
+    out_dir: &Path,
+    actors_dir: &Path,
+) -> Result<PathBuf, String> {
+    let chronicle = Chronicle::of(save, subject).ok_or("no such fallen empire")?;
+    let document = chronicle_view::render(&chronicle_view::chronicle_blocks(&chronicle, areas), format);
+
+    let portraits = out_dir.join("portraits");
+    std::fs::create_dir_all(&portraits)
+        .map_err(|e| format!("Failed to create {}: {}", portraits.display(), e))?;
+    for dealer in chronicle.roster {
+        let file = crate::assets::loader::dealer_portrait_file(&dealer.portrait);
+        let source = actors_dir.join(&file);
+        if source.exists() {
+            std::fs::copy(&source, portraits.join(&file))
+                .map_err(|e| format!("Failed to copy {}: {}", source.display(), e))?;
+        }
+    }
+
+    let path = out_dir.join(format!("chronicle.{}", format.extension()));
+    std::fs::write(&path, document).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// The ledger's default destination: `<save dir>/chronicles/<unix secs>/`
+pub fn default_out_dir() -> PathBuf {
+    io::get_save_directory()
+        .join("chronicles")
+        .join(super::current_timestamp().to_string())
+}
+
+/// The ledger's portrait source (the game runs from the asset root's parent)
+pub fn default_actors_dir() -> PathBuf {
+    Path::new("assets").join(ACTORS_DIR)
+}
+
+/// CLI entry: parse the subject and flags, export, print the path
+pub fn run_cli(args: &[String]) {
+    const USAGE: &str =
+        "usage: chronicle [current|<n>] [--format md|html] [--out <dir>] [--dir <save dir>] [--assets <dir>]";
+    let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1));
+
+    let subject = match args.first().filter(|a| !a.starts_with("--")).map(String::as_str) {
+        None | Some("current") => ChronicleSubject::Current,
+        Some(n) => match n.parse::<usize>() {
+            Ok(n) if n > 0 => ChronicleSubject::Fallen(n - 1),
+            _ => {
+                eprintln!("{USAGE}");
+                std::process::exit(2);
+            }
+        },
+    };
+    let Some(format) = ChronicleFormat::parse(flag("--format").map_or("md", String::as_str)) else {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    };
+
+    let save_dir = flag("--dir").map(PathBuf::from).unwrap_or_else(io::get_save_directory);
+    let save = match io::load_save(&save_dir.join("save.dat")) {
+        Ok(save) => save,
+        Err(e) => {
+            eprintln!("chronicle: couldn't load {}: {e:?}", save_dir.join("save.dat").display());
+            std::process::exit(2);
+        }
+    };
+
+    // Zone names are nice-to-have: raw ids when the content isn't there
+    let assets = flag("--assets").map_or("assets", String::as_str);
+    let areas = crate::assets::loader::load_shop_locations(&format!("{assets}/data/shop_locations.ron"))
+        .unwrap_or_else(|e| {
+            eprintln!("chronicle: {e} - zones will show as ids");
+            Vec::new()
+        });
+
+    let out_dir = flag("--out").map(PathBuf::from).unwrap_or_else(default_out_dir);
+    let actors_dir = Path::new(assets).join(ACTORS_DIR);
+    match export(&save, subject, format, &areas, &out_dir, &actors_dir) {
+        Ok(path) => println!("chronicle -> {}", path.display()),
+        Err(e) => {
+            eprintln!("chronicle: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::DealerState;
+    use tempfile::tempdir;
+
+    #[test]
+    fn export_writes_the_document_and_copies_known_portraits() {
+        let dir = tempdir().unwrap();
+        let actors = dir.path().join("actors");
+        std::fs::create_dir_all(&actors).unwrap();
+        std::fs::write(actors.join("silhouette.png"), b"png").unwrap();
+
+        let mut save = SaveData::new();
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Slim", "Gladys"));
+        let out = dir.path().join("out");
+        let path = export(&save, ChronicleSubject::Current, ChronicleFormat::Html, &[], &out, &actors).unwrap();
+
+        assert_eq!(path, out.join("chronicle.html"));
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains("<img src=\"portraits/dealer-gladys.png\""));
+        assert!(out.join("portraits/silhouette.png").exists());
+        // Gladys has no file on disk: skipped, not an error
+        assert!(!out.join("portraits/dealer-gladys.png").exists());
+    }
+
+    #[test]
+    fn export_rejects_a_missing_fallen_empire() {
+        let dir = tempdir().unwrap();
+        let err = export(
+            &SaveData::new(),
+            ChronicleSubject::Fallen(0),
+            ChronicleFormat::Markdown,
+            &[],
+            dir.path(),
+            dir.path(),
+        )
+        .unwrap_err();
+        assert_eq!(err, "no such fallen empire");
+    }
+}
@@ -10,6 +10,7 @@
 
 use super::io;
 use super::types::*;
+use crate::models::hand_state::HandOutcome;
 use std::path::PathBuf;
 
 /// Build a named scenario. Pure - the caller decides where it goes.
@@ -99,10 +100,22 @@ pub fn scenario(name: &str) -> Option<SaveData> {
             for _ in 0..3 {
                 save.dealers[0].add_cred(DEFAULT_STATION);
             }
+            // SOW-050: records carry enough for the ledger's filters to bite
+            let story = |text: &str, dealer: &str, area: &str, outcome: HandOutcome, run: u32| StoryRecord {
+                text: text.to_string(),
+                outcome: Some(outcome),
+                area: area.to_string(),
+                dealer: dealer.to_string(),
+                run,
+                ..Default::default()
+            };
             save.dealers[0].character.story_history.extend([
-                "Moved product at the park while the beat cop bought donuts.".to_string(),
-                "The frat house wanted it loud; the kingpin kept it quiet.".to_string(),
-                "A noise complaint nearly ended the night early.".to_string(),
+                story("Moved product at the park while the beat cop bought donuts.",
+                      "The Kingpin", DEFAULT_STATION, HandOutcome::Safe, 1),
+                story("The frat house wanted it loud; the kingpin kept it quiet.",
+                      "The Kingpin", DEFAULT_STATION, HandOutcome::Safe, 2),
+                story("A noise complaint nearly ended the night early.",
+                      "The Kingpin", DEFAULT_STATION, HandOutcome::Folded, 2),
             ]);
 
             // SOW-039: Red Light's authored signature face (Marcus), stationed
@@ -114,11 +127,35 @@ pub fn scenario(name: &str) -> Option<SaveData> {
             for _ in 0..2 {
                 ray.add_cred("red_light_district");
             }
-            ray.character.story_history.push(
-                "Ray worked the velvet rope like he owned the club.".to_string(),
-            );
+            ray.character.story_history.push(story(
+                "Ray worked the velvet rope like he owned the club.",
+                "Ray", "red_light_district", HandOutcome::Safe, 3,
+            ));
             save.dealers.push(ray);
 
+            // SOW-051: the books behind the roster, for the chronicle export
+            let at = |at: u64, event: ChronicleEvent| ChronicleEntry { at, event };
+            save.chronicle.extend([
+                at(1_720_000_000, ChronicleEvent::ZoneBought {
+                    area_id: "red_light_district".to_string(),
+                    price: 1200,
+                }),
+                at(1_720_000_100, ChronicleEvent::Hired {
+                    dealer: "Ray".to_string(),
+                    area_id: "red_light_district".to_string(),
+                    cost: 500,
+                }),
+                at(1_720_000_200, ChronicleEvent::Jailed {
+                    dealer: "Ray".to_string(),
+                    runs: 2,
+                    heat: 70,
+                }),
+                at(1_720_000_300, ChronicleEvent::BailedOut {
+                    dealer: "Ray".to_string(),
+                    cost: 300,
+                }),
+            ]);
+
             save.fallen_empires.push(EmpireEpitaph {
                 ended_at: 1_700_000_000,
                 lifetime_revenue: 900,
@@ -126,9 +163,13 @@ pub fn scenario(name: &str) -> Option<SaveData> {
                 dealers_hired: 0,
                 total_prior_convictions: 1,
                 decks_played: 4,
-                stories: vec![
-                    "The first empire died broke on a corner.".to_string(),
-                ],
+                stories: vec![StoryRecord::note("The first empire died broke on a corner.")],
+                roster: Vec::new(),
+                chronicle: Vec::new(),
+                retirement: None,
+                dynasty: Vec::new(),
+                zones_unlocked: 1,
+                ascension: Default::default(),
             });
             save.fallen_empires.push(EmpireEpitaph {
                 ended_at: 1_710_000_000,
@@ -138,10 +179,18 @@ pub fn scenario(name: &str) -> Option<SaveData> {
                 total_prior_convictions: 3,
                 decks_played: 15,
                 stories: vec![
-                    "The second empire ran three dealers deep.".to_string(),
-                    "It ended in a warehouse full of warrants.".to_string(),
+                    StoryRecord::note("The second empire ran three dealers deep."),
+                    StoryRecord::note("It ended in a warehouse full of warrants."),
                 ],
+                roster: vec![DealerState::kingpin()],
+                chronicle: vec![at(1_710_000_000, ChronicleEvent::Fell { lifetime_revenue: 5000 })],
+                retirement: None,
+                dynasty: Vec::new(),
+                zones_unlocked: 2,
+                ascension: Default::default(),
             });
+            // SOW-064: the legacy those empires left, banked as close_empire would
+            save.legacy.earned = save.fallen_empires.iter().map(EmpireEpitaph::legacy_earned).sum();
         }
         // SOW-031/034: a live front mid-window - a Shrooms BATCH on Lil
         // Smoke's credit ($125 owed, 3 of 4 runs left), $60 cash (can pay after
@@ -345,6 +394,9 @@ mod tests {
         assert!(!save.dealers[0].character.story_history.is_empty());
         assert_eq!(save.dealers[1].name, "Ray");
         assert!(!save.dealers[1].character.story_history.is_empty());
+        // SOW-051: the books the chronicle export renders - buy, hire, jail, bail
+        assert_eq!(save.chronicle.len(), 4);
+        assert!(save.fallen_empires.iter().any(|e| !e.roster.is_empty()));
     }
 
     #[test]
@@ -4,6 +4,8 @@ mod types;
 mod crypto;
 mod io;
 pub mod forge; // SOW-023: dev save forge for e2e scenarios
+pub mod chronicle; // SOW-051: empire chronicle export (ledger button + CLI)
+pub mod settings; // SOW-053: player settings (locale), outside the signed save
 
 pub use types::*;
 
@@ -0,0 +1,68 @@
+// SOW-053: Player settings - preferences that aren't part of the empire.
+//
+// Kept out of the signed save on purpose: a player should be able to edit
+// settings.ron by hand, and wiping a save for a version bump (SOW-021) must
+// not lose them. Read once at startup by main.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+const SETTINGS_FILE: &str = "settings.ron";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    /// Locale id: "en", "pseudo", or a folder under assets/locales/
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+fn default_locale() -> String {
+    "en".to_string()
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self { locale: default_locale() }
+    }
+}
+
+/// Settings live next to the save (DDD_SAVE_DIR applies here too)
+pub fn settings_dir() -> PathBuf {
+    super::io::get_save_directory()
+}
+
+impl Settings {
+    /// The settings in `dir`; defaults when the file is missing or unreadable
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(dir.join(SETTINGS_FILE))
+            .ok()
+            .and_then(|content| ron::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self, dir: &Path) -> Result<(), String> {
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize settings: {e}"))?;
+        std::fs::write(dir.join(SETTINGS_FILE), content)
+            .map_err(|e| format!("Failed to write {SETTINGS_FILE}: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_round_trip_and_default_when_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(Settings::load(dir.path()), Settings::default());
+
+        let settings = Settings { locale: "pseudo".to_string() };
+        settings.store(dir.path()).unwrap();
+        assert_eq!(Settings::load(dir.path()), settings);
+
+        std::fs::write(dir.path().join(SETTINGS_FILE), "not ron").unwrap();
+        assert_eq!(Settings::load(dir.path()).locale, "en");
+    }
+}
@@ -5,6 +5,13 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::models::ascension::{Ascension, MAX_ASCENSION};
+use crate::models::boss::{BossDef, BossTrigger};
+use crate::models::victory::{VictoryCondition, VictoryDef};
+use crate::models::hand_state::HandOutcome;
+use crate::models::relic::{RelicDef, RelicEffect, RelicEffects, RelicMilestone, RelicSource};
+use crate::models::shop_location::{ZoneGraph, ZoneRoute};
+
 /// Current save file format version
 /// SOW-021: Bumped 1 -> 2 (upgrade thresholds changed from testing to spec values;
 /// older saves are rejected and the game starts a fresh account)
@@ -33,7 +40,49 @@ use std::time::{SystemTime, UNIX_EPOCH};
 // back-compat within the payload, but per the SOW-021 version-bump policy the
 // mismatch wipes older saves to a fresh account (io.rs rejects the mismatch) -
 // a fresh account re-offers the arc, which is exactly the intended state.
-pub const SAVE_VERSION: u32 = 11;
+// SOW-041: v12 gives every dealer their own saved deck + named loadouts
+// (DealerState.deck / .loadouts). serde-default keeps the payload back-compat,
+// but per the SOW-021 version-bump policy the mismatch wipes older saves to a
+// fresh account (io.rs rejects the mismatch).
+// SOW-042: v13 adds DealerState.order (day planning). serde-default keeps the
+// payload back-compat; the SOW-021 policy wipes older saves regardless.
+// SOW-043: v14 gives DealerStatus::Relocating the route being walked (zone
+// path + total runs) so the map can put a moving dealer on the road.
+// SOW-044: v15 adds SaveData.regulars (named buyers with relationship
+// memory). serde-default; the SOW-021 policy wipes older saves regardless.
+// SOW-050: v16 turns story_history / EmpireEpitaph.stories into StoryRecords
+// (text plus outcome, area, buyer, dealer, run...). bincode can't read the
+// old Vec<String> as records, so the SOW-021 policy wipes older saves.
+// SOW-051: v17 adds SaveData.chronicle and archives it (with the roster) in
+// EmpireEpitaph for the chronicle export. serde-default; the SOW-021 policy
+// wipes older saves regardless.
+// SOW-052: v18 adds SaveData.press_queue and .headlines (the morning paper
+// and its archive). serde-default; the SOW-021 policy wipes older saves
+// regardless.
+// SOW-056: v19 adds SaveData.mods (the content packs the save was last played
+// with). serde-default; the SOW-021 policy wipes older saves regardless.
+// SOW-057: v20 adds PendingUpgrade.evolution and CharacterState.evolved_cards
+// (card evolutions). serde-default; the SOW-021 policy wipes older saves
+// regardless.
+// SOW-058: v21 turns PendingUpgrade.options into UpgradeChoices (a stat or a
+// perk) and adds CardUpgrades.perks. bincode can't read the old options, so
+// the SOW-021 policy wipes older saves.
+// SOW-059: v22 adds SaveData.relics (the empire's relics). serde-default; the
+// SOW-021 policy wipes older saves regardless.
+// SOW-061: v23 adds SaveData.operations (task-force operations faced) and the
+// Operation chronicle entry. The SOW-021 policy wipes older saves.
+// SOW-062: v24 adds EmpireEpitaph.retirement (an empire that cashed out) and
+// the Retired chronicle entry. The SOW-021 policy wipes older saves.
+// SOW-063: v25 adds SaveData.succession and .dynasty, EmpireEpitaph.dynasty
+// and the Succeeded chronicle entry. The SOW-021 policy wipes older saves.
+// SOW-064: v26 adds SaveData.legacy and EmpireEpitaph.zones_unlocked. The
+// SOW-021 policy wipes older saves.
+// SOW-065: v27 adds SaveData.ascension and .ascension_unlocked, and
+// EmpireEpitaph.ascension. The SOW-021 policy wipes older saves.
+// SOW-064: v28 adds Legacy.earned, .active and .first_hire_used (legacy
+// banked as empires close, unlocks snapshotted per empire). The SOW-021
+// policy wipes older saves.
+pub const SAVE_VERSION: u32 = 28;
 
 /// Maximum sanity values for validation
 const MAX_HEAT: u32 = 10_000;
@@ -114,6 +163,312 @@ pub struct SaveData {
     /// Offered.
     #[serde(default)]
     pub tutorial: TutorialState,
+    /// SOW-044: named regulars - buyers who remember the empire. Dies with
+    /// the empire (reset_empire starts from new()): nobody on the street
+    /// knows the next kingpin.
+    #[serde(default)]
+    pub regulars: Vec<Regular>,
+    /// SOW-051: what the books remember that the stories don't - fronts,
+    /// jail terms, hires, zone buys and the fall, in order. Archived in the
+    /// epitaph; the next empire starts a fresh one.
+    #[serde(default)]
+    pub chronicle: Vec<ChronicleEntry>,
+    /// SOW-052: newsworthy chronicle entries the press hasn't written up
+    /// yet. The save layer has no fragments to write with, so the hub's
+    /// press system drains this. Survives reset_empire - the fall is news.
+    #[serde(default)]
+    pub press_queue: Vec<ChronicleEntry>,
+    /// SOW-052: every front page printed, oldest first (capped at
+    /// HEADLINE_ARCHIVE_CAP). Survives reset_empire like the board does.
+    #[serde(default)]
+    pub headlines: Vec<Headline>,
+    /// SOW-056: the mod packs this save last loaded with, in load order.
+    /// Survives reset_empire - it describes the install, not the empire.
+    #[serde(default)]
+    pub mods: Vec<ModStamp>,
+    /// SOW-059: the empire's relics, in the order they came. Die with the
+    /// empire (reset_empire starts from new()).
+    #[serde(default)]
+    pub relics: Vec<OwnedRelic>,
+    /// SOW-061: the task-force operations this empire has faced (beaten or
+    /// busted by), by id. A faced operation never comes due again; the next
+    /// empire starts with a clean slate.
+    #[serde(default)]
+    pub operations: Vec<String>,
+    /// SOW-063: the succession rule - when on, a qualified lieutenant takes
+    /// over from a busted kingpin instead of the empire falling. A house
+    /// rule: it carries into the next empire.
+    #[serde(default)]
+    pub succession: bool,
+    /// SOW-063: the kingpins this empire has lost to succession, oldest
+    /// first (the reigning one is dealers[0])
+    #[serde(default)]
+    pub dynasty: Vec<Reign>,
+    /// SOW-064: the permanent unlocks bought with what fallen empires left
+    /// behind. Outlives every empire; applied when a fresh one starts.
+    #[serde(default)]
+    pub legacy: Legacy,
+    /// SOW-065: this empire's difficulty level, chosen before its first deal
+    #[serde(default)]
+    pub ascension: Ascension,
+    /// SOW-065: the highest level on offer - retiring (or beating a
+    /// task-force operation) at the top level opens the next one
+    #[serde(default)]
+    pub ascension_unlocked: Ascension,
+}
+
+/// SOW-064: a permanent unlock - each slot can be filled once, and every
+/// empire after the purchase starts with it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum LegacyUnlock {
+    /// A second product in the starting collection, with a batch in stock
+    StartingProduct(String),
+    /// A zone owned from day one, with the kingpin stationed there
+    StartingZone(String),
+    /// The first hire costs LEGACY_FIRST_HIRE_PCT of the ladder
+    CheaperFirstHire,
+    /// A relic held from day one (copied at purchase, like a taken relic)
+    StartingRelic(OwnedRelic),
+}
+
+impl LegacyUnlock {
+    /// What the unlock costs in legacy
+    pub fn cost(&self) -> u64 {
+        match self {
+            LegacyUnlock::StartingProduct(_) => LEGACY_PRODUCT_COST,
+            LegacyUnlock::StartingZone(_) => LEGACY_ZONE_COST,
+            LegacyUnlock::CheaperFirstHire => LEGACY_HIRE_COST,
+            LegacyUnlock::StartingRelic(_) => LEGACY_RELIC_COST,
+        }
+    }
+
+    /// Whether two unlocks fill the same slot (one product, one zone...)
+    pub fn same_slot(&self, other: &LegacyUnlock) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// SOW-064: the unlocks bought so far and what they cost. The balance is
+/// `earned` less `spent`; `earned` is banked as each empire closes, so it
+/// doesn't lean on the archive of fallen empires.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Legacy {
+    pub earned: u64,
+    pub spent: u64,
+    pub unlocks: Vec<LegacyUnlock>,
+    /// The unlocks this empire started with - snapshotted at its start, so
+    /// a purchase mid-empire waits for the next one. The accessors below
+    /// read this.
+    pub active: Vec<LegacyUnlock>,
+    /// This empire has made its first hire (the discount is spent)
+    pub first_hire_used: bool,
+}
+
+impl Legacy {
+    /// The legacy as a fresh empire starts with it: today's unlocks become
+    /// the active ones, and the first hire is still to come
+    pub fn for_new_empire(mut self) -> Self {
+        self.active = self.unlocks.clone();
+        self.first_hire_used = false;
+        self
+    }
+
+    pub fn starting_product(&self) -> Option<&str> {
+        self.active.iter().find_map(|u| match u {
+            LegacyUnlock::StartingProduct(id) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn starting_zone(&self) -> Option<&str> {
+        self.active.iter().find_map(|u| match u {
+            LegacyUnlock::StartingZone(id) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn starting_relic(&self) -> Option<&OwnedRelic> {
+        self.active.iter().find_map(|u| match u {
+            LegacyUnlock::StartingRelic(relic) => Some(relic),
+            _ => None,
+        })
+    }
+
+    /// Whether this empire's next hire is discounted
+    pub fn cheaper_first_hire(&self) -> bool {
+        !self.first_hire_used && self.active.contains(&LegacyUnlock::CheaperFirstHire)
+    }
+}
+
+/// SOW-063: a kingpin who went down and was succeeded
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Reign {
+    /// The kingpin as they stood at the bust (dossier and stories)
+    pub kingpin: DealerState,
+    /// Unix seconds
+    pub fell_at: u64,
+    /// The empire's lifetime revenue at the bust - a reign's take is the
+    /// difference from the one before
+    pub lifetime_revenue: u64,
+}
+
+/// SOW-059: a relic the empire holds. Its effects are copied from the
+/// authored def when it's taken, so the rule points don't need the assets.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OwnedRelic {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub effects: Vec<RelicEffect>,
+}
+
+impl From<&RelicDef> for OwnedRelic {
+    fn from(def: &RelicDef) -> Self {
+        Self {
+            id: def.id.clone(),
+            name: def.name.clone(),
+            description: def.description.clone(),
+            effects: def.effects.clone(),
+        }
+    }
+}
+
+/// SOW-056: a mod pack as the save remembers it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ModStamp {
+    pub id: String,
+    pub version: String,
+}
+
+/// SOW-056: how the active packs differ from the ones a save remembers,
+/// one line per pack (empty when nothing changed)
+pub fn mod_changes(saved: &[ModStamp], active: &[ModStamp]) -> Vec<String> {
+    let mut changes = Vec::new();
+    for pack in active {
+        match saved.iter().find(|s| s.id == pack.id) {
+            None => changes.push(format!("added {} {}", pack.id, pack.version)),
+            Some(old) if old.version != pack.version => {
+                changes.push(format!("updated {} {} -> {}", pack.id, old.version, pack.version))
+            }
+            Some(_) => {}
+        }
+    }
+    for pack in saved.iter().filter(|s| !active.iter().any(|a| a.id == s.id)) {
+        changes.push(format!("removed {} {}", pack.id, pack.version));
+    }
+    changes
+}
+
+/// SOW-052: front pages kept in the archive; the oldest go first
+pub const HEADLINE_ARCHIVE_CAP: usize = 60;
+
+/// SOW-052: what made the paper. Picks the fragment set in headlines.ron.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeadlineKind {
+    /// The kingpin went down and the empire with them
+    KingpinFell,
+    /// A dealer jailed at Inferno heat
+    InfernoBust,
+    /// Any other dealer jailed
+    DealerJailed,
+    MuscleSeized,
+    MuscleBenched,
+    SupplierCutOff,
+    SupplierSoured,
+    /// A new zone bought into
+    ZoneOpened,
+}
+
+impl HeadlineKind {
+    pub const ALL: [HeadlineKind; 8] = [
+        Self::KingpinFell,
+        Self::InfernoBust,
+        Self::DealerJailed,
+        Self::MuscleSeized,
+        Self::MuscleBenched,
+        Self::SupplierCutOff,
+        Self::SupplierSoured,
+        Self::ZoneOpened,
+    ];
+
+    /// The story a chronicle entry makes, if it makes one. Hires, fronts
+    /// taken or paid and bail are the books, not the news.
+    pub fn of(event: &ChronicleEvent) -> Option<Self> {
+        match event {
+            ChronicleEvent::Fell { .. } => Some(Self::KingpinFell),
+            ChronicleEvent::Jailed { heat, .. } if HeatTier::from_heat(*heat) == HeatTier::Inferno => {
+                Some(Self::InfernoBust)
+            }
+            ChronicleEvent::Jailed { .. } => Some(Self::DealerJailed),
+            ChronicleEvent::MuscleSeized { .. } => Some(Self::MuscleSeized),
+            ChronicleEvent::MuscleBenched { .. } => Some(Self::MuscleBenched),
+            ChronicleEvent::CutOff { .. } => Some(Self::SupplierCutOff),
+            ChronicleEvent::Soured { .. } => Some(Self::SupplierSoured),
+            ChronicleEvent::ZoneBought { .. } => Some(Self::ZoneOpened),
+            ChronicleEvent::Hired { .. }
+            | ChronicleEvent::FrontTaken { .. }
+            | ChronicleEvent::FrontPaid { .. }
+            | ChronicleEvent::BailedOut { .. }
+            | ChronicleEvent::Operation { .. }
+            | ChronicleEvent::Retired { .. }
+            | ChronicleEvent::Succeeded { .. } => None,
+        }
+    }
+}
+
+/// SOW-052: one printed front page
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Headline {
+    /// When it happened (the chronicle entry's time), Unix seconds
+    pub at: u64,
+    pub kind: HeadlineKind,
+    pub headline: String,
+    pub article: String,
+}
+
+/// SOW-051: one line of the empire's chronicle
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ChronicleEntry {
+    /// Unix seconds
+    pub at: u64,
+    pub event: ChronicleEvent,
+}
+
+/// SOW-051: the events a chronicle records. Written by the SaveData methods
+/// that make them happen, so no caller can forget one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ChronicleEvent {
+    Hired { dealer: String, area_id: String, cost: u64 },
+    ZoneBought { area_id: String, price: u64 },
+    FrontTaken { card_id: String, area_id: String, owed: u64 },
+    FrontPaid { card_id: String, area_id: String, owed: u64 },
+    /// First blown due date
+    CutOff { area_id: String },
+    MuscleSeized { area_id: String, amount: u64 },
+    MuscleBenched { area_id: String, dealer: String },
+    Soured { area_id: String, card_id: String },
+    /// A dealer's bust: the sentence and the career heat it was priced at
+    Jailed { dealer: String, runs: u32, heat: u32 },
+    BailedOut { dealer: String, cost: u64 },
+    /// The kingpin's bust - the last line of every fallen empire
+    Fell { lifetime_revenue: u64 },
+    /// SOW-061: a task-force operation faced, and how it went
+    Operation { name: String, dealer: String, beaten: bool },
+    /// SOW-063: the kingpin went down and a lieutenant took over
+    Succeeded { fallen: String, heir: String, zones_lost: u32, seized: u64 },
+    /// SOW-062: the kingpin cashed out - the last line of a retired empire
+    Retired { score: u64 },
+}
+
+impl From<&FrontEvent> for ChronicleEvent {
+    fn from(event: &FrontEvent) -> Self {
+        match event.clone() {
+            FrontEvent::CutOff { area_id } => Self::CutOff { area_id },
+            FrontEvent::MuscleSeized { area_id, amount } => Self::MuscleSeized { area_id, amount },
+            FrontEvent::MuscleBenched { area_id, dealer } => Self::MuscleBenched { area_id, dealer },
+            FrontEvent::Soured { area_id, card_id } => Self::Soured { area_id, card_id },
+        }
+    }
 }
 
 /// SOW-032: where the player stands with the "Road to Your First Dealer" arc.
@@ -233,6 +588,93 @@ pub enum FrontEvent {
     Soured { area_id: String, card_id: String },
 }
 
+/// SOW-044: a named REGULAR - a buyer persona who came back. Spawned from a
+/// persona after a good deal, remembered across sessions: how they feel
+/// about the empire (`relationship`, 0..=REGULAR_RELATIONSHIP_MAX) and what
+/// they like. The persona (display_name) supplies their demands, reaction
+/// deck and portrait; the regular is the memory on top.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Regular {
+    pub name: String,
+    /// BuyerPersona.display_name they're a face of
+    pub persona: String,
+    /// Area they buy in (their persona's home)
+    pub area: String,
+    pub relationship: u32,
+    /// Product card name from the deal that made them a regular
+    pub favourite_product: Option<String>,
+    /// Location card name from that deal
+    pub favourite_location: Option<String>,
+    /// Deals closed with them since they became a regular
+    pub deals: u32,
+    /// The regular who sent them, if they came by referral
+    pub referred_by: Option<String>,
+    /// Turned on the empire: never buys again, stays on the record
+    pub informant: bool,
+}
+
+impl Regular {
+    /// Still buying (informants never come back)
+    pub fn is_active(&self) -> bool {
+        !self.informant
+    }
+
+    /// What the relationship does to their payout: loyal regulars pay a
+    /// premium, sour ones haggle. Scales the persona's base multiplier.
+    pub fn loyalty_factor(&self) -> f32 {
+        if self.relationship >= REGULAR_LOYAL {
+            1.2
+        } else if self.relationship >= REGULAR_START_RELATIONSHIP {
+            1.1
+        } else if self.relationship < REGULAR_WARY {
+            0.85
+        } else {
+            1.0
+        }
+    }
+
+    /// The relationship at a glance, as a locale id the views tr():
+    /// regular.informant (turned, whatever the score), then regular.loyal /
+    /// .friendly / .wary / .sour by relationship (SOW-053)
+    pub fn mood(&self) -> &'static str {
+        if self.informant {
+            "regular.informant"
+        } else if self.relationship >= REGULAR_LOYAL {
+            "regular.loyal"
+        } else if self.relationship >= REGULAR_START_RELATIONSHIP {
+            "regular.friendly"
+        } else if self.relationship >= REGULAR_WARY {
+            "regular.wary"
+        } else {
+            "regular.sour"
+        }
+    }
+}
+
+/// SOW-044: a hand just resolved in front of a buyer - everything the
+/// regulars bookkeeping needs (SaveData::record_buyer_deal)
+pub struct BuyerDeal<'a> {
+    pub dealer_idx: usize,
+    pub area: &'a str,
+    pub buyer: &'a crate::models::buyer::BuyerPersona,
+    pub outcome: &'a crate::models::hand_state::HandOutcome,
+    pub profit: u32,
+    pub played: &'a [crate::models::card::Card],
+}
+
+/// SOW-044: what a deal did to the street's memory (the relationship
+/// moving on its own isn't news - the ledger shows it)
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegularEvent {
+    /// A first-time buyer liked the deal enough to come back
+    BecameRegular { name: String },
+    /// A loyal regular sent a friend
+    Referred { by: String, name: String },
+    /// Burned once too often: they talked, and the dealer they knew
+    /// picked up the heat
+    TurnedInformant { name: String, dealer: String, heat: u32 },
+}
+
 /// The tombstone of one empire: summary stats for the arcade leaderboard
 /// plus the archived stories of everyone who worked it
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -247,7 +689,37 @@ pub struct EmpireEpitaph {
     /// Decks played across the whole roster
     pub decks_played: u32,
     /// Aggregate story history of every dealer (archive for SOW-026)
-    pub stories: Vec<String>,
+    pub stories: Vec<StoryRecord>,
+    /// SOW-051: the roster as it stood at the fall (dossiers for the
+    /// chronicle export)
+    #[serde(default)]
+    pub roster: Vec<DealerState>,
+    /// SOW-051: the empire's chronicle, ending with its fall
+    #[serde(default)]
+    pub chronicle: Vec<ChronicleEntry>,
+    /// SOW-062: Some when the kingpin cashed out instead of falling
+    #[serde(default)]
+    pub retirement: Option<Retirement>,
+    /// SOW-063: the kingpins succeeded before the last one (roster[0])
+    #[serde(default)]
+    pub dynasty: Vec<Reign>,
+    /// SOW-064: zones the empire owned at the end
+    #[serde(default)]
+    pub zones_unlocked: u32,
+    /// SOW-065: the difficulty level it played at - boards rank by it first
+    #[serde(default)]
+    pub ascension: Ascension,
+}
+
+/// SOW-062: how a retired empire scored
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct Retirement {
+    /// Lifetime revenue + clean cash + every met victory's bonus
+    pub score: u64,
+    /// Cash on hand with every front paid off
+    pub clean_cash: u64,
+    /// Names of the victories met, in authored order
+    pub victories: Vec<String>,
 }
 
 impl EmpireEpitaph {
@@ -260,40 +732,315 @@ impl EmpireEpitaph {
             dealers_hired: save.dealers.len().saturating_sub(1) as u32,
             total_prior_convictions: save.dealers.iter().map(|d| d.prior_convictions).sum(),
             decks_played: save.dealers.iter().map(|d| d.character.decks_played).sum(),
+            // SOW-063: the succeeded kingpins' stories open the archive
             stories: save
-                .dealers
+                .dynasty
                 .iter()
+                .map(|reign| &reign.kingpin)
+                .chain(&save.dealers)
                 .flat_map(|d| d.character.story_history.iter().cloned())
                 .collect(),
+            roster: save.dealers.clone(),
+            chronicle: save.chronicle.clone(),
+            retirement: None,
+            dynasty: save.dynasty.clone(),
+            zones_unlocked: save.account.unlocked_locations.len() as u32,
+            ascension: save.ascension,
         }
     }
+
+    /// SOW-064: the legacy this empire left behind - its take, the crew it
+    /// built and the ground it held
+    pub fn legacy_earned(&self) -> u64 {
+        self.lifetime_revenue / LEGACY_PER_REVENUE
+            + u64::from(self.dealers_hired) * LEGACY_PER_HIRE
+            + u64::from(self.zones_unlocked) * LEGACY_PER_ZONE
+    }
 }
 
 /// Top-N fallen empires by lifetime revenue (indices into the input slice,
-/// so callers can mark "this run" by comparing against the latest index)
+/// so callers can mark "this run" by comparing against the latest index).
+/// SOW-062: retirements are ranked on their own board.
+/// SOW-065: a higher ascension level outranks any revenue.
 pub fn leaderboard_top(fallen: &[EmpireEpitaph], n: usize) -> Vec<usize> {
-    let mut order: Vec<usize> = (0..fallen.len()).collect();
-    order.sort_by(|a, b| fallen[*b].lifetime_revenue.cmp(&fallen[*a].lifetime_revenue));
+    let mut order: Vec<usize> = (0..fallen.len()).filter(|i| fallen[*i].retirement.is_none()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse((fallen[*i].ascension, fallen[*i].lifetime_revenue)));
+    order.truncate(n);
+    order
+}
+
+/// SOW-062: top-N retired empires by score (indices into the input slice).
+/// SOW-065: level first, as on the fallen board.
+pub fn retirement_top(epitaphs: &[EmpireEpitaph], n: usize) -> Vec<usize> {
+    let score = |i: &usize| epitaphs[*i].retirement.as_ref().map(|r| (epitaphs[*i].ascension, r.score));
+    let mut order: Vec<usize> = (0..epitaphs.len()).filter(|i| score(i).is_some()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse(score(i)));
     order.truncate(n);
     order
 }
 
 impl SaveData {
     pub fn new() -> Self {
+        Self::with_legacy(Legacy::default())
+    }
+
+    /// SOW-064: a fresh empire starting with what the legacy has unlocked
+    pub fn with_legacy(legacy: Legacy) -> Self {
+        let legacy = legacy.for_new_empire();
+        let mut dealers = vec![DealerState::kingpin()];
+        if let Some(zone) = legacy.starting_zone() {
+            dealers[0].station = zone.to_string();
+        }
         Self {
             // RFC-023: every empire starts with the kingpin dealing in person
-            dealers: vec![DealerState::kingpin()],
+            dealers,
             active_dealer: 0,
-            account: AccountState::new(),
+            account: AccountState::with_legacy(&legacy),
             fallen_empires: Vec::new(),
             fronts: Vec::new(),
             supplier_standing: HashMap::new(),
             // SOW-032: a fresh empire is offered the guided start. reset_empire
             // routes through new(), so every fresh empire re-offers the arc.
             tutorial: TutorialState::default(),
+            regulars: Vec::new(),
+            chronicle: Vec::new(),
+            press_queue: Vec::new(),
+            headlines: Vec::new(),
+            mods: Vec::new(),
+            relics: legacy.starting_relic().cloned().into_iter().collect(),
+            operations: Vec::new(),
+            succession: false,
+            dynasty: Vec::new(),
+            legacy,
+            ascension: Ascension::default(),
+            ascension_unlocked: Ascension::default(),
         }
     }
 
+    /// SOW-065: pick this empire's difficulty. Only before its first deal,
+    /// and only up to the highest level unlocked.
+    pub fn set_ascension(&mut self, level: Ascension) -> Result<(), &'static str> {
+        if level > self.ascension_unlocked {
+            return Err("not unlocked yet");
+        }
+        if self.underway() {
+            return Err("the empire is already underway");
+        }
+        self.ascension = level;
+        Ok(())
+    }
+
+    /// SOW-065: whether the empire has dealt or done business yet
+    pub fn underway(&self) -> bool {
+        self.account.hands_completed > 0 || !self.chronicle.is_empty()
+    }
+
+    /// SOW-064: legacy earned across every closed empire, less what's
+    /// been spent
+    pub fn legacy_balance(&self) -> u64 {
+        self.legacy.earned.saturating_sub(self.legacy.spent)
+    }
+
+    /// SOW-064: buy a permanent unlock. It takes effect when the next
+    /// empire starts. Returns the legacy spent.
+    pub fn buy_legacy(&mut self, unlock: LegacyUnlock) -> Result<u64, &'static str> {
+        if self.legacy.unlocks.iter().any(|owned| owned.same_slot(&unlock)) {
+            return Err("already unlocked");
+        }
+        let cost = unlock.cost();
+        if self.legacy_balance() < cost {
+            return Err("not enough legacy");
+        }
+        self.legacy.spent += cost;
+        self.legacy.unlocks.push(unlock);
+        Ok(cost)
+    }
+
+    /// SOW-059: whether the empire holds a relic
+    pub fn has_relic(&self, id: &str) -> bool {
+        self.relics.iter().any(|r| r.id == id)
+    }
+
+    /// SOW-059: every held relic's effects, added up
+    pub fn relic_effects(&self) -> RelicEffects {
+        RelicEffects::sum(self.relics.iter().flat_map(|r| &r.effects))
+    }
+
+    /// SOW-059: the event and milestone relics the empire has earned but
+    /// doesn't hold yet. Events count from this empire's chronicle.
+    pub fn relics_due<'a>(&self, defs: &'a [RelicDef]) -> Vec<&'a RelicDef> {
+        defs.iter()
+            .filter(|def| !self.has_relic(&def.id))
+            .filter(|def| match &def.source {
+                RelicSource::Event(event) => self.chronicle.iter().any(|entry| event.matches(&entry.event)),
+                RelicSource::Milestone(milestone) => match *milestone {
+                    RelicMilestone::Revenue(n) => self.account.lifetime_revenue >= n,
+                    RelicMilestone::Hands(n) => self.account.hands_completed >= n,
+                    RelicMilestone::Zones(n) => self.account.unlocked_locations.len() >= n as usize,
+                    RelicMilestone::Roster(n) => self.dealers.len() >= n as usize,
+                },
+                RelicSource::Shop { .. } => false,
+            })
+            .collect()
+    }
+
+    /// SOW-059: take a relic. False if the empire already holds it.
+    pub fn grant_relic(&mut self, def: &RelicDef) -> bool {
+        if self.has_relic(&def.id) {
+            return false;
+        }
+        self.relics.push(OwnedRelic::from(def));
+        true
+    }
+
+    /// SOW-059: buy a relic from its zone's rare slot
+    pub fn buy_relic(&mut self, def: &RelicDef) -> Result<u64, &'static str> {
+        let RelicSource::Shop { area, price } = &def.source else {
+            return Err("not for sale");
+        };
+        if self.has_relic(&def.id) {
+            return Err("already yours");
+        }
+        if !self.account.unlocked_locations.contains(area) {
+            return Err("zone not yours yet");
+        }
+        let price = u64::from(*price);
+        if !self.account.spend(price) {
+            return Err("not enough cash");
+        }
+        self.grant_relic(def);
+        Ok(price)
+    }
+
+    /// SOW-061: the first operation the empire has earned and not faced -
+    /// the one waiting at the next run's last call
+    pub fn operation_due<'a>(&self, defs: &'a [BossDef]) -> Option<&'a BossDef> {
+        defs.iter()
+            .filter(|def| !self.operations.contains(&def.id))
+            .find(|def| match def.trigger {
+                BossTrigger::Tier(tier) => self
+                    .dealers
+                    .iter()
+                    .any(|d| HeatTier::from_heat(d.character.heat) >= tier),
+                BossTrigger::Revenue(n) => self.account.lifetime_revenue >= n,
+                BossTrigger::Zones(n) => self.account.unlocked_locations.len() >= n as usize,
+            })
+    }
+
+    /// SOW-061: close the books on an operation the active dealer played.
+    /// Safe pays the bounty and the relic; a bust adds the extra time to
+    /// the sentence (jail_for_bust has already run). Either way it's faced
+    /// and goes in the chronicle. Any other outcome (a fold, a bail) leaves
+    /// it waiting - false.
+    pub fn settle_operation(&mut self, def: &BossDef, outcome: HandOutcome, relic: Option<&RelicDef>) -> bool {
+        let beaten = match outcome {
+            HandOutcome::Safe => true,
+            HandOutcome::Busted => false,
+            _ => return false,
+        };
+        if self.operations.contains(&def.id) {
+            return false;
+        }
+        let runner = self.active_dealer;
+        if beaten {
+            let account = &mut self.account;
+            account.cash_on_hand = account.cash_on_hand.saturating_add(u64::from(def.bounty)).min(MAX_CASH);
+            if let Some(relic) = relic {
+                self.grant_relic(relic);
+            }
+        } else if let Some(dealer) = self.dealers.get_mut(runner) {
+            dealer.extend_sentence(def.sentence);
+        }
+        self.operations.push(def.id.clone());
+        let dealer = self.active_dealer_state().name.clone();
+        self.chronicle_event(ChronicleEvent::Operation { name: def.name.clone(), dealer, beaten });
+        true
+    }
+
+    /// SOW-062: the victories the empire meets right now. `zones` is how
+    /// many zones the map has.
+    pub fn victories_met<'a>(&self, defs: &'a [VictoryDef], zones: usize) -> Vec<&'a VictoryDef> {
+        defs.iter()
+            .filter(|def| match def.condition {
+                VictoryCondition::AllZones => zones > 0 && self.account.unlocked_locations.len() >= zones,
+                VictoryCondition::CleanCash(n) => self.clean_cash() >= n,
+                VictoryCondition::FreeDealers(n) => {
+                    let free = self.dealers.iter().filter(|d| !d.is_kingpin && d.jail_remaining().is_none());
+                    free.count() >= n as usize
+                }
+                VictoryCondition::Revenue(n) => self.account.lifetime_revenue >= n,
+            })
+            .collect()
+    }
+
+    /// SOW-062: cash on hand once every front is paid off
+    pub fn clean_cash(&self) -> u64 {
+        self.account.cash_on_hand.saturating_sub(self.total_debt())
+    }
+
+    /// SOW-062: what the empire would retire with today
+    pub fn retirement(&self, defs: &[VictoryDef], zones: usize) -> Retirement {
+        let met = self.victories_met(defs, zones);
+        let clean_cash = self.clean_cash();
+        let bonus: u64 = met.iter().map(|def| def.bonus).sum();
+        Retirement {
+            score: self.account.lifetime_revenue.saturating_add(clean_cash).saturating_add(bonus),
+            clean_cash,
+            victories: met.iter().map(|def| def.name.clone()).collect(),
+        }
+    }
+
+    /// SOW-062: the kingpin cashes out. The epitaph is flagged as a
+    /// retirement and closes on `ending` (the story engine's last word),
+    /// then the empire starts over just like a fall.
+    pub fn retire(&mut self, retirement: Retirement, ending: String) {
+        self.chronicle_event(ChronicleEvent::Retired { score: retirement.score });
+        let mut epitaph = EmpireEpitaph::from_save(self, current_timestamp());
+        epitaph.stories.push(StoryRecord {
+            dealer: self.dealers.iter().find(|d| d.is_kingpin).map(|d| d.name.clone()).unwrap_or_default(),
+            timestamp: epitaph.ended_at,
+            ..StoryRecord::note(ending)
+        });
+        epitaph.retirement = Some(retirement);
+        self.close_empire(epitaph);
+    }
+
+    /// SOW-056: remember the packs this session loaded with. True when they
+    /// differ from the last session's (the caller saves and reports it).
+    pub fn sync_mods(&mut self, active: &[ModStamp]) -> bool {
+        if self.mods == active {
+            return false;
+        }
+        self.mods = active.to_vec();
+        true
+    }
+
+    /// SOW-051: write a line in the chronicle, stamped now
+    pub fn chronicle_event(&mut self, event: ChronicleEvent) {
+        let entry = ChronicleEntry { at: current_timestamp(), event };
+        // SOW-052: the news goes to the press as well as the books
+        if HeadlineKind::of(&entry.event).is_some() {
+            self.press_queue.push(entry.clone());
+        }
+        self.chronicle.push(entry);
+    }
+
+    /// SOW-052: file printed front pages in the archive, dropping the
+    /// oldest past HEADLINE_ARCHIVE_CAP
+    pub fn file_headlines(&mut self, headlines: Vec<Headline>) {
+        self.headlines.extend(headlines);
+        let excess = self.headlines.len().saturating_sub(HEADLINE_ARCHIVE_CAP);
+        self.headlines.drain(..excess);
+    }
+
+    /// SOW-051: buy a zone (AccountState::purchase_location) and put it on
+    /// the record
+    pub fn buy_zone(&mut self, area_id: &str, price: u64) -> Result<(), &'static str> {
+        self.account.purchase_location(area_id, price)?;
+        self.chronicle_event(ChronicleEvent::ZoneBought { area_id: area_id.to_string(), price });
+        Ok(())
+    }
+
     /// The dealer selected to run sessions (roster is never empty and the
     /// index is validated on load, so plain indexing is safe)
     pub fn active_dealer_state(&self) -> &DealerState {
@@ -320,7 +1067,13 @@ impl SaveData {
     /// now grows ONLY through hire_signature_dealer / hire_zone_dealer, both of
     /// which spend this same ladder).
     pub fn next_hire_cost(&self) -> u64 {
-        hire_cost(self.dealers.len())
+        let cost = hire_cost(self.dealers.len());
+        // SOW-064: the legacy discount covers the empire's first hire only
+        if self.legacy.cheaper_first_hire() {
+            cost * LEGACY_FIRST_HIRE_PCT / 100
+        } else {
+            cost
+        }
     }
 
     /// SOW-038 (was `has_signature_dealer`): whether a specific zone dealer -
@@ -360,8 +1113,14 @@ impl SaveData {
         if !self.account.spend(cost) {
             return false;
         }
+        self.legacy.first_hire_used = true;
         self.dealers
             .push(DealerState::zone_dealer(area_id, name, portrait));
+        self.chronicle_event(ChronicleEvent::Hired {
+            dealer: name.to_string(),
+            area_id: area_id.to_string(),
+            cost,
+        });
         true
     }
 
@@ -397,41 +1156,49 @@ impl SaveData {
     /// A run just completed somewhere in the empire: every jailed dealer's
     /// sentence ticks down, EXCEPT the runner's (a dealer jailed by this very
     /// run must not start serving on it). Returns the names released.
-    pub fn complete_run_tick(&mut self, runner: usize) -> Vec<String> {
+    /// SOW-063: None when the runner is gone (a kingpin bust) - everyone
+    /// still on the roster ticks.
+    pub fn complete_run_tick(&mut self, runner: Option<usize>) -> Vec<String> {
         self.dealers
             .iter_mut()
             .enumerate()
-            .filter(|(idx, _)| *idx != runner)
+            .filter(|(idx, _)| Some(*idx) != runner)
             .filter_map(|(_, d)| d.tick_sentence().then(|| d.name.clone()))
             .collect()
     }
 
-    /// SOW-025: relocate a dealer to another area. Costs the flat move fee
-    /// from global cash plus one run of downtime ("getting established").
-    /// The station changes immediately; the dealer is unavailable until the
-    /// relocation ticks out. Returns false (no mutation) if the dealer is
-    /// unavailable, already stationed there, or the fee is unaffordable.
-    pub fn move_dealer(&mut self, dealer_idx: usize, to_area: &str) -> bool {
+    /// SOW-025: relocate a dealer to another area, paid from global cash,
+    /// with downtime while they get established. The dealer is unavailable
+    /// until the relocation ticks out.
+    /// SOW-043: the move follows a planned route (ZoneGraph::route) and is
+    /// priced by it: its cost up front, one run of downtime per run on the
+    /// road. The station becomes the
+    /// route's last zone immediately; the route rides on the Relocating
+    /// status so the map can show where on the road they are. Returns false
+    /// (no mutation) if the route doesn't start at the dealer's station.
+    pub fn move_dealer_along(&mut self, dealer_idx: usize, route: &ZoneRoute) -> bool {
         let Some(dealer) = self.dealers.get(dealer_idx) else {
             return false;
         };
-        if !dealer.is_available() || dealer.station == to_area {
+        let (Some(from), Some(to_area)) = (route.path.first(), route.path.last()) else {
+            return false;
+        };
+        if !dealer.is_available() || *from != dealer.station || dealer.station == *to_area {
             return false;
         }
-        if !self.account.spend(MOVE_FEE) {
+        if !self.account.spend(route.cost) {
             return false;
         }
         let dealer = &mut self.dealers[dealer_idx];
-        dealer.station = to_area.to_string();
-        dealer.status = DealerStatus::Relocating { runs_remaining: 1 };
+        dealer.station = to_area.clone();
+        dealer.status = DealerStatus::Relocating {
+            runs_remaining: route.runs.max(1),
+            route: route.path.clone(),
+            total_runs: route.runs.max(1),
+        };
         true
     }
 
-    /// SOW-025: the flat relocation fee (exposed for UI labels)
-    pub fn move_fee(&self) -> u64 {
-        MOVE_FEE
-    }
-
     /// SOW-027: send a dealer underground - LAY_LOW_COST up front, benched
     /// for LAY_LOW_RUNS runs, sheds LAY_LOW_COOLING heat on resurfacing.
     /// Available-only with heat to shed: a jailed dealer's heat is settled
@@ -459,6 +1226,332 @@ impl SaveData {
         LAY_LOW_COST
     }
 
+    /// SOW-042: give a dealer their order for the day. The kingpin never
+    /// runs off-screen (a bust there would end the empire behind the
+    /// player's back), and a move must target another unlocked zone.
+    /// Error strings are display-ready.
+    pub fn set_order(&mut self, dealer_idx: usize, order: DealerOrder) -> Result<(), &'static str> {
+        let Some(dealer) = self.dealers.get(dealer_idx) else {
+            return Err("no such dealer");
+        };
+        match &order {
+            DealerOrder::Run(_) if dealer.is_kingpin => {
+                return Err("the boss only deals in person");
+            }
+            DealerOrder::Move { to_area } if *to_area == dealer.station => {
+                return Err("already stationed there");
+            }
+            DealerOrder::Move { to_area } if !self.account.unlocked_locations.contains(to_area) => {
+                return Err("that zone isn't yours yet");
+            }
+            _ => {}
+        }
+        self.dealers[dealer_idx].order = order;
+        Ok(())
+    }
+
+    /// SOW-042: carry out a dealer's NON-run order at day's end. Rest and
+    /// Scout always land; LayLow and Move reuse the roster actions (and
+    /// their guards), then fall back to Rest - they're one-shot. Returns
+    /// None for a Run order (the caller simulates it) or an unavailable
+    /// dealer (they serve their downtime instead).
+    pub fn carry_out_order(
+        &mut self,
+        dealer_idx: usize,
+        graph: &ZoneGraph,
+    ) -> Option<OrderOutcome> {
+        let dealer = self.dealers.get(dealer_idx)?;
+        if !dealer.is_available() {
+            return None;
+        }
+        let order = dealer.order.clone();
+        let outcome = match &order {
+            DealerOrder::Run(_) => return None,
+            DealerOrder::Rest => {
+                let heat = &mut self.dealers[dealer_idx].character.heat;
+                let cooled = (*heat).min(REST_COOLING);
+                *heat -= cooled;
+                OrderOutcome::Rested { cooled }
+            }
+            DealerOrder::Scout => {
+                let dealer = &mut self.dealers[dealer_idx];
+                let area = dealer.station.clone();
+                dealer.add_cred(&area);
+                let cred = dealer.cred_in(&area);
+                OrderOutcome::Scouted { area, cred }
+            }
+            DealerOrder::LayLow => {
+                if self.lay_low(dealer_idx) {
+                    OrderOutcome::LayingLow
+                } else if self.dealers[dealer_idx].character.heat == 0 {
+                    OrderOutcome::Refused("day.reason_no_heat")
+                } else {
+                    OrderOutcome::Refused("day.reason_lay_low_cost")
+                }
+            }
+            DealerOrder::Move { to_area } => {
+                // SOW-043: the order walks the graph like a map move
+                let route = graph.route(&self.dealers[dealer_idx].station, to_area);
+                if route.is_some_and(|route| self.move_dealer_along(dealer_idx, &route)) {
+                    OrderOutcome::Moved { to_area: to_area.clone() }
+                } else {
+                    OrderOutcome::Refused("day.reason_no_route")
+                }
+            }
+        };
+        if order.is_one_shot() {
+            self.dealers[dealer_idx].order = DealerOrder::Rest;
+        }
+        Some(outcome)
+    }
+
+    /// SOW-042: bank one Safe hand for a dealer - +1 street cred in the run's
+    /// area (SOW-025), the profit into account cash (RFC-016), and a play
+    /// count per player card with any upgrade it earned queued (RFC-017/019).
+    /// The ONE save path for a Safe hand: the played hand
+    /// (save_after_resolution_system) and off-screen runs both bank here.
+    /// Returns the names of cards that queued an upgrade.
+    pub fn bank_safe_hand(
+        &mut self,
+        dealer_idx: usize,
+        area: &str,
+        profit: u32,
+        played: &[crate::models::card::Card],
+    ) -> Vec<String> {
+        use crate::models::card::CardType;
+        let Some(dealer) = self.dealers.get_mut(dealer_idx) else {
+            return Vec::new();
+        };
+        dealer.add_cred(area);
+        // SOW-058: +1 more per played card with the StreetCred perk
+        for card in played {
+            let upgrades = dealer.character.card_upgrades.get(&card.name);
+            if upgrades.is_some_and(|u| u.has_perk(UpgradePerk::StreetCred)) {
+                dealer.add_cred(area);
+            }
+        }
+        let mut upgraded = Vec::new();
+        if profit > 0 {
+            // Player card types only (not Narc Evidence/Conviction or the
+            // buyer's reaction cards)
+            for card in played {
+                let is_player_card = matches!(
+                    card.card_type,
+                    CardType::Product { .. }
+                        | CardType::Location { .. }
+                        | CardType::Cover { .. }
+                        | CardType::DealModifier { .. }
+                        | CardType::Insurance { .. }
+                );
+                if is_player_card {
+                    dealer.character.increment_play_count(&card.name);
+                    if dealer.character.queue_pending_upgrade(&card.name, &card.card_type) {
+                        upgraded.push(card.name.clone());
+                    }
+                }
+            }
+            self.account.add_profit(profit);
+        }
+        upgraded
+    }
+
+    /// SOW-042: close out a dealer's session - the signed session heat onto
+    /// their career heat, the stories into their history, and a completed
+    /// deck on a Safe finish. Shared by GO HOME and off-screen runs. A
+    /// BUSTED session is priced at resolution instead (see jail_for_bust).
+    /// SOW-050: the records come from resolution; the dealer and run they
+    /// belong to are stamped here.
+    pub fn close_session(
+        &mut self,
+        dealer_idx: usize,
+        session_heat: i32,
+        stories: &[StoryRecord],
+        finished_safe: bool,
+    ) {
+        let Some(dealer) = self.dealers.get_mut(dealer_idx) else {
+            return;
+        };
+        let run = dealer.character.decks_played + 1;
+        let records: Vec<StoryRecord> = stories
+            .iter()
+            .map(|story| StoryRecord { dealer: dealer.name.clone(), run, ..story.clone() })
+            .collect();
+        let character = &mut dealer.character;
+        // Signed transfer: a cooling session reduces career heat (floor 0)
+        character.apply_session_heat(session_heat);
+        character.last_played = current_timestamp();
+        character.story_history.extend(records);
+        if finished_safe {
+            character.mark_deck_completed();
+        }
+    }
+
+    /// SOW-042: RFC-023's dealer bust - session heat lands first so the
+    /// crime is priced at the heat it happened at, then the sentence. Not
+    /// for the kingpin (a kingpin bust ends the empire - reset_empire).
+    /// Returns the sentence in runs.
+    /// SOW-059: relics can shorten the sentence.
+    pub fn jail_for_bust(&mut self, dealer_idx: usize, session_heat: i32) -> u32 {
+        let relics = self.relic_effects();
+        let Some(dealer) = self.dealers.get_mut(dealer_idx) else {
+            return 0;
+        };
+        dealer.character.apply_session_heat(session_heat);
+        dealer.jail_with(&relics);
+        let runs = dealer.jail_remaining().unwrap_or(0);
+        let event = ChronicleEvent::Jailed {
+            dealer: dealer.name.clone(),
+            runs,
+            heat: dealer.character.heat,
+        };
+        self.chronicle_event(event);
+        runs
+    }
+
+    /// SOW-044: the area's active regulars, in the order they were made
+    pub fn regulars_in(&self, area: &str) -> impl Iterator<Item = &Regular> {
+        let area = area.to_string();
+        self.regulars.iter().filter(move |r| r.is_active() && r.area == area)
+    }
+
+    /// SOW-044: the street remembers a hand. `roll` in [0, 1) decides the
+    /// one chance the outcome can trigger (callers pass rand; tests pin it):
+    /// - a first-time buyer after a Safe deal may become a regular
+    /// - a loyal regular after a Safe deal may refer another of the area's
+    ///   personas (`personas`) as a new regular
+    /// - a regular burned down to REGULAR_INFORMANT_THRESHOLD may turn
+    ///   informant, putting REGULAR_INFORMANT_HEAT on the dealer
+    ///
+    /// Safe deals warm a regular (more with their favourites on the table);
+    /// folding, busting and walked-out deals chill them. Call it BEFORE
+    /// jail_for_bust so an informant's heat prices the sentence.
+    pub fn record_buyer_deal(
+        &mut self,
+        deal: &BuyerDeal,
+        personas: &[crate::models::buyer::BuyerPersona],
+        roll: f32,
+    ) -> Option<RegularEvent> {
+        use crate::models::hand_state::HandOutcome;
+
+        let Some(regular_name) = deal.buyer.regular.as_ref().map(|r| r.name.clone()) else {
+            // A walk-in: only a good deal brings them back
+            if *deal.outcome != HandOutcome::Safe || deal.profit == 0 || roll >= REGULAR_SPAWN_CHANCE {
+                return None;
+            }
+            return self
+                .add_regular(deal.buyer, deal.area, deal.played, REGULAR_START_RELATIONSHIP, None)
+                .map(|name| RegularEvent::BecameRegular { name });
+        };
+        let idx = self
+            .regulars
+            .iter()
+            .position(|r| r.name == regular_name && r.is_active())?;
+
+        let regular = &mut self.regulars[idx];
+        match deal.outcome {
+            HandOutcome::Safe => {
+                let mut warmth = REGULAR_DEAL_WARMTH;
+                let on_table = |name: &Option<String>| {
+                    name.as_ref().is_some_and(|n| deal.played.iter().any(|c| c.name == *n))
+                };
+                if on_table(&regular.favourite_product) {
+                    warmth += REGULAR_FAVOURITE_WARMTH;
+                }
+                if on_table(&regular.favourite_location) {
+                    warmth += REGULAR_FAVOURITE_WARMTH;
+                }
+                regular.relationship = (regular.relationship + warmth).min(REGULAR_RELATIONSHIP_MAX);
+                regular.deals += 1;
+                if regular.relationship < REGULAR_LOYAL || roll >= REGULAR_REFERRAL_CHANCE {
+                    return None;
+                }
+                let by = regular.name.clone();
+                let taken: Vec<&str> = self
+                    .regulars_in(deal.area)
+                    .map(|r| r.persona.as_str())
+                    .collect();
+                let friend = personas
+                    .iter()
+                    .find(|p| p.area == deal.area && !taken.contains(&p.display_name.as_str()))?
+                    .clone();
+                self.add_regular(&friend, deal.area, &[], REGULAR_REFERRAL_RELATIONSHIP, Some(by.clone()))
+                    .map(|name| RegularEvent::Referred { by, name })
+            }
+            HandOutcome::Folded | HandOutcome::Busted | HandOutcome::BuyerBailed => {
+                let chill = match deal.outcome {
+                    HandOutcome::Folded => REGULAR_FOLD_CHILL,
+                    HandOutcome::Busted => REGULAR_BUST_CHILL,
+                    _ => REGULAR_WALKOUT_CHILL,
+                };
+                regular.relationship = regular.relationship.saturating_sub(chill);
+                if regular.relationship > REGULAR_INFORMANT_THRESHOLD || roll >= REGULAR_INFORMANT_CHANCE {
+                    return None;
+                }
+                regular.informant = true;
+                let name = regular.name.clone();
+                let dealer = self.dealers.get_mut(deal.dealer_idx)?;
+                dealer.character.heat = (dealer.character.heat + REGULAR_INFORMANT_HEAT).min(MAX_HEAT);
+                Some(RegularEvent::TurnedInformant {
+                    name,
+                    dealer: dealer.name.clone(),
+                    heat: REGULAR_INFORMANT_HEAT,
+                })
+            }
+            HandOutcome::InvalidDeal => None,
+        }
+    }
+
+    /// SOW-044: make `persona` a regular in `area` - one active regular per
+    /// persona per area, MAX_REGULARS active at most. Favourites come from
+    /// the cards on the table. The name is the persona's first authored
+    /// regular_names entry not already taken, else "<Persona> #n".
+    /// Returns the new regular's name.
+    fn add_regular(
+        &mut self,
+        persona: &crate::models::buyer::BuyerPersona,
+        area: &str,
+        played: &[crate::models::card::Card],
+        relationship: u32,
+        referred_by: Option<String>,
+    ) -> Option<String> {
+        use crate::models::card::CardType;
+
+        let active = self.regulars.iter().filter(|r| r.is_active()).count();
+        if active >= MAX_REGULARS
+            || self.regulars_in(area).any(|r| r.persona == persona.display_name)
+        {
+            return None;
+        }
+        let name = persona
+            .regular_names
+            .iter()
+            .find(|n| !self.regulars.iter().any(|r| r.name == **n))
+            .cloned()
+            .unwrap_or_else(|| {
+                let seen = self
+                    .regulars
+                    .iter()
+                    .filter(|r| r.persona == persona.display_name)
+                    .count();
+                format!("{} #{}", persona.display_name, seen + 1)
+            });
+        let first = |is_kind: fn(&CardType) -> bool| {
+            played.iter().find(|c| is_kind(&c.card_type)).map(|c| c.name.clone())
+        };
+        self.regulars.push(Regular {
+            name: name.clone(),
+            persona: persona.display_name.clone(),
+            area: area.to_string(),
+            relationship,
+            favourite_product: first(|t| matches!(t, CardType::Product { .. })),
+            favourite_location: first(|t| matches!(t, CardType::Location { .. })),
+            deals: 0,
+            referred_by,
+            informant: false,
+        });
+        Some(name)
+    }
+
     /// SOW-027: pay the crooked lawyer - LAWYER_COST for an immediate
     /// LAWYER_COOLING heat reduction, no downtime. Same eligibility as
     /// lay_low: available, with heat to shed.
@@ -505,6 +1598,13 @@ impl SaveData {
         self.fronts.iter().map(|f| f.owed).sum()
     }
 
+    /// SOW-059: what a front on a batch would put on the books, with the
+    /// relics' cut of the vig
+    /// SOW-065: off the ascension level's vig
+    pub fn front_owed_for(&self, batch_cost: u32) -> u64 {
+        front_owed_at(batch_cost, self.relic_effects().vig_pct(self.ascension))
+    }
+
     /// SOW-034: take a BATCH of an already-unlocked product on the zone
     /// supplier's credit. The batch (BATCH_SIZE charges) lands in stock NOW,
     /// the lump (batch cost + vig) is due in FRONT_WINDOW_RUNS. Access is a
@@ -532,14 +1632,20 @@ impl SaveData {
         if batch_cost == 0 {
             return Err("nothing to front");
         }
+        let owed = self.front_owed_for(batch_cost);
         self.fronts.push(FrontState {
             card_id: card_id.to_string(),
             area_id: area_id.to_string(),
-            owed: front_owed(batch_cost),
+            owed,
             runs_remaining: FRONT_WINDOW_RUNS,
             charges: BATCH_SIZE,
         });
         self.account.add_stock(card_id, BATCH_SIZE);
+        self.chronicle_event(ChronicleEvent::FrontTaken {
+            card_id: card_id.to_string(),
+            area_id: area_id.to_string(),
+            owed,
+        });
         Ok(())
     }
 
@@ -557,11 +1663,16 @@ impl SaveData {
         if !self.account.spend(self.fronts[pos].owed) {
             return false;
         }
-        self.fronts.remove(pos);
+        let front = self.fronts.remove(pos);
         if self.standing_with(area_id) == SupplierStanding::CutOff {
             self.supplier_standing
                 .insert(area_id.to_string(), SupplierStanding::Good);
         }
+        self.chronicle_event(ChronicleEvent::FrontPaid {
+            card_id: front.card_id,
+            area_id: front.area_id,
+            owed: front.owed,
+        });
         true
     }
 
@@ -618,7 +1729,12 @@ impl SaveData {
                         .get_mut(self.active_dealer)
                         .filter(|d| d.is_available() && has_other_runner)
                     {
-                        dealer.status = DealerStatus::Relocating { runs_remaining: 1 };
+                        // Benched where they stand - no road walked
+                        dealer.status = DealerStatus::Relocating {
+                            runs_remaining: 1,
+                            route: Vec::new(),
+                            total_runs: 1,
+                        };
                         let dealer = dealer.name.clone();
                         events.push(FrontEvent::MuscleBenched {
                             area_id: area_id.clone(),
@@ -640,6 +1756,9 @@ impl SaveData {
                 }
             }
         }
+        for event in &events {
+            self.chronicle_event(event.into());
+        }
         events
     }
 
@@ -666,22 +1785,175 @@ impl SaveData {
         let Some(remaining) = dealer.jail_remaining() else {
             return false;
         };
-        if !self.account.spend(bail_cost(remaining)) {
+        let cost = bail_cost(remaining);
+        if !self.account.spend(cost) {
             return false;
         }
         self.dealers[dealer_idx].release();
+        let dealer = self.dealers[dealer_idx].name.clone();
+        self.chronicle_event(ChronicleEvent::BailedOut { dealer, cost });
+        true
+    }
+
+    /// SOW-058: pay to redraw the options on one of the active dealer's
+    /// pending upgrades. Returns what it cost.
+    pub fn buy_upgrade_reroll(&mut self, card_name: &str) -> Result<u64, &'static str> {
+        let character = self.active_character();
+        let Some(pending) = character.pending_upgrades.iter().find(|p| p.card_name == card_name) else {
+            return Err("no upgrade pending for that card");
+        };
+        let cost = pending.reroll_cost();
+        if self.account.cash_on_hand < cost {
+            return Err("not enough cash to reroll");
+        }
+        if !self.active_character_mut().reroll_upgrade(card_name) {
+            return Err("nothing else to draw");
+        }
+        self.account.spend(cost);
+        Ok(cost)
+    }
+
+    /// SOW-063: whether a lieutenant could take over today - out of jail,
+    /// loyal (SUCCESSION_MIN_RUNS worked), respected (SUCCESSION_MIN_CRED
+    /// deals closed), with a war chest on hand (SUCCESSION_MIN_CASH)
+    pub fn succession_check(&self, dealer_idx: usize) -> Result<(), &'static str> {
+        let Some(dealer) = self.dealers.get(dealer_idx) else {
+            return Err("no such dealer");
+        };
+        if dealer.is_kingpin {
+            return Err("already the kingpin");
+        }
+        if dealer.jail_remaining().is_some() {
+            return Err("in jail");
+        }
+        if dealer.character.decks_played < SUCCESSION_MIN_RUNS {
+            return Err("not loyal enough yet");
+        }
+        if dealer.street_cred.values().sum::<u32>() < SUCCESSION_MIN_CRED {
+            return Err("not enough cred");
+        }
+        if self.account.cash_on_hand < SUCCESSION_MIN_CASH {
+            return Err("no war chest");
+        }
+        Ok(())
+    }
+
+    /// SOW-063: the lieutenant who'd take over - the most cred among the
+    /// qualified, then the most runs, then the longest on the roster
+    pub fn heir(&self) -> Option<usize> {
+        let mut heir: Option<(usize, (u32, u32))> = None;
+        for (idx, dealer) in self.dealers.iter().enumerate() {
+            if self.succession_check(idx).is_err() {
+                continue;
+            }
+            let standing = (dealer.street_cred.values().sum::<u32>(), dealer.character.decks_played);
+            if heir.is_none_or(|(_, best)| standing > best) {
+                heir = Some((idx, standing));
+            }
+        }
+        heir.map(|(idx, _)| idx)
+    }
+
+    /// SOW-063: the kingpin went down. With the succession rule on and an
+    /// heir qualified, the heir takes the chair (dealers[0]) and the empire
+    /// carries on with penalties: the feds seize SUCCESSION_SEIZURE_PCT of
+    /// the cash, every zone nobody is stationed in is lost (home turf
+    /// stays), and every dealer picks up SUCCESSION_HEAT. The old kingpin
+    /// joins the dynasty. False (nothing touched) when nobody can take over
+    /// - the caller resets the empire.
+    pub fn succeed(&mut self) -> bool {
+        if !self.succession {
+            return false;
+        }
+        let (Some(heir), Some(king)) = (self.heir(), self.dealers.iter().position(|d| d.is_kingpin)) else {
+            return false;
+        };
+        let fallen = self.dealers.remove(king);
+        let mut heir = self.dealers.remove(if heir > king { heir - 1 } else { heir });
+        heir.is_kingpin = true;
+        self.dealers.insert(0, heir);
+        self.active_dealer = 0;
+
+        let seized = self.account.cash_on_hand * SUCCESSION_SEIZURE_PCT / 100;
+        self.account.cash_on_hand -= seized;
+        let held: HashSet<String> = self
+            .dealers
+            .iter()
+            .map(|d| d.station.clone())
+            .chain(std::iter::once(default_station()))
+            .collect();
+        let before = self.account.unlocked_locations.len();
+        self.account.unlocked_locations.retain(|zone| held.contains(zone));
+        let zones_lost = (before - self.account.unlocked_locations.len()) as u32;
+        for dealer in &mut self.dealers {
+            dealer.character.heat = (dealer.character.heat + SUCCESSION_HEAT).min(MAX_HEAT);
+        }
+
+        let event = ChronicleEvent::Succeeded {
+            fallen: fallen.name.clone(),
+            heir: self.dealers[0].name.clone(),
+            zones_lost,
+            seized,
+        };
+        self.dynasty.push(Reign {
+            kingpin: fallen,
+            fell_at: current_timestamp(),
+            lifetime_revenue: self.account.lifetime_revenue,
+        });
+        self.chronicle_event(event);
         true
     }
 
+    /// SOW-063: turn the succession rule on or off
+    pub fn set_succession(&mut self, on: bool) {
+        self.succession = on;
+    }
+
     /// RFC-023: the KINGPIN busting ends the empire - the one remaining
     /// permadeath. Everything resets, including the books - EXCEPT the
     /// arcade board: the falling empire's epitaph is appended first and the
     /// board carries into the fresh save (SOW-023 addendum).
     pub fn reset_empire(&mut self) {
+        // SOW-051: the fall is the chronicle's last line
+        let lifetime_revenue = self.account.lifetime_revenue;
+        self.chronicle_event(ChronicleEvent::Fell { lifetime_revenue });
+        self.close_empire(EmpireEpitaph::from_save(self, current_timestamp()));
+    }
+
+    /// Archive the epitaph and start a fresh empire, carrying over only
+    /// what outlives one (the board, the presses, the packs). SOW-065: the
+    /// next empire starts at the same level; retiring or beating an
+    /// operation at the top level unlocks the one above it.
+    fn close_empire(&mut self, epitaph: EmpireEpitaph) {
+        let beaten = epitaph.retirement.is_some()
+            || epitaph
+                .chronicle
+                .iter()
+                .any(|entry| matches!(entry.event, ChronicleEvent::Operation { beaten: true, .. }));
+        let mut unlocked = self.ascension_unlocked;
+        if beaten && epitaph.ascension >= unlocked {
+            unlocked = Ascension((epitaph.ascension.0 + 1).min(MAX_ASCENSION));
+        }
+        let ascension = self.ascension;
+        // SOW-064: banked here, so the legacy doesn't lean on the archive
+        let earned = epitaph.legacy_earned();
         let mut fallen = std::mem::take(&mut self.fallen_empires);
-        fallen.push(EmpireEpitaph::from_save(self, current_timestamp()));
-        *self = SaveData::new();
+        fallen.push(epitaph);
+        // SOW-052: the presses keep running - the fall still has to be written up
+        let press_queue = std::mem::take(&mut self.press_queue);
+        let headlines = std::mem::take(&mut self.headlines);
+        let mods = std::mem::take(&mut self.mods);
+        let succession = self.succession;
+        let mut legacy = std::mem::take(&mut self.legacy);
+        legacy.earned += earned;
+        *self = SaveData::with_legacy(legacy);
         self.fallen_empires = fallen;
+        self.press_queue = press_queue;
+        self.headlines = headlines;
+        self.mods = mods;
+        self.succession = succession;
+        self.ascension = ascension;
+        self.ascension_unlocked = unlocked;
     }
 
     /// SOW-031: normalize loaded state that predates a content decision.
@@ -730,8 +2002,9 @@ pub enum CharacterProfile {
     // Future: Named profiles with narrative flavor
 }
 
-/// Heat tier based on current heat value
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Heat tier based on current heat value. SOW-061: ordered coldest first,
+/// and authored in bosses.ron operation triggers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum HeatTier {
     Cold,      // 0-29
     Warm,      // 30-59
@@ -753,6 +2026,21 @@ impl HeatTier {
         }
     }
 
+    /// SOW-060: the tier `by` steps hotter (negative: cooler), held at the
+    /// ends - how a run-map node raises or lowers the narc deck
+    pub fn shifted(self, by: i8) -> Self {
+        const TIERS: [HeatTier; 6] = [
+            HeatTier::Cold,
+            HeatTier::Warm,
+            HeatTier::Hot,
+            HeatTier::Blazing,
+            HeatTier::Scorching,
+            HeatTier::Inferno,
+        ];
+        let index = TIERS.iter().position(|t| *t == self).unwrap_or(0) as i32 + by as i32;
+        TIERS[index.clamp(0, TIERS.len() as i32 - 1) as usize]
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             HeatTier::Cold => "Cold",
@@ -899,24 +2187,109 @@ impl UpgradeableStat {
         }
     }
 
-    /// Select 2 random stats for upgrade choice
-    /// For cards with exactly 2 stats, returns both (no randomness needed)
-    /// For cards with 3+ stats, randomly selects 2
-    pub fn random_pair(card_type: &crate::models::card::CardType) -> Option<[Self; 2]> {
-        use rand::prelude::*;
-        let available = Self::available_for(card_type);
+    // SOW-058: random_pair replaced by UpgradeChoice::roll (stats and perks
+    // drawn from one weighted pool)
+}
 
-        match available.len() {
-            0 | 1 => None, // Can't offer choice with less than 2 options
-            2 => Some([available[0], available[1]]),
-            _ => {
-                // Randomly select 2 from available
-                let mut shuffled = available;
-                shuffled.shuffle(&mut rand::rng());
-                Some([shuffled[0], shuffled[1]])
-            }
+/// SOW-058: a rarer, qualitative upgrade - it changes what a card does
+/// rather than how much. A card takes each perk at most once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpgradePerk {
+    /// Shrug off one Conviction per session while this card is in play
+    ConvictionImmunity,
+    /// A folded hand hands this product's charge back
+    FoldRefund,
+    /// +1 street cred when a hand with this card goes Safe
+    StreetCred,
+    /// This insurance costs 25% less to activate
+    CheapInsurance,
+}
+
+/// SOW-058: CheapInsurance's discount, in percent
+pub const CHEAP_INSURANCE_DISCOUNT_PCT: u32 = 25;
+
+/// SOW-058: draw weight of a stat bonus at every tier (perk weights are
+/// UpgradePerk::weight)
+const STAT_WEIGHT: u32 = 10;
+
+/// SOW-058: rerolling an upgrade's options costs this per tier (Tier1 $100,
+/// Tier5 $500)
+const REROLL_COST_PER_TIER: u64 = 100;
+
+impl UpgradePerk {
+    /// Perks a card type can take
+    pub fn available_for(card_type: &crate::models::card::CardType) -> Vec<Self> {
+        use crate::models::card::CardType;
+        match card_type {
+            CardType::Product { .. } => vec![Self::FoldRefund, Self::StreetCred],
+            CardType::Location { .. } => vec![Self::StreetCred, Self::ConvictionImmunity],
+            CardType::Cover { .. } => vec![Self::ConvictionImmunity],
+            CardType::Insurance { .. } => vec![Self::CheapInsurance, Self::ConvictionImmunity],
+            CardType::DealModifier { .. } => vec![Self::StreetCred],
+            CardType::Evidence { .. } | CardType::Conviction { .. } => vec![],
         }
     }
+
+    /// Draw weight when upgrading to `tier`: rare early, common near foil.
+    /// Against STAT_WEIGHT a StreetCred perk is 1 in 6 at Tier1 and even
+    /// odds at Tier5.
+    pub fn weight(self, tier: UpgradeTier) -> u32 {
+        let rarity = match self {
+            Self::ConvictionImmunity => 1,
+            Self::FoldRefund | Self::StreetCred | Self::CheapInsurance => 2,
+        };
+        let tier_weight = match tier {
+            UpgradeTier::Base => 0,
+            UpgradeTier::Tier1 => 1,
+            UpgradeTier::Tier2 => 2,
+            UpgradeTier::Tier3 => 3,
+            UpgradeTier::Tier4 => 4,
+            UpgradeTier::Tier5 => 5,
+        };
+        rarity * tier_weight
+    }
+}
+
+/// SOW-058: one option on the upgrade screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpgradeChoice {
+    Stat(UpgradeableStat),
+    Perk(UpgradePerk),
+}
+
+impl From<UpgradeableStat> for UpgradeChoice {
+    fn from(stat: UpgradeableStat) -> Self {
+        Self::Stat(stat)
+    }
+}
+
+impl UpgradeChoice {
+    /// Draw two different options for a card reaching `tier` from one
+    /// weighted pool of its stats and the perks it doesn't have yet. None
+    /// when it has fewer than two.
+    pub fn roll(
+        card_type: &crate::models::card::CardType,
+        tier: UpgradeTier,
+        taken: &CardUpgrades,
+        rng: &mut impl rand::Rng,
+    ) -> Option<[Self; 2]> {
+        use rand::seq::IndexedRandom;
+        let mut pool: Vec<(Self, u32)> = UpgradeableStat::available_for(card_type)
+            .into_iter()
+            .map(|stat| (Self::Stat(stat), STAT_WEIGHT))
+            .chain(
+                UpgradePerk::available_for(card_type)
+                    .into_iter()
+                    .filter(|perk| !taken.has_perk(*perk))
+                    .map(|perk| (Self::Perk(perk), perk.weight(tier))),
+            )
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+        let first = pool.choose_weighted(rng, |(_, weight)| *weight).ok()?.0;
+        pool.retain(|(choice, _)| *choice != first);
+        let second = pool.choose_weighted(rng, |(_, weight)| *weight).ok()?.0;
+        Some([first, second])
+    }
 }
 
 /// RFC-019: Upgrade choices for a single card
@@ -925,12 +2298,15 @@ impl UpgradeableStat {
 pub struct CardUpgrades {
     /// Stats chosen at each tier (index 0 = Tier1, index 1 = Tier2, etc.)
     pub upgrades: Vec<UpgradeableStat>,
+    /// SOW-058: perks taken (each also spends a tier)
+    #[serde(default)]
+    pub perks: Vec<UpgradePerk>,
 }
 
 impl CardUpgrades {
     /// Create new empty upgrade history
     pub fn new() -> Self {
-        Self { upgrades: Vec::new() }
+        Self { upgrades: Vec::new(), perks: Vec::new() }
     }
 
     /// Add an upgrade choice
@@ -938,6 +2314,24 @@ impl CardUpgrades {
         self.upgrades.push(stat);
     }
 
+    /// SOW-058: record a tier's choice, stat or perk
+    pub fn add_choice(&mut self, choice: UpgradeChoice) {
+        match choice {
+            UpgradeChoice::Stat(stat) => self.upgrades.push(stat),
+            UpgradeChoice::Perk(perk) => self.perks.push(perk),
+        }
+    }
+
+    /// SOW-058: tiers spent, on stats and perks
+    pub fn taken(&self) -> usize {
+        self.upgrades.len() + self.perks.len()
+    }
+
+    /// SOW-058: whether the card has taken `perk`
+    pub fn has_perk(&self, perk: UpgradePerk) -> bool {
+        self.perks.contains(&perk)
+    }
+
     /// Count how many times a specific stat was upgraded
     pub fn count_stat(&self, stat: UpgradeableStat) -> usize {
         self.upgrades.iter().filter(|&&s| s == stat).count()
@@ -949,6 +2343,13 @@ impl CardUpgrades {
         1.0 + (self.count_stat(stat) as f32 * 0.1)
     }
 
+    /// SOW-057: whether `stat` is the card's main upgrade path - chosen at
+    /// least once and no less often than any other stat (ties count)
+    pub fn favours(&self, stat: UpgradeableStat) -> bool {
+        let count = self.count_stat(stat);
+        count > 0 && self.upgrades.iter().all(|&other| self.count_stat(other) <= count)
+    }
+
 }
 
 /// RFC-019: A pending upgrade choice waiting for player input
@@ -960,21 +2361,92 @@ pub struct PendingUpgrade {
     pub card_type: crate::models::card::CardType,
     /// The tier being upgraded to
     pub tier: UpgradeTier,
-    /// Two stat options randomly selected for player choice
-    pub options: [UpgradeableStat; 2],
+    /// Two options drawn for the player (SOW-058: stats or perks)
+    pub options: [UpgradeChoice; 2],
+    /// SOW-057: the evolution the card qualifies for, offered beside the
+    /// two stats
+    #[serde(default)]
+    pub evolution: Option<EvolutionOffer>,
+}
+
+/// SOW-057: an evolution on the upgrade screen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvolutionOffer {
+    /// Card id that evolves
+    pub from: String,
+    /// Card id and name of the variant it becomes
+    pub into: String,
+    pub into_name: String,
+    /// The path that earned it; taking the evolution counts as this tier's
+    /// upgrade on it
+    pub path: UpgradeableStat,
 }
 
 impl PendingUpgrade {
-    /// Create a new pending upgrade with random stat options
-    pub fn new(card_name: String, card_type: crate::models::card::CardType, tier: UpgradeTier) -> Option<Self> {
-        let options = UpgradeableStat::random_pair(&card_type)?;
+    /// Create a new pending upgrade with random options (SOW-058: `taken`
+    /// is the card's upgrades so far - a perk isn't offered twice)
+    pub fn new(
+        card_name: String,
+        card_type: crate::models::card::CardType,
+        tier: UpgradeTier,
+        taken: &CardUpgrades,
+    ) -> Option<Self> {
+        let options = UpgradeChoice::roll(&card_type, tier, taken, &mut rand::rng())?;
         Some(Self {
             card_name,
             card_type,
             tier,
             options,
+            evolution: None,
         })
     }
+
+    /// SOW-058: what drawing new options costs
+    pub fn reroll_cost(&self) -> u64 {
+        let tier = match self.tier {
+            UpgradeTier::Base | UpgradeTier::Tier1 => 1,
+            UpgradeTier::Tier2 => 2,
+            UpgradeTier::Tier3 => 3,
+            UpgradeTier::Tier4 => 4,
+            UpgradeTier::Tier5 => 5,
+        };
+        REROLL_COST_PER_TIER * tier
+    }
+}
+
+/// SOW-050: one story on the record - the text plus what it was about, so
+/// the ledger can filter and link it back. Hand stories are written at
+/// resolution (StoryComposer::narrate_hand); close_session stamps the
+/// dealer and run.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct StoryRecord {
+    pub text: String,
+    /// None for anything that isn't a hand (a session's closing summary)
+    pub outcome: Option<crate::models::hand_state::HandOutcome>,
+    /// Area id the hand was played in
+    pub area: String,
+    /// Buyer persona display name
+    pub buyer: Option<String>,
+    /// The persona's active scenario display name
+    pub scenario: Option<String>,
+    /// Product on the table at resolution
+    pub product: Option<String>,
+    /// Name of the dealer who ran the session
+    pub dealer: String,
+    /// Safe hands only (a summary carries the session's total)
+    pub profit: u32,
+    /// Session heat the hand added, signed (a summary carries the session's)
+    pub heat_delta: i32,
+    /// Which of the dealer's runs this was, 1-based
+    pub run: u32,
+    pub timestamp: u64,
+}
+
+impl StoryRecord {
+    /// A record carrying nothing but its text (forged saves, tests)
+    pub fn note(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Default::default() }
+    }
 }
 
 /// Character state that persists across sessions until permadeath
@@ -1002,9 +2474,14 @@ pub struct CharacterState {
     /// These persist across sessions so player can make choice on next load
     #[serde(default)]
     pub pending_upgrades: Vec<PendingUpgrade>,
+    /// SOW-057: cards this dealer has evolved (card id -> variant id). The
+    /// deck builder swaps the variant in wherever the dealer would see the
+    /// original.
+    #[serde(default)]
+    pub evolved_cards: HashMap<String, String>,
     /// Story history from completed deals (narrative log)
     #[serde(default)]
-    pub story_history: Vec<String>,
+    pub story_history: Vec<StoryRecord>,
 }
 
 impl CharacterState {
@@ -1019,6 +2496,7 @@ impl CharacterState {
             card_play_counts: HashMap::new(),
             card_upgrades: HashMap::new(),
             pending_upgrades: Vec::new(),
+            evolved_cards: HashMap::new(),
             story_history: Vec::new(),
         }
     }
@@ -1036,12 +2514,14 @@ impl CharacterState {
 
     /// Calculate and apply heat decay based on elapsed time
     /// Returns the amount of heat that decayed
-    pub fn apply_decay(&mut self) -> u32 {
+    /// SOW-059: with the empire's relics in play
+    /// SOW-065: and at the empire's ascension level, under the relics
+    pub fn apply_decay_with(&mut self, relics: &RelicEffects, ascension: Ascension) -> u32 {
         let now = current_timestamp();
         let elapsed_secs = now.saturating_sub(self.last_played);
         let elapsed_hours = (elapsed_secs / 3600).min(168) as u32; // Cap at 1 week
 
-        let decay = elapsed_hours.min(self.heat);
+        let decay = relics.decay(ascension.decay(elapsed_hours)).min(self.heat);
         self.heat = self.heat.saturating_sub(decay);
         // SOW-021: consume the decay window so repeated calls are idempotent.
         // OnEnter(DeckBuilding) fires more than once per launch (UpgradeChoice
@@ -1090,7 +2570,7 @@ impl CharacterState {
 
         let upgrade_count = self.card_upgrades
             .get(card_name)
-            .map(|u| u.upgrades.len())
+            .map(|u| u.taken())
             .unwrap_or(0);
         let tier_from_upgrades = match upgrade_count {
             0 => UpgradeTier::Base,
@@ -1125,7 +2605,8 @@ impl CharacterState {
 
         // Check if there's actually a pending upgrade
         if let Some(tier) = self.check_pending_upgrade(card_name) {
-            if let Some(pending) = PendingUpgrade::new(card_name.to_string(), card_type.clone(), tier) {
+            let taken = self.card_upgrades.get(card_name).cloned().unwrap_or_default();
+            if let Some(pending) = PendingUpgrade::new(card_name.to_string(), card_type.clone(), tier, &taken) {
                 self.pending_upgrades.push(pending);
                 return true;
             }
@@ -1143,22 +2624,98 @@ impl CharacterState {
         !self.pending_upgrades.is_empty()
     }
 
-    /// SOW-021: Apply an upgrade choice for a SPECIFIC pending card (batched
-    /// upgrade screen lets the player resolve pending upgrades in any order).
-    /// Returns true if the upgrade was applied.
-    pub fn apply_upgrade_choice_for(&mut self, card_name: &str, stat: UpgradeableStat) -> bool {
+    /// SOW-021: Apply an upgrade choice for a SPECIFIC pending card (batched
+    /// upgrade screen lets the player resolve pending upgrades in any order).
+    /// Returns true if the upgrade was applied.
+    /// SOW-058: the choice may be a stat or a perk.
+    pub fn apply_upgrade_choice_for(&mut self, card_name: &str, choice: impl Into<UpgradeChoice>) -> bool {
+        let choice = choice.into();
+        let Some(index) = self.pending_upgrades.iter().position(|p| p.card_name == card_name) else {
+            return false;
+        };
+
+        // Verify the choice is one of this card's offered options
+        let pending = &self.pending_upgrades[index];
+        if !pending.options.contains(&choice) {
+            return false;
+        }
+
+        self.card_upgrades
+            .entry(card_name.to_string())
+            .or_default()
+            .add_choice(choice);
+        self.pending_upgrades.remove(index);
+        true
+    }
+
+    /// SOW-058: draw fresh options for a card's pending upgrade (the
+    /// evolution offer, if any, stays). False if nothing is pending.
+    pub fn reroll_upgrade(&mut self, card_name: &str) -> bool {
+        let taken = self.card_upgrades.get(card_name).cloned().unwrap_or_default();
+        let Some(pending) = self.pending_upgrades.iter_mut().find(|p| p.card_name == card_name) else {
+            return false;
+        };
+        match UpgradeChoice::roll(&pending.card_type, pending.tier, &taken, &mut rand::rng()) {
+            Some(options) => {
+                pending.options = options;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// SOW-057: offer an evolution on the card's pending upgrade, if its
+    /// tier and upgrade path qualify for one of the authored ones. `variant`
+    /// looks up the card an evolution turns into. Returns true when offered.
+    pub fn offer_evolution<'a>(
+        &mut self,
+        card: &crate::models::card::Card,
+        variant: impl Fn(&str) -> Option<&'a crate::models::card::Card>,
+    ) -> bool {
+        let history = self.card_upgrades.get(&card.name).cloned().unwrap_or_default();
+        let Some(pending) = self.pending_upgrades.iter_mut().find(|p| p.card_name == card.name) else {
+            return false;
+        };
+        let offer = card.evolutions.iter().find_map(|evolution| {
+            if pending.tier < evolution.tier || !history.favours(evolution.path) {
+                return None;
+            }
+            let into = variant(&evolution.into)?;
+            Some(EvolutionOffer {
+                from: card.id.clone(),
+                into: into.id.clone(),
+                into_name: into.name.clone(),
+                path: evolution.path,
+            })
+        });
+        pending.evolution = offer;
+        pending.evolution.is_some()
+    }
+
+    /// SOW-057: take the evolution offered on a card's pending upgrade. The
+    /// tier's upgrade goes on the offer's path, the variant inherits the
+    /// card's plays and upgrades (so it keeps its tier and foil), and the
+    /// card is swapped for it from now on. Returns true if it evolved.
+    pub fn apply_evolution_for(&mut self, card_name: &str) -> bool {
         let Some(index) = self.pending_upgrades.iter().position(|p| p.card_name == card_name) else {
             return false;
         };
-
-        // Verify the stat is one of this card's offered options
-        let pending = &self.pending_upgrades[index];
-        if pending.options[0] != stat && pending.options[1] != stat {
+        let Some(offer) = self.pending_upgrades[index].evolution.clone() else {
             return false;
-        }
+        };
 
-        self.add_card_upgrade(card_name, stat);
+        self.add_card_upgrade(card_name, offer.path);
         self.pending_upgrades.remove(index);
+        let plays = self.get_play_count(card_name);
+        self.card_play_counts.insert(offer.into_name.clone(), plays);
+        if let Some(upgrades) = self.card_upgrades.get(card_name).cloned() {
+            self.card_upgrades.insert(offer.into_name, upgrades);
+        }
+        // An evolved card evolving again: whatever became it becomes the new one
+        for variant in self.evolved_cards.values_mut().filter(|v| **v == offer.from) {
+            *variant = offer.into.clone();
+        }
+        self.evolved_cards.insert(offer.from, offer.into);
         true
     }
 
@@ -1202,8 +2759,8 @@ const BAIL_COST_PER_RUN: u64 = 300;
 /// First hire beyond the kingpin costs $500; each subsequent hire doubles
 const HIRE_BASE_COST: u64 = 500;
 
-/// SOW-025: flat relocation fee (tuning candidate - see SOW-025 Discussion)
-const MOVE_FEE: u64 = 250;
+// SOW-025's flat relocation fee (MOVE_FEE) now lives with the zone graph
+// (SOW-043): it prices one hop on a map with no authored roads.
 
 /// SOW-027 coolers (tuning candidates - see SOW-027 Discussion).
 /// Lay Low is the committed package: the dealer goes dark for
@@ -1217,6 +2774,11 @@ pub const LAY_LOW_COOLING: u32 = 40;
 const LAWYER_COST: u64 = 625;
 pub const LAWYER_COOLING: u32 = 25;
 
+/// SOW-042: a REST order is the free breather - a little heat off, nothing
+/// earned. Well under a lay-low package on purpose: resting is what a dealer
+/// does when there's nothing better to do, not a cooler.
+pub const REST_COOLING: u32 = 5;
+
 /// SOW-034: a batch of a product is BATCH_SIZE charges. One global constant
 /// for now; per-zone batch sizes are a deferred later knob (SOW-034 §2.7).
 pub const BATCH_SIZE: u32 = 4;
@@ -1230,11 +2792,72 @@ pub const FRONT_WINDOW_RUNS: u32 = 4;
 /// Muscle takes this cut of cash on hand at the second blown window
 pub const MUSCLE_SEIZURE_PCT: u64 = 20;
 
-/// What a front on a card at `shop_price` will put on the books
-pub fn front_owed(shop_price: u32) -> u64 {
-    shop_price as u64 * (100 + FRONT_VIG_PCT) / 100
+/// SOW-063 succession (tuning candidates - see SOW-063). A lieutenant's
+/// loyalty is the runs they've worked for the empire...
+pub const SUCCESSION_MIN_RUNS: u32 = 5;
+/// ...their standing is the deals they've closed (street cred)...
+pub const SUCCESSION_MIN_CRED: u32 = 10;
+/// ...and the empire needs a war chest to hold together through the handover
+pub const SUCCESSION_MIN_CASH: u64 = 2_000;
+/// The feds take this cut of cash on hand with the old kingpin
+pub const SUCCESSION_SEIZURE_PCT: u64 = 50;
+/// Every dealer left picks up this much heat - the task force has the names
+pub const SUCCESSION_HEAT: u32 = 25;
+
+/// SOW-064 legacy (tuning candidates - see SOW-064). A fallen empire leaves
+/// one legacy per this much lifetime revenue...
+pub const LEGACY_PER_REVENUE: u64 = 1_000;
+/// ...plus this much per dealer it hired...
+pub const LEGACY_PER_HIRE: u64 = 5;
+/// ...and per zone it owned
+pub const LEGACY_PER_ZONE: u64 = 10;
+pub const LEGACY_PRODUCT_COST: u64 = 30;
+pub const LEGACY_ZONE_COST: u64 = 50;
+pub const LEGACY_HIRE_COST: u64 = 20;
+pub const LEGACY_RELIC_COST: u64 = 60;
+/// The first hire costs this cut of the ladder with the discount unlocked
+pub const LEGACY_FIRST_HIRE_PCT: u64 = 50;
+
+/// What a front on a card at `shop_price` will put on the books at
+/// `vig_pct` (SOW-059: relics can cut it - the game asks
+/// SaveData::front_owed_for)
+pub fn front_owed_at(shop_price: u32, vig_pct: u64) -> u64 {
+    shop_price as u64 * (100 + vig_pct) / 100
 }
 
+/// SOW-044 regulars (tuning candidates - see SOW-044 Discussion).
+/// Odds a first-time buyer comes back as a regular after a Safe deal
+pub const REGULAR_SPAWN_CHANCE: f32 = 0.3;
+/// Odds a session's buyer is one of the area's regulars (when it has any)
+pub const REGULAR_VISIT_CHANCE: f32 = 0.5;
+/// Most active regulars the street remembers at once
+pub const MAX_REGULARS: usize = 12;
+pub const REGULAR_RELATIONSHIP_MAX: u32 = 100;
+/// Where a new regular starts - friendly, not yet loyal
+pub const REGULAR_START_RELATIONSHIP: u32 = 50;
+/// Loyal from here: premium payouts, and they refer friends
+pub const REGULAR_LOYAL: u32 = 75;
+/// Wary below here: they haggle
+pub const REGULAR_WARY: u32 = 25;
+/// A Safe deal warms them; their favourite product/location on the table
+/// warms them a little more
+const REGULAR_DEAL_WARMTH: u32 = 10;
+const REGULAR_FAVOURITE_WARMTH: u32 = 5;
+/// Bailing on a deal (folding) / busting with them in the room / a deal
+/// that fell apart on them
+const REGULAR_FOLD_CHILL: u32 = 15;
+const REGULAR_BUST_CHILL: u32 = 30;
+const REGULAR_WALKOUT_CHILL: u32 = 5;
+/// Odds a loyal regular refers a new buyer after a Safe deal
+pub const REGULAR_REFERRAL_CHANCE: f32 = 0.25;
+/// A referral starts warmer than a walk-in
+const REGULAR_REFERRAL_RELATIONSHIP: u32 = 60;
+/// At or below this, bad treatment can turn a regular informant...
+pub const REGULAR_INFORMANT_THRESHOLD: u32 = 20;
+/// ...with these odds, landing this much heat on the dealer they knew
+pub const REGULAR_INFORMANT_CHANCE: f32 = 0.5;
+pub const REGULAR_INFORMANT_HEAT: u32 = 20;
+
 /// SOW-025: where every fresh dealer starts (the home turf; matches the
 /// area flagged `unlocked: true` in shop_locations.ron)
 pub const DEFAULT_STATION: &str = "trailer_park";
@@ -1243,6 +2866,21 @@ fn default_station() -> String {
     DEFAULT_STATION.to_string()
 }
 
+/// SOW-041: most cards a saved deck may hold (the deck builder's cap)
+pub const MAX_DECK_SIZE: usize = 20;
+
+/// SOW-041: named deck presets per dealer - a short shelf, not a library
+pub const MAX_LOADOUTS: usize = 3;
+
+/// SOW-041: a named deck preset a dealer can swap in before a run. Card ids
+/// only (content resolves at the deck builder), so a loadout holding a card
+/// the empire no longer owns simply loads without it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DeckLoadout {
+    pub name: String,
+    pub cards: Vec<String>,
+}
+
 // SOW-039: DEALER_NAME_POOL and DEALER_PORTRAIT_POOL retired. The generic
 // hire drew a random name + face from these pools; the roster now grows ONLY
 // through authored zone dealers (SOW-036 signatures + SOW-038 cred-gated
@@ -1263,8 +2901,12 @@ pub enum DealerStatus {
     },
     /// SOW-025: mid-move - getting established in the new station.
     /// Ticks down like a sentence; no heat effects (moving is cash + time).
+    /// SOW-043: `route` is the zone path being walked (origin first; empty
+    /// when benched in place by a front's muscle) over `total_runs` runs.
     Relocating {
         runs_remaining: u32,
+        route: Vec<String>,
+        total_runs: u32,
     },
     /// SOW-027: gone dark to shed heat - benched like a relocation, but
     /// completing the package sheds LAY_LOW_COOLING heat.
@@ -1273,6 +2915,76 @@ pub enum DealerStatus {
     },
 }
 
+/// SOW-042: how an off-screen runner plays their session. Drives the
+/// auto-resolved HandState (hand_state::autoplay) - how hard they push a
+/// deal and how many hands they work before heading home.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RunPolicy {
+    /// Covers up first, folds a losing hand, heads home early
+    Cautious,
+    #[default]
+    Balanced,
+    /// Chases the multiplier, never folds, works the deck dry
+    Greedy,
+}
+
+impl RunPolicy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunPolicy::Cautious => "CAUTIOUS",
+            RunPolicy::Balanced => "BALANCED",
+            RunPolicy::Greedy => "GREEDY",
+        }
+    }
+
+    /// Most hands the runner works before heading home (a non-Safe hand or
+    /// an exhausted deck ends the session sooner)
+    pub fn hand_limit(&self) -> usize {
+        match self {
+            RunPolicy::Cautious => 2,
+            RunPolicy::Balanced => 3,
+            RunPolicy::Greedy => 5,
+        }
+    }
+}
+
+/// SOW-042: what a dealer does with the day while the active dealer plays
+/// their hand. Resolved at go-home for every OTHER available dealer. Run /
+/// Scout / Rest are standing orders; LayLow and Move are one-shot (they
+/// commit the dealer elsewhere) and fall back to Rest once carried out.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub enum DealerOrder {
+    #[default]
+    Rest,
+    /// Work their station off-screen with the given policy
+    Run(RunPolicy),
+    /// Walk the zone without product: street cred, no cash, no heat
+    Scout,
+    /// Same package as the roster's LAY LOW (cost, downtime, cooling)
+    LayLow,
+    /// Same move as the roster's MOVE (fee, one run of downtime)
+    Move { to_area: String },
+}
+
+impl DealerOrder {
+    pub fn is_one_shot(&self) -> bool {
+        matches!(self, DealerOrder::LayLow | DealerOrder::Move { .. })
+    }
+}
+
+/// SOW-042: what a non-run order came to at day's end (Run orders are
+/// simulated by the caller - they need content the save layer doesn't hold)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderOutcome {
+    Rested { cooled: u32 },
+    Scouted { area: String, cred: u32 },
+    LayingLow,
+    Moved { to_area: String },
+    /// The order couldn't be carried out (SOW-053: the reason is a locale
+    /// id - the views tr() it)
+    Refused(&'static str),
+}
+
 /// A dealer in the kingpin's roster: an identity plus the career record that
 /// used to be the singular CharacterState. `dealers[0]` is the KINGPIN -
 /// you start the game dealing yourself. Non-kingpin busts mean jail;
@@ -1302,6 +3014,19 @@ pub struct DealerState {
     /// Enforces one-signature-per-zone and marks the themed hires.
     #[serde(default)]
     pub signature_of: Option<String>,
+    /// SOW-041: this dealer's own deck (card ids, selection order). Loaded
+    /// into the deck builder when the dealer is selected and rewritten as the
+    /// selection is edited. Empty means "never built" - the deck builder
+    /// seeds the default deck from the owned pool.
+    #[serde(default)]
+    pub deck: Vec<String>,
+    /// SOW-041: named deck presets (at most MAX_LOADOUTS), in slot order
+    #[serde(default)]
+    pub loadouts: Vec<DeckLoadout>,
+    /// SOW-042: the dealer's order for the day (ignored for whoever runs in
+    /// person; jailed/benched dealers just serve their downtime)
+    #[serde(default)]
+    pub order: DealerOrder,
     /// Career record: heat, play counts, upgrades, stories (RFC-017/018/019)
     pub character: CharacterState,
 }
@@ -1339,6 +3064,9 @@ impl DealerState {
             station: default_station(),
             street_cred: HashMap::new(),
             signature_of: None,
+            deck: Vec::new(),
+            loadouts: Vec::new(),
+            order: DealerOrder::default(),
             character: CharacterState::new(),
         }
     }
@@ -1358,6 +3086,9 @@ impl DealerState {
             station: area_id.to_string(),
             street_cred: HashMap::new(),
             signature_of: Some(area_id.to_string()),
+            deck: Vec::new(),
+            loadouts: Vec::new(),
+            order: DealerOrder::default(),
             character: CharacterState::new(),
         }
     }
@@ -1367,6 +3098,57 @@ impl DealerState {
     // DealerState::zone_dealer(area, name, portrait) - see hire_signature_dealer
     // / hire_zone_dealer.
 
+    /// SOW-041: replace this dealer's saved deck. Returns true when the deck
+    /// actually changed (callers save only on a real edit).
+    pub fn set_deck(&mut self, cards: Vec<String>) -> bool {
+        if self.deck == cards {
+            return false;
+        }
+        self.deck = cards;
+        true
+    }
+
+    /// SOW-041: store the current deck as a named preset in `slot`. An
+    /// occupied slot is overwritten; the next free slot appends. Error
+    /// strings are display-ready.
+    pub fn save_loadout(&mut self, slot: usize, name: &str) -> Result<(), &'static str> {
+        if self.deck.is_empty() {
+            return Err("nothing to save");
+        }
+        if name.trim().is_empty() {
+            return Err("loadout needs a name");
+        }
+        let loadout = DeckLoadout {
+            name: name.trim().to_string(),
+            cards: self.deck.clone(),
+        };
+        if slot < self.loadouts.len() {
+            self.loadouts[slot] = loadout;
+        } else if slot == self.loadouts.len() && slot < MAX_LOADOUTS {
+            self.loadouts.push(loadout);
+        } else {
+            return Err("no such loadout slot");
+        }
+        Ok(())
+    }
+
+    /// SOW-041: swap a saved preset in as the dealer's deck. Returns false
+    /// (no mutation) for an empty slot or when the deck already matches.
+    /// A card that evolved since the preset was saved comes back as its
+    /// variant (SOW-057), so a build around it keeps the evolved id.
+    pub fn apply_loadout(&mut self, slot: usize) -> bool {
+        let Some(loadout) = self.loadouts.get(slot) else {
+            return false;
+        };
+        let evolved = &self.character.evolved_cards;
+        let cards = loadout
+            .cards
+            .iter()
+            .map(|id| evolved.get(id).unwrap_or(id).clone())
+            .collect();
+        self.set_deck(cards)
+    }
+
     /// SOW-025: +1 street cred in an area (one successful deal there)
     pub fn add_cred(&mut self, area: &str) {
         *self.street_cred.entry(area.to_string()).or_insert(0) += 1;
@@ -1380,7 +3162,7 @@ impl DealerState {
     /// SOW-025: remaining relocation downtime in runs (None if not moving)
     pub fn relocating_remaining(&self) -> Option<u32> {
         match self.status {
-            DealerStatus::Relocating { runs_remaining } => Some(runs_remaining),
+            DealerStatus::Relocating { runs_remaining, .. } => Some(runs_remaining),
             _ => None,
         }
     }
@@ -1395,10 +3177,12 @@ impl DealerState {
 
     /// Sentence this dealer for a bust. Kingpins are never jailed - the
     /// caller handles a kingpin bust as game over.
-    pub fn jail(&mut self) {
+    /// SOW-059: with the empire's relics in play (the game goes through
+    /// jail_for_bust)
+    pub fn jail_with(&mut self, relics: &RelicEffects) {
         debug_assert!(!self.is_kingpin, "kingpin busts end the empire, not jail");
         let heat_at_bust = self.character.heat as i32;
-        let sentence = jail_sentence_from_heat(heat_at_bust);
+        let sentence = relics.sentence(jail_sentence_from_heat(heat_at_bust));
         self.status = DealerStatus::Jailed {
             runs_remaining: sentence,
             sentence_total: sentence,
@@ -1438,7 +3222,7 @@ impl DealerState {
                 }
                 false
             }
-            DealerStatus::Relocating { ref mut runs_remaining } => {
+            DealerStatus::Relocating { ref mut runs_remaining, .. } => {
                 *runs_remaining = runs_remaining.saturating_sub(1);
                 if *runs_remaining == 0 {
                     self.status = DealerStatus::Available;
@@ -1460,6 +3244,15 @@ impl DealerState {
         }
     }
 
+    /// SOW-061: a task force's extra time on a sentence being served (a
+    /// dealer who isn't inside is untouched)
+    pub fn extend_sentence(&mut self, runs: u32) {
+        if let DealerStatus::Jailed { runs_remaining, sentence_total, .. } = &mut self.status {
+            *runs_remaining += runs;
+            *sentence_total += runs;
+        }
+    }
+
     /// Remaining sentence in runs (None if not jailed)
     pub fn jail_remaining(&self) -> Option<u32> {
         match self.status {
@@ -1484,6 +3277,19 @@ impl DealerState {
         if self.station.is_empty() {
             return Err(SaveError::ValidationError("Dealer has empty station".into()));
         }
+        // SOW-041: decks and presets obey the deck builder's caps
+        if self.deck.len() > MAX_DECK_SIZE {
+            return Err(SaveError::ValidationError(format!(
+                "Dealer deck has {} cards (max {})",
+                self.deck.len(),
+                MAX_DECK_SIZE
+            )));
+        }
+        if self.loadouts.len() > MAX_LOADOUTS
+            || self.loadouts.iter().any(|l| l.cards.len() > MAX_DECK_SIZE)
+        {
+            return Err(SaveError::ValidationError("Dealer loadouts exceed limits".into()));
+        }
         self.character.validate()
     }
 }
@@ -1529,6 +3335,11 @@ pub struct AccountState {
 
 impl AccountState {
     pub fn new() -> Self {
+        Self::with_legacy(&Legacy::default())
+    }
+
+    /// SOW-064: a fresh account with the legacy's starting product and zone
+    pub fn with_legacy(legacy: &Legacy) -> Self {
         let mut account = Self {
             cash_on_hand: 0,
             lifetime_revenue: 0,
@@ -1542,6 +3353,13 @@ impl AccountState {
         // front (0-start is legal since fronting is the floor, but a seeded
         // batch is friendlier - SOW-034 §2.2)
         account.add_stock("weed", BATCH_SIZE);
+        if let Some(product) = legacy.starting_product() {
+            account.unlocked_cards.insert(product.to_string());
+            account.add_stock(product, BATCH_SIZE);
+        }
+        if let Some(zone) = legacy.starting_zone() {
+            account.unlock_location(zone);
+        }
         account
     }
 
@@ -1758,7 +3576,7 @@ mod tests {
         let mut dealer = DealerState::zone_dealer("trailer_park", "Gladys", "Gladys");
         dealer.character.heat = 50; // sentence: 1 + 50/25 = 3 runs
 
-        dealer.jail();
+        dealer.jail_with(&RelicEffects::default());
         assert_eq!(dealer.jail_remaining(), Some(3));
 
         assert!(!dealer.tick_sentence()); // 2 left
@@ -1777,11 +3595,11 @@ mod tests {
         data.account.cash_on_hand = 10_000;
         assert!(data.hire_signature_dealer("trailer_park", &sig("Bubba")));
         data.dealers[1].character.heat = 100; // sentence: 5 runs
-        data.dealers[1].jail();
+        data.dealers[1].jail_with(&RelicEffects::default());
 
         // Serve 2 of 5 runs (ticked by other dealers' completed runs)
-        data.complete_run_tick(0);
-        data.complete_run_tick(0);
+        data.complete_run_tick(Some(0));
+        data.complete_run_tick(Some(0));
         assert_eq!(data.dealers[1].jail_remaining(), Some(3));
 
         // Bail: $300 x 3 remaining
@@ -1803,15 +3621,15 @@ mod tests {
         data.account.cash_on_hand = 10_000;
         assert!(data.hire_signature_dealer("trailer_park", &sig("Bubba")));
         data.dealers[1].character.heat = 10; // sentence: 1 run
-        data.dealers[1].jail();
+        data.dealers[1].jail_with(&RelicEffects::default());
 
         // The just-jailed dealer's own run must not count toward the sentence
-        let released = data.complete_run_tick(1);
+        let released = data.complete_run_tick(Some(1));
         assert!(released.is_empty());
         assert_eq!(data.dealers[1].jail_remaining(), Some(1));
 
         // Someone else runs: sentence served, auto-release
-        let released = data.complete_run_tick(0);
+        let released = data.complete_run_tick(Some(0));
         assert_eq!(released, vec![data.dealers[1].name.clone()]);
         assert!(data.dealers[1].is_available());
     }
@@ -2131,9 +3949,9 @@ mod tests {
 
     #[test]
     fn test_front_owed_carries_the_vig() {
-        assert_eq!(front_owed(500), 625); // the design doc's example
-        assert_eq!(front_owed(100), 125);
-        assert_eq!(front_owed(0), 0);
+        assert_eq!(front_owed_at(500, FRONT_VIG_PCT), 625); // the design doc's example
+        assert_eq!(front_owed_at(100, FRONT_VIG_PCT), 125);
+        assert_eq!(front_owed_at(0, FRONT_VIG_PCT), 0);
     }
 
     #[test]
@@ -2146,7 +3964,7 @@ mod tests {
         assert!(data.take_front("shrooms", "trailer_park", 100).is_ok());
         assert_eq!(data.account.charges_in("shrooms"), BATCH_SIZE, "batch delivered");
         let front = data.front_in("trailer_park").expect("front live");
-        assert_eq!(front.owed, 125); // front_owed(100)
+        assert_eq!(front.owed, 125); // front_owed_at(100, FRONT_VIG_PCT)
         assert_eq!(front.charges, BATCH_SIZE);
         assert_eq!(front.runs_remaining, FRONT_WINDOW_RUNS);
         assert_eq!(data.total_debt(), 125);
@@ -2401,11 +4219,251 @@ mod tests {
         assert_eq!(data.fallen_empires.len(), 1);
     }
 
+    // ---- SOW-051: the empire's chronicle ----
+
+    #[test]
+    fn chronicle_save_version_is_at_least_seventeen() {
+        // v17 added SaveData.chronicle and archives it in EmpireEpitaph
+        const { assert!(SAVE_VERSION >= 17) };
+        let mut data = SaveData::new();
+        assert!(data.chronicle.is_empty());
+        data.account.cash_on_hand = 999; // a real epitaph is carved
+        data.reset_empire();
+        assert!(data.fallen_empires[0].chronicle.iter().any(|e| matches!(e.event, ChronicleEvent::Fell { .. })));
+    }
+
+    #[test]
+    fn test_chronicle_records_the_books_in_order() {
+        let mut data = SaveData::new();
+        data.account.cash_on_hand = 10_000;
+        data.account.unlocked_cards.insert("shrooms".to_string());
+        data.buy_zone("suburbia", 1200).unwrap();
+        assert!(data.hire_signature_dealer("suburbia", &sig("Roxanne")));
+        data.take_front("shrooms", "trailer_park", 100).unwrap();
+        assert!(data.pay_front("trailer_park"));
+        let runs = data.jail_for_bust(1, 40);
+        assert!(data.bail_out(1));
+
+        let events: Vec<&ChronicleEvent> = data.chronicle.iter().map(|e| &e.event).collect();
+        assert!(matches!(events[0], ChronicleEvent::ZoneBought { area_id, price: 1200 } if area_id == "suburbia"));
+        assert!(matches!(events[1], ChronicleEvent::Hired { dealer, .. } if dealer == "Roxanne"));
+        assert!(matches!(events[2], ChronicleEvent::FrontTaken { owed: 125, .. }));
+        assert!(matches!(events[3], ChronicleEvent::FrontPaid { owed: 125, .. }));
+        assert!(matches!(events[4], ChronicleEvent::Jailed { runs: r, heat: 40, .. } if *r == runs));
+        assert!(matches!(events[5], ChronicleEvent::BailedOut { .. }));
+        assert_eq!(events.len(), 6);
+
+        // Failed actions leave no trace
+        assert!(data.buy_zone("suburbia", 1200).is_err());
+        assert!(!data.pay_front("trailer_park"));
+        assert_eq!(data.chronicle.len(), 6);
+    }
+
+    #[test]
+    fn test_chronicle_records_blown_fronts_and_the_fall() {
+        let mut data = SaveData::new();
+        data.account.unlocked_cards.insert("shrooms".to_string());
+        data.take_front("shrooms", "trailer_park", 100).unwrap();
+        for _ in 0..FRONT_WINDOW_RUNS {
+            data.tick_fronts();
+        }
+        assert!(matches!(
+            data.chronicle.last().map(|e| &e.event),
+            Some(ChronicleEvent::CutOff { .. })
+        ));
+
+        data.account.lifetime_revenue = 700;
+        data.reset_empire();
+        assert!(data.chronicle.is_empty(), "the next empire starts a fresh chronicle");
+        let epitaph = &data.fallen_empires[0];
+        assert_eq!(epitaph.roster.len(), 1);
+        assert!(matches!(
+            epitaph.chronicle.last().map(|e| &e.event),
+            Some(ChronicleEvent::Fell { lifetime_revenue: 700 })
+        ));
+    }
+
+    #[test]
+    fn press_save_version_is_at_least_eighteen() {
+        // v18 added SaveData.press_queue and .headlines
+        const { assert!(SAVE_VERSION >= 18) };
+        let data = SaveData::new();
+        assert!(data.press_queue.is_empty());
+        assert!(data.headlines.is_empty());
+    }
+
+    #[test]
+    fn test_news_goes_to_the_press_and_survives_the_fall() {
+        let mut data = SaveData::new();
+        data.dealers.push(DealerState::zone_dealer("trailer_park", "Slim", "Slim"));
+        data.account.cash_on_hand = 10_000;
+        data.buy_zone("suburbia", 500).unwrap();
+        data.dealers[1].character.heat = 200;
+        data.jail_for_bust(1, 0);
+        data.bail_out(1);
+        let kinds: Vec<_> = data.press_queue.iter().filter_map(|e| HeadlineKind::of(&e.event)).collect();
+        assert_eq!(kinds, vec![HeadlineKind::ZoneOpened, HeadlineKind::InfernoBust]);
+
+        data.reset_empire();
+        assert_eq!(data.press_queue.len(), 3, "the fall joins the unprinted news");
+        assert_eq!(HeadlineKind::of(&data.press_queue[2].event), Some(HeadlineKind::KingpinFell));
+
+        let page = |n: u64| Headline { at: n, kind: HeadlineKind::ZoneOpened, headline: n.to_string(), article: String::new() };
+        data.file_headlines((0..HEADLINE_ARCHIVE_CAP as u64 + 2).map(page).collect());
+        assert_eq!(data.headlines.len(), HEADLINE_ARCHIVE_CAP);
+        assert_eq!(data.headlines[0].at, 2, "oldest pages go first");
+    }
+
+    #[test]
+    fn test_heat_tier_shifts_hold_at_the_ends() {
+        assert_eq!(HeatTier::Warm.shifted(1), HeatTier::Hot);
+        assert_eq!(HeatTier::Warm.shifted(-1), HeatTier::Cold);
+        assert_eq!(HeatTier::Cold.shifted(-1), HeatTier::Cold);
+        assert_eq!(HeatTier::Scorching.shifted(2), HeatTier::Inferno);
+        assert_eq!(HeatTier::Hot.shifted(0), HeatTier::Hot);
+    }
+
+    // ---- SOW-059: relics ----
+
+    #[test]
+    fn relics_save_version_is_at_least_twenty_two() {
+        // v22 added SaveData.relics
+        const { assert!(SAVE_VERSION >= 22) };
+        assert!(SaveData::new().relics.is_empty());
+    }
+
+    #[test]
+    fn test_relics_due_from_the_chronicle_and_milestones() {
+        use crate::models::relic::RelicEvent;
+        use crate::models::test_helpers::create_relic as relic;
+        let defs = vec![
+            relic("bail_bond", vec![RelicEffect::Sentence(1)], RelicSource::Event(RelicEvent::BailedOut)),
+            relic("ledger", vec![RelicEffect::Profit(10)], RelicSource::Milestone(RelicMilestone::Revenue(5000))),
+            relic("scanner", vec![RelicEffect::Cover(5)], RelicSource::Shop { area: "trailer_park".to_string(), price: 700 }),
+        ];
+        let mut data = with_crew();
+        assert!(data.relics_due(&defs).is_empty());
+
+        data.jail_for_bust(1, 0);
+        assert!(data.relics_due(&defs).is_empty(), "jailed is not bailed out");
+        data.account.cash_on_hand = 10_000;
+        assert!(data.bail_out(1));
+        data.account.lifetime_revenue = 5000;
+        let due: Vec<&str> = data.relics_due(&defs).iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(due, ["bail_bond", "ledger"], "shop relics are bought, never found");
+
+        assert!(data.grant_relic(&defs[0]));
+        assert!(!data.grant_relic(&defs[0]), "one of each");
+        assert_eq!(data.relics_due(&defs).len(), 1);
+
+        // A new empire starts with an empty shelf and a clean chronicle
+        data.reset_empire();
+        assert!(data.relics.is_empty());
+        assert!(data.relics_due(&defs).is_empty());
+    }
+
+    #[test]
+    fn test_buy_relic_guards_and_cost() {
+        use crate::models::test_helpers::create_relic as relic;
+        let scanner = |area: &str| {
+            relic("scanner", vec![RelicEffect::Cover(5)], RelicSource::Shop { area: area.to_string(), price: 700 })
+        };
+        let mut data = SaveData::new();
+        data.account.cash_on_hand = 500;
+        assert_eq!(data.buy_relic(&scanner("suburbia")), Err("zone not yours yet"));
+        assert_eq!(data.buy_relic(&scanner("trailer_park")), Err("not enough cash"));
+        assert!(data.relics.is_empty());
+
+        data.account.cash_on_hand = 1000;
+        assert_eq!(data.buy_relic(&scanner("trailer_park")), Ok(700));
+        assert_eq!(data.account.cash_on_hand, 300);
+        assert_eq!(data.relic_effects().cover, 5);
+        assert_eq!(data.buy_relic(&scanner("trailer_park")), Err("already yours"));
+
+        let found = relic("dice", vec![RelicEffect::Profit(5)], RelicSource::Milestone(RelicMilestone::Hands(1)));
+        assert_eq!(data.buy_relic(&found), Err("not for sale"));
+    }
+
+    #[test]
+    fn test_relics_bend_sentences_vig_and_decay() {
+        use crate::models::test_helpers::create_relic as relic;
+        let found = |id: &str, effect| relic(id, vec![effect], RelicSource::Milestone(RelicMilestone::Hands(0)));
+
+        let mut plain = with_crew();
+        let mut lenient = with_crew();
+        lenient.grant_relic(&found("judge", RelicEffect::Sentence(1)));
+        let runs = plain.jail_for_bust(1, 60);
+        assert_eq!(lenient.jail_for_bust(1, 60), (runs - 1).max(1));
+
+        let mut data = SaveData::new();
+        data.grant_relic(&found("friend", RelicEffect::Vig(15)));
+        data.account.unlocked_cards.insert("shrooms".to_string());
+        data.take_front("shrooms", "trailer_park", 100).unwrap();
+        assert_eq!(data.front_in("trailer_park").unwrap().owed, 110);
+
+        let mut state = CharacterState::new();
+        state.heat = 100;
+        state.last_played = current_timestamp().saturating_sub(10 * 3600);
+        let relics = RelicEffects::sum(&[RelicEffect::Decay(50)]);
+        assert_eq!(state.apply_decay_with(&relics, Ascension::default()), 15);
+        assert_eq!(state.heat, 85);
+    }
+
+    // ---- SOW-061: task-force operations ----
+
+    #[test]
+    fn operations_save_version_is_at_least_twenty_three() {
+        // v23 added SaveData.operations
+        const { assert!(SAVE_VERSION >= 23) };
+        assert!(SaveData::new().operations.is_empty());
+    }
+
+    #[test]
+    fn test_operations_come_due_once_and_settle() {
+        use crate::models::boss::{BossDef, BossRule, BossTrigger};
+        use crate::models::test_helpers::{create_boss as boss, create_relic as relic};
+        let badge = relic("badge", vec![RelicEffect::Cover(5)], RelicSource::Milestone(RelicMilestone::Hands(999)));
+        let defs = vec![
+            BossDef { bounty: 2000, relic: Some("badge".to_string()), ..boss("dea", vec![BossRule::NoInsurance], BossTrigger::Tier(HeatTier::Hot)) },
+            BossDef { sentence: 2, ..boss("ia", vec![BossRule::ExtraRounds(1)], BossTrigger::Zones(2)) },
+        ];
+        let mut data = with_crew();
+        assert_eq!(data.operation_due(&defs), None);
+
+        // Any dealer on the roster crossing the tier brings the DEA in
+        data.dealers[1].character.heat = 60;
+        assert_eq!(data.operation_due(&defs).map(|b| b.id.as_str()), Some("dea"));
+        assert!(!data.settle_operation(&defs[0], HandOutcome::Folded, Some(&badge)), "a fold leaves it waiting");
+        assert!(data.settle_operation(&defs[0], HandOutcome::Safe, Some(&badge)));
+        assert_eq!(data.account.cash_on_hand, 2000);
+        assert!(data.has_relic("badge"));
+        assert_eq!(data.operation_due(&defs), None, "faced once");
+        assert!(!data.settle_operation(&defs[0], HandOutcome::Safe, Some(&badge)));
+
+        // A bust on the IA sting adds its time to the sentence
+        data.account.unlocked_locations.insert("suburbia".to_string());
+        assert_eq!(data.operation_due(&defs).map(|b| b.id.as_str()), Some("ia"));
+        data.active_dealer = 1;
+        let runs = data.jail_for_bust(1, 0);
+        assert!(data.settle_operation(&defs[1], HandOutcome::Busted, None));
+        assert_eq!(data.dealers[1].jail_remaining(), Some(runs + 2));
+        assert!(matches!(
+            data.chronicle.last().map(|e| &e.event),
+            Some(ChronicleEvent::Operation { beaten: false, .. })
+        ));
+
+        // The next empire hasn't met them
+        data.reset_empire();
+        assert!(data.operations.is_empty());
+    }
+
     // ---- SOW-032: tutorial arc save state ----
 
     #[test]
-    fn tutorial_save_version_is_eleven() {
-        assert_eq!(SAVE_VERSION, 11);
+    fn tutorial_save_version_is_at_least_eleven() {
+        // v11 introduced the tutorial arc. Each later bump pins its own
+        // version next to the fields it added (see the SAVE_VERSION comment)
+        const { assert!(SAVE_VERSION >= 11) };
     }
 
     #[test]
@@ -2457,7 +4515,7 @@ mod tests {
         data.account.cash_on_hand = 500;
         assert!(data.hire_signature_dealer("trailer_park", &sig("Bubba")));
         data.dealers[1].character.heat = 40;
-        data.dealers[1].jail();
+        data.dealers[1].jail_with(&RelicEffects::default());
         data.active_dealer = 0;
 
         // Serialize through the same path the save file uses
@@ -2477,13 +4535,303 @@ mod tests {
         data.active_dealer = 3;
         assert!(data.validate().is_err());
 
-        let mut data = SaveData::new();
-        data.dealers[0].status = DealerStatus::Jailed {
-            runs_remaining: 1,
-            sentence_total: 1,
-            heat_at_bust: 0,
+        let mut data = SaveData::new();
+        data.dealers[0].status = DealerStatus::Jailed {
+            runs_remaining: 1,
+            sentence_total: 1,
+            heat_at_bust: 0,
+        };
+        assert!(data.validate().is_err());
+    }
+
+    // ========================================================================
+    // SOW-041: per-dealer decks + loadouts
+    // ========================================================================
+
+    fn ids(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_each_dealer_keeps_their_own_deck() {
+        let mut data = SaveData::new();
+        data.account.cash_on_hand = 500;
+        assert!(data.hire_signature_dealer("trailer_park", &sig("Bubba")));
+        assert!(data.dealers.iter().all(|d| d.deck.is_empty()));
+
+        assert!(data.dealers[0].set_deck(ids(&["weed", "alibi"])));
+        assert!(data.dealers[1].set_deck(ids(&["weed", "lookout"])));
+        // Re-setting the same deck is not an edit
+        assert!(!data.dealers[0].set_deck(ids(&["weed", "alibi"])));
+
+        let bytes = bincode::serialize(&data).unwrap();
+        let loaded: SaveData = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(loaded.dealers[0].deck, ids(&["weed", "alibi"]));
+        assert_eq!(loaded.dealers[1].deck, ids(&["weed", "lookout"]));
+    }
+
+    #[test]
+    fn loadouts_save_version_is_at_least_twelve() {
+        // v12 added DealerState.deck and .loadouts
+        const { assert!(SAVE_VERSION >= 12) };
+        let dealer = DealerState::kingpin();
+        assert!(dealer.deck.is_empty());
+        assert!(dealer.loadouts.is_empty());
+    }
+
+    #[test]
+    fn test_loadouts_fill_overwrite_and_apply() {
+        let mut dealer = DealerState::kingpin();
+        assert_eq!(dealer.save_loadout(0, "Empty"), Err("nothing to save"));
+
+        dealer.set_deck(ids(&["weed", "alibi"]));
+        assert_eq!(dealer.save_loadout(0, "  "), Err("loadout needs a name"));
+        assert_eq!(dealer.save_loadout(1, "Gap"), Err("no such loadout slot"));
+        assert_eq!(dealer.save_loadout(0, " Weed "), Ok(()));
+        assert_eq!(dealer.loadouts[0].name, "Weed");
+
+        dealer.set_deck(ids(&["weed", "lookout"]));
+        assert_eq!(dealer.save_loadout(1, "Lookout"), Ok(()));
+        // Overwrite in place
+        dealer.set_deck(ids(&["weed", "lookout", "alibi"]));
+        assert_eq!(dealer.save_loadout(1, "Full"), Ok(()));
+        assert_eq!(dealer.loadouts.len(), 2);
+        assert_eq!(dealer.loadouts[1].cards, ids(&["weed", "lookout", "alibi"]));
+
+        // Apply swaps the preset in; an empty slot or a no-op swap reports false
+        assert!(dealer.apply_loadout(0));
+        assert_eq!(dealer.deck, ids(&["weed", "alibi"]));
+        assert!(!dealer.apply_loadout(0));
+        assert!(!dealer.apply_loadout(2));
+    }
+
+    #[test]
+    fn test_apply_loadout_keeps_evolved_ids() {
+        let mut dealer = DealerState::kingpin();
+        dealer.set_deck(ids(&["weed", "alibi"]));
+        assert_eq!(dealer.save_loadout(0, "Weed"), Ok(()));
+
+        // The alibi evolves after the preset was saved
+        dealer.character.evolved_cards.insert("alibi".to_string(), "ironclad".to_string());
+        dealer.set_deck(ids(&["weed"]));
+        assert!(dealer.apply_loadout(0));
+        assert_eq!(dealer.deck, ids(&["weed", "ironclad"]));
+        // The preset itself still holds what was saved
+        assert_eq!(dealer.loadouts[0].cards, ids(&["weed", "alibi"]));
+    }
+
+    #[test]
+    fn test_loadout_shelf_is_capped() {
+        let mut dealer = DealerState::kingpin();
+        dealer.set_deck(ids(&["weed"]));
+        for slot in 0..MAX_LOADOUTS {
+            assert_eq!(dealer.save_loadout(slot, "Preset"), Ok(()));
+        }
+        assert_eq!(dealer.save_loadout(MAX_LOADOUTS, "One too many"), Err("no such loadout slot"));
+        assert!(dealer.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_oversized_decks_and_shelves() {
+        let mut data = SaveData::new();
+        data.dealers[0].deck = vec!["weed".to_string(); MAX_DECK_SIZE + 1];
+        assert!(data.validate().is_err());
+
+        let mut data = SaveData::new();
+        let preset = DeckLoadout { name: "x".into(), cards: ids(&["weed"]) };
+        data.dealers[0].loadouts = vec![preset; MAX_LOADOUTS + 1];
+        assert!(data.validate().is_err());
+    }
+
+    // ------------------------------------------------------------------
+    // SOW-042: day planning - orders and the shared bank paths
+    // ------------------------------------------------------------------
+
+    /// Kingpin plus one zone dealer (index 1) stationed at the trailer park
+    fn with_crew() -> SaveData {
+        let mut save = SaveData::new();
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Slim", "Slim"));
+        save
+    }
+
+    #[test]
+    fn orders_save_version_is_at_least_thirteen() {
+        // v13 added DealerState.order
+        const { assert!(SAVE_VERSION >= 13) };
+        assert_eq!(DealerState::kingpin().order, DealerOrder::Rest);
+    }
+
+    #[test]
+    fn test_set_order_guards() {
+        let mut save = with_crew();
+        assert_eq!(save.dealers[1].order, DealerOrder::Rest);
+
+        assert_eq!(save.set_order(1, DealerOrder::Run(RunPolicy::Greedy)), Ok(()));
+        assert_eq!(save.dealers[1].order, DealerOrder::Run(RunPolicy::Greedy));
+
+        assert_eq!(
+            save.set_order(0, DealerOrder::Run(RunPolicy::Balanced)),
+            Err("the boss only deals in person")
+        );
+        assert_eq!(save.set_order(0, DealerOrder::Scout), Ok(()));
+        assert_eq!(save.set_order(9, DealerOrder::Rest), Err("no such dealer"));
+
+        let home = DealerOrder::Move { to_area: "trailer_park".into() };
+        assert_eq!(save.set_order(1, home), Err("already stationed there"));
+        let locked = DealerOrder::Move { to_area: "suburbia".into() };
+        assert_eq!(save.set_order(1, locked.clone()), Err("that zone isn't yours yet"));
+        save.account.unlocked_locations.insert("suburbia".into());
+        assert_eq!(save.set_order(1, locked), Ok(()));
+    }
+
+    #[test]
+    fn test_rest_and_scout_orders_are_standing() {
+        let mut save = with_crew();
+        save.dealers[1].character.heat = 3;
+        assert_eq!(save.carry_out_order(1, &ZoneGraph::default()), Some(OrderOutcome::Rested { cooled: 3 }));
+        assert_eq!(save.dealers[1].character.heat, 0);
+        assert_eq!(save.dealers[1].order, DealerOrder::Rest);
+
+        save.set_order(1, DealerOrder::Scout).unwrap();
+        let cash = save.account.cash_on_hand;
+        assert_eq!(
+            save.carry_out_order(1, &ZoneGraph::default()),
+            Some(OrderOutcome::Scouted { area: "trailer_park".into(), cred: 1 })
+        );
+        assert_eq!(save.account.cash_on_hand, cash, "scouting earns no cash");
+        assert_eq!(save.dealers[1].order, DealerOrder::Scout, "standing order");
+
+        // Run orders are the caller's to simulate
+        save.set_order(1, DealerOrder::Run(RunPolicy::Cautious)).unwrap();
+        assert_eq!(save.carry_out_order(1, &ZoneGraph::default()), None);
+    }
+
+    #[test]
+    fn test_one_shot_orders_fall_back_to_rest() {
+        let mut save = with_crew();
+        save.account.cash_on_hand = 10_000;
+        save.account.unlocked_locations.insert("suburbia".into());
+        save.dealers[1].character.heat = 40;
+
+        save.set_order(1, DealerOrder::LayLow).unwrap();
+        assert_eq!(save.carry_out_order(1, &ZoneGraph::default()), Some(OrderOutcome::LayingLow));
+        assert!(save.dealers[1].laying_low_remaining().is_some());
+        assert_eq!(save.dealers[1].order, DealerOrder::Rest);
+        // Benched dealers just serve their downtime
+        assert_eq!(save.carry_out_order(1, &ZoneGraph::default()), None);
+
+        let mut save = with_crew();
+        save.account.cash_on_hand = 10_000;
+        save.account.unlocked_locations.insert("suburbia".into());
+        save.set_order(1, DealerOrder::Move { to_area: "suburbia".into() }).unwrap();
+        assert_eq!(
+            save.carry_out_order(1, &ZoneGraph::default()),
+            Some(OrderOutcome::Moved { to_area: "suburbia".into() })
+        );
+        assert_eq!(save.dealers[1].station, "suburbia");
+        assert_eq!(save.dealers[1].order, DealerOrder::Rest);
+
+        // A refused one-shot still clears - no retrying it every day
+        let mut save = with_crew();
+        save.set_order(1, DealerOrder::LayLow).unwrap();
+        assert_eq!(save.carry_out_order(1, &ZoneGraph::default()), Some(OrderOutcome::Refused("day.reason_no_heat")));
+        assert_eq!(save.dealers[1].order, DealerOrder::Rest);
+    }
+
+    #[test]
+    fn test_bank_safe_hand_counts_only_player_cards() {
+        use crate::models::test_helpers::*;
+
+        let mut save = with_crew();
+        let cash = save.account.cash_on_hand;
+        let played = vec![
+            create_product("Weed", 30, 5),
+            create_location("Alley", 5, 20, 0),
+            create_evidence("Patrol", 5, 5),
+        ];
+        save.bank_safe_hand(1, "trailer_park", 45, &played);
+
+        let slim = &save.dealers[1];
+        assert_eq!(slim.cred_in("trailer_park"), 1);
+        assert_eq!(slim.character.get_play_count("Weed"), 1);
+        assert_eq!(slim.character.get_play_count("Alley"), 1);
+        assert_eq!(slim.character.get_play_count("Patrol"), 0);
+        assert_eq!(save.account.cash_on_hand, cash + 45);
+        // The kingpin's record is untouched
+        assert_eq!(save.dealers[0].character.get_play_count("Weed"), 0);
+
+        // A zero-profit Safe hand still earns cred, but nothing toward upgrades
+        save.bank_safe_hand(1, "trailer_park", 0, &played);
+        assert_eq!(save.dealers[1].cred_in("trailer_park"), 2);
+        assert_eq!(save.dealers[1].character.get_play_count("Weed"), 1);
+    }
+
+    #[test]
+    fn test_street_cred_perk_adds_cred() {
+        use crate::models::test_helpers::*;
+
+        let mut save = with_crew();
+        save.dealers[1]
+            .character
+            .card_upgrades
+            .entry("Weed".to_string())
+            .or_default()
+            .add_choice(UpgradeChoice::Perk(UpgradePerk::StreetCred));
+        let played = vec![create_product("Weed", 30, 5), create_location("Alley", 5, 20, 0)];
+        save.bank_safe_hand(1, "trailer_park", 45, &played);
+        assert_eq!(save.dealers[1].cred_in("trailer_park"), 2);
+    }
+
+    #[test]
+    fn test_close_session_and_jail_for_bust() {
+        let mut save = with_crew();
+        save.dealers[1].character.heat = 10;
+        save.close_session(1, -4, &[StoryRecord::note("a quiet night")], true);
+        let slim = &save.dealers[1].character;
+        assert_eq!(slim.heat, 6);
+        assert_eq!(slim.story_history.len(), 1);
+        assert_eq!(slim.story_history[0].text, "a quiet night");
+        assert_eq!(slim.decks_played, 1);
+
+        let runs = save.jail_for_bust(1, 30);
+        assert!(runs > 0);
+        assert_eq!(save.dealers[1].jail_remaining(), Some(runs));
+        // Session heat landed before sentencing
+        assert_eq!(save.dealers[1].character.heat, 36);
+    }
+
+    #[test]
+    fn story_records_save_version_is_at_least_sixteen() {
+        // v16 turned story_history into StoryRecords
+        const { assert!(SAVE_VERSION >= 16) };
+        let mut data = SaveData::new();
+        data.dealers[0].character.story_history.push(StoryRecord::note("a quiet night"));
+        let bytes = bincode::serialize(&data).unwrap();
+        let loaded: SaveData = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(loaded.dealers[0].character.story_history, data.dealers[0].character.story_history);
+    }
+
+    #[test]
+    fn test_close_session_stamps_dealer_and_run() {
+        use crate::models::hand_state::HandOutcome;
+        let mut save = with_crew();
+        save.dealers[1].character.decks_played = 2;
+        let hand = StoryRecord {
+            text: "Sold to the Frat Bro.".to_string(),
+            outcome: Some(HandOutcome::Safe),
+            area: "trailer_park".to_string(),
+            profit: 40,
+            heat_delta: 8,
+            timestamp: 77,
+            ..Default::default()
         };
-        assert!(data.validate().is_err());
+        save.close_session(1, 8, &[hand.clone(), StoryRecord::note("A good night.")], true);
+
+        let history = &save.dealers[1].character.story_history;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0], StoryRecord { dealer: "Slim".to_string(), run: 3, ..hand });
+        assert_eq!(history[1].dealer, "Slim");
+        assert_eq!(history[1].run, 3, "the summary belongs to the same run");
     }
 
     #[test]
@@ -2492,11 +4840,11 @@ mod tests {
         save.account.lifetime_revenue = 12_400;
         save.account.cash_on_hand = 900;
         save.dealers[0].character.decks_played = 5;
-        save.dealers[0].character.story_history.push("The boss's tale".into());
+        save.dealers[0].character.story_history.push(StoryRecord::note("The boss's tale"));
         let mut hired = DealerState::zone_dealer("trailer_park", "Slim", "Gladys");
         hired.prior_convictions = 2;
         hired.character.decks_played = 4;
-        hired.character.story_history.push("Slim's tale".into());
+        hired.character.story_history.push(StoryRecord::note("Slim's tale"));
         save.dealers.push(hired);
 
         let epitaph = EmpireEpitaph::from_save(&save, 1234);
@@ -2541,6 +4889,299 @@ mod tests {
         assert!(top.contains(&3));
     }
 
+    // ---- SOW-062: victories and retirement ----
+
+    #[test]
+    fn retirement_save_version_is_at_least_twenty_four() {
+        // v24 added EmpireEpitaph.retirement
+        const { assert!(SAVE_VERSION >= 24) };
+        let mut data = SaveData::new();
+        data.account.cash_on_hand = 999; // a real epitaph is carved
+        data.reset_empire();
+        assert_eq!(data.fallen_empires[0].retirement, None, "a fall is not a retirement");
+    }
+
+    #[test]
+    fn test_retirement_scores_victories_and_ranks_apart() {
+        let victory = |id: &str, condition: VictoryCondition| VictoryDef {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            description: String::new(),
+            condition,
+            bonus: 1_000,
+        };
+        let defs = vec![
+            victory("city", VictoryCondition::AllZones),
+            victory("clean", VictoryCondition::CleanCash(3_000)),
+            victory("crew", VictoryCondition::FreeDealers(1)),
+            victory("rich", VictoryCondition::Revenue(10_000)),
+        ];
+        let mut save = with_crew();
+        save.account.cash_on_hand = 4_000;
+        save.account.lifetime_revenue = 5_000;
+        save.fronts.push(FrontState {
+            card_id: "weed".to_string(),
+            area_id: "trailer_park".to_string(),
+            owed: 1_500,
+            runs_remaining: 3,
+            charges: 0,
+        });
+        let met = |save: &SaveData| -> Vec<String> {
+            save.victories_met(&defs, 1).iter().map(|d| d.id.clone()).collect()
+        };
+        // Owing the supplier keeps the cash dirty; a jailed crew isn't free
+        assert_eq!(met(&save), vec!["city", "crew"]);
+        save.jail_for_bust(1, 0);
+        assert_eq!(met(&save), vec!["city"]);
+        assert!(save.victories_met(&defs, 3).is_empty(), "one zone of three");
+        save.fronts.clear();
+        assert_eq!(met(&save), vec!["city", "clean"]);
+
+        let retirement = save.retirement(&defs, 1);
+        assert_eq!(retirement.clean_cash, 4_000);
+        assert_eq!(retirement.score, 5_000 + 4_000 + 2_000);
+        assert_eq!(retirement.victories, vec!["CITY", "CLEAN"]);
+
+        save.retire(retirement.clone(), "I walked away.".to_string());
+        assert_eq!(save.account.lifetime_revenue, 0, "a fresh empire");
+        let epitaph = &save.fallen_empires[0];
+        assert_eq!(epitaph.retirement.as_ref(), Some(&retirement));
+        assert_eq!(epitaph.stories.last().map(|s| s.text.as_str()), Some("I walked away."));
+        assert_eq!(epitaph.stories.last().map(|s| s.dealer.as_str()), Some(save.dealers[0].name.as_str()));
+        assert!(matches!(
+            epitaph.chronicle.last().map(|e| &e.event),
+            Some(ChronicleEvent::Retired { score: 11_000 })
+        ));
+
+        // The fall and the retirement each rank on their own board
+        save.account.lifetime_revenue = 700;
+        save.reset_empire();
+        assert_eq!(leaderboard_top(&save.fallen_empires, 5), vec![1]);
+        assert_eq!(retirement_top(&save.fallen_empires, 5), vec![0]);
+    }
+
+    #[test]
+    fn succession_save_version_is_at_least_twenty_five() {
+        // v25 added SaveData.succession and .dynasty, and EmpireEpitaph.dynasty
+        const { assert!(SAVE_VERSION >= 25) };
+        let mut data = SaveData::new();
+        assert!(!data.succession);
+        assert!(data.dynasty.is_empty());
+        data.account.cash_on_hand = 999; // a real epitaph is carved
+        data.reset_empire();
+        assert!(data.fallen_empires[0].dynasty.is_empty());
+    }
+
+    #[test]
+    fn test_succession_hands_the_empire_to_the_heir() {
+        let mut save = with_crew();
+        save.dealers.push(DealerState::zone_dealer("red_light_district", "Nova", "Nova"));
+        save.account.cash_on_hand = 4_000;
+        save.account.lifetime_revenue = 6_000;
+        save.account.unlocked_locations.insert("red_light_district".into());
+        save.account.unlocked_locations.insert("suburbia".into());
+        let fallen = save.dealers[0].name.clone();
+
+        assert!(!save.succeed(), "the rule is off by default");
+        save.set_succession(true);
+        assert_eq!(save.heir(), None);
+        assert_eq!(save.succession_check(1), Err("not loyal enough yet"));
+        assert!(!save.succeed(), "nobody qualifies");
+        assert_eq!(save.dealers[0].name, fallen);
+
+        save.dealers[1].character.decks_played = SUCCESSION_MIN_RUNS;
+        save.dealers[1].street_cred.insert("trailer_park".into(), SUCCESSION_MIN_CRED);
+        save.dealers[2].character.decks_played = SUCCESSION_MIN_RUNS;
+        save.dealers[2].street_cred.insert("red_light_district".into(), SUCCESSION_MIN_CRED + 5);
+        assert_eq!(save.heir(), Some(2), "the most cred takes it");
+        save.jail_for_bust(2, 0);
+        assert_eq!(save.succession_check(2), Err("in jail"));
+        assert_eq!(save.heir(), Some(1));
+        assert_eq!(save.succession_check(0), Err("already the kingpin"));
+
+        let heat = save.dealers[1].character.heat;
+        assert!(save.succeed());
+        assert_eq!(save.dealers.len(), 2);
+        assert!(save.dealers[0].is_kingpin);
+        assert_eq!(save.dealers[0].name, "Slim");
+        assert_eq!(save.active_dealer, 0);
+        assert_eq!(save.dealers[0].character.heat, heat + SUCCESSION_HEAT);
+        assert_eq!(save.account.cash_on_hand, 2_000);
+        assert!(save.account.unlocked_locations.contains("red_light_district"), "Nova still holds it");
+        assert!(!save.account.unlocked_locations.contains("suburbia"));
+        assert_eq!(save.dynasty.len(), 1);
+        assert_eq!(save.dynasty[0].kingpin.name, fallen);
+        assert_eq!(save.dynasty[0].lifetime_revenue, 6_000);
+        assert!(matches!(
+            save.chronicle.last().map(|e| &e.event),
+            Some(ChronicleEvent::Succeeded { zones_lost: 1, seized: 2_000, .. })
+        ));
+
+        // The dynasty goes to the ledger with the empire; the rule stays on
+        save.dynasty[0].kingpin.character.story_history.push(StoryRecord::note("the old days"));
+        save.reset_empire();
+        let epitaph = &save.fallen_empires[0];
+        assert_eq!(epitaph.dynasty.len(), 1);
+        assert_eq!(epitaph.stories.first().map(|s| s.text.as_str()), Some("the old days"));
+        assert!(save.succession);
+        assert!(save.dynasty.is_empty());
+    }
+
+    #[test]
+    fn legacy_save_version_is_at_least_twenty_six() {
+        // v26 added SaveData.legacy (then just spent and unlocks) and
+        // EmpireEpitaph.zones_unlocked
+        const { assert!(SAVE_VERSION >= 26) };
+        let mut data = SaveData::new();
+        assert_eq!(data.legacy, Legacy::default());
+        data.account.cash_on_hand = 999; // a real epitaph is carved
+        data.reset_empire();
+        assert_eq!(data.fallen_empires[0].zones_unlocked, 1);
+    }
+
+    #[test]
+    fn legacy_ledger_save_version_is_at_least_twenty_eight() {
+        // v28 added Legacy.earned, .active and .first_hire_used
+        const { assert!(SAVE_VERSION >= 28) };
+        let mut data = SaveData::new();
+        assert_eq!((data.legacy.earned, data.legacy.active.len(), data.legacy.first_hire_used), (0, 0, false));
+        data.account.lifetime_revenue = 100_000;
+        data.reset_empire();
+        assert_eq!(data.legacy.earned, data.fallen_empires[0].legacy_earned());
+    }
+
+    #[test]
+    fn test_legacy_earned_spent_and_applied_to_the_next_empire() {
+        use crate::models::relic::RelicEvent;
+        use crate::models::test_helpers::create_relic as relic;
+        let mut save = with_crew();
+        assert_eq!(save.legacy_balance(), 0);
+        assert_eq!(save.buy_legacy(LegacyUnlock::CheaperFirstHire), Err("not enough legacy"));
+
+        // $100k, one hire, two zones: 100 + 5 + 20
+        save.account.lifetime_revenue = 100_000;
+        save.account.unlocked_locations.insert("suburbia".into());
+        save.reset_empire();
+        assert_eq!(save.fallen_empires[0].zones_unlocked, 2);
+        assert_eq!(save.legacy_balance(), 125);
+
+        let scanner = relic("scanner", vec![RelicEffect::Cover(5)], RelicSource::Event(RelicEvent::BailedOut));
+        assert_eq!(save.buy_legacy(LegacyUnlock::StartingProduct("shrooms".into())), Ok(LEGACY_PRODUCT_COST));
+        assert_eq!(
+            save.buy_legacy(LegacyUnlock::StartingProduct("meth".into())),
+            Err("already unlocked"),
+            "one product slot"
+        );
+        assert_eq!(save.buy_legacy(LegacyUnlock::StartingZone("suburbia".into())), Ok(LEGACY_ZONE_COST));
+        assert_eq!(save.buy_legacy(LegacyUnlock::StartingRelic(OwnedRelic::from(&scanner))), Err("not enough legacy"));
+        assert_eq!(save.buy_legacy(LegacyUnlock::CheaperFirstHire), Ok(LEGACY_HIRE_COST));
+        assert_eq!(save.legacy_balance(), 25);
+        assert!(!save.account.unlocked_cards.contains("shrooms"), "unlocks wait for the next empire");
+        assert_eq!(save.next_hire_cost(), hire_cost(1), "so does the discount");
+
+        save.reset_empire();
+        assert!(save.account.unlocked_cards.contains("shrooms"));
+        assert_eq!(save.account.charges_in("shrooms"), BATCH_SIZE);
+        assert!(save.account.unlocked_locations.contains("suburbia"));
+        assert!(save.account.unlocked_locations.contains(DEFAULT_STATION));
+        assert_eq!(save.dealers[0].station, "suburbia");
+        assert_eq!(save.next_hire_cost(), 250);
+        save.account.cash_on_hand = 250;
+        assert!(save.hire_signature_dealer("suburbia", &sig("Slim")));
+        assert_eq!(save.account.cash_on_hand, 0);
+        assert_eq!(save.next_hire_cost(), hire_cost(2), "only the first hire");
+        assert_eq!(save.legacy.unlocks.len(), 3, "the legacy outlives the empire");
+        assert!(save.relics.is_empty());
+        assert!(SaveData::new().legacy.unlocks.is_empty());
+
+        let relic_start = SaveData::with_legacy(Legacy {
+            spent: LEGACY_RELIC_COST,
+            unlocks: vec![LegacyUnlock::StartingRelic(OwnedRelic::from(&scanner))],
+            ..Default::default()
+        });
+        assert!(relic_start.has_relic("scanner"));
+    }
+
+    #[test]
+    fn test_legacy_discount_is_the_empires_first_hire_only() {
+        let mut save = SaveData::with_legacy(Legacy { unlocks: vec![LegacyUnlock::CheaperFirstHire], ..Default::default() });
+        save.account.cash_on_hand = 10_000;
+        assert_eq!(save.next_hire_cost(), hire_cost(1) * LEGACY_FIRST_HIRE_PCT / 100);
+        assert!(save.hire_signature_dealer(DEFAULT_STATION, &sig("Slim")));
+        assert!(save.legacy.first_hire_used);
+
+        // Slim takes the chair: the roster is one dealer again, but the
+        // empire's first hire is long gone
+        save.set_succession(true);
+        save.dealers[1].character.decks_played = SUCCESSION_MIN_RUNS;
+        save.dealers[1].street_cred.insert(DEFAULT_STATION.into(), SUCCESSION_MIN_CRED);
+        assert!(save.succeed());
+        assert_eq!(save.dealers.len(), 1);
+        assert_eq!(save.next_hire_cost(), hire_cost(1));
+
+        // The next empire gets its discount back
+        save.reset_empire();
+        assert!(!save.legacy.first_hire_used);
+        assert_eq!(save.next_hire_cost(), hire_cost(1) * LEGACY_FIRST_HIRE_PCT / 100);
+    }
+
+    #[test]
+    fn test_legacy_is_banked_as_each_empire_closes() {
+        let mut save = with_crew();
+        save.account.lifetime_revenue = 100_000;
+        save.reset_empire();
+        let earned = save.fallen_empires[0].legacy_earned();
+        assert_eq!(save.legacy.earned, earned);
+
+        // The archive can be trimmed without touching the balance
+        save.fallen_empires.clear();
+        assert_eq!(save.legacy_balance(), earned);
+        save.account.lifetime_revenue = 3_000;
+        save.retire(Retirement::default(), String::new());
+        assert_eq!(save.legacy.earned, earned + 3 + LEGACY_PER_ZONE, "retirements leave a legacy too");
+    }
+
+    #[test]
+    fn ascension_save_version_is_at_least_twenty_seven() {
+        // v27 added SaveData.ascension, .ascension_unlocked and EmpireEpitaph.ascension
+        const { assert!(SAVE_VERSION >= 27) };
+        let mut data = SaveData::new();
+        assert_eq!((data.ascension, data.ascension_unlocked), (Ascension(0), Ascension(0)));
+        data.account.cash_on_hand = 999; // a real epitaph is carved
+        data.reset_empire();
+        assert_eq!(data.fallen_empires[0].ascension, Ascension(0));
+    }
+
+    #[test]
+    fn test_ascension_is_picked_fresh_unlocked_by_beating_and_ranked_first() {
+        let mut save = SaveData::new();
+        assert_eq!(save.set_ascension(Ascension(1)), Err("not unlocked yet"));
+
+        // A fall doesn't open the next level; beating an operation does
+        save.account.lifetime_revenue = 9_000;
+        save.reset_empire();
+        assert_eq!(save.ascension_unlocked, Ascension(0));
+        save.chronicle_event(ChronicleEvent::Operation { name: "Sweep".into(), dealer: "You".into(), beaten: true });
+        assert_eq!(save.set_ascension(Ascension(0)), Err("the empire is already underway"));
+        save.reset_empire();
+        assert_eq!(save.ascension_unlocked, Ascension(1));
+
+        assert_eq!(save.set_ascension(Ascension(1)), Ok(()));
+        assert_eq!(save.front_owed_for(100), front_owed_at(100, FRONT_VIG_PCT), "level 1 only heats the narcs");
+
+        // A level-1 fall at $500 outranks a level-0 fall at $9,000, and the
+        // next empire keeps the level
+        save.account.lifetime_revenue = 500;
+        save.reset_empire();
+        assert_eq!(save.fallen_empires[2].ascension, Ascension(1));
+        assert_eq!(leaderboard_top(&save.fallen_empires, 3), vec![2, 0, 1]);
+        assert_eq!(save.ascension, Ascension(1));
+
+        // Retiring at the top level opens the one above it
+        save.retire(Retirement::default(), "Out.".into());
+        assert_eq!(save.ascension_unlocked, Ascension(2));
+    }
+
     #[test]
     fn test_purchase_location() {
         // SOW-024: buy once, double-buy rejected, insufficient funds rejected
@@ -2575,13 +5216,23 @@ mod tests {
         assert!(save.account.is_location_unlocked("trailer_park")); // fresh default
     }
 
+    /// A move the way the map offers it on an unauthored graph (every zone
+    /// one direct hop apart)
+    fn move_direct(save: &mut SaveData, dealer_idx: usize, to_area: &str) -> bool {
+        let route = save
+            .dealers
+            .get(dealer_idx)
+            .and_then(|d| ZoneGraph::default().route(&d.station, to_area));
+        route.is_some_and(|route| save.move_dealer_along(dealer_idx, &route))
+    }
+
     #[test]
     fn test_move_dealer_costs_fee_and_downtime() {
         // SOW-025: relocation = cash + 1 run of unavailability
         let mut save = SaveData::new();
         save.account.cash_on_hand = 1000;
 
-        assert!(save.move_dealer(0, "suburbia"));
+        assert!(move_direct(&mut save, 0, "suburbia"));
         assert_eq!(save.account.cash_on_hand, 750); // $250 fee
         assert_eq!(save.dealers[0].station, "suburbia"); // station changes immediately
         assert_eq!(save.dealers[0].relocating_remaining(), Some(1));
@@ -2597,18 +5248,120 @@ mod tests {
     fn test_move_dealer_rejections() {
         let mut save = SaveData::new();
         save.account.cash_on_hand = 100; // can't afford the $250 fee
-        assert!(!save.move_dealer(0, "suburbia"));
+        assert!(!move_direct(&mut save, 0, "suburbia"));
         assert_eq!(save.dealers[0].station, DEFAULT_STATION);
 
         save.account.cash_on_hand = 1000;
-        assert!(!save.move_dealer(0, DEFAULT_STATION)); // already stationed there
-        assert!(!save.move_dealer(9, "suburbia")); // out of range
+        assert!(!move_direct(&mut save, 0, DEFAULT_STATION)); // already stationed there
+        assert!(!move_direct(&mut save, 9, "suburbia")); // out of range
 
-        assert!(save.move_dealer(0, "suburbia"));
-        assert!(!save.move_dealer(0, "trailer_park")); // mid-relocation = unavailable
+        assert!(move_direct(&mut save, 0, "suburbia"));
+        assert!(!move_direct(&mut save, 0, "trailer_park")); // mid-relocation = unavailable
         assert_eq!(save.account.cash_on_hand, 750); // only one fee charged
     }
 
+    #[test]
+    fn routes_save_version_is_at_least_fourteen() {
+        // v14 put the route being walked on DealerStatus::Relocating
+        const { assert!(SAVE_VERSION >= 14) };
+        let mut save = SaveData::new();
+        save.account.cash_on_hand = 1000;
+        assert!(move_direct(&mut save, 0, "suburbia"));
+        let DealerStatus::Relocating { route, total_runs, .. } = &save.dealers[0].status else {
+            panic!("moving dealer should be relocating");
+        };
+        assert_eq!(route, &vec![DEFAULT_STATION.to_string(), "suburbia".to_string()]);
+        assert_eq!(*total_runs, 1);
+    }
+
+    fn two_leg_route() -> ZoneRoute {
+        ZoneRoute {
+            path: vec!["trailer_park".into(), "suburbia".into(), "downtown".into()],
+            runs: 3,
+            cost: 600,
+        }
+    }
+
+    #[test]
+    fn test_move_along_route_charges_route_cost_and_runs() {
+        // SOW-043: the route prices the move; the road rides on the status
+        let mut save = SaveData::new();
+        save.account.cash_on_hand = 1000;
+
+        assert!(save.move_dealer_along(0, &two_leg_route()));
+        assert_eq!(save.account.cash_on_hand, 400);
+        assert_eq!(save.dealers[0].station, "downtown");
+        assert_eq!(
+            save.dealers[0].status,
+            DealerStatus::Relocating {
+                runs_remaining: 3,
+                route: two_leg_route().path,
+                total_runs: 3,
+            }
+        );
+
+        // Three completed runs on the road, then arrived
+        assert!(!save.dealers[0].tick_sentence());
+        assert!(!save.dealers[0].tick_sentence());
+        assert_eq!(save.dealers[0].relocating_remaining(), Some(1));
+        assert!(save.dealers[0].tick_sentence());
+        assert!(save.dealers[0].is_available());
+    }
+
+    #[test]
+    fn test_move_along_route_rejections() {
+        let mut save = SaveData::new();
+        save.account.cash_on_hand = 599; // one short of the route
+        assert!(!save.move_dealer_along(0, &two_leg_route()));
+
+        save.account.cash_on_hand = 1000;
+        // A route that doesn't start where the dealer stands
+        let mut elsewhere = two_leg_route();
+        elsewhere.path.remove(0);
+        assert!(!save.move_dealer_along(0, &elsewhere));
+        // An empty route goes nowhere
+        let nowhere = ZoneRoute { path: vec![], runs: 0, cost: 0 };
+        assert!(!save.move_dealer_along(0, &nowhere));
+        assert_eq!(save.account.cash_on_hand, 1000);
+        assert!(save.dealers[0].is_available());
+    }
+
+    #[test]
+    fn test_move_order_walks_the_graph() {
+        use crate::models::shop_location::{ShopLocationDef, ZoneLink};
+        let area = |id: &str, links: Vec<ZoneLink>| ShopLocationDef {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            unlocked: true,
+            price: 0,
+            identity: String::new(),
+            narc_hint: String::new(),
+            supplier: None,
+            signature_dealer: None,
+            unlockable_dealers: Vec::new(),
+            narc_portrait: None,
+            restock_margin: 0.5,
+            map_position: None,
+            links,
+        };
+        let graph = ZoneGraph::from_areas(&[
+            area("trailer_park", vec![ZoneLink { to: "suburbia".into(), runs: 2, cost: 400 }]),
+            area("suburbia", vec![]),
+        ]);
+        let mut save = SaveData::new();
+        save.account.cash_on_hand = 1000;
+        save.account.unlocked_locations.insert("suburbia".into());
+        save.set_order(0, DealerOrder::Move { to_area: "suburbia".into() }).unwrap();
+
+        assert_eq!(
+            save.carry_out_order(0, &graph),
+            Some(OrderOutcome::Moved { to_area: "suburbia".into() })
+        );
+        assert_eq!(save.account.cash_on_hand, 600);
+        assert_eq!(save.dealers[0].relocating_remaining(), Some(2));
+    }
+
     #[test]
     fn test_lay_low_costs_cash_and_downtime_then_cools() {
         // SOW-027: $200 up front, 2 runs dark, -40 heat on resurfacing
@@ -2651,7 +5404,7 @@ mod tests {
         assert!(!save.lay_low(9)); // out of range
 
         save.dealers[1].character.heat = 50;
-        save.dealers[1].jail();
+        save.dealers[1].jail_with(&RelicEffects::default());
         assert!(!save.lay_low(1)); // jailed - heat settles via release/bail
 
         save.dealers[0].character.heat = 50;
@@ -2715,7 +5468,7 @@ mod tests {
 
         // Jail round-trip: cred untouched
         dealer.character.heat = 50;
-        dealer.jail();
+        dealer.jail_with(&RelicEffects::default());
         dealer.release();
         assert_eq!(dealer.cred_in("suburbia"), 2);
 
@@ -2723,7 +5476,7 @@ mod tests {
         let mut save = SaveData::new();
         save.account.cash_on_hand = 1000;
         save.dealers.push(dealer);
-        assert!(save.move_dealer(1, "suburbia"));
+        assert!(move_direct(&mut save, 1, "suburbia"));
         assert_eq!(save.dealers[1].cred_in("suburbia"), 2);
     }
 
@@ -2745,7 +5498,7 @@ mod tests {
     fn test_kingpin_can_relocate_but_never_jail() {
         let mut save = SaveData::new();
         save.account.cash_on_hand = 1000;
-        assert!(save.move_dealer(0, "suburbia"));
+        assert!(move_direct(&mut save, 0, "suburbia"));
         assert!(save.validate().is_ok()); // relocating kingpin is legal
 
         let mut jailed_kingpin = DealerState::kingpin();
@@ -2762,7 +5515,7 @@ mod tests {
         let mut save = SaveData::new();
         save.account.cash_on_hand = 1000;
         save.dealers[0].add_cred("suburbia");
-        assert!(save.move_dealer(0, "suburbia"));
+        assert!(move_direct(&mut save, 0, "suburbia"));
 
         save.reset_empire();
         assert_eq!(save.dealers[0].station, DEFAULT_STATION);
@@ -2804,7 +5557,7 @@ mod tests {
         // Simulate 10 hours ago
         state.last_played = current_timestamp().saturating_sub(10 * 3600);
 
-        let decay = state.apply_decay();
+        let decay = state.apply_decay_with(&RelicEffects::default(), Ascension::default());
         assert_eq!(decay, 10);
         assert_eq!(state.heat, 90);
     }
@@ -2816,7 +5569,7 @@ mod tests {
         // Simulate 1 year ago (way more than 168 hours)
         state.last_played = current_timestamp().saturating_sub(365 * 24 * 3600);
 
-        let decay = state.apply_decay();
+        let decay = state.apply_decay_with(&RelicEffects::default(), Ascension::default());
         assert_eq!(decay, 168); // Capped at 168
         assert_eq!(state.heat, 32); // 200 - 168
     }
@@ -2829,11 +5582,11 @@ mod tests {
         state.heat = 100;
         state.last_played = current_timestamp().saturating_sub(10 * 3600); // 10h ago
 
-        assert_eq!(state.apply_decay(), 10);
+        assert_eq!(state.apply_decay_with(&RelicEffects::default(), Ascension::default()), 10);
         assert_eq!(state.heat, 90);
 
         // Second call in the same session decays nothing further
-        assert_eq!(state.apply_decay(), 0);
+        assert_eq!(state.apply_decay_with(&RelicEffects::default(), Ascension::default()), 0);
         assert_eq!(state.heat, 90);
     }
 
@@ -2843,7 +5596,7 @@ mod tests {
         state.heat = 5;
         state.last_played = current_timestamp().saturating_sub(100 * 3600);
 
-        let decay = state.apply_decay();
+        let decay = state.apply_decay_with(&RelicEffects::default(), Ascension::default());
         assert_eq!(decay, 5); // Only 5 available to decay
         assert_eq!(state.heat, 0);
     }
@@ -3066,13 +5819,15 @@ mod tests {
             card_name: "Card A".to_string(),
             card_type: product.clone(),
             tier: UpgradeTier::Tier1,
-            options: [UpgradeableStat::Price, UpgradeableStat::Heat],
+            options: [UpgradeableStat::Price.into(), UpgradeableStat::Heat.into()],
+            evolution: None,
         });
         state.pending_upgrades.push(PendingUpgrade {
             card_name: "Card B".to_string(),
             card_type: product,
             tier: UpgradeTier::Tier1,
-            options: [UpgradeableStat::Price, UpgradeableStat::Heat],
+            options: [UpgradeableStat::Price.into(), UpgradeableStat::Heat.into()],
+            evolution: None,
         });
 
         // Resolve the SECOND pending first
@@ -3093,6 +5848,107 @@ mod tests {
         assert!(state.pending_upgrades.is_empty());
     }
 
+    #[test]
+    fn perks_save_version_is_at_least_twenty_one() {
+        // v21 turned PendingUpgrade.options into UpgradeChoices and added
+        // CardUpgrades.perks
+        const { assert!(SAVE_VERSION >= 21) };
+        assert!(CardUpgrades::new().perks.is_empty());
+        let mut state = CharacterState::new();
+        state.pending_upgrades.push(PendingUpgrade {
+            card_name: "Weed".to_string(),
+            card_type: crate::models::card::CardType::Product { price: 30, heat: 5 },
+            tier: UpgradeTier::Tier1,
+            options: [UpgradeableStat::Price.into(), UpgradeChoice::Perk(UpgradePerk::StreetCred)],
+            evolution: None,
+        });
+        let bytes = bincode::serialize(&state).unwrap();
+        let loaded: CharacterState = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(loaded.pending_upgrades[0].options, state.pending_upgrades[0].options);
+    }
+
+    #[test]
+    fn upgrade_roll_draws_two_options_without_owned_perks() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(58);
+        let insurance = crate::models::card::CardType::Insurance { cover: 20, cost: 200, heat_penalty: 10 };
+        let mut taken = CardUpgrades::new();
+        taken.add_choice(UpgradeChoice::Perk(UpgradePerk::CheapInsurance));
+
+        for _ in 0..200 {
+            let [a, b] = UpgradeChoice::roll(&insurance, UpgradeTier::Tier5, &taken, &mut rng).unwrap();
+            assert_ne!(a, b);
+            assert!(![a, b].contains(&UpgradeChoice::Perk(UpgradePerk::CheapInsurance)));
+            assert!(![a, b].contains(&UpgradeChoice::Perk(UpgradePerk::FoldRefund)), "not an insurance perk");
+        }
+        // Evidence has nothing to upgrade
+        let evidence = crate::models::card::CardType::Evidence { evidence: 5, heat: 5 };
+        assert!(UpgradeChoice::roll(&evidence, UpgradeTier::Tier1, &taken, &mut rng).is_none());
+    }
+
+    #[test]
+    fn perks_grow_more_common_up_the_tiers() {
+        let tiers = [
+            UpgradeTier::Tier1,
+            UpgradeTier::Tier2,
+            UpgradeTier::Tier3,
+            UpgradeTier::Tier4,
+            UpgradeTier::Tier5,
+        ];
+        for pair in tiers.windows(2) {
+            assert!(UpgradePerk::StreetCred.weight(pair[0]) < UpgradePerk::StreetCred.weight(pair[1]));
+        }
+        assert!(UpgradePerk::ConvictionImmunity.weight(UpgradeTier::Tier5) < UpgradePerk::StreetCred.weight(UpgradeTier::Tier5));
+        // Rarer than any stat at Tier1
+        assert!(UpgradePerk::StreetCred.weight(UpgradeTier::Tier1) < STAT_WEIGHT);
+    }
+
+    #[test]
+    fn taking_a_perk_spends_the_tier() {
+        let mut state = CharacterState::new();
+        let product = crate::models::card::CardType::Product { price: 30, heat: 5 };
+        state.pending_upgrades.push(PendingUpgrade {
+            card_name: "Weed".to_string(),
+            card_type: product,
+            tier: UpgradeTier::Tier1,
+            options: [UpgradeableStat::Price.into(), UpgradeChoice::Perk(UpgradePerk::StreetCred)],
+            evolution: None,
+        });
+
+        assert!(!state.apply_upgrade_choice_for("Weed", UpgradeChoice::Perk(UpgradePerk::FoldRefund)));
+        assert!(state.apply_upgrade_choice_for("Weed", UpgradeChoice::Perk(UpgradePerk::StreetCred)));
+        let upgrades = &state.card_upgrades["Weed"];
+        assert!(upgrades.has_perk(UpgradePerk::StreetCred));
+        assert!(upgrades.upgrades.is_empty(), "a perk is not a stat bonus");
+        assert_eq!(upgrades.taken(), 1);
+    }
+
+    #[test]
+    fn buying_a_reroll_costs_by_tier() {
+        let mut save = SaveData::new();
+        let product = crate::models::card::CardType::Product { price: 30, heat: 5 };
+        save.active_character_mut().pending_upgrades.push(PendingUpgrade {
+            card_name: "Weed".to_string(),
+            card_type: product,
+            tier: UpgradeTier::Tier3,
+            options: [UpgradeableStat::Price.into(), UpgradeableStat::Heat.into()],
+            evolution: None,
+        });
+
+        save.account.cash_on_hand = 299;
+        assert_eq!(save.buy_upgrade_reroll("Weed"), Err("not enough cash to reroll"));
+        assert_eq!(save.account.cash_on_hand, 299);
+
+        save.account.cash_on_hand = 1000;
+        assert_eq!(save.buy_upgrade_reroll("Weed"), Ok(300));
+        assert_eq!(save.account.cash_on_hand, 700);
+        let pending = &save.active_character().pending_upgrades[0];
+        assert_eq!(pending.card_name, "Weed", "still pending after a reroll");
+        assert_ne!(pending.options[0], pending.options[1]);
+
+        assert_eq!(save.buy_upgrade_reroll("Coke"), Err("no upgrade pending for that card"));
+    }
+
     #[test]
     fn test_character_get_card_tier() {
         let mut state = CharacterState::new();
@@ -3147,4 +6003,361 @@ mod tests {
 
     // SOW-027: danger_name test retired with the method (RFC-018 leftover -
     // no UI surface ever consumed the danger adjectives)
+
+    // ---- SOW-044: regulars ----
+
+    #[test]
+    fn regulars_save_version_is_at_least_fifteen() {
+        // v15 added SaveData.regulars
+        const { assert!(SAVE_VERSION >= 15) };
+        assert!(SaveData::new().regulars.is_empty());
+    }
+
+    fn buyer_deal<'a>(
+        buyer: &'a crate::models::buyer::BuyerPersona,
+        outcome: &'a crate::models::hand_state::HandOutcome,
+        played: &'a [crate::models::card::Card],
+    ) -> BuyerDeal<'a> {
+        BuyerDeal { dealer_idx: 0, area: "trailer_park", buyer, outcome, profit: 120, played }
+    }
+
+    /// "Test Buyer" (trailer_park) as a regular of `data` - the persona as
+    /// buyer_for_session would dress it
+    fn as_regular(data: &SaveData, name: &str) -> crate::models::buyer::BuyerPersona {
+        let mut buyer = crate::models::test_helpers::create_mock_buyer_persona();
+        buyer.regular = data.regulars.iter().find(|r| r.name == name).cloned();
+        buyer
+    }
+
+    #[test]
+    fn test_good_deal_can_make_a_regular_with_favourites() {
+        use crate::models::hand_state::HandOutcome;
+        use crate::models::test_helpers::{create_location, create_mock_buyer_persona, create_product};
+
+        let mut data = SaveData::new();
+        let mut walk_in = create_mock_buyer_persona();
+        walk_in.regular_names = vec!["Chad".to_string()];
+        let played = [create_product("Weed", 30, 5), create_location("Safe House", 5, 20, 0)];
+
+        // The roll decides: over the spawn chance, they're just a walk-in
+        let deal = buyer_deal(&walk_in, &HandOutcome::Safe, &played);
+        assert_eq!(data.record_buyer_deal(&deal, &[], 0.9), None);
+        // Only a Safe deal brings them back
+        let folded = buyer_deal(&walk_in, &HandOutcome::Folded, &played);
+        assert_eq!(data.record_buyer_deal(&folded, &[], 0.0), None);
+        assert!(data.regulars.is_empty());
+
+        assert_eq!(
+            data.record_buyer_deal(&deal, &[], 0.0),
+            Some(RegularEvent::BecameRegular { name: "Chad".to_string() })
+        );
+        let chad = &data.regulars[0];
+        assert_eq!(chad.persona, "Test Buyer");
+        assert_eq!(chad.area, "trailer_park");
+        assert_eq!(chad.relationship, REGULAR_START_RELATIONSHIP);
+        assert_eq!(chad.favourite_product.as_deref(), Some("Weed"));
+        assert_eq!(chad.favourite_location.as_deref(), Some("Safe House"));
+
+        // One regular per persona per area
+        assert_eq!(data.record_buyer_deal(&deal, &[], 0.0), None);
+        assert_eq!(data.regulars.len(), 1);
+    }
+
+    #[test]
+    fn test_regular_names_fall_back_and_cap() {
+        use crate::models::hand_state::HandOutcome;
+        use crate::models::test_helpers::create_mock_buyer_persona;
+
+        let mut data = SaveData::new();
+        let mut buyer = create_mock_buyer_persona();
+        buyer.area = "suburbia".to_string();
+        let deal = BuyerDeal { area: "suburbia", ..buyer_deal(&buyer, &HandOutcome::Safe, &[]) };
+        // No authored names: "<Persona> #n"
+        assert_eq!(
+            data.record_buyer_deal(&deal, &[], 0.0),
+            Some(RegularEvent::BecameRegular { name: "Test Buyer #1".to_string() })
+        );
+        assert_eq!(data.regulars[0].favourite_product, None);
+
+        // The street only remembers so many
+        let mut data = SaveData::new();
+        for i in 0..MAX_REGULARS {
+            data.regulars.push(Regular {
+                name: format!("R{i}"),
+                persona: format!("P{i}"),
+                area: "downtown".to_string(),
+                relationship: 50,
+                favourite_product: None,
+                favourite_location: None,
+                deals: 0,
+                referred_by: None,
+                informant: false,
+            });
+        }
+        assert_eq!(data.record_buyer_deal(&deal, &[], 0.0), None);
+        // An informant frees the slot
+        data.regulars[0].informant = true;
+        assert!(data.record_buyer_deal(&deal, &[], 0.0).is_some());
+    }
+
+    #[test]
+    fn test_regular_warms_with_deals_and_favourites() {
+        use crate::models::hand_state::HandOutcome;
+        use crate::models::test_helpers::{create_location, create_mock_buyer_persona, create_product};
+
+        let mut data = SaveData::new();
+        let weed = [create_product("Weed", 30, 5)];
+        let walk_in = create_mock_buyer_persona();
+        data.record_buyer_deal(&buyer_deal(&walk_in, &HandOutcome::Safe, &weed), &[], 0.0);
+        let name = data.regulars[0].name.clone();
+        let chad = as_regular(&data, &name);
+
+        // Favourite product on the table: +10 +5
+        data.record_buyer_deal(&buyer_deal(&chad, &HandOutcome::Safe, &weed), &[], 0.9);
+        assert_eq!(data.regulars[0].relationship, 65);
+        // Something else: +10
+        let coke = [create_product("Coke", 60, 10), create_location("Alley", 5, 0, 0)];
+        data.record_buyer_deal(&buyer_deal(&chad, &HandOutcome::Safe, &coke), &[], 0.9);
+        assert_eq!(data.regulars[0].relationship, 75);
+        assert_eq!(data.regulars[0].deals, 2);
+
+        // Capped at the max
+        for _ in 0..5 {
+            data.record_buyer_deal(&buyer_deal(&chad, &HandOutcome::Safe, &weed), &[], 0.9);
+        }
+        assert_eq!(data.regulars[0].relationship, REGULAR_RELATIONSHIP_MAX);
+        assert_eq!(data.regulars[0].loyalty_factor(), 1.2);
+    }
+
+    #[test]
+    fn test_loyal_regular_refers_a_friend() {
+        use crate::models::hand_state::HandOutcome;
+        use crate::models::test_helpers::create_mock_buyer_persona;
+
+        let mut data = SaveData::new();
+        let walk_in = create_mock_buyer_persona();
+        data.record_buyer_deal(&buyer_deal(&walk_in, &HandOutcome::Safe, &[]), &[], 0.0);
+        data.regulars[0].relationship = 70;
+        let name = data.regulars[0].name.clone();
+        let chad = as_regular(&data, &name);
+
+        let mut friend = create_mock_buyer_persona();
+        friend.display_name = "Desperate Housewife".to_string();
+        let mut elsewhere = create_mock_buyer_persona();
+        elsewhere.display_name = "Wall Street Wolf".to_string();
+        elsewhere.area = "suburbia".to_string();
+        let personas = [walk_in.clone(), elsewhere, friend];
+
+        // Loyal now (70 -> 80), but a high roll: no referral
+        let deal = buyer_deal(&chad, &HandOutcome::Safe, &[]);
+        assert_eq!(data.record_buyer_deal(&deal, &personas, 0.5), None);
+        // Loyal and lucky: the one persona of the area not yet a regular
+        assert_eq!(
+            data.record_buyer_deal(&deal, &personas, 0.1),
+            Some(RegularEvent::Referred {
+                by: name.clone(),
+                name: "Desperate Housewife #1".to_string()
+            })
+        );
+        let referred = &data.regulars[1];
+        assert_eq!(referred.referred_by.as_deref(), Some(name.as_str()));
+        assert_eq!(referred.relationship, 60);
+        assert_eq!(referred.area, "trailer_park");
+
+        // Everyone in the area is a regular: nobody left to send
+        assert_eq!(data.record_buyer_deal(&deal, &personas, 0.1), None);
+    }
+
+    #[test]
+    fn test_burned_regular_turns_informant() {
+        use crate::models::hand_state::HandOutcome;
+        use crate::models::test_helpers::create_mock_buyer_persona;
+
+        let mut data = SaveData::new();
+        let walk_in = create_mock_buyer_persona();
+        data.record_buyer_deal(&buyer_deal(&walk_in, &HandOutcome::Safe, &[]), &[], 0.0);
+        let name = data.regulars[0].name.clone();
+        let chad = as_regular(&data, &name);
+        data.dealers[0].character.heat = 10;
+
+        // 50 -15 (fold) -5 (walked out) = 30: still above the line
+        data.record_buyer_deal(&buyer_deal(&chad, &HandOutcome::Folded, &[]), &[], 0.0);
+        data.record_buyer_deal(&buyer_deal(&chad, &HandOutcome::BuyerBailed, &[]), &[], 0.0);
+        assert_eq!(data.regulars[0].relationship, 30);
+        assert!(data.regulars[0].is_active());
+
+        // Busting with them in the room: 30 - 30 = 0, and a low roll talks
+        let bust = buyer_deal(&chad, &HandOutcome::Busted, &[]);
+        assert_eq!(
+            data.record_buyer_deal(&bust, &[], 0.2),
+            Some(RegularEvent::TurnedInformant {
+                name: name.clone(),
+                dealer: data.dealers[0].name.clone(),
+                heat: REGULAR_INFORMANT_HEAT,
+            })
+        );
+        assert!(data.regulars[0].informant);
+        assert_eq!(data.dealers[0].character.heat, 10 + REGULAR_INFORMANT_HEAT);
+        // Gone from rotation - but still on the record
+        assert_eq!(data.regulars_in("trailer_park").count(), 0);
+        assert_eq!(data.regulars.len(), 1);
+        // An informant never comes back to be burned twice
+        assert_eq!(data.record_buyer_deal(&bust, &[], 0.0), None);
+        assert_eq!(data.dealers[0].character.heat, 10 + REGULAR_INFORMANT_HEAT);
+    }
+
+    #[test]
+    fn test_regulars_survive_roundtrip_and_die_with_the_empire() {
+        let mut data = SaveData::new();
+        data.regulars.push(Regular {
+            name: "Chad".to_string(),
+            persona: "Frat Bro".to_string(),
+            area: "trailer_park".to_string(),
+            relationship: 80,
+            favourite_product: Some("Weed".to_string()),
+            favourite_location: None,
+            deals: 4,
+            referred_by: Some("Tyler".to_string()),
+            informant: false,
+        });
+        let bytes = bincode::serialize(&data).unwrap();
+        let back: SaveData = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.regulars, data.regulars);
+
+        data.reset_empire();
+        assert!(data.regulars.is_empty(), "nobody knows the next kingpin");
+    }
+
+    // ---- SOW-056: mod packs ----
+
+    fn stamp(id: &str, version: &str) -> ModStamp {
+        ModStamp { id: id.to_string(), version: version.to_string() }
+    }
+
+    #[test]
+    fn save_notices_when_its_mods_change() {
+        let mut data = SaveData::new();
+        assert!(!data.sync_mods(&[]), "no packs then, none now");
+        let night = [stamp("night", "1.0")];
+        assert!(data.sync_mods(&night));
+        assert!(!data.sync_mods(&night));
+
+        data.reset_empire();
+        assert_eq!(data.mods, night, "the install outlives the empire");
+
+        let changes = mod_changes(&data.mods, &[stamp("night", "1.1"), stamp("docks", "0.3")]);
+        assert_eq!(changes, ["updated night 1.0 -> 1.1", "added docks 0.3"]);
+        assert_eq!(mod_changes(&night, &[]), ["removed night 1.0"]);
+    }
+
+    #[test]
+    fn mods_save_version_is_at_least_nineteen() {
+        // v19 added SaveData.mods
+        const { assert!(SAVE_VERSION >= 19) };
+        assert!(SaveData::new().mods.is_empty());
+    }
+
+    // ---- SOW-057: card evolutions ----
+
+    fn evolving_alibi() -> (crate::models::card::Card, crate::models::card::Card) {
+        use crate::models::test_helpers::create_cover;
+        let mut alibi = create_cover("Alibi", 20, 0);
+        alibi.id = "alibi".to_string();
+        let mut ironclad = create_cover("Ironclad Alibi", 40, -5);
+        ironclad.id = "ironclad".to_string();
+        alibi.evolutions = vec![crate::models::card::CardEvolution {
+            into: "ironclad".to_string(),
+            tier: UpgradeTier::Tier3,
+            path: UpgradeableStat::Cover,
+        }];
+        (alibi, ironclad)
+    }
+
+    /// Plays `card` until its next upgrade is pending, upgrading `path` at
+    /// each tier on the way
+    fn play_to_tier(state: &mut CharacterState, card: &crate::models::card::Card, path: &[UpgradeableStat]) {
+        for &stat in path {
+            state.add_card_upgrade(&card.name, stat);
+        }
+        let plays = [3, 8, 15, 25, 40][path.len()];
+        state.card_play_counts.insert(card.name.clone(), plays);
+        assert!(state.queue_pending_upgrade(&card.name, &card.card_type));
+    }
+
+    #[test]
+    fn evolutions_save_version_is_at_least_twenty() {
+        // v20 added PendingUpgrade.evolution and CharacterState.evolved_cards
+        const { assert!(SAVE_VERSION >= 20) };
+        let (alibi, _) = evolving_alibi();
+        let mut state = CharacterState::new();
+        assert!(state.evolved_cards.is_empty());
+        play_to_tier(&mut state, &alibi, &[UpgradeableStat::Cover]);
+        assert_eq!(state.pending_upgrades[0].evolution, None, "nothing offered until it qualifies");
+    }
+
+    #[test]
+    fn upgrade_path_is_the_most_chosen_stat() {
+        let mut upgrades = CardUpgrades::new();
+        assert!(!upgrades.favours(UpgradeableStat::Cover));
+        upgrades.add_upgrade(UpgradeableStat::Cover);
+        upgrades.add_upgrade(UpgradeableStat::Heat);
+        assert!(upgrades.favours(UpgradeableStat::Cover), "ties count");
+        upgrades.add_upgrade(UpgradeableStat::Heat);
+        assert!(!upgrades.favours(UpgradeableStat::Cover));
+        assert!(upgrades.favours(UpgradeableStat::Heat));
+    }
+
+    #[test]
+    fn evolution_is_offered_at_its_tier_along_its_path() {
+        let (alibi, ironclad) = evolving_alibi();
+        let variant = |id: &str| (id == "ironclad").then_some(&ironclad);
+
+        // Tier2 is too early
+        let mut early = CharacterState::new();
+        play_to_tier(&mut early, &alibi, &[UpgradeableStat::Cover]);
+        assert!(!early.offer_evolution(&alibi, variant));
+
+        // Tier3, but mostly Heat
+        let mut heat = CharacterState::new();
+        play_to_tier(&mut heat, &alibi, &[UpgradeableStat::Heat, UpgradeableStat::Heat]);
+        assert!(!heat.offer_evolution(&alibi, variant));
+
+        let mut cover = CharacterState::new();
+        play_to_tier(&mut cover, &alibi, &[UpgradeableStat::Cover, UpgradeableStat::Heat]);
+        assert!(cover.offer_evolution(&alibi, variant));
+        let offer = cover.pending_upgrades[0].evolution.clone().unwrap();
+        assert_eq!((offer.from.as_str(), offer.into_name.as_str()), ("alibi", "Ironclad Alibi"));
+    }
+
+    #[test]
+    fn evolving_keeps_the_cards_record_under_its_new_name() {
+        let (alibi, ironclad) = evolving_alibi();
+        let mut state = CharacterState::new();
+        play_to_tier(&mut state, &alibi, &[UpgradeableStat::Cover, UpgradeableStat::Cover]);
+        assert!(!state.apply_evolution_for("Alibi"), "nothing offered yet");
+        state.offer_evolution(&alibi, |_| Some(&ironclad));
+
+        assert!(state.apply_evolution_for("Alibi"));
+        assert!(!state.has_pending_upgrades());
+        assert_eq!(state.evolved_cards["alibi"], "ironclad");
+        assert_eq!(state.get_play_count("Ironclad Alibi"), 15);
+        assert_eq!(state.card_upgrades["Ironclad Alibi"].count_stat(UpgradeableStat::Cover), 3);
+        assert_eq!(state.check_pending_upgrade("Alibi"), None, "the tier is spent");
+
+        // Evolving the variant again re-points the original
+        state.pending_upgrades.push(PendingUpgrade {
+            card_name: "Ironclad Alibi".to_string(),
+            card_type: ironclad.card_type.clone(),
+            tier: UpgradeTier::Tier4,
+            options: [UpgradeableStat::Cover.into(), UpgradeableStat::Heat.into()],
+            evolution: Some(EvolutionOffer {
+                from: "ironclad".to_string(),
+                into: "bulletproof".to_string(),
+                into_name: "Bulletproof Alibi".to_string(),
+                path: UpgradeableStat::Cover,
+            }),
+        });
+        assert!(state.apply_evolution_for("Ironclad Alibi"));
+        assert_eq!(state.evolved_cards["alibi"], "bulletproof");
+    }
 }
@@ -8,11 +8,20 @@
 use bevy::prelude::*;
 
 use crate::assets::GameAssets;
+use crate::locale::tr;
+use crate::models::shop_location::ZoneGraph;
 use crate::save::SaveData;
 use crate::ui::components::*;
 use crate::ui::map_view::{self, AreaDealerOfferState, MoveEligibility, SignatureStatus, ZoneStatus};
 use crate::ui::theme;
 
+/// SOW-043: the node canvas in design-space px (the 1920x1080 overlay less
+/// its padding and header). map_view::layout_zones places everything on it.
+const MAP_CANVAS: (f32, f32) = (1872.0, 940.0);
+
+/// SOW-043: thickness of a drawn road
+const MAP_ROAD_WIDTH: f32 = 6.0;
+
 /// Map overlay state. Selection arms the move flow: pick a dealer chip,
 /// then a destination node's SEND button commits (via RosterMoveButton).
 #[derive(Resource, Default)]
@@ -67,7 +76,7 @@ pub fn spawn_map_overlay(parent: &mut ChildSpawnerCommands) {
                         })
                         .with_children(|title| {
                             title.spawn((
-                                Text::new("THE CITY"),
+                                Text::new(tr("map.the_city")),
                                 TextFont::from_font_size(28.0),
                                 TextColor(theme::TEXT_HEADER),
                             ));
@@ -95,21 +104,20 @@ pub fn spawn_map_overlay(parent: &mut ChildSpawnerCommands) {
                         ))
                         .with_children(|btn| {
                             btn.spawn((
-                                Text::new("CLOSE"),
+                                Text::new(tr("map.close")),
                                 TextFont::from_font_size(16.0),
                                 TextColor(Color::WHITE),
                             ));
                         });
                 });
 
-            // The three zone nodes (children rebuilt by populate system)
+            // The zone canvas (children rebuilt by populate system).
+            // SOW-043: nodes, roads and dealers on the road are absolutely
+            // placed from map_view::layout_zones - any number of zones.
             overlay.spawn((
                 Node {
-                    flex_grow: 1.0,
-                    flex_direction: FlexDirection::Row,
-                    column_gap: Val::Px(30.0),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
+                    width: Val::Px(MAP_CANVAS.0),
+                    height: Val::Px(MAP_CANVAS.1),
                     ..default()
                 },
                 MapNodesRow,
@@ -208,7 +216,7 @@ pub fn populate_map_nodes_system(
     let selected = map_state.selected_dealer;
 
     if let Ok(mut hint) = hint_query.single_mut() {
-        let line = map_view::map_hint(&save_data, selected);
+        let line = map_view::map_hint(&save_data, &game_assets.zone_graph, selected);
         if **hint != line {
             **hint = line;
         }
@@ -220,19 +228,128 @@ pub fn populate_map_nodes_system(
         }
     }
 
+    let areas = &game_assets.shop_locations;
+    let graph = &game_assets.zone_graph;
+    let layout = map_view::layout_zones(areas, graph, MAP_CANVAS);
+
     commands.entity(row).with_children(|parent| {
-        for area in &game_assets.shop_locations {
+        // SOW-043: roads first so the node cards sit on top of them
+        for road in &layout.roads {
+            spawn_road(parent, road);
+        }
+        for (area, placement) in areas.iter().zip(&layout.nodes) {
             let node = map_view::zone_node_view(
                 area,
                 &save_data,
                 &game_assets.buyers,
                 game_assets.products.values(),
             );
-            spawn_zone_node(parent, &node, &save_data, selected);
+            spawn_zone_node(parent, &node, placement, layout.card_size, &save_data, graph, selected);
+        }
+        // Labels and dealers on the road stay readable over the cards
+        for road in &layout.roads {
+            spawn_road_label(parent, road);
+        }
+        for chip in map_view::transit_chips(&save_data, graph, &layout, areas) {
+            spawn_transit_chip(parent, &chip);
         }
     });
 }
 
+/// SOW-043: a road between two node centres - a thin bar rotated into place
+fn spawn_road(parent: &mut ChildSpawnerCommands, road: &map_view::RoadView) {
+    let (mid_x, mid_y) = road.midpoint();
+    let length = road.length();
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(mid_x - length / 2.0),
+            top: Val::Px(mid_y - MAP_ROAD_WIDTH / 2.0),
+            width: Val::Px(length),
+            height: Val::Px(MAP_ROAD_WIDTH),
+            ..default()
+        },
+        UiTransform::from_rotation(Rot2::radians(road.angle())),
+        BackgroundColor(theme::MAP_ROAD),
+    ));
+}
+
+/// SOW-043: "2 RUNS · $400" at a road's midpoint
+fn spawn_road_label(parent: &mut ChildSpawnerCommands, road: &map_view::RoadView) {
+    let (mid_x, mid_y) = road.midpoint();
+    parent
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            // Centred on the midpoint: a zero-size anchor, the label
+            // overflowing it both ways
+            left: Val::Px(mid_x),
+            top: Val::Px(mid_y),
+            width: Val::Px(0.0),
+            height: Val::Px(0.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|anchor| {
+            anchor
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                        border_radius: BorderRadius::all(Val::Px(4.0)),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    BackgroundColor(theme::MAP_ROAD_LABEL_BG),
+                ))
+                .with_children(|label| {
+                    label.spawn((
+                        Text::new(road.label.as_str()),
+                        TextFont::from_font_size(12.0),
+                        TextColor(theme::V2_LABEL),
+                        TextLayout::new_with_no_wrap(),
+                    ));
+                });
+        });
+}
+
+/// SOW-043: a dealer mid-move, drawn where they are on the road
+fn spawn_transit_chip(parent: &mut ChildSpawnerCommands, chip: &map_view::TransitChip) {
+    let (x, y) = chip.at;
+    parent
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(x),
+            top: Val::Px(y),
+            width: Val::Px(0.0),
+            height: Val::Px(0.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|anchor| {
+            anchor
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    BackgroundColor(theme::ROSTER_CARD_BG_JAILED),
+                    BorderColor::all(theme::ROSTER_MOVE_BG),
+                ))
+                .with_children(|body| {
+                    body.spawn((
+                        Text::new(chip.label.as_str()),
+                        TextFont::from_font_size(13.0),
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_no_wrap(),
+                    ));
+                });
+        });
+}
+
 /// Text color, dimmed on locked nodes (the aspiration is visible, grayed)
 fn ink(color: Color, locked: bool) -> Color {
     if locked { color.with_alpha(0.45) } else { color }
@@ -241,7 +358,10 @@ fn ink(color: Color, locked: bool) -> Color {
 fn spawn_zone_node(
     parent: &mut ChildSpawnerCommands,
     node: &map_view::ZoneNodeView,
+    placement: &map_view::NodePlacement,
+    (card_w, card_h): (f32, f32),
     save_data: &SaveData,
+    graph: &ZoneGraph,
     selected: Option<usize>,
 ) {
     let locked = !matches!(node.status, ZoneStatus::Unlocked);
@@ -249,8 +369,14 @@ fn spawn_zone_node(
     parent
         .spawn((
             Node {
-                width: Val::Px(480.0),
-                height: Val::Px(680.0),
+                // SOW-043: placed by layout_zones; a crowded map shrinks
+                // the cards, so content past the bottom is clipped
+                position_type: PositionType::Absolute,
+                left: Val::Px(placement.left),
+                top: Val::Px(placement.top),
+                width: Val::Px(card_w),
+                height: Val::Px(card_h),
+                overflow: Overflow::clip(),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(20.0)),
                 row_gap: Val::Px(10.0),
@@ -318,7 +444,7 @@ fn spawn_zone_node(
 
             // Products
             card.spawn((
-                Text::new("PRODUCTS"),
+                Text::new(tr("map.products")),
                 TextFont::from_font_size(12.0),
                 TextColor(ink(theme::BUYER_BUBBLE_LABEL, locked)),
                 Node { margin: UiRect::top(Val::Px(6.0)), ..default() },
@@ -332,14 +458,14 @@ fn spawn_zone_node(
             // Dealers (unlocked zones only - stations are unlocked areas)
             if !locked {
                 card.spawn((
-                    Text::new("DEALERS"),
+                    Text::new(tr("map.dealers")),
                     TextFont::from_font_size(12.0),
                     TextColor(theme::BUYER_BUBBLE_LABEL),
                     Node { margin: UiRect::top(Val::Px(6.0)), ..default() },
                 ));
                 if node.dealers.is_empty() {
                     card.spawn((
-                        Text::new("nobody stationed here"),
+                        Text::new(tr("map.nobody_stationed_here")),
                         TextFont::from_font_size(13.0),
                         TextColor(theme::V2_LABEL),
                     ));
@@ -408,7 +534,7 @@ fn spawn_zone_node(
                     // selected; otherwise the resting state offers the zone's
                     // signature-dealer hire (SOW-036).
                     if let Some(dealer_index) = selected {
-                        spawn_send_action(card, save_data, dealer_index, node);
+                        spawn_send_action(card, save_data, graph, dealer_index, node);
                     } else {
                         spawn_signature_action(card, node);
                         // SOW-038: the zone's cred-gated unlockable dealers, below
@@ -496,6 +622,7 @@ fn spawn_dealer_chip(
 fn spawn_send_action(
     card: &mut ChildSpawnerCommands,
     save_data: &SaveData,
+    graph: &ZoneGraph,
     dealer_index: usize,
     node: &map_view::ZoneNodeView,
 ) {
@@ -505,7 +632,7 @@ fn spawn_send_action(
         .map(|d| d.name.to_uppercase())
         .unwrap_or_default();
 
-    match map_view::move_eligibility(save_data, dealer_index, &node.area_id) {
+    match map_view::move_eligibility(save_data, graph, dealer_index, &node.area_id) {
         MoveEligibility::StationedHere => {
             card.spawn((
                 Text::new(format!("{dealer_name} IS STATIONED HERE")),
@@ -515,7 +642,16 @@ fn spawn_send_action(
             ));
         }
         MoveEligibility::DealerUnavailable => {} // stale selection settles next rebuild
-        eligibility @ (MoveEligibility::Eligible { fee } | MoveEligibility::CantAfford { fee }) => {
+        MoveEligibility::NoRoute => {
+            card.spawn((
+                Text::new(tr("map.no_road")),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::V2_LABEL),
+                Node { align_self: AlignSelf::Center, ..default() },
+            ));
+        }
+        eligibility @ (MoveEligibility::Eligible { fee, runs }
+        | MoveEligibility::CantAfford { fee, runs }) => {
             let affordable = matches!(eligibility, MoveEligibility::Eligible { .. });
             card.spawn((
                 Button,
@@ -540,7 +676,10 @@ fn spawn_send_action(
             ))
             .with_children(|btn| {
                 btn.spawn((
-                    Text::new(format!("SEND {dealer_name} HERE · ${fee} · 1 RUN OUT")),
+                    Text::new(format!(
+                        "SEND {dealer_name} HERE · ${fee} · {}",
+                        map_view::runs_out(runs)
+                    )),
                     TextFont::from_font_size(15.0),
                     TextColor(Color::WHITE),
                 ));
@@ -0,0 +1,513 @@
+// SOW-042: Day planning - while the active dealer plays their session in
+// person, every other available dealer carries out the order they were
+// given on the roster (RUN / SCOUT / REST / LAY LOW / MOVE). The day
+// resolves at GO HOME: run_day auto-plays RUN orders through
+// hand_state::autoplay and banks them through the same SaveData paths as
+// the played hand; everything else is SaveData::carry_out_order. The hub
+// then shows what happened in a dismissable summary overlay.
+//
+// Presentation (order cycle, labels, summary lines) lives in ui::day_view.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::locale::tr;
+use crate::data::{buyer_for_session, create_buyer_personas};
+use crate::models::deck_builder::DeckBuilder;
+use crate::models::hand_state::autoplay::auto_play_session;
+use crate::models::hand_state::{HandState, KingpinFall};
+use crate::models::narrative::StoryComposer;
+use crate::save::{DealerOrder, OrderOutcome, RunPolicy, SaveData, SaveManager};
+use crate::ui::components::*;
+use crate::ui::day_view;
+use crate::ui::theme;
+
+/// One line of the day, recorded as it resolves
+#[derive(Debug, Clone, PartialEq)]
+pub enum DayEntry {
+    /// An off-screen session (profit is what the Safe hands banked)
+    Ran {
+        dealer: String,
+        area: String,
+        hands: usize,
+        deals: usize,
+        profit: u32,
+        jailed_for: Option<u32>,
+    },
+    /// A RUN order that never left the house (SOW-053: the reason is a
+    /// locale id - the view tr()s it)
+    StayedHome { dealer: String, reason: &'static str },
+    /// A non-run order carried out (or refused)
+    Order { dealer: String, outcome: OrderOutcome },
+    /// Dealers whose jail time / downtime ran out at today's tick
+    BackInAction { dealers: Vec<String> },
+}
+
+/// The day that just ended. Inserted at GO HOME when anything happened;
+/// DISMISS removes it.
+#[derive(Resource, Debug, Default)]
+pub struct DaySummary {
+    pub entries: Vec<DayEntry>,
+}
+
+/// GO HOME's half of the day, after the night's session is closed: the
+/// runner keeps the deck they played, sentences and fronts tick, and the
+/// crew who were free at dawn carry out their orders. SOW-063: after a
+/// kingpin bust the runner is gone - nobody's deck is overwritten and
+/// everyone left ticks. An heir's empire still has its day; a fallen one
+/// has nobody left to run.
+pub fn end_the_day(
+    save: &mut SaveData,
+    played_ids: &[String],
+    fall: Option<KingpinFall>,
+    assets: &GameAssets,
+    composer: &StoryComposer,
+) -> Vec<DayEntry> {
+    // SOW-041: the deck the runner just played is THEIR deck now (burned
+    // insurance stays burned) - other dealers' decks are untouched
+    let runner = fall.is_none().then_some(save.active_dealer);
+    if runner.is_some() {
+        save.active_dealer_state_mut().set_deck(played_ids.to_vec());
+    }
+
+    // The day's crew is whoever was free when it started - dealers the
+    // tick releases below are back in action TOMORROW
+    let crew: Vec<usize> = save
+        .dealers
+        .iter()
+        .enumerate()
+        .filter(|(i, d)| Some(*i) != runner && d.is_available())
+        .map(|(i, _)| i)
+        .collect();
+    // RFC-023/SOW-025: a completed run anywhere in the empire serves a unit
+    // of every OTHER dealer's jail sentence or relocation
+    let now_available = save.complete_run_tick(runner);
+    if !now_available.is_empty() {
+        info!("Back in action: {}", now_available.join(", "));
+    }
+
+    // SOW-031: fronts tick at the same choke - the runner's own run
+    // INCLUDED (an unproductive run still spends a tick; that's the
+    // run-quality pressure the mechanic exists for)
+    for event in save.tick_fronts() {
+        match event {
+            crate::save::FrontEvent::CutOff { area_id } => {
+                info!("Front overdue in {area_id}: supplier cut you off - one more window");
+            }
+            crate::save::FrontEvent::MuscleSeized { area_id, amount } => {
+                info!("Muscle visited over the {area_id} front: seized ${amount}");
+            }
+            crate::save::FrontEvent::MuscleBenched { area_id, dealer } => {
+                info!("Muscle visited over the {area_id} front: {dealer} took a beating (benched 1 run)");
+            }
+            crate::save::FrontEvent::Soured { area_id, card_id } => {
+                info!("Supplier in {area_id} soured: {card_id} repossessed, no more fronts there");
+            }
+        }
+    }
+
+    if fall == Some(KingpinFall::EmpireFell) {
+        return Vec::new();
+    }
+    let mut entries = run_day(save, &crew, assets, composer);
+    if !now_available.is_empty() {
+        entries.push(DayEntry::BackInAction { dealers: now_available });
+    }
+    entries
+}
+
+/// Resolve the day's orders for `crew` - the dealers who were free when the
+/// day started (the runner and anyone jailed/benched are never in it).
+/// Dealers benched mid-day (a front's muscle) sit their orders out.
+pub fn run_day(
+    save: &mut SaveData,
+    crew: &[usize],
+    assets: &GameAssets,
+    composer: &StoryComposer,
+) -> Vec<DayEntry> {
+    let mut entries = Vec::new();
+    for &idx in crew {
+        let Some(dealer) = save.dealers.get(idx) else {
+            continue;
+        };
+        if !dealer.is_available() {
+            continue;
+        }
+        let name = dealer.name.clone();
+        if let DealerOrder::Run(policy) = dealer.order {
+            entries.push(run_off_screen(save, idx, policy, assets, composer));
+        } else if let Some(outcome) = save.carry_out_order(idx, &assets.zone_graph) {
+            entries.push(DayEntry::Order { dealer: name, outcome });
+        }
+    }
+    entries
+}
+
+/// Play one dealer's session at their station with their own deck, heat
+/// tier and upgrade record, then bank it: Safe hands through
+/// bank_safe_hand, the session through close_session - or jail_for_bust.
+fn run_off_screen(
+    save: &mut SaveData,
+    idx: usize,
+    policy: RunPolicy,
+    assets: &GameAssets,
+    composer: &StoryComposer,
+) -> DayEntry {
+    let dealer = &save.dealers[idx];
+    let name = dealer.name.clone();
+    let area = dealer.station.clone();
+    let stay_home = |reason| DayEntry::StayedHome { dealer: name.clone(), reason };
+
+    if dealer.is_kingpin {
+        return stay_home("day.reason_kingpin");
+    }
+    if !save.account.unlocked_locations.contains(&area) {
+        return stay_home("day.reason_station_closed");
+    }
+    let builder = DeckBuilder::for_dealer(
+        assets,
+        &save.account.unlocked_cards,
+        &dealer.deck,
+        &dealer.character.evolved_cards,
+    );
+    if !builder.is_valid() {
+        return stay_home("day.reason_deck");
+    }
+    let Some(mut buyer) = buyer_for_session(&create_buyer_personas(assets), &save.regulars, &area) else {
+        return stay_home("day.reason_no_buyers");
+    };
+
+    let mut hand = HandState::with_custom_deck(
+        builder.selected_cards,
+        assets,
+        save.ascension.narc_tier(dealer.character.heat_tier()), // SOW-065
+        &area,
+    );
+    save.ascension.apply_to_buyer(&mut buyer);
+    hand.buyer_persona = Some(buyer.clone());
+    hand.card_play_counts = dealer.character.card_play_counts.clone();
+    hand.card_upgrades = dealer.character.card_upgrades.clone();
+    hand.relics = save.relic_effects(); // SOW-059
+
+    let session = auto_play_session(hand, policy, &mut save.account, composer);
+    for played in &session.hands {
+        if played.outcome == crate::HandOutcome::Safe {
+            save.bank_safe_hand(idx, &area, played.profit, &played.cards_played);
+        }
+        // SOW-044: off-screen deals make (and burn) regulars too
+        let deal = crate::save::BuyerDeal {
+            dealer_idx: idx,
+            area: &area,
+            buyer: &buyer,
+            outcome: &played.outcome,
+            profit: played.profit,
+            played: &played.cards_played,
+        };
+        if let Some(event) = save.record_buyer_deal(&deal, &assets.buyers, rand::random()) {
+            info!("Regulars: {:?}", event);
+        }
+    }
+    let jailed_for = if session.busted() {
+        Some(save.jail_for_bust(idx, session.session_heat))
+    } else {
+        // SOW-048: the summary closes out the session's stories, as on GO HOME
+        let mut stories = session.stories.clone();
+        stories.extend(session.summary.clone());
+        save.close_session(idx, session.session_heat, &stories, session.finished_safe());
+        None
+    };
+    info!(
+        "{} ran {} off-screen ({}): {} hand(s), ${}{}",
+        name,
+        area,
+        policy.name(),
+        session.hands.len(),
+        session.profit(),
+        if jailed_for.is_some() { " - BUSTED" } else { "" }
+    );
+
+    DayEntry::Ran {
+        dealer: name,
+        area,
+        hands: session.hands.len(),
+        deals: session.deals_closed(),
+        profit: session.profit(),
+        jailed_for,
+    }
+}
+
+/// ORDER on a roster card cycles that dealer's order (day_view::next_order)
+pub fn order_button_system(
+    interaction_query: Query<(&Interaction, &RosterOrderButton), Changed<Interaction>>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<SaveManager>>,
+    game_assets: Res<GameAssets>,
+) {
+    let (Some(mut save_data), Some(save_manager)) = (save_data, save_manager) else {
+        return;
+    };
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(dealer) = save_data.dealers.get(button.dealer_index) else {
+            continue;
+        };
+        let move_to = day_view::move_target(&game_assets.shop_locations, &save_data, dealer)
+            .map(|area| area.id.clone());
+        let next = day_view::next_order(&dealer.order, dealer.is_kingpin, move_to.as_deref());
+        match save_data.set_order(button.dealer_index, next) {
+            Ok(()) => {
+                if let Err(e) = save_manager.save(&save_data) {
+                    warn!("Failed to save after order change: {:?}", e);
+                }
+            }
+            Err(reason) => warn!("Order refused: {reason}"),
+        }
+    }
+}
+
+/// Spawn the (hidden) day summary under DeckBuilderRoot - same inheritance
+/// as the ledger overlay; populate_day_summary_system shows it.
+pub fn spawn_day_summary_overlay(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(theme::DAY_SUMMARY_SCRIM),
+            // SOW-029 lesson: Block so clicks never reach the hub beneath
+            bevy::ui::FocusPolicy::Block,
+            GlobalZIndex(93),
+            DaySummaryOverlay,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(620.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(12.0),
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        border_radius: BorderRadius::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme::LEDGER_CANVAS_BG),
+                    BorderColor::all(theme::ROSTER_CARD_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(tr("day.end_of_day")),
+                        TextFont::from_font_size(26.0),
+                        TextColor(theme::TEXT_HEADER),
+                    ));
+
+                    // Take + one line per entry, rebuilt by populate
+                    panel.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                        DaySummaryBody,
+                    ));
+
+                    panel
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(140.0),
+                                height: Val::Px(44.0),
+                                align_self: AlignSelf::End,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border_radius: BorderRadius::all(Val::Px(6.0)),
+                                ..default()
+                            },
+                            BackgroundColor(theme::BUTTON_NEUTRAL_BG),
+                            DaySummaryDismissButton,
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new(tr("day.dismiss")),
+                                TextFont::from_font_size(16.0),
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                });
+        });
+}
+
+/// Show the overlay while a DaySummary is pending, rebuilding its body when
+/// the summary changes (or the hub was just rebuilt around it)
+pub fn populate_day_summary_system(
+    mut commands: Commands,
+    summary: Option<Res<DaySummary>>,
+    mut overlay_query: Query<&mut Node, With<DaySummaryOverlay>>,
+    body_query: Query<Entity, With<DaySummaryBody>>,
+    children_query: Query<&Children>,
+    game_assets: Res<GameAssets>,
+) {
+    let Ok(mut overlay) = overlay_query.single_mut() else {
+        return; // overlay only exists on the deck-builder screen
+    };
+    let display = if summary.is_some() { Display::Flex } else { Display::None };
+    if overlay.display != display {
+        overlay.display = display;
+    }
+    let (Some(summary), Ok(body)) = (summary, body_query.single()) else {
+        return;
+    };
+
+    let is_empty = children_query.get(body).map(|c| c.is_empty()).unwrap_or(true);
+    if !summary.is_changed() && !is_empty {
+        return;
+    }
+    if let Ok(children) = children_query.get(body) {
+        for child in children.iter() {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let take = day_view::day_take(&summary.entries);
+    let lines = day_view::summary_lines(&summary.entries, &game_assets.shop_locations);
+    commands.entity(body).with_children(|parent| {
+        parent.spawn((
+            Text::new(day_view::crew_take_line(take)),
+            TextFont::from_font_size(16.0),
+            TextColor(theme::ROSTER_STATUS_READY),
+        ));
+        for line in lines {
+            parent.spawn((
+                Text::new(line),
+                TextFont::from_font_size(14.0),
+                TextColor(theme::LEDGER_STORY_TEXT),
+            ));
+        }
+    });
+}
+
+/// DISMISS clears the summary (the overlay hides on the next populate)
+pub fn day_summary_dismiss_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<DaySummaryDismissButton>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.remove_resource::<DaySummary>();
+        }
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::narrative::{DynamicPattern, NarrativeFragments};
+    use crate::models::test_helpers::create_mock_game_assets;
+    use crate::save::DealerState;
+
+    fn crew_save() -> SaveData {
+        let mut save = SaveData::new();
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Slim", "Slim"));
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Dee", "Dee"));
+        save
+    }
+
+    #[test]
+    fn test_run_day_resolves_each_order() {
+        let assets = create_mock_game_assets();
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        let mut save = crew_save();
+        save.dealers[0].character.heat = 8;
+        save.set_order(0, DealerOrder::Rest).unwrap();
+        save.set_order(1, DealerOrder::Run(RunPolicy::Cautious)).unwrap();
+        save.set_order(2, DealerOrder::Scout).unwrap();
+
+        let entries = run_day(&mut save, &[0, 1, 2], &assets, &composer);
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            DayEntry::Order {
+                dealer: save.dealers[0].name.clone(),
+                outcome: OrderOutcome::Rested { cooled: crate::save::REST_COOLING },
+            }
+        );
+        match &entries[1] {
+            DayEntry::Ran { dealer, area, hands, jailed_for, .. } => {
+                assert_eq!(dealer, "Slim");
+                assert_eq!(area, "trailer_park");
+                assert!(*hands >= 1 && *hands <= RunPolicy::Cautious.hand_limit());
+                assert_eq!(jailed_for.is_some(), save.dealers[1].jail_remaining().is_some());
+            }
+            other => panic!("expected a run, got {other:?}"),
+        }
+        assert_eq!(save.dealers[2].cred_in("trailer_park"), 1);
+        // Standing orders survive the day
+        assert_eq!(save.dealers[1].order, DealerOrder::Run(RunPolicy::Cautious));
+    }
+
+    #[test]
+    fn test_run_day_skips_dealers_who_are_out() {
+        let assets = create_mock_game_assets();
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        let mut save = crew_save();
+        save.set_order(1, DealerOrder::Scout).unwrap();
+        save.dealers[1].character.heat = 10;
+        save.dealers[1].jail_with(&crate::models::relic::RelicEffects::default());
+
+        // Not in the crew (the runner), or jailed: nothing happens
+        let entries = run_day(&mut save, &[1], &assets, &composer);
+        assert!(entries.is_empty());
+        assert_eq!(save.dealers[1].cred_in("trailer_park"), 0);
+        assert!(run_day(&mut save, &[], &assets, &composer).is_empty());
+    }
+
+    #[test]
+    fn test_go_home_after_a_succession_still_runs_the_day() {
+        use crate::models::relic::RelicEffects;
+        use crate::save::{SUCCESSION_MIN_CASH, SUCCESSION_MIN_CRED, SUCCESSION_MIN_RUNS};
+        let assets = create_mock_game_assets();
+        let composer = StoryComposer::with_patterns(NarrativeFragments::default(), DynamicPattern::shipped_patterns());
+        let mut save = crew_save();
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Rex", "Rex"));
+        save.set_succession(true);
+        save.account.cash_on_hand = SUCCESSION_MIN_CASH;
+        save.dealers[1].character.decks_played = SUCCESSION_MIN_RUNS;
+        save.dealers[1].street_cred.insert("trailer_park".into(), SUCCESSION_MIN_CRED);
+        save.dealers[1].set_deck(vec!["alibi".to_string()]);
+        save.set_order(2, DealerOrder::Scout).unwrap();
+        save.dealers[3].jail_with(&RelicEffects::default());
+        let sentence = save.dealers[3].jail_remaining().unwrap();
+
+        // The kingpin busts at resolution and Slim takes the chair; the
+        // night's deck was the fallen kingpin's
+        assert!(save.succeed());
+        let entries = end_the_day(&mut save, &["weed".to_string()], Some(KingpinFall::Succeeded), &assets, &composer);
+
+        assert_eq!(save.dealers[0].deck, vec!["alibi".to_string()], "the heir keeps their own deck");
+        assert_ne!(save.dealers[2].jail_remaining(), Some(sentence), "the jail clock still ticks");
+        assert_eq!(save.dealers[1].cred_in("trailer_park"), 1, "Dee's scouting happened");
+        assert!(
+            entries.iter().any(|e| matches!(e, DayEntry::Order { dealer, .. } if dealer == "Slim")),
+            "the heir wasn't the runner - they get their day too: {entries:?}"
+        );
+
+        // No heir: the empire fell at resolution, nobody is left to run
+        save.reset_empire();
+        let fresh = save.dealers[0].deck.clone();
+        assert!(end_the_day(&mut save, &["weed".to_string()], Some(KingpinFall::EmpireFell), &assets, &composer).is_empty());
+        assert_eq!(save.dealers[0].deck, fresh);
+    }
+}
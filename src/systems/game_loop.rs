@@ -47,7 +47,7 @@ pub fn auto_flip_system(
             ai_timer.dealer_timer.reset();
             ai_timer.dealer_timer_started = true;
 
-            // SOW-009: Buyer plays random card from visible hand
+            // SOW-009: Buyer plays from visible hand (SOW-045: with intent)
             if let Some(buyer_card) = hand_state.buyer_plays_card() {
                 println!("Buyer plays: {} (starting 1s timer...)", buyer_card.name);
             } else {
@@ -84,10 +84,8 @@ pub fn auto_flip_system(
         let outcome = hand_state.resolve_hand();
         println!("Resolution outcome: {:?}, new state: {:?}", outcome, hand_state.current_state);
 
-        // Generate story after resolution
-        let story = story_composer.compose_story_from_hand(&hand_state);
-        hand_state.hand_story = Some(story.clone());
-        hand_state.session_stories.push(story.clone()); // Add to session history
+        // Generate story after resolution (SOW-048: told as part of the session)
+        let story = story_composer.narrate_hand(&mut hand_state);
         println!("\n📖 Story: {}\n", story);
     }
 }
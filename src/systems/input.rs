@@ -3,13 +3,14 @@
 // Updated for Bevy 0.18
 
 use bevy::prelude::*;
-use rand::prelude::*;
 use crate::{Owner, HandState, HandPhase, HandOutcome, DeckBuilder};
+use crate::models::hand_state::KingpinFall;
 use crate::models::card::{Card, CardType};
 use crate::game_state::GameState;
 use crate::ui::components::*;
 use crate::ui::theme;
 use crate::data::create_buyer_personas;
+use crate::locale::tr;
 
 // ============================================================================
 // SOW-008: BETTING BUTTON SYSTEM
@@ -20,6 +21,7 @@ pub fn betting_button_system(
     fold_query: Query<&Interaction, (Changed<Interaction>, With<FoldButton>)>,
     mut hand_state_query: Query<&mut HandState>,
     story_composer: Res<crate::models::narrative::StoryComposer>,
+    mut save_data: Option<ResMut<crate::save::SaveData>>,
 ) {
     let Ok(mut hand_state) = hand_state_query.single_mut() else {
         return;
@@ -54,14 +56,19 @@ pub fn betting_button_system(
         if *interaction == Interaction::Pressed {
             println!("Player folds during turn!");
 
+            // SOW-058: FoldRefund perks hand their burned charge back
+            if let Some(ref mut save_data) = save_data {
+                for id in hand_state.fold_refunds() {
+                    save_data.account.add_stock(&id, 1);
+                }
+            }
+
             // Set outcome and transition state
             hand_state.outcome = Some(HandOutcome::Folded);
             hand_state.current_state = HandPhase::Bust;
 
-            // Generate story
-            let story = story_composer.compose_story_from_hand(&hand_state);
-            hand_state.hand_story = Some(story.clone());
-            hand_state.session_stories.push(story.clone()); // Add to session history
+            // Generate story (SOW-048: told as part of the session)
+            let story = story_composer.narrate_hand(&mut hand_state);
             println!("\n📖 Story: {}\n", story);
 
             // Discard played cards, keep unplayed
@@ -129,54 +136,14 @@ pub fn update_betting_button_states(
     }
 }
 
-// ============================================================================
-// RESTART BUTTON SYSTEM
-// ============================================================================
-pub fn restart_button_system(
-    restart_query: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
-    mut hand_state_query: Query<&mut HandState>,
-) {
-    let Ok(mut hand_state) = hand_state_query.single_mut() else {
-        return;
-    };
-
-    // Only at Bust state
-    if hand_state.current_state != HandPhase::Bust {
-        return;
-    }
-
-    // NEW DEAL button - only for Safe/Folded outcomes (not Busted)
-    for interaction in restart_query.iter() {
-        if *interaction == Interaction::Pressed {
-            // SOW-005: Can't new deal if busted (game over)
-            if matches!(hand_state.outcome, Some(HandOutcome::Busted)) {
-                return; // Button should be hidden, but ignore click if somehow pressed
-            }
-
-            // Check if deck is exhausted
-            // SOW-021: count deck + unplayed hand (matches start_next_hand's
-            // own post-shuffle-back exhaustion check)
-            if hand_state.playable_cards_remaining() < 3 {
-                // Button disabled, ignore click
-                return;
-            }
-
-            // Start next hand (preserve cash/heat) and draw cards
-            let can_continue = hand_state.start_next_hand();
-            if can_continue {
-                hand_state.draw_cards();
-            }
-        }
-    }
-}
+// SOW-060: restart_button_system (NEW DEAL) removed - the next hand is a
+// run-map stop, picked in systems::run_route
 
 // ============================================================================
-// UPDATE RESTART BUTTON STATES
+// UPDATE GO HOME BUTTON STATE
 // ============================================================================
-pub fn update_restart_button_states(
+pub fn update_go_home_button_state(
     hand_state_query: Query<&HandState>,
-    save_data: Option<Res<crate::save::SaveData>>, // SOW-023: kingpin game-over label
-    mut restart_button_query: Query<(&mut BackgroundColor, &mut Visibility, &Children), With<RestartButton>>,
     go_home_button_query: Query<(Entity, &Children), With<GoHomeButton>>,
     mut text_query: Query<&mut Text>,
 ) {
@@ -191,37 +158,6 @@ pub fn update_restart_button_states(
 
     let is_busted = matches!(hand_state.outcome, Some(HandOutcome::Busted));
 
-    // NEW DEAL button: Hide if busted, disable if deck exhausted
-    let (mut bg_color, mut visibility, restart_children) = restart_button_query
-        .single_mut()
-        .expect("Expected exactly one RestartButton in resolution overlay");
-
-    if is_busted {
-        // Busted: Hide NEW DEAL button entirely
-        *visibility = Visibility::Hidden;
-    } else {
-        // Safe/Folded: Show NEW DEAL, disable if deck exhausted
-        // SOW-021: exhaustion counts deck + unplayed hand, matching the engine
-        *visibility = Visibility::Visible;
-        let can_deal = hand_state.playable_cards_remaining() >= 3;
-        *bg_color = if can_deal {
-            theme::BUTTON_ENABLED_BG.into()
-        } else {
-            theme::BUTTON_DISABLED_BG.into()
-        };
-
-        // SOW-021: Explain WHY the button is disabled on deck exhaustion
-        // (write only on change - this system runs every frame)
-        let label = if can_deal { "NEW DEAL" } else { "OUT OF CARDS" };
-        for child in restart_children.iter() {
-            if let Ok(mut text) = text_query.get_mut(child) {
-                if **text != label {
-                    **text = label.to_string();
-                }
-            }
-        }
-    }
-
     // GO HOME button text: "GO HOME" if safe, "END RUN" if busted,
     // "NEW EMPIRE" when the KINGPIN busted (SOW-023: the empire already fell;
     // this button walks into the fresh one)
@@ -229,22 +165,19 @@ pub fn update_restart_button_states(
         .single()
         .expect("Expected exactly one GoHomeButton in resolution overlay");
 
-    let kingpin_fell = is_busted
-        && save_data
-            .as_ref()
-            .map(|save| save.active_dealer_state().is_kingpin)
-            .unwrap_or(false);
+    // SOW-063: only when reset_empire ran - an heir's empire carries on
+    let kingpin_fell = hand_state.kingpin_fall == Some(KingpinFall::EmpireFell);
     let go_home_label = if kingpin_fell {
-        "NEW EMPIRE"
+        tr("hub.new_empire")
     } else if is_busted {
-        "END RUN"
+        tr("hub.end_run")
     } else {
-        "GO HOME"
+        tr("hub.go_home")
     };
     for child in children.iter() {
         if let Ok(mut text) = text_query.get_mut(child) {
             if **text != go_home_label {
-                **text = go_home_label.to_string();
+                **text = go_home_label.clone();
             }
         }
     }
@@ -258,9 +191,10 @@ pub fn go_home_button_system(
     go_home_query: Query<&Interaction, (Changed<Interaction>, With<GoHomeButton>)>,
     hand_state_query: Query<(Entity, &HandState)>,
     mut next_state: ResMut<NextState<GameState>>,
-    game_assets: Res<crate::assets::GameAssets>, // SOW-013-B: Need for DeckBuilder::from_assets
+    game_assets: Res<crate::assets::GameAssets>, // SOW-013-B: Need for DeckBuilder::for_dealer
     save_data: Option<ResMut<crate::save::SaveData>>,
     save_manager: Option<Res<crate::save::SaveManager>>,
+    story_composer: Res<crate::models::narrative::StoryComposer>, // SOW-042: off-screen runs
 ) {
     let Ok((entity, hand_state)) = hand_state_query.single() else {
         return;
@@ -289,6 +223,30 @@ pub fn go_home_button_system(
             .as_ref()
             .map(|data| data.account.unlocked_cards.clone())
             .unwrap_or_else(|| crate::save::AccountState::starting_collection());
+        // SOW-057: the runner's evolved cards, owned through their originals
+        let evolved = save_data
+            .as_ref()
+            .map(|data| data.active_character().evolved_cards.clone())
+            .unwrap_or_default();
+        let owned = |id: &String| {
+            unlocked_cards.contains(id)
+                || evolved.iter().any(|(from, into)| into == id && unlocked_cards.contains(from))
+        };
+
+        // SOW-013-B: Collect all cards from HandState before despawning
+        let mut player_cards = hand_state.owner_cards.get(&Owner::Player)
+            .expect("Player cards not found")
+            .clone();
+
+        // Collect all cards (hand + deck + played) back into deck
+        player_cards.collect_all();
+
+        // The just-played deck goes through the access filter so stale
+        // content can't ride into the next run. SOW-034: an out-of-stock
+        // product is still ACCESSED, so it correctly stays in the deck
+        // (inert, drawable) - stock gates play, not deck membership.
+        player_cards.deck.retain(|c| owned(&c.id));
+        let played_ids: Vec<String> = player_cards.deck.iter().map(|c| c.id.clone()).collect();
 
         // Transfer deck heat and stories to the active dealer before despawning HandState
         if let (Some(mut save_data), Some(save_manager)) = (save_data, save_manager) {
@@ -296,51 +254,33 @@ pub fn go_home_button_system(
             // for a dealer, empire reset for the kingpin) - transferring
             // here too would double-charge the heat
             if !matches!(hand_state.outcome, Some(HandOutcome::Busted)) {
-                let character = save_data.active_character_mut();
-                let deck_heat = hand_state.current_heat;
-                // Signed transfer: a cooling session reduces career heat (floor 0)
-                character.apply_session_heat(deck_heat);
-                character.last_played = crate::save::current_timestamp();
-
-                // Add session stories to the dealer's history
-                character.story_history.extend(hand_state.session_stories.iter().cloned());
-
-                // Count as completed deck if outcome was Safe
-                if matches!(hand_state.outcome, Some(HandOutcome::Safe)) {
-                    character.mark_deck_completed();
-                }
-
+                // SOW-042: shared with off-screen runs (SaveData::close_session)
+                let runner = save_data.active_dealer;
+                // SOW-048: the session summary closes out the night's stories
+                let mut stories = hand_state.session_stories.clone();
+                stories.extend(story_composer.compose_session_record(hand_state));
+                save_data.close_session(
+                    runner,
+                    hand_state.current_heat,
+                    &stories,
+                    matches!(hand_state.outcome, Some(HandOutcome::Safe)),
+                );
                 bevy::log::info!("Go Home - transferred {} deck heat to dealer (total: {}), {} stories",
-                      deck_heat, character.heat, hand_state.session_stories.len());
+                      hand_state.current_heat, save_data.active_character().heat,
+                      hand_state.session_stories.len());
             }
 
-            // RFC-023: a completed run anywhere in the empire serves a unit
-            // of every OTHER jailed dealer's sentence (turn-based jail)
-            let runner = save_data.active_dealer;
-            // SOW-025: the tick now serves jail sentences AND relocations
-            let now_available = save_data.complete_run_tick(runner);
-            if !now_available.is_empty() {
-                bevy::log::info!("Back in action: {}", now_available.join(", "));
-            }
-
-            // SOW-031: fronts tick at the same choke - the runner's own
-            // run INCLUDED (an unproductive run still spends a tick;
-            // that's the run-quality pressure the mechanic exists for)
-            for event in save_data.tick_fronts() {
-                match event {
-                    crate::save::FrontEvent::CutOff { area_id } => {
-                        bevy::log::info!("Front overdue in {area_id}: supplier cut you off - one more window");
-                    }
-                    crate::save::FrontEvent::MuscleSeized { area_id, amount } => {
-                        bevy::log::info!("Muscle visited over the {area_id} front: seized ${amount}");
-                    }
-                    crate::save::FrontEvent::MuscleBenched { area_id, dealer } => {
-                        bevy::log::info!("Muscle visited over the {area_id} front: {dealer} took a beating (benched 1 run)");
-                    }
-                    crate::save::FrontEvent::Soured { area_id, card_id } => {
-                        bevy::log::info!("Supplier in {area_id} soured: {card_id} repossessed, no more fronts there");
-                    }
-                }
+            // SOW-042: the rest of the day (SOW-063: with the runner gone
+            // after a kingpin bust)
+            let entries = crate::systems::day_planning::end_the_day(
+                &mut save_data,
+                &played_ids,
+                hand_state.kingpin_fall,
+                &game_assets,
+                &story_composer,
+            );
+            if !entries.is_empty() {
+                commands.insert_resource(crate::systems::day_planning::DaySummary { entries });
             }
 
             if let Err(e) = save_manager.save(&save_data) {
@@ -348,27 +288,18 @@ pub fn go_home_button_system(
             }
         }
 
-        // SOW-013-B: Collect all cards from HandState before despawning
-        let mut player_cards = hand_state.owner_cards.get(&Owner::Player)
-            .expect("Player cards not found")
-            .clone();
-
-        // Collect all cards (hand + deck + played) back into deck
-        player_cards.collect_all();
-
-        // The just-played deck goes through the access filter so stale
-        // content can't ride selected_cards into the next run. SOW-034: an
-        // out-of-stock product is still ACCESSED, so it correctly stays in the
-        // deck (inert, drawable) - stock gates play, not deck membership.
-        player_cards.deck.retain(|c| unlocked_cards.contains(&c.id));
-
         // SOW-020: Update DeckBuilder with unlocked cards filter
-        let mut deck_builder = DeckBuilder::from_assets_filtered(&game_assets, &unlocked_cards);
-        deck_builder.selected_cards = player_cards.deck; // Cards you just played with
-        commands.insert_resource(deck_builder);
+        // SOW-041: loaded from the runner's own deck, not a shared selection.
+        // SOW-063: after a kingpin bust resolution dropped it - DeckBuilding
+        // builds it for the new kingpin
+        if hand_state.kingpin_fall.is_none() {
+            let deck_builder = DeckBuilder::for_dealer(&game_assets, &unlocked_cards, &played_ids, &evolved);
+            commands.insert_resource(deck_builder);
+        }
 
-        // Despawn HandState
+        // Despawn HandState (SOW-060: and the night's map with it)
         commands.entity(entity).despawn();
+        commands.remove_resource::<crate::systems::run_route::RunRoute>();
 
         // Transition back to DeckBuilding state
         next_state.set(GameState::DeckBuilding);
@@ -403,7 +334,7 @@ pub fn deck_builder_card_click_system(
                 deck_builder.selected_cards.retain(|c| c.id != button.card_id);
             } else {
                 // Add to selected deck (if under max)
-                if deck_builder.selected_cards.len() < 20 {
+                if deck_builder.selected_cards.len() < crate::save::MAX_DECK_SIZE {
                     deck_builder.selected_cards.push(card);
                 }
             }
@@ -481,30 +412,25 @@ pub fn start_run_button_system(
             };
             let run_area = run_area.as_str();
 
+            // SOW-042: the area draw is shared with off-screen runs.
+            // SOW-044: regulars of the area get first look.
             let buyer_personas = create_buyer_personas(&game_assets);
-            let area_personas = crate::data::personas_in_area(&buyer_personas, run_area);
-            // Load-time validation guarantees clientele per area; fall back to
-            // the full pool defensively rather than crash a run
-            let mut random_buyer = if let Some(buyer) = area_personas.choose(&mut rand::rng()) {
-                (**buyer).clone()
-            } else {
-                bevy::log::warn!("area '{run_area}' has no clientele at runtime - drawing from all personas");
-                buyer_personas.choose(&mut rand::rng()).unwrap().clone()
-            };
+            let regulars = save_data.as_ref().map(|save| save.regulars.as_slice()).unwrap_or_default();
+            let mut random_buyer = crate::data::buyer_for_session(&buyer_personas, regulars, run_area)
+                .expect("load-time validation guarantees buyer personas");
             bevy::log::info!("Run area: {} - buyer: {}", run_area, random_buyer.display_name);
 
-            // SOW-010: Randomly select one of the Buyer's 2 scenarios
-            if !random_buyer.scenarios.is_empty() {
-                let scenario_index = rand::rng().random_range(0..random_buyer.scenarios.len());
-                random_buyer.active_scenario_index = Some(scenario_index);
-            }
-
             // SOW-027: narc difficulty = deck composition for (run area x the
-            // ACTIVE dealer's heat tier) - WHO you send and WHERE both matter
+            // ACTIVE dealer's heat tier) - WHO you send and WHERE both matter.
+            // SOW-065: the empire's ascension can run the narcs hotter and
+            // the buyer jumpier.
             let heat_tier = save_data
                 .as_ref()
-                .map(|save| save.active_character().heat_tier())
+                .map(|save| save.ascension.narc_tier(save.active_character().heat_tier()))
                 .unwrap_or(crate::save::HeatTier::Cold);
+            if let Some(save) = save_data.as_ref() {
+                save.ascension.apply_to_buyer(&mut random_buyer);
+            }
 
             // Create new HandState; the constructor records the run area and
             // builds the narc deck from it (Safe hands here earn the runner
@@ -523,11 +449,24 @@ pub fn start_run_button_system(
                 let character = save.active_character();
                 hand_state.card_play_counts = character.card_play_counts.clone();
                 hand_state.card_upgrades = character.card_upgrades.clone();
+                hand_state.relics = save.relic_effects(); // SOW-059
             }
 
             hand_state.draw_cards(); // This will also initialize buyer hand
             commands.spawn(hand_state);
 
+            // SOW-060: tonight's map - the first hand is its opening deal.
+            // SOW-061: a task force that's due waits at last call.
+            let mut map = crate::models::run_map::RunMap::generate(&mut rand::rng());
+            let operation = save_data
+                .as_ref()
+                .and_then(|save| save.operation_due(&game_assets.bosses))
+                .map(|boss| boss.id.clone());
+            if operation.is_some() {
+                map.stage_operation();
+            }
+            commands.insert_resource(crate::systems::run_route::RunRoute { map, heat_tier, operation });
+
             // Transition to InRun state
             next_state.set(GameState::InRun);
         }
@@ -645,6 +584,9 @@ pub fn roster_button_system(
     area_dealer_hire_query: Query<(&Interaction, &MapAreaDealerHireButton), Changed<Interaction>>,
     save_data: Option<ResMut<crate::save::SaveData>>,
     save_manager: Option<Res<crate::save::SaveManager>>,
+    // SOW-041: selecting a dealer swaps their own deck into the builder
+    mut deck_builder: Option<ResMut<DeckBuilder>>,
+    game_assets: Res<crate::assets::GameAssets>,
 ) {
     let (Some(mut save_data), Some(save_manager)) = (save_data, save_manager) else {
         return;
@@ -659,7 +601,21 @@ pub fn roster_button_system(
             && button.dealer_index < save_data.dealers.len()
             && save_data.active_dealer != button.dealer_index
         {
+            // SOW-041: bank the outgoing dealer's selection before the swap
+            // (an edit made this frame must not bleed into the next dealer)
+            if let Some(ref mut db) = deck_builder {
+                let outgoing = db.selected_ids();
+                save_data.active_dealer_state_mut().set_deck(outgoing);
+            }
             save_data.active_dealer = button.dealer_index;
+            if let Some(ref mut db) = deck_builder {
+                **db = DeckBuilder::for_dealer(
+                    &game_assets,
+                    &save_data.account.unlocked_cards,
+                    &save_data.active_dealer_state().deck,
+                    &save_data.active_character().evolved_cards,
+                );
+            }
             dirty = true;
         }
     }
@@ -675,11 +631,17 @@ pub fn roster_button_system(
     // only through the map's signature (SOW-036) and cred-gated unlockable
     // (SOW-038) hires handled below.
 
-    // SOW-025: relocate a dealer (move_dealer no-ops when unavailable,
-    // already there, or the fee is unaffordable)
+    // SOW-025: relocate a dealer (move_dealer_along no-ops when unavailable,
+    // already there, or the route is unaffordable). SOW-043: the move walks
+    // the zone graph's best route, priced per road.
     for (interaction, button) in move_query.iter() {
         if *interaction == Interaction::Pressed {
-            let moved = save_data.move_dealer(button.dealer_index, &button.to_area);
+            let route = save_data
+                .dealers
+                .get(button.dealer_index)
+                .and_then(|d| game_assets.zone_graph.route(&d.station, &button.to_area));
+            let moved =
+                route.is_some_and(|route| save_data.move_dealer_along(button.dealer_index, &route));
             if moved {
                 bevy::log::info!(
                     "{} is relocating to {}",
@@ -772,6 +734,63 @@ pub fn roster_button_system(
     }
 }
 
+/// SOW-041: loadout shelf clicks. SAVE stores the active dealer's current
+/// deck in the slot (named after its products); LOAD swaps the preset in as
+/// their deck and rebuilds the selection from it.
+pub fn loadout_button_system(
+    save_query: Query<(&Interaction, &LoadoutSaveButton), Changed<Interaction>>,
+    load_query: Query<(&Interaction, &LoadoutLoadButton), Changed<Interaction>>,
+    save_data: Option<ResMut<crate::save::SaveData>>,
+    save_manager: Option<Res<crate::save::SaveManager>>,
+    deck_builder: Option<ResMut<DeckBuilder>>,
+    game_assets: Res<crate::assets::GameAssets>,
+) {
+    let (Some(mut save_data), Some(save_manager), Some(mut deck_builder)) =
+        (save_data, save_manager, deck_builder)
+    else {
+        return;
+    };
+
+    let mut dirty = false;
+
+    for (interaction, button) in save_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let name = deck_builder.loadout_name();
+        let dealer = save_data.active_dealer_state_mut();
+        dealer.set_deck(deck_builder.selected_ids());
+        match dealer.save_loadout(button.slot, &name) {
+            Ok(()) => {
+                bevy::log::info!("{} saved loadout {}: {}", dealer.name, button.slot + 1, name);
+                dirty = true;
+            }
+            Err(reason) => bevy::log::info!("Loadout not saved: {reason}"),
+        }
+    }
+
+    for (interaction, button) in load_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if save_data.active_dealer_state_mut().apply_loadout(button.slot) {
+            *deck_builder = DeckBuilder::for_dealer(
+                &game_assets,
+                &save_data.account.unlocked_cards,
+                &save_data.active_dealer_state().deck,
+                &save_data.active_character().evolved_cards,
+            );
+            dirty = true;
+        }
+    }
+
+    if dirty {
+        if let Err(e) = save_manager.save(&save_data) {
+            bevy::log::warn!("Failed to save loadout change: {:?}", e);
+        }
+    }
+}
+
 /// SOW-023: START RUN reflects the active dealer's availability (the click
 /// handler independently guards, this is the visual)
 pub fn update_start_run_button_system(
@@ -789,14 +808,14 @@ pub fn update_start_run_button_system(
     // SOW-025: the label says WHY the active dealer can't be sent out
     let dealer = save_data.active_dealer_state();
     let (color, label) = if dealer.is_available() {
-        (theme::CONTINUE_BUTTON_BG, "START RUN")
+        (theme::CONTINUE_BUTTON_BG, tr("hub.start_run"))
     } else if dealer.relocating_remaining().is_some() {
-        (theme::BUTTON_DISABLED_BG, "MOVING")
+        (theme::BUTTON_DISABLED_BG, tr("hub.moving"))
     } else if dealer.laying_low_remaining().is_some() {
         // SOW-027: committed to the package - can't run until it ticks out
-        (theme::BUTTON_DISABLED_BG, "LAYING LOW")
+        (theme::BUTTON_DISABLED_BG, tr("hub.laying_low"))
     } else {
-        (theme::BUTTON_DISABLED_BG, "JAILED")
+        (theme::BUTTON_DISABLED_BG, tr("hub.jailed"))
     };
 
     if bg.0 != color {
@@ -805,7 +824,7 @@ pub fn update_start_run_button_system(
     for child in children.iter() {
         if let Ok(mut text) = text_query.get_mut(child) {
             if **text != label {
-                **text = label.to_string();
+                **text = label.clone();
             }
         }
     }
@@ -828,6 +847,7 @@ mod tests {
             shop_location: None,
             shop_price: None,
             shop_cred_required: None,
+            evolutions: Vec::new(),
         }
     }
 
@@ -8,21 +8,29 @@
 // where setup early-returns), and the overlay root carries
 // FocusPolicy::Block so canvas clicks never reach the hub beneath.
 
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
 use bevy::prelude::*;
 
 use crate::assets::GameAssets;
+use crate::locale::{tr, tr_with};
 use crate::save::SaveData;
+use crate::ui::chronicle_view::{ChronicleFormat, ChronicleSubject};
 use crate::ui::components::*;
 use crate::ui::ledger_view;
 use crate::ui::theme;
 use crate::ui::view::format_cash;
 
 /// Ledger overlay state. `story_focus` is which record's story feed is
-/// open in the third panel.
+/// open in the third panel; `story_filter` (SOW-050) narrows that feed and
+/// resets whenever the focus changes. `retire_armed` (SOW-062) is the
+/// retire button waiting on its confirming click.
 #[derive(Resource, Default)]
 pub struct LedgerUiState {
     pub open: bool,
     pub story_focus: Option<StoryFocus>,
+    pub story_filter: ledger_view::StoryFilter,
+    pub retire_armed: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -77,37 +85,28 @@ pub fn spawn_ledger_overlay(parent: &mut ChildSpawnerCommands) {
                         })
                         .with_children(|title| {
                             title.spawn((
-                                Text::new("THE LEDGER"),
+                                Text::new(tr("ledger.the_ledger")),
                                 TextFont::from_font_size(28.0),
                                 TextColor(theme::TEXT_HEADER),
                             ));
                             title.spawn((
-                                Text::new("Every deal remembered. Click a dossier or a fallen empire for its stories."),
+                                Text::new(tr("ledger.subtitle")),
                                 TextFont::from_font_size(13.0),
                                 TextColor(theme::V2_LABEL),
                             ));
                         });
 
+                    // SOW-051: EXPORT sits left of CLOSE; CLOSE keeps the
+                    // header's right edge
                     header
-                        .spawn((
-                            Button,
-                            Node {
-                                width: Val::Px(110.0),
-                                height: Val::Px(44.0),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                border_radius: BorderRadius::all(Val::Px(6.0)),
-                                ..default()
-                            },
-                            BackgroundColor(theme::BUTTON_NEUTRAL_BG),
-                            LedgerCloseButton,
-                        ))
-                        .with_children(|btn| {
-                            btn.spawn((
-                                Text::new("CLOSE"),
-                                TextFont::from_font_size(16.0),
-                                TextColor(Color::WHITE),
-                            ));
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            spawn_header_button(buttons, &tr("ledger.export"), LedgerExportButton);
+                            spawn_header_button(buttons, &tr("ledger.close"), LedgerCloseButton);
                         });
                 });
 
@@ -125,6 +124,30 @@ pub fn spawn_ledger_overlay(parent: &mut ChildSpawnerCommands) {
         });
 }
 
+fn spawn_header_button(parent: &mut ChildSpawnerCommands, label: &str, marker: impl Component) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(110.0),
+                height: Val::Px(44.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(theme::BUTTON_NEUTRAL_BG),
+            marker,
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont::from_font_size(16.0),
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
 /// LEDGER tab opens (or closes) the overlay; CLOSE closes it. Story focus
 /// resets on close so a stale feed never survives a hidden overlay.
 pub fn ledger_toggle_system(
@@ -151,6 +174,8 @@ pub fn ledger_toggle_system(
     }
     if !state.open {
         state.story_focus = None;
+        state.story_filter = Default::default();
+        state.retire_armed = false;
     }
     if let Ok(mut node) = overlay_query.single_mut() {
         node.display = if state.open { Display::Flex } else { Display::None };
@@ -168,14 +193,225 @@ pub fn ledger_story_click_system(
         if *interaction == Interaction::Pressed {
             let focus = StoryFocus::Dealer(dossier.dealer_index);
             state.story_focus = (state.story_focus != Some(focus)).then_some(focus);
+            state.story_filter = Default::default();
         }
     }
     for (interaction, epitaph) in epitaph_query.iter() {
         if *interaction == Interaction::Pressed {
             let focus = StoryFocus::Epitaph(epitaph.epitaph_index);
             state.story_focus = (state.story_focus != Some(focus)).then_some(focus);
+            state.story_filter = Default::default();
+        }
+    }
+}
+
+/// SOW-051: EXPORT writes an HTML chronicle (portraits alongside) to
+/// `<save dir>/chronicles/<unix secs>/` - the focused fallen empire's, or
+/// the living empire's when no epitaph is focused. Same document as
+/// `cargo run -- chronicle`.
+pub fn ledger_export_system(
+    export_query: Query<&Interaction, (Changed<Interaction>, With<LedgerExportButton>)>,
+    save_data: Option<Res<SaveData>>,
+    state: Res<LedgerUiState>,
+    game_assets: Res<GameAssets>,
+) {
+    let Some(save_data) = save_data else {
+        return;
+    };
+    for interaction in export_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let subject = match state.story_focus {
+            Some(StoryFocus::Epitaph(i)) => ChronicleSubject::Fallen(i),
+            _ => ChronicleSubject::Current,
+        };
+        match crate::save::chronicle::export(
+            &save_data,
+            subject,
+            ChronicleFormat::Html,
+            &game_assets.shop_locations,
+            &crate::save::chronicle::default_out_dir(),
+            &crate::save::chronicle::default_actors_dir(),
+        ) {
+            Ok(path) => info!("Chronicle exported to {}", path.display()),
+            Err(e) => warn!("Chronicle export failed: {}", e),
+        }
+    }
+}
+
+/// SOW-062: CASH OUT & RETIRE. The first click arms the button; the second
+/// scores the empire, has the story engine write its ending, archives it as
+/// a retirement and re-enters the hub on a fresh empire (the same re-entry
+/// hot reload uses). The ending closes the epitaph's story feed.
+pub fn ledger_retire_system(
+    mut commands: Commands,
+    retire_query: Query<&Interaction, (Changed<Interaction>, With<LedgerRetireButton>)>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<crate::save::SaveManager>>,
+    mut state: ResMut<LedgerUiState>,
+    game_assets: Res<GameAssets>,
+    story_composer: Res<crate::models::narrative::StoryComposer>,
+) {
+    let Some(mut save_data) = save_data else {
+        return;
+    };
+    if !retire_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        return;
+    }
+    if !state.retire_armed {
+        state.retire_armed = true;
+        return;
+    }
+
+    let retirement = save_data.retirement(&game_assets.victories, game_assets.shop_locations.len());
+    let ending = story_composer.compose_retirement(&save_data, &retirement);
+    info!("The kingpin cashed out with a score of {}: {}", retirement.score, ending);
+    save_data.retire(retirement, ending);
+    if let Some(save_manager) = save_manager {
+        if let Err(e) = save_manager.save(&save_data) {
+            warn!("Failed to save after retiring: {:?}", e);
         }
     }
+    // The fresh empire rebuilds its deck builder and hub from scratch
+    commands.remove_resource::<crate::models::deck_builder::DeckBuilder>();
+    commands.set_state(crate::game_state::GameState::DeckBuilding);
+}
+
+/// SOW-063: SUCCESSION ON/OFF - flips the rule and saves it
+pub fn ledger_succession_system(
+    toggle_query: Query<&Interaction, (Changed<Interaction>, With<LedgerSuccessionButton>)>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<crate::save::SaveManager>>,
+) {
+    let Some(mut save_data) = save_data else {
+        return;
+    };
+    if !toggle_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        return;
+    }
+    let on = !save_data.succession;
+    save_data.set_succession(on);
+    if let Some(save_manager) = save_manager {
+        if let Err(e) = save_manager.save(&save_data) {
+            warn!("Failed to save the succession rule: {:?}", e);
+        }
+    }
+}
+
+/// SOW-065: start the fresh empire at a level and save it
+pub fn ledger_ascension_system(
+    level_query: Query<(&Interaction, &LedgerAscensionButton), Changed<Interaction>>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<crate::save::SaveManager>>,
+) {
+    let Some(mut save_data) = save_data else {
+        return;
+    };
+    for (interaction, button) in level_query.iter() {
+        if *interaction != Interaction::Pressed || save_data.ascension == button.level {
+            continue;
+        }
+        if let Err(reason) = save_data.set_ascension(button.level) {
+            info!("Ascension {} refused: {}", button.level.0, reason);
+            continue;
+        }
+        if let Some(save_manager) = save_manager.as_ref() {
+            if let Err(e) = save_manager.save(&save_data) {
+                warn!("Failed to save the ascension level: {:?}", e);
+            }
+        }
+    }
+}
+
+/// SOW-064: buy a legacy unlock and save it
+pub fn ledger_legacy_system(
+    offer_query: Query<(&Interaction, &LedgerLegacyButton), Changed<Interaction>>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<crate::save::SaveManager>>,
+) {
+    let Some(mut save_data) = save_data else {
+        return;
+    };
+    for (interaction, offer) in offer_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match save_data.buy_legacy(offer.unlock.clone()) {
+            Ok(cost) => info!("Legacy unlock bought for {}: {:?}", cost, offer.unlock),
+            Err(reason) => {
+                info!("Legacy unlock refused: {}", reason);
+                continue;
+            }
+        }
+        if let Some(save_manager) = save_manager.as_ref() {
+            if let Err(e) = save_manager.save(&save_data) {
+                warn!("Failed to save after a legacy unlock: {:?}", e);
+            }
+        }
+    }
+}
+
+/// The focused feed's records, unfiltered (newest first for a dealer,
+/// archive order for a fallen empire)
+fn focused_stories(save: &SaveData, focus: Option<StoryFocus>) -> Vec<crate::save::StoryRecord> {
+    match focus {
+        Some(StoryFocus::Dealer(i)) => ledger_view::dealer_stories(save, i),
+        Some(StoryFocus::Epitaph(i)) => ledger_view::epitaph_stories(save, i),
+        None => Vec::new(),
+    }
+}
+
+/// SOW-050: filter chips - each click moves its field to the next value
+/// the open feed actually carries (then back to ALL)
+pub fn ledger_story_filter_system(
+    chip_query: Query<(&Interaction, &LedgerStoryFilterButton), Changed<Interaction>>,
+    save_data: Option<Res<SaveData>>,
+    mut state: ResMut<LedgerUiState>,
+) {
+    let Some(save_data) = save_data else {
+        return;
+    };
+    for (interaction, chip) in chip_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let options = ledger_view::story_filter_options(&focused_stories(&save_data, state.story_focus));
+        let filter = &mut state.story_filter;
+        match chip {
+            LedgerStoryFilterButton::Dealer => filter.dealer = ledger_view::cycle_filter(&filter.dealer, &options.dealers),
+            LedgerStoryFilterButton::Zone => filter.zone = ledger_view::cycle_filter(&filter.zone, &options.zones),
+            LedgerStoryFilterButton::Outcome => filter.outcome = ledger_view::cycle_filter(&filter.outcome, &options.outcomes),
+            LedgerStoryFilterButton::Buyer => filter.buyer = ledger_view::cycle_filter(&filter.buyer, &options.buyers),
+            LedgerStoryFilterButton::Clear => *filter = Default::default(),
+        }
+    }
+}
+
+/// SOW-050: typing while a feed is open searches it; Backspace takes a
+/// character back. Only touches the state when the search changes, so the
+/// body isn't rebuilt on every keypress elsewhere.
+pub fn ledger_story_search_system(
+    mut keys: MessageReader<KeyboardInput>,
+    mut state: ResMut<LedgerUiState>,
+) {
+    let searching = state.open && state.story_focus.is_some();
+    let mut search = state.story_filter.search.clone();
+    for key in keys.read() {
+        if !searching || key.state != ButtonState::Pressed {
+            continue;
+        }
+        match (&key.logical_key, &key.text) {
+            (Key::Backspace, _) => {
+                search.pop();
+            }
+            (_, Some(text)) => search.extend(text.chars().filter(|c| !c.is_control())),
+            _ => {}
+        }
+    }
+    if searching && search != state.story_filter.search {
+        state.story_filter.search = search;
+    }
 }
 
 /// Rebuild the ledger body when the save or the ledger state changes
@@ -231,8 +467,25 @@ pub fn populate_ledger_system(
             })
             .with_children(|panels| {
                 spawn_roster_panel(panels, &save_data, &game_assets, focus);
-                spawn_board_panel(panels, &save_data, focus);
-                spawn_story_panel(panels, &save_data, focus);
+                // SOW-044: the board shares its column with the regulars
+                panels
+                    .spawn(Node {
+                        width: Val::Px(560.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(20.0),
+                        overflow: Overflow::clip_y(),
+                        ..default()
+                    })
+                    .with_children(|column| {
+                        spawn_ascension_panel(column, &save_data); // SOW-065
+                        spawn_lineage_panel(column, &save_data, focus); // SOW-063
+                        spawn_retire_panel(column, &save_data, &game_assets, focus, state.retire_armed); // SOW-062
+                        spawn_legacy_panel(column, &save_data, &game_assets); // SOW-064
+                        spawn_board_panel(column, &save_data, focus);
+                        spawn_regulars_panel(column, &save_data, &game_assets);
+                        spawn_paper_panel(column, &save_data); // SOW-052
+                    });
+                spawn_story_panel(panels, &save_data, &game_assets, focus, &state.story_filter);
             });
     });
 }
@@ -335,7 +588,7 @@ fn spawn_roster_panel(
         ledger_view::dossier_rows(save, &game_assets.shop_locations),
         ledger_view::ROSTER_PANEL_CAP,
     );
-    panel_frame(panels, "THE ROSTER", Val::Px(560.0)).with_children(|panel| {
+    panel_frame(panels, &tr("ledger.roster"), Val::Px(560.0)).with_children(|panel| {
         for row in &rows {
             let focused = focus == Some(StoryFocus::Dealer(row.dealer_index));
             panel
@@ -429,6 +682,285 @@ fn spawn_roster_panel(
     });
 }
 
+/// SOW-065: ASCENSION - the level in play and its modifiers; while the
+/// empire hasn't dealt, a button per unlocked level
+fn spawn_ascension_panel(panels: &mut ChildSpawnerCommands, save: &SaveData) {
+    let view = ledger_view::ascension_view(save);
+    panel_frame(panels, &tr("ledger.ascension"), Val::Percent(100.0)).with_children(|panel| {
+        panel
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|row| {
+                for level in &view.levels {
+                    let active = *level == view.level;
+                    if !view.open && !active {
+                        continue;
+                    }
+                    let mut button = row.spawn((
+                        Node {
+                            height: Val::Px(28.0),
+                            padding: UiRect::horizontal(Val::Px(12.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border_radius: BorderRadius::all(Val::Px(6.0)),
+                            ..default()
+                        },
+                        BackgroundColor(if active { theme::LEDGER_LEVEL_ACTIVE_BG } else { theme::LEDGER_LEVEL_BG }),
+                    ));
+                    if view.open {
+                        button.insert((Button, LedgerAscensionButton { level: *level }));
+                    }
+                    button.with_children(|btn| {
+                        btn.spawn((
+                            Text::new(tr_with("ledger.ascension_level", &[("level", &level.0.to_string())])),
+                            TextFont::from_font_size(12.0),
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+                }
+            });
+        if view.modifiers.is_empty() {
+            panel.spawn((
+                Text::new(tr("ledger.ascension_base")),
+                TextFont::from_font_size(12.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+        for line in &view.modifiers {
+            panel.spawn((
+                Text::new(line.as_str()),
+                TextFont::from_font_size(12.0),
+                TextColor(theme::ROSTER_STATION_TEXT),
+            ));
+        }
+        let footer = if view.open { view.next.clone() } else { Some(tr("ledger.ascension_locked")) };
+        if let Some(footer) = footer {
+            panel.spawn((
+                Text::new(footer),
+                TextFont::from_font_size(11.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+    });
+}
+
+/// SOW-063: LINEAGE - the kingpins of the focused archived empire, or of
+/// the living one with the succession toggle and who'd take over
+fn spawn_lineage_panel(panels: &mut ChildSpawnerCommands, save: &SaveData, focus: Option<StoryFocus>) {
+    let epitaph = match focus {
+        Some(StoryFocus::Epitaph(i)) => Some(i),
+        _ => None,
+    };
+    let rows = ledger_view::lineage_rows(save, epitaph);
+    panel_frame(panels, &tr("ledger.lineage"), Val::Percent(100.0)).with_children(|panel| {
+        for row in &rows {
+            panel.spawn((
+                Text::new(ledger_view::lineage_text(row)),
+                TextFont::from_font_size(13.0),
+                TextColor(if row.end == ledger_view::ReignEnd::Reigning {
+                    theme::LEDGER_BOARD_CURRENT
+                } else {
+                    theme::ROSTER_STATION_TEXT
+                }),
+            ));
+        }
+        if epitaph.is_some() {
+            return;
+        }
+        panel.spawn((
+            Text::new(ledger_view::succession_line(save)),
+            TextFont::from_font_size(12.0),
+            TextColor(theme::V2_LABEL),
+        ));
+        panel
+            .spawn((
+                Button,
+                Node {
+                    height: Val::Px(32.0),
+                    padding: UiRect::horizontal(Val::Px(14.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    align_self: AlignSelf::FlexStart,
+                    border_radius: BorderRadius::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(if save.succession {
+                    theme::LEDGER_SUCCESSION_ON_BG
+                } else {
+                    theme::LEDGER_SUCCESSION_OFF_BG
+                }),
+                LedgerSuccessionButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(tr(if save.succession { "ledger.succession_on" } else { "ledger.succession_off" })),
+                    TextFont::from_font_size(13.0),
+                    TextColor(Color::WHITE),
+                ));
+            });
+    });
+}
+
+/// SOW-064: LEGACY - what the archived empires earned, what it bought,
+/// and what it could buy next
+fn spawn_legacy_panel(panels: &mut ChildSpawnerCommands, save: &SaveData, game_assets: &GameAssets) {
+    let content = ledger_view::LegacyContent {
+        products: &game_assets.products,
+        areas: &game_assets.shop_locations,
+        relics: &game_assets.relics,
+    };
+    let view = ledger_view::legacy_view(save, &content);
+    panel_frame(panels, &tr("ledger.legacy"), Val::Percent(100.0)).with_children(|panel| {
+        panel.spawn((
+            Text::new(tr_with("ledger.legacy_balance", &[("points", &view.balance.to_string())])),
+            TextFont::from_font_size(13.0),
+            TextColor(Color::WHITE),
+        ));
+        for label in &view.owned {
+            panel.spawn((
+                Text::new(tr_with("ledger.legacy_owned", &[("label", label)])),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::LEDGER_BOARD_CURRENT),
+            ));
+        }
+        for offer in &view.offers {
+            panel
+                .spawn((
+                    Button,
+                    Node {
+                        height: Val::Px(28.0),
+                        padding: UiRect::horizontal(Val::Px(12.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        align_self: AlignSelf::FlexStart,
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(if offer.affordable { theme::LEDGER_LEGACY_BG } else { theme::LEDGER_LEGACY_LOCKED_BG }),
+                    LedgerLegacyButton { unlock: offer.unlock.clone() },
+                ))
+                .with_children(|btn| {
+                    let cost = offer.cost.to_string();
+                    btn.spawn((
+                        Text::new(tr_with("ledger.legacy_offer", &[("label", &offer.label), ("cost", &cost)])),
+                        TextFont::from_font_size(12.0),
+                        TextColor(if offer.affordable { Color::WHITE } else { theme::V2_LABEL }),
+                    ));
+                });
+        }
+    });
+}
+
+/// SOW-062: RETIREMENT - the authored victories checked against the living
+/// empire, what cashing out would score, the button, and the board of
+/// empires that took the way out
+fn spawn_retire_panel(
+    panels: &mut ChildSpawnerCommands,
+    save: &SaveData,
+    game_assets: &GameAssets,
+    focus: Option<StoryFocus>,
+    armed: bool,
+) {
+    let view = ledger_view::retire_view(save, &game_assets.victories, game_assets.shop_locations.len());
+    let rows = ledger_view::retirement_rows(save);
+    panel_frame(panels, &tr("ledger.retirement"), Val::Percent(100.0)).with_children(|panel| {
+        if view.victories.is_empty() {
+            panel.spawn((
+                Text::new(tr("ledger.no_victories")),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+        for line in &view.victories {
+            panel.spawn((
+                Text::new(ledger_view::victory_text(line)),
+                TextFont::from_font_size(13.0),
+                TextColor(if line.met { theme::LEDGER_BOARD_CURRENT } else { theme::V2_LABEL }),
+            ));
+        }
+        panel.spawn((
+            Text::new(tr_with(
+                "ledger.retire_score",
+                &[("score", &format_cash(view.score)), ("clean", &format_cash(view.clean_cash))],
+            )),
+            TextFont::from_font_size(14.0),
+            TextColor(Color::WHITE),
+        ));
+        panel
+            .spawn((
+                Button,
+                Node {
+                    height: Val::Px(40.0),
+                    padding: UiRect::horizontal(Val::Px(16.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    align_self: AlignSelf::FlexStart,
+                    border_radius: BorderRadius::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(if armed { theme::LEDGER_RETIRE_ARMED_BG } else { theme::LEDGER_RETIRE_BG }),
+                LedgerRetireButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(tr(if armed { "ledger.retire_confirm" } else { "ledger.retire" })),
+                    TextFont::from_font_size(15.0),
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+        if rows.is_empty() {
+            return;
+        }
+        panel.spawn((
+            Text::new(tr("ledger.retired_empires")),
+            TextFont::from_font_size(12.0),
+            TextColor(theme::BUYER_BUBBLE_LABEL),
+        ));
+        for row in rows.iter().take(ledger_view::BOARD_PANEL_CAP) {
+            panel
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(7.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme::ROSTER_CARD_BG),
+                    BorderColor::all(if focus == Some(StoryFocus::Epitaph(row.epitaph_index)) {
+                        theme::ROSTER_CARD_BORDER_ACTIVE
+                    } else {
+                        theme::ROSTER_CARD_BORDER
+                    }),
+                    LedgerEpitaphButton { epitaph_index: row.epitaph_index },
+                ))
+                .with_children(|board_row| {
+                    let rank = row.rank.to_string();
+                    board_row.spawn((
+                        Text::new(
+                            tr_with("ledger.retired_row", &[("rank", &rank), ("score", &format_cash(row.score))])
+                                + &ledger_view::level_tag(row.ascension),
+                        ),
+                        TextFont::from_font_size(15.0),
+                        TextColor(Color::WHITE),
+                    ));
+                    board_row.spawn((
+                        Text::new(ledger_view::retirement_detail(row)),
+                        TextFont::from_font_size(11.0),
+                        TextColor(theme::ROSTER_STATION_TEXT),
+                    ));
+                });
+        }
+    });
+}
+
 /// Panel 3: FALLEN EMPIRES - the arcade board with the living empire
 /// slotted unranked at its would-be position
 fn spawn_board_panel(
@@ -438,10 +970,10 @@ fn spawn_board_panel(
 ) {
     let (rows, tail) =
         ledger_view::board_view(ledger_view::board_rows(save), ledger_view::BOARD_PANEL_CAP);
-    panel_frame(panels, "FALLEN EMPIRES", Val::Px(560.0)).with_children(|panel| {
-        if save.fallen_empires.is_empty() {
+    panel_frame(panels, &tr("ledger.fallen_empires"), Val::Percent(100.0)).with_children(|panel| {
+        if rows.iter().all(|row| row.is_current) {
             panel.spawn((
-                Text::new("no empires have fallen — yet"),
+                Text::new(tr("ledger.no_epitaphs")),
                 TextFont::from_font_size(13.0),
                 TextColor(theme::V2_LABEL),
             ));
@@ -450,7 +982,7 @@ fn spawn_board_panel(
             let title = match row.rank {
                 Some(rank) => format!("{}. {}", rank, format_cash(row.lifetime_revenue)),
                 None => format!("— {} · IN PROGRESS", format_cash(row.lifetime_revenue)),
-            };
+            } + &ledger_view::level_tag(row.ascension);
             let detail = format!(
                 "{} DECKS · {} HIRES · {} CONVICTIONS",
                 row.decks_played, row.dealers_hired, row.convictions
@@ -505,39 +1037,181 @@ fn spawn_board_panel(
     });
 }
 
-/// The story feed for whatever record is focused
+/// Panel 5 (SOW-052): THE PAPER - the front pages, newest first. Outlives
+/// the empire (the archive survives reset_empire like the board).
+fn spawn_paper_panel(panels: &mut ChildSpawnerCommands, save: &SaveData) {
+    let (pages, tail) = ledger_view::paper_view(&save.headlines, ledger_view::PAPER_PANEL_CAP);
+    panel_frame(panels, &tr("ledger.the_paper"), Val::Percent(100.0)).with_children(|panel| {
+        if pages.is_empty() {
+            panel.spawn((
+                Text::new(tr("ledger.no_headlines")),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+        for page in &pages {
+            panel
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                })
+                .with_children(|entry| {
+                    entry.spawn((
+                        Text::new(ledger_view::paper_dateline(page)),
+                        TextFont::from_font_size(10.0),
+                        TextColor(theme::ROSTER_STATION_TEXT),
+                    ));
+                    entry.spawn((
+                        Text::new(page.headline.to_uppercase()),
+                        TextFont::from_font_size(14.0),
+                        TextColor(Color::WHITE),
+                    ));
+                    entry.spawn((
+                        Text::new(page.article.clone()),
+                        TextFont::from_font_size(12.0),
+                        TextColor(theme::LEDGER_STORY_TEXT),
+                    ));
+                });
+        }
+        if let Some(tail) = tail {
+            panel.spawn((
+                Text::new(tail),
+                TextFont::from_font_size(12.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+    });
+}
+
+/// Panel 4 (SOW-044): REGULARS - who on the street knows the empire, and
+/// how they feel about it
+fn spawn_regulars_panel(panels: &mut ChildSpawnerCommands, save: &SaveData, game_assets: &GameAssets) {
+    let (rows, tail) = ledger_view::regulars_view(
+        ledger_view::regular_rows(save, &game_assets.shop_locations),
+        ledger_view::REGULARS_PANEL_CAP,
+    );
+    panel_frame(panels, &tr("ledger.regulars"), Val::Percent(100.0)).with_children(|panel| {
+        if rows.is_empty() {
+            panel.spawn((
+                Text::new(tr("ledger.no_regulars")),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+        for row in &rows {
+            let mut detail = format!(
+                "{} · {} · {} DEAL{}",
+                row.persona.to_uppercase(),
+                row.area.to_uppercase(),
+                row.deals,
+                if row.deals == 1 { "" } else { "S" }
+            );
+            if let Some(likes) = &row.likes {
+                detail.push_str(&format!(" · LIKES {likes}"));
+            }
+            if let Some(by) = &row.referred_by {
+                detail.push_str(&format!(" · SENT BY {by}"));
+            }
+            panel
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(7.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(if row.informant {
+                        theme::ROSTER_CARD_BG_JAILED
+                    } else {
+                        theme::ROSTER_CARD_BG
+                    }),
+                    BorderColor::all(theme::ROSTER_CARD_BORDER),
+                ))
+                .with_children(|regular| {
+                    regular
+                        .spawn(Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            ..default()
+                        })
+                        .with_children(|top| {
+                            top.spawn((
+                                Text::new(row.name.clone()),
+                                TextFont::from_font_size(15.0),
+                                TextColor(Color::WHITE),
+                            ));
+                            top.spawn((
+                                Text::new(if row.informant {
+                                    row.mood.to_string()
+                                } else {
+                                    format!("{} {}", row.mood, row.relationship)
+                                }),
+                                TextFont::from_font_size(12.0),
+                                TextColor(if row.informant {
+                                    theme::ROSTER_STATUS_JAILED
+                                } else {
+                                    theme::SHOP_CREDIT_LINE_TEXT
+                                }),
+                            ));
+                        });
+                    regular.spawn((
+                        Text::new(detail),
+                        TextFont::from_font_size(11.0),
+                        TextColor(theme::ROSTER_STATION_TEXT),
+                    ));
+                });
+        }
+        if let Some(tail) = &tail {
+            panel.spawn((
+                Text::new(tail.as_str()),
+                TextFont::from_font_size(12.0),
+                TextColor(theme::V2_LABEL),
+            ));
+        }
+    });
+}
+
+/// The story feed for whatever record is focused, narrowed by the filter
+/// chips and the search (SOW-050)
 fn spawn_story_panel(
     panels: &mut ChildSpawnerCommands,
     save: &SaveData,
+    game_assets: &GameAssets,
     focus: Option<StoryFocus>,
+    filter: &ledger_view::StoryFilter,
 ) {
-    let (heading, stories) = match focus {
+    let heading = match focus {
         Some(StoryFocus::Dealer(i)) => {
             let name = save
                 .dealers
                 .get(i)
                 .map(|d| d.name.to_uppercase())
                 .unwrap_or_default();
-            (format!("STORIES — {name}"), ledger_view::dealer_stories(save, i))
-        }
-        Some(StoryFocus::Epitaph(i)) => {
-            let revenue = save
-                .fallen_empires
-                .get(i)
-                .map(|e| format_cash(e.lifetime_revenue))
-                .unwrap_or_default();
-            (
-                format!("STORIES — FALLEN EMPIRE ({revenue})"),
-                ledger_view::epitaph_stories(save, i),
-            )
+            tr_with("ledger.stories_of", &[("name", &name)])
         }
-        None => ("STORIES".to_string(), Vec::new()),
+        Some(StoryFocus::Epitaph(i)) => match save.fallen_empires.get(i) {
+            // SOW-062: a retirement is headed by its score
+            Some(e) => match &e.retirement {
+                Some(retirement) => tr_with("ledger.stories_of_retired", &[("score", &format_cash(retirement.score))]),
+                None => tr_with("ledger.stories_of_fallen", &[("revenue", &format_cash(e.lifetime_revenue))]),
+            },
+            None => tr_with("ledger.stories_of_fallen", &[("revenue", "")]),
+        },
+        None => tr("ledger.stories"),
     };
+    let stories = focused_stories(save, focus);
+    let areas = &game_assets.shop_locations;
 
     panel_frame(panels, &heading, Val::Auto).with_children(|panel| {
         if focus.is_none() {
             panel.spawn((
-                Text::new("click a dossier or a fallen empire to read its record"),
+                Text::new(tr("ledger.pick_a_record")),
                 TextFont::from_font_size(13.0),
                 TextColor(theme::V2_LABEL),
             ));
@@ -545,7 +1219,75 @@ fn spawn_story_panel(
         }
         if stories.is_empty() {
             panel.spawn((
-                Text::new("nothing on the record yet"),
+                Text::new(tr("ledger.empty_record")),
+                TextFont::from_font_size(13.0),
+                TextColor(theme::V2_LABEL),
+            ));
+            return;
+        }
+
+        let zone = filter.zone.as_ref().map(|id| {
+            areas.iter().find(|a| &a.id == id).map_or(id.clone(), |a| a.name.clone())
+        });
+        let mut chips = vec![
+            (LedgerStoryFilterButton::Dealer, tr("ledger.filter_dealer"), filter.dealer.clone()),
+            (LedgerStoryFilterButton::Zone, tr("ledger.filter_zone"), zone),
+            (LedgerStoryFilterButton::Outcome, tr("ledger.filter_outcome"), filter.outcome.map(ledger_view::outcome_label)),
+            (LedgerStoryFilterButton::Buyer, tr("ledger.filter_buyer"), filter.buyer.clone()),
+        ];
+        if filter.is_active() {
+            chips.push((LedgerStoryFilterButton::Clear, tr("ledger.filter_clear"), None));
+        }
+        panel
+            .spawn(Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(6.0),
+                row_gap: Val::Px(6.0),
+                ..default()
+            })
+            .with_children(|row| {
+                for (chip, label, value) in chips {
+                    let text = match (chip, value) {
+                        (LedgerStoryFilterButton::Clear, _) => label,
+                        (_, value) => ledger_view::filter_chip_text(&label, value.as_deref()),
+                    };
+                    row.spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                            border: UiRect::all(Val::Px(1.0)),
+                            border_radius: BorderRadius::all(Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(theme::BUTTON_NEUTRAL_BG),
+                        BorderColor::all(theme::ROSTER_CARD_BORDER),
+                        chip,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(text),
+                            TextFont::from_font_size(11.0),
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+                }
+            });
+        panel.spawn((
+            Text::new(if filter.search.is_empty() {
+                "SEARCH — type to search".to_string()
+            } else {
+                format!("SEARCH — {}_", filter.search)
+            }),
+            TextFont::from_font_size(11.0),
+            TextColor(theme::V2_LABEL),
+        ));
+
+        let stories = ledger_view::filter_stories(stories, filter);
+        if stories.is_empty() {
+            panel.spawn((
+                Text::new(tr("ledger.no_stories_match")),
                 TextFont::from_font_size(13.0),
                 TextColor(theme::V2_LABEL),
             ));
@@ -555,10 +1297,18 @@ fn spawn_story_panel(
             ledger_view::story_feed(stories, ledger_view::STORY_FEED_CAP);
         for story in &visible {
             panel.spawn((
-                Text::new(story.as_str()),
+                Text::new(story.text.as_str()),
                 TextFont::from_font_size(13.0),
                 TextColor(theme::LEDGER_STORY_TEXT),
             ));
+            let meta = ledger_view::story_meta_line(story, areas);
+            if !meta.is_empty() {
+                panel.spawn((
+                    Text::new(meta),
+                    TextFont::from_font_size(10.0),
+                    TextColor(theme::ROSTER_STATION_TEXT),
+                ));
+            }
         }
         if let Some(tail) = &tail {
             panel.spawn((
@@ -10,6 +10,10 @@ pub mod shop;
 pub mod city_map;
 pub mod kingpin_ledger;
 pub mod tutorial; // SOW-032: tutorial arc orchestration
+pub mod day_planning; // SOW-042: roster orders + off-screen runs
+pub mod morning_paper; // SOW-052: headlines on the hub
+pub mod relics; // SOW-059: relics found, earned and shelved
+pub mod run_route; // SOW-060: the night's route between hands
 
 pub use input::*;
 pub use ui_update::*;
@@ -20,3 +24,7 @@ pub use shop::*;
 pub use city_map::*;
 pub use kingpin_ledger::*;
 pub use tutorial::*;
+pub use day_planning::*;
+pub use morning_paper::*;
+pub use relics::*;
+pub use run_route::*;
@@ -0,0 +1,215 @@
+// SOW-052: The morning paper - big empire events make the news.
+//
+// SaveData queues newsworthy chronicle entries as they happen (a kingpin's
+// fall, a dealer jailed, a front's muscle, a zone bought). Back on the hub,
+// press_headlines_system writes each one up with the HeadlineDesk, files
+// the pages in the save's archive (the ledger's THE PAPER panel) and puts
+// the fresh ones in front of the player as a dismissable overlay - the
+// same MorningPaper-resource pattern as the SOW-042 day summary.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::locale::tr;
+use crate::models::narrative::HeadlineDesk;
+use crate::save::{Headline, SaveData, SaveManager};
+use crate::ui::components::*;
+use crate::ui::{ledger_view, theme};
+
+/// Front pages printed since the player last put the paper down. Inserted
+/// by the press; DISMISS removes it.
+#[derive(Resource, Debug, Default)]
+pub struct MorningPaper {
+    pub pages: Vec<Headline>,
+}
+
+/// Write up whatever the save has queued for the press, archive it and
+/// put it on the hub
+pub fn press_headlines_system(
+    mut commands: Commands,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<SaveManager>>,
+    desk: Option<Res<HeadlineDesk>>,
+    paper: Option<ResMut<MorningPaper>>,
+    game_assets: Res<GameAssets>,
+) {
+    let (Some(mut save_data), Some(desk)) = (save_data, desk) else {
+        return;
+    };
+    if save_data.press_queue.is_empty() {
+        return;
+    }
+    let queue = std::mem::take(&mut save_data.press_queue);
+    let pages: Vec<Headline> = queue
+        .iter()
+        .filter_map(|entry| desk.compose(entry, &game_assets.shop_locations))
+        .collect();
+    for page in &pages {
+        info!("Morning paper: {}", page.headline);
+    }
+    save_data.file_headlines(pages.clone());
+    if let Some(save_manager) = save_manager {
+        if let Err(e) = save_manager.save(&save_data) {
+            warn!("Failed to save after printing headlines: {:?}", e);
+        }
+    }
+    match paper {
+        Some(mut paper) => paper.pages.extend(pages),
+        None if !pages.is_empty() => commands.insert_resource(MorningPaper { pages }),
+        None => {}
+    }
+}
+
+/// Spawn the (hidden) paper under DeckBuilderRoot - same inheritance as the
+/// day summary, one layer above it so the news reads first.
+pub fn spawn_morning_paper_overlay(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(theme::DAY_SUMMARY_SCRIM),
+            // SOW-029 lesson: Block so clicks never reach the hub beneath
+            bevy::ui::FocusPolicy::Block,
+            GlobalZIndex(94),
+            MorningPaperOverlay,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(680.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(12.0),
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme::PAPER_BG),
+                    BorderColor::all(theme::PAPER_RULE),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(tr("paper.the_morning_paper")),
+                        TextFont::from_font_size(30.0),
+                        TextColor(theme::PAPER_INK),
+                        Node {
+                            align_self: AlignSelf::Center,
+                            ..default()
+                        },
+                    ));
+                    panel.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(2.0),
+                            ..default()
+                        },
+                        BackgroundColor(theme::PAPER_RULE),
+                    ));
+
+                    // One front page per fresh headline, rebuilt by populate
+                    panel.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(14.0),
+                            ..default()
+                        },
+                        MorningPaperBody,
+                    ));
+
+                    panel
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(140.0),
+                                height: Val::Px(44.0),
+                                align_self: AlignSelf::End,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border_radius: BorderRadius::all(Val::Px(6.0)),
+                                ..default()
+                            },
+                            BackgroundColor(theme::BUTTON_NEUTRAL_BG),
+                            MorningPaperDismissButton,
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new(tr("paper.put_it_down")),
+                                TextFont::from_font_size(16.0),
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                });
+        });
+}
+
+/// Show the overlay while a MorningPaper is pending, rebuilding its pages
+/// when the paper changes (or the hub was just rebuilt around it)
+pub fn populate_morning_paper_system(
+    mut commands: Commands,
+    paper: Option<Res<MorningPaper>>,
+    mut overlay_query: Query<&mut Node, With<MorningPaperOverlay>>,
+    body_query: Query<Entity, With<MorningPaperBody>>,
+    children_query: Query<&Children>,
+) {
+    let Ok(mut overlay) = overlay_query.single_mut() else {
+        return; // overlay only exists on the deck-builder screen
+    };
+    let display = if paper.is_some() { Display::Flex } else { Display::None };
+    if overlay.display != display {
+        overlay.display = display;
+    }
+    let (Some(paper), Ok(body)) = (paper, body_query.single()) else {
+        return;
+    };
+
+    let is_empty = children_query.get(body).map(|c| c.is_empty()).unwrap_or(true);
+    if !paper.is_changed() && !is_empty {
+        return;
+    }
+    if let Ok(children) = children_query.get(body) {
+        for child in children.iter() {
+            commands.entity(child).despawn();
+        }
+    }
+
+    // The lead story is the newest; older pages of the same edition follow
+    // smaller
+    commands.entity(body).with_children(|parent| {
+        for (i, page) in paper.pages.iter().rev().enumerate() {
+            parent.spawn((
+                Text::new(ledger_view::paper_dateline(page)),
+                TextFont::from_font_size(11.0),
+                TextColor(theme::PAPER_RULE),
+            ));
+            parent.spawn((
+                Text::new(page.headline.to_uppercase()),
+                TextFont::from_font_size(if i == 0 { 26.0 } else { 18.0 }),
+                TextColor(theme::PAPER_INK),
+            ));
+            parent.spawn((
+                Text::new(page.article.clone()),
+                TextFont::from_font_size(14.0),
+                TextColor(theme::PAPER_INK),
+            ));
+        }
+    });
+}
+
+/// PUT IT DOWN clears the paper (the overlay hides on the next populate)
+pub fn morning_paper_dismiss_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<MorningPaperDismissButton>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.remove_resource::<MorningPaper>();
+        }
+    }
+}
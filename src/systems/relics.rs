@@ -0,0 +1,52 @@
+// SOW-059: Relics on the hub - found and earned relics are handed over as
+// the empire's books change, and the shelf under the lifetime line lists
+// what the empire holds. Shop relics are bought in systems::shop.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::save::{SaveData, SaveManager};
+use crate::ui::components::RelicShelfText;
+
+/// Hand over every event and milestone relic the empire has earned. Reads
+/// first, so an empire with nothing due never marks the save changed.
+pub fn award_relics_system(
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<SaveManager>>,
+    game_assets: Res<GameAssets>,
+) {
+    let Some(mut save_data) = save_data else {
+        return;
+    };
+    if !save_data.is_changed() {
+        return;
+    }
+    let due = save_data.relics_due(&game_assets.relics);
+    if due.is_empty() {
+        return;
+    }
+    for relic in due {
+        save_data.grant_relic(relic);
+        info!("Relic found: {} ({})", relic.name, relic.description);
+    }
+    if let Some(save_manager) = save_manager {
+        if let Err(e) = save_manager.save(&save_data) {
+            warn!("Failed to save after finding a relic: {:?}", e);
+        }
+    }
+}
+
+/// The hub shelf - rewritten when the save changes or the hub is rebuilt
+pub fn update_relic_shelf_system(
+    save_data: Option<Res<SaveData>>,
+    mut query: Query<(Ref<RelicShelfText>, &mut Text)>,
+) {
+    let Some(save_data) = save_data else {
+        return;
+    };
+    for (shelf, mut text) in query.iter_mut() {
+        if save_data.is_changed() || shelf.is_added() {
+            **text = crate::ui::relic_view::shelf_text(&save_data.relics);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// SOW-060: Run route - picking the night's path between hands.
+//
+// START RUN rolls a RunMap and plays its opening deal. When a hand ends
+// (anything but a bust), the resolution overlay's route row offers the
+// stops linked from here; picking one plays it - a hand at the stop's
+// stakes, or a rest stop's reshuffle - and moves the runner along. At last
+// call only GO HOME is left. GO HOME removes the map with the HandState.
+//
+// SOW-061: when a task-force operation is due at START RUN, the last call is
+// its stop: the boss's rules ride on the HandState for that hand.
+//
+// Presentation (labels, stakes, heading) lives in ui::run_map_view.
+
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::data::create_narc_deck;
+use crate::locale::tr;
+use crate::models::boss::Operation;
+use crate::models::hand_state::{HandOutcome, HandPhase, HandState};
+use crate::models::run_map::{RunMap, RunNodeKind};
+use crate::save::HeatTier;
+use crate::ui::components::*;
+use crate::ui::run_map_view;
+use crate::ui::theme;
+
+/// Tonight's map. Inserted at START RUN, removed at GO HOME.
+#[derive(Resource, Debug)]
+pub struct RunRoute {
+    pub map: RunMap,
+    /// The runner's heat tier at START RUN - each stop's narc deck is built
+    /// from it, shifted by the stop
+    pub heat_tier: HeatTier,
+    /// SOW-061: the operation staged at last call (boss id)
+    pub operation: Option<String>,
+}
+
+/// What the route row was last built from: (row, col, cards left to deal)
+type RouteSignature = (usize, usize, bool);
+
+/// Whether a hand has ended in a way the night can go on from
+fn can_move_on(hand: &HandState) -> bool {
+    hand.current_state == HandPhase::Bust && !matches!(hand.outcome, Some(HandOutcome::Busted))
+}
+
+/// Take a stop from the route row: a hand at its stakes, or a rest
+pub fn route_choice_system(
+    interaction_query: Query<(&Interaction, &RunNodeButton), Changed<Interaction>>,
+    mut hand_state_query: Query<&mut HandState>,
+    route: Option<ResMut<RunRoute>>,
+    game_assets: Res<GameAssets>,
+) {
+    let (Some(mut route), Ok(mut hand_state)) = (route, hand_state_query.single_mut()) else {
+        return;
+    };
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed || !can_move_on(&hand_state) {
+            continue;
+        }
+        let Some(kind) = route.map.choices().into_iter().find(|(col, _)| *col == button.col).map(|(_, k)| k) else {
+            continue;
+        };
+        if !run_map_view::stop_open(kind, hand_state.playable_cards_remaining()) {
+            continue;
+        }
+        route.map.advance(button.col);
+        match kind.params() {
+            None => {
+                hand_state.rest();
+                info!("Rest stop: deck reshuffled, session heat {}", hand_state.current_heat);
+            }
+            Some(params) => {
+                let tier = route.heat_tier.shifted(params.narc_shift);
+                let narc_deck = create_narc_deck(&game_assets, &hand_state.run_area, tier);
+                if hand_state.start_node_hand(kind, narc_deck) {
+                    // SOW-061: the operation's rules are on before the draw
+                    if kind == RunNodeKind::Operation {
+                        let boss = route.operation.as_deref().and_then(|id| game_assets.boss(id));
+                        hand_state.operation = boss.map(Operation::from);
+                    }
+                    hand_state.draw_cards();
+                    info!("Run map: {:?} (narcs at {})", kind, tier.name());
+                }
+            }
+        }
+    }
+}
+
+/// Rebuild the route row when the runner moves, the hand ends, or the deck
+/// runs low; empty while a hand is being played or after a bust
+pub fn populate_run_route_system(
+    mut commands: Commands,
+    route: Option<Res<RunRoute>>,
+    game_assets: Res<GameAssets>,
+    hand_state_query: Query<&HandState>,
+    row_query: Query<Entity, With<RunRouteRow>>,
+    children_query: Query<&Children>,
+    mut last: Local<Option<RouteSignature>>,
+) {
+    let Ok(row) = row_query.single() else {
+        return;
+    };
+    let hand = hand_state_query.single().ok();
+    let signature = match (&route, hand) {
+        (Some(route), Some(hand)) if can_move_on(hand) => {
+            Some((route.map.row, route.map.col, hand.playable_cards_remaining() >= 3))
+        }
+        _ => None,
+    };
+    if signature == *last {
+        return;
+    }
+    *last = signature;
+
+    if let Ok(children) = children_query.get(row) {
+        for child in children.iter() {
+            commands.entity(child).despawn();
+        }
+    }
+    let (Some(route), Some(hand)) = (route, hand) else {
+        return;
+    };
+    if signature.is_none() {
+        return;
+    }
+
+    let playable = hand.playable_cards_remaining();
+    let boss = route.operation.as_deref().and_then(|id| game_assets.boss(id));
+    commands.entity(row).with_children(|parent| {
+        parent.spawn((
+            Text::new(run_map_view::route_heading(&route.map)),
+            TextFont::from_font_size(16.0),
+            TextColor(theme::TEXT_HEADER),
+        ));
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(12.0),
+                ..default()
+            })
+            .with_children(|stops| {
+                for (col, kind) in route.map.choices() {
+                    let label = match kind {
+                        RunNodeKind::Operation => run_map_view::operation_label(boss.map(|b| b.name.as_str())),
+                        _ => run_map_view::node_label(kind),
+                    };
+                    spawn_stop_button(stops, col, kind, label, run_map_view::stop_open(kind, playable));
+                }
+            });
+    });
+}
+
+/// One stop: its name, its stakes, greyed out when the deck can't deal it
+fn spawn_stop_button(parent: &mut ChildSpawnerCommands, col: usize, kind: RunNodeKind, label: String, open: bool) {
+    let background = match (open, kind) {
+        (false, _) => theme::BUTTON_DISABLED_BG,
+        (true, RunNodeKind::Sting | RunNodeKind::Operation) => theme::RESTART_BUTTON_BG,
+        (true, _) => theme::BUTTON_ENABLED_BG,
+    };
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(160.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(background),
+            RunNodeButton { col },
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont::from_font_size(18.0),
+                TextColor(Color::WHITE),
+            ));
+            btn.spawn((
+                Text::new(if open { run_map_view::node_stakes(kind) } else { tr("run.out_of_cards") }),
+                TextFont::from_font_size(12.0),
+                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            ));
+        });
+}
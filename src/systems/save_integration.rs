@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use crate::save::{SaveManager, SaveData};
-use crate::models::hand_state::{HandState, HandPhase, HandOutcome};
+use crate::models::hand_state::{HandState, HandPhase, HandOutcome, KingpinFall};
 use crate::ui::components::{DecayInfoDisplay, AccountCashText, LifetimeRevenueText, StoryHistoryText, StoryHistoryButton, StoryHistoryOverlay, StoryHistoryCloseButton};
 
 /// Resource tracking if character data has been loaded this session
@@ -21,13 +21,26 @@ pub fn load_character_system(
     mut commands: Commands,
     save_manager: Res<SaveManager>,
     mut character_loaded: ResMut<CharacterLoaded>,
+    mod_packs: Option<Res<crate::assets::mods::ModPacks>>,
 ) {
     // Only load once per session
     if character_loaded.0 {
         return;
     }
 
-    let save_data = save_manager.load_or_create();
+    let mut save_data = save_manager.load_or_create();
+
+    // SOW-056: a save played under different mod packs may hold cards,
+    // zones or buyers this content doesn't have - say so, then remember
+    // the new set
+    let active = mod_packs.map(|packs| packs.stamps()).unwrap_or_default();
+    let changes = crate::save::mod_changes(&save_data.mods, &active);
+    if save_data.sync_mods(&active) {
+        warn!("Mod packs changed since this save was last played: {}", changes.join(", "));
+        if let Err(e) = save_manager.save(&save_data) {
+            warn!("Failed to save the new mod set: {:?}", e);
+        }
+    }
 
     // RFC-023: sentences are turn-based (ticked in go_home_button_system as
     // runs complete) - nothing to sweep on load
@@ -53,13 +66,15 @@ pub fn apply_decay_system(
     // RFC-023: decay applies per dealer; jailed dealers skip it - serving
     // the term IS their heat reset, decaying them too would double-dip
     let active_idx = save_data.active_dealer;
+    let relics = save_data.relic_effects(); // SOW-059
+    let ascension = save_data.ascension; // SOW-065
     let mut total_decay = 0;
     let mut active_decay = 0;
     for (idx, dealer) in save_data.dealers.iter_mut().enumerate() {
         if !dealer.is_available() {
             continue;
         }
-        let decay = dealer.character.apply_decay();
+        let decay = dealer.character.apply_decay_with(&relics, ascension);
         if idx == active_idx {
             active_decay = decay;
         }
@@ -101,78 +116,62 @@ pub fn ensure_roster_on_run_start(
 
 /// System to save character heat and account cash after hand resolution
 pub fn save_after_resolution_system(
-    hand_state_query: Query<&HandState, Changed<HandState>>,
+    mut hand_state_query: Query<&mut HandState, Changed<HandState>>,
     mut save_data: ResMut<SaveData>,
     save_manager: Res<SaveManager>,
+    game_assets: Res<crate::assets::GameAssets>,
     mut commands: Commands,
 ) {
-    for hand_state in hand_state_query.iter() {
+    for mut hand_state in hand_state_query.iter_mut() {
         // Only save when hand reaches terminal state with an outcome
         if hand_state.current_state != HandPhase::Bust {
             continue;
         }
 
-        let Some(outcome) = &hand_state.outcome else {
+        let Some(outcome) = hand_state.outcome else {
             continue;
         };
 
-        // SOW-025: a successful deal earns the runner +1 street cred in the
-        // run's area - reputation is per dealer, per territory, and permanent
-        if *outcome == HandOutcome::Safe {
+        // SOW-025/RFC-016/017/019: a successful deal earns the runner +1
+        // street cred in the run's area, banks the profit, and counts the
+        // played cards toward upgrades. SOW-042: one bank path shared with
+        // off-screen runs (SaveData::bank_safe_hand).
+        if outcome == HandOutcome::Safe {
             let area = hand_state.run_area.clone();
-            let dealer = save_data.active_dealer_state_mut();
-            dealer.add_cred(&area);
+            let runner = save_data.active_dealer;
+            let upgraded = save_data.bank_safe_hand(
+                runner,
+                &area,
+                hand_state.last_profit,
+                &hand_state.cards_played,
+            );
+            let dealer = save_data.active_dealer_state();
             info!(
-                "{} earned street cred in {} (now {})",
+                "{} earned street cred in {} (now {}); profit ${} (account total ${})",
                 dealer.name,
                 area,
-                dealer.cred_in(&area)
-            );
-        }
-
-        // RFC-016: Add profit to account-wide cash on Safe outcome
-        if *outcome == HandOutcome::Safe && hand_state.last_profit > 0 {
-            save_data.account.add_profit(hand_state.last_profit);
-            info!(
-                "Profit earned: ${} (Account total: ${})",
+                dealer.cred_in(&area),
                 hand_state.last_profit,
                 save_data.account.cash_on_hand
             );
+            for card in upgraded {
+                info!("Card '{}' earned an upgrade! Queued for player choice.", card);
+            }
+        }
 
-            // RFC-017: Increment play counts for player cards on successful deal
-            // Only player card types get upgrades (not Narc Evidence/Conviction)
-            {
-                let character = save_data.active_character_mut();
-                for card in &hand_state.cards_played {
-                    // Player card types: Product, Location, Cover, DealModifier, Insurance
-                    let is_player_card = matches!(
-                        card.card_type,
-                        crate::CardType::Product { .. }
-                            | crate::CardType::Location { .. }
-                            | crate::CardType::Cover { .. }
-                            | crate::CardType::DealModifier { .. }
-                            | crate::CardType::Insurance { .. }
-                    );
-
-                    if is_player_card {
-                        character.increment_play_count(&card.name);
-                        let tier = character.get_card_tier(&card.name);
-                        info!(
-                            "Card '{}' play count: {} (Tier: {})",
-                            card.name,
-                            character.get_play_count(&card.name),
-                            tier.name()
-                        );
-
-                        // RFC-019: Check if this card has earned a new upgrade
-                        if character.queue_pending_upgrade(&card.name, &card.card_type) {
-                            info!(
-                                "Card '{}' earned an upgrade! Queued for player choice.",
-                                card.name
-                            );
-                        }
-                    }
-                }
+        // SOW-044: the buyer remembers how the hand went - before the jail
+        // below, so an informant's heat prices the sentence
+        if let Some(buyer) = &hand_state.buyer_persona {
+            let deal = crate::save::BuyerDeal {
+                dealer_idx: save_data.active_dealer,
+                area: &hand_state.run_area,
+                buyer,
+                outcome: &outcome,
+                profit: hand_state.last_profit,
+                played: &hand_state.cards_played,
+            };
+            if let Some(event) = save_data.record_buyer_deal(&deal, &game_assets.buyers, rand::random()) {
+                info!("Regulars: {:?}", event);
             }
         }
 
@@ -198,23 +197,42 @@ pub fn save_after_resolution_system(
         // RFC-023: a bust JAILS the active dealer - sentence scales with
         // their heat at the moment of bust (session heat transferred first
         // so the crime is priced at the heat it happened at). If the
-        // KINGPIN busts, the empire ends: the one remaining permadeath.
-        if *outcome == HandOutcome::Busted {
+        // KINGPIN busts, the empire ends: the one remaining permadeath -
+        // unless SOW-063 succession hands the chair to a lieutenant. Either
+        // way the kingpin is gone, so the operation below is not settled.
+        let empire_fell = outcome == HandOutcome::Busted && save_data.active_dealer_state().is_kingpin;
+        if outcome == HandOutcome::Busted {
             if save_data.active_dealer_state().is_kingpin {
-                info!("THE KINGPIN WAS BUSTED - the empire falls. Starting fresh.");
-                save_data.reset_empire();
-                // Drop the stale deck selection so the fresh empire rebuilds
-                // its deck builder from the fresh account's collection
+                let fall = if save_data.succeed() {
+                    info!("THE KINGPIN WAS BUSTED - {} takes over the empire.", save_data.dealers[0].name);
+                    KingpinFall::Succeeded
+                } else {
+                    info!("THE KINGPIN WAS BUSTED - the empire falls. Starting fresh.");
+                    save_data.reset_empire();
+                    KingpinFall::EmpireFell
+                };
+                // GO HOME reads it - without flagging the change this
+                // system listens for
+                hand_state.bypass_change_detection().kingpin_fall = Some(fall);
+                // Drop the stale deck selection so the empire rebuilds its
+                // deck builder for the new kingpin (or the fresh account)
                 commands.remove_resource::<crate::models::deck_builder::DeckBuilder>();
             } else {
-                let session_heat = hand_state.current_heat;
-                let dealer = save_data.active_dealer_state_mut();
-                dealer.character.apply_session_heat(session_heat);
-                dealer.jail();
-                if let Some(runs) = dealer.jail_remaining() {
-                    info!("{} jailed for {} run(s) (heat {} at bust)",
-                          dealer.name, runs, dealer.character.heat);
-                }
+                let runner = save_data.active_dealer;
+                let runs = save_data.jail_for_bust(runner, hand_state.current_heat);
+                let dealer = save_data.active_dealer_state();
+                info!("{} jailed for {} run(s) (heat {} at bust)",
+                      dealer.name, runs, dealer.character.heat);
+            }
+        }
+
+        // SOW-061: a task-force operation pays out or adds its time (after
+        // the jail above, which its extra runs stretch). A fallen empire's
+        // books are already closed.
+        if let Some(boss) = hand_state.operation.as_ref().and_then(|op| game_assets.boss(&op.id)) {
+            let relic = boss.relic.as_deref().and_then(|id| game_assets.relic(id));
+            if !empire_fell && save_data.settle_operation(boss, outcome, relic) {
+                info!("Operation {} settled: {:?}", boss.name, outcome);
             }
         }
 
@@ -236,6 +254,36 @@ pub fn mark_deck_completed_system(
     // This system kept for potential future cleanup needs on state exit
 }
 
+/// SOW-041: mirror deck-builder edits into the ACTIVE dealer's saved deck.
+/// Dealer switches bank the outgoing selection themselves (roster_button_system),
+/// so by the time this sees a change the builder already belongs to whoever is
+/// active. set_deck reports real edits only - a rebuild that lands on the same
+/// ids doesn't hit the disk.
+pub fn sync_dealer_deck_system(
+    deck_builder: Option<Res<crate::models::deck_builder::DeckBuilder>>,
+    save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<SaveManager>>,
+) {
+    let (Some(deck_builder), Some(mut save_data), Some(save_manager)) =
+        (deck_builder, save_data, save_manager)
+    else {
+        return;
+    };
+    if !deck_builder.is_changed() {
+        return;
+    }
+    let ids = deck_builder.selected_ids();
+    // Compare before taking the mutable borrow so an unchanged deck doesn't
+    // flag SaveData as changed (that would rebuild every save-driven panel)
+    if save_data.active_dealer_state().deck == ids {
+        return;
+    }
+    save_data.active_dealer_state_mut().set_deck(ids);
+    if let Err(e) = save_manager.save(&save_data) {
+        warn!("Failed to save dealer deck: {:?}", e);
+    }
+}
+
 // SOW-023: update_character_heat_display_system removed - the roster panel is
 // the per-dealer heat display (the old stats-block line duplicated it)
 
@@ -334,7 +382,7 @@ pub fn update_story_history_display_system(
             let story_text = history
                 .iter()
                 .rev()
-                .map(|s| s.as_str())
+                .map(|s| s.text.as_str())
                 .collect::<Vec<_>>()
                 .join("\n───\n");
             **text = story_text;
@@ -1,6 +1,7 @@
 // SOW-020: Shop system for purchasing cards
 
 use bevy::prelude::*;
+use crate::locale::tr;
 use crate::ui::components::*;
 use crate::ui::theme;
 use crate::models::card::{Card, CardType};
@@ -11,6 +12,9 @@ use crate::assets::GameAssets;
 /// Hover color for buttons
 const BUTTON_HOVER_BG: Color = Color::srgb(0.4, 0.9, 0.4);
 
+/// SOW-059: the rare slot's border - relic gold
+const RELIC_SLOT_BORDER: Color = Color::srgb(0.95, 0.75, 0.2);
+
 /// SOW-034: defensive fallback margin if a selected zone's def is somehow
 /// missing (validate_shop_locations guarantees a valid per-zone margin, so
 /// this is never hit in practice - the real margins live in shop_locations.ron).
@@ -223,6 +227,7 @@ pub fn populate_shop_cards_system(
         standing: data.standing_with(&shop_state.selected_location),
         has_front: data.front_in(&shop_state.selected_location).is_some(),
         cash: data.account.cash_on_hand,
+        vig_pct: data.relic_effects().vig_pct(data.ascension), // SOW-065
     });
 
     // Spawn shop card displays
@@ -249,6 +254,10 @@ pub fn populate_shop_cards_system(
         // the display label, and the front, which all read this one `margin`.
         let best_cred = area_best_cred.as_ref().map(|(_, c)| *c).unwrap_or(0);
         let margin = effective_restock_margin(base_margin, best_cred);
+        // SOW-059: a Restock relic discounts the same seam
+        let margin = save_data
+            .as_ref()
+            .map_or(margin, |data| data.relic_effects().restock_margin(margin));
 
         for card in location_cards {
             let is_unlocked = unlocked_cards.contains(&card.id);
@@ -286,14 +295,96 @@ pub fn populate_shop_cards_system(
                 product_stock,
             );
         }
+
+        // SOW-059: the zone's rare slot - relics sold here, after the cards
+        for relic in &game_assets.relics {
+            let crate::models::relic::RelicSource::Shop { area, price } = &relic.source else {
+                continue;
+            };
+            if *area == shop_state.selected_location {
+                let owned = save_data.as_ref().is_some_and(|data| data.has_relic(&relic.id));
+                spawn_relic_slot(parent, relic, *price, owned);
+            }
+        }
     });
 }
 
+/// SOW-059: a relic for sale - name, flavour, effects, then BUY or OWNED
+fn spawn_relic_slot(
+    parent: &mut ChildSpawnerCommands,
+    relic: &crate::models::relic::RelicDef,
+    price: u32,
+    owned: bool,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(200.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                margin: UiRect::all(Val::Px(5.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.18, 0.14, 0.05, 0.9)),
+            BorderColor::all(RELIC_SLOT_BORDER),
+            ShopCardDisplay,
+        ))
+        .with_children(|slot| {
+            slot.spawn((
+                Text::new(format!("{} · {}", tr("shop.relic"), relic.name)),
+                TextFont::from_font_size(14.0),
+                TextColor(RELIC_SLOT_BORDER),
+            ));
+            slot.spawn((
+                Text::new(relic.description.as_str()),
+                TextFont::from_font_size(11.0),
+                TextColor(Color::srgb(0.75, 0.72, 0.65)),
+            ));
+            slot.spawn((
+                Text::new(crate::ui::relic_view::effects_line(&relic.effects)),
+                TextFont::from_font_size(12.0),
+                TextColor(Color::WHITE),
+            ));
+            if owned {
+                slot.spawn((
+                    Text::new(tr("shop.owned")),
+                    TextFont::from_font_size(14.0),
+                    TextColor(Color::srgb(0.3, 0.8, 0.3)),
+                ));
+            } else {
+                slot.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(30.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(theme::CONTINUE_BUTTON_BG),
+                    RelicBuyButton { relic_id: relic.id.clone() },
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(format!("${price}")),
+                        TextFont::from_font_size(14.0),
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+}
+
 /// SOW-031: what the supplier relationship means for this shop tab
 struct FrontContext {
     standing: crate::save::SupplierStanding,
     has_front: bool,
     cash: u64,
+    /// SOW-059: the vig a front carries (relics can cut it)
+    vig_pct: u64,
 }
 
 /// SOW-031: the supplier header - name, voice, status, PAY when owed
@@ -439,6 +530,7 @@ fn spawn_front_button(
     card_id: &str,
     area_id: &str,
     batch_cost: u32,
+    owed: u64,
 ) {
     parent
         .spawn((
@@ -460,7 +552,7 @@ fn spawn_front_button(
         ))
         .with_children(|btn| {
             btn.spawn((
-                Text::new(crate::ui::front_view::front_button_label(batch_cost)),
+                Text::new(crate::ui::front_view::front_button_label(owed)),
                 TextFont::from_font_size(11.0),
                 TextColor(Color::WHITE),
             ));
@@ -494,7 +586,7 @@ fn spawn_cred_lock_line(parent: &mut ChildSpawnerCommands, gate: &CredGate) {
 /// The shop's "CUT OFF — settle your debt" stock lock line
 fn spawn_cut_off_line(parent: &mut ChildSpawnerCommands) {
     parent.spawn((
-        Text::new("CUT OFF\nsettle your debt"),
+        Text::new(tr("shop.cut_off")),
         TextFont::from_font_size(12.0),
         TextColor(theme::ROSTER_STATUS_JAILED),
         TextLayout::new_with_justify(bevy::text::Justify::Center),
@@ -609,14 +701,14 @@ fn spawn_shop_card(
                     // SOW-034: FRONT a batch when you have access but can't
                     // afford it in cash, while the supplier still deals on
                     // trust (Good + no front already running with them).
-                    if is_unlocked
-                        && front_ctx.is_some_and(|ctx| {
-                            ctx.standing == crate::save::SupplierStanding::Good
-                                && !ctx.has_front
-                                && ctx.cash < batch as u64
-                        })
-                    {
-                        spawn_front_button(card_parent, &card.id, area_id, batch);
+                    let front_vig = front_ctx.filter(|ctx| {
+                        ctx.standing == crate::save::SupplierStanding::Good
+                            && !ctx.has_front
+                            && ctx.cash < batch as u64
+                    });
+                    if let (true, Some(ctx)) = (is_unlocked, front_vig) {
+                        let owed = crate::save::front_owed_at(batch, ctx.vig_pct);
+                        spawn_front_button(card_parent, &card.id, area_id, batch, owed);
                     }
                 }
             }
@@ -624,14 +716,14 @@ fn spawn_shop_card(
             None => {
                 if is_unlocked {
                     card_parent.spawn((
-                        Text::new("✓ OWNED"),
+                        Text::new(tr("shop.owned")),
                         TextFont::from_font_size(14.0),
                         TextColor(Color::srgb(0.3, 0.8, 0.3)),
                     ));
                 } else if price == 0 {
                     // Shouldn't happen - starting cards are unlocked
                     card_parent.spawn((
-                        Text::new("FREE"),
+                        Text::new(tr("shop.free")),
                         TextFont::from_font_size(14.0),
                         TextColor(Color::srgb(0.8, 0.8, 0.3)),
                     ));
@@ -730,7 +822,11 @@ pub fn shop_purchase_system(
         // SOW-031 review fix: the pool reflects the buy NOW - the
         // DeckBuilder is otherwise only rebuilt at go-home
         if let Some(ref mut db) = deck_builder {
-            db.resync_available(&game_assets, &data.account.unlocked_cards);
+            db.resync_available(
+                &game_assets,
+                &data.account.unlocked_cards,
+                &data.active_character().evolved_cards,
+            );
         }
 
         // Save immediately
@@ -781,7 +877,11 @@ pub fn front_take_system(
                 // for the go-home rebuild burns a tick before the card
                 // can earn
                 if let Some(ref mut db) = deck_builder {
-                    db.resync_available(&game_assets, &data.account.unlocked_cards);
+                    db.resync_available(
+                        &game_assets,
+                        &data.account.unlocked_cards,
+                        &data.active_character().evolved_cards,
+                    );
                 }
                 if let Some(ref manager) = save_manager {
                     if let Err(e) = manager.save(data) {
@@ -827,6 +927,38 @@ pub fn front_pay_system(
     }
 }
 
+/// SOW-059: buy a relic from the rare slot. SaveData::buy_relic owns the
+/// guards (for sale, not held, zone owned, cash).
+pub fn relic_purchase_system(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &RelicBuyButton), Changed<Interaction>>,
+    mut save_data: Option<ResMut<SaveData>>,
+    save_manager: Option<Res<SaveManager>>,
+    shop_state: Res<ShopState>,
+    game_assets: Res<GameAssets>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let (Some(data), Some(relic)) = (save_data.as_mut(), game_assets.relic(&button.relic_id)) else {
+            continue;
+        };
+        match data.buy_relic(relic) {
+            Ok(price) => {
+                info!("Bought relic {} for ${} (remaining: ${})", relic.name, price, data.account.cash_on_hand);
+                if let Some(ref manager) = save_manager {
+                    if let Err(e) = manager.save(data) {
+                        warn!("Failed to save after relic purchase: {:?}", e);
+                    }
+                }
+                refresh_shop_tab(&mut commands, &shop_state);
+            }
+            Err(reason) => info!("No relic {}: {}", button.relic_id, reason),
+        }
+    }
+}
+
 /// SOW-031: the shop tab rebuild trick shared by purchase/front/pay
 /// (reinsert ShopState so populate_shop_cards_system sees a change)
 fn refresh_shop_tab(commands: &mut Commands, shop_state: &ShopState) {
@@ -879,7 +1011,7 @@ pub fn area_unlock_button_system(
             continue;
         };
 
-        match data.account.purchase_location(&button.location_id, button.price as u64) {
+        match data.buy_zone(&button.location_id, button.price as u64) {
             Ok(()) => {
                 let area_name = game_assets
                     .shop_locations
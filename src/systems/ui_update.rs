@@ -4,6 +4,7 @@
 // Updated for Bevy 0.18
 
 use bevy::prelude::*;
+use crate::locale::{tr, tr_with};
 use crate::{CardType, HandState, HandPhase, DeckBuilder, Owner};
 use crate::game_state::GameState;
 use crate::ui::components::*;
@@ -150,7 +151,7 @@ pub fn recreate_hand_display_system(
                     } else {
                         ui::spawn_placeholder(
                             parent,
-                            "Drawing...",
+                            &tr("hub.drawing"),
                             ui::CardSize::Hand,
                             theme::CARD_BORDER_NORMAL,
                             game_assets.card_back.clone(),
@@ -217,7 +218,8 @@ pub fn update_deck_discard_system(
     // Deck count
     if let Ok(entity) = deck_text_query.single() {
         if let Ok(mut text) = texts.get_mut(entity) {
-            let label = format!("DECK · {}", hand_state.cards(Owner::Player).deck.len());
+            let count = hand_state.cards(Owner::Player).deck.len().to_string();
+            let label = tr_with("hub.deck_count", &[("count", &count)]);
             if **text != label {
                 **text = label;
             }
@@ -238,7 +240,7 @@ pub fn update_deck_discard_system(
 
     if let Ok(entity) = discard_text_query.single() {
         if let Ok(mut text) = texts.get_mut(entity) {
-            let label = format!("DISCARD · {discard_count}");
+            let label = tr_with("hub.discard_count", &[("count", &discard_count.to_string())]);
             if **text != label {
                 **text = label;
             }
@@ -378,7 +380,8 @@ pub fn update_buyer_played_bubble_system(
         return;
     };
 
-    let Some(played) = view::buyer_played(hand_state) else {
+    // SOW-045: intent telegraph while the round plays, then the reaction
+    let Some(played) = view::buyer_bubble(hand_state) else {
         bubble_node.display = Display::None;
         return;
     };
@@ -434,13 +437,13 @@ pub fn update_buyer_panel_system(
             view::BuyerConfidence::Scared => theme::RISK_CHIP_TEXT,
         };
         for (entity_result, value) in [
-            (confidence_emoji_query.single(), confidence.emoji()),
+            (confidence_emoji_query.single(), confidence.emoji().to_string()),
             (confidence_text_query.single(), confidence.label()),
         ] {
             if let Ok(entity) = entity_result {
                 if let Ok(mut text) = texts.get_mut(entity) {
                     if **text != value {
-                        **text = value.to_string();
+                        **text = value.clone();
                     }
                 }
                 if let Ok(mut text_color) = text_colors.get_mut(entity) {
@@ -466,7 +469,7 @@ pub fn update_buyer_panel_system(
         return;
     };
 
-    set_text(name_query.single(), persona.display_name.to_uppercase());
+    set_text(name_query.single(), view::buyer_name(persona));
     set_text(payout_query.single(), format!("×{:.1}", persona.base_multiplier));
 
     let scenario = persona
@@ -476,7 +479,7 @@ pub fn update_buyer_panel_system(
     if let Some(scenario) = scenario {
         set_text(
             scenario_query.single(),
-            format!("WANTS · {}", scenario.display_name.to_uppercase()),
+            tr_with("hub.wants_scenario", &[("scenario", &scenario.display_name.to_uppercase())]),
         );
         set_text(demand_query.single(), scenario.products.join(" / "));
 
@@ -484,13 +487,19 @@ pub fn update_buyer_panel_system(
         // panel now that the confidence face summarizes them at a glance
         let mut detail = scenario.description.clone();
         if !scenario.locations.is_empty() {
-            detail.push_str(&format!("\n\nPREFERS: {}", scenario.locations.join(", ")));
+            detail.push_str("\n\n");
+            detail.push_str(&tr_with("hub.prefers", &[("locations", &scenario.locations.join(", "))]));
         }
         if let Some(heat_cap) = scenario.heat_threshold {
-            detail.push_str(&format!("\nBAILS AT HEAT {heat_cap}"));
+            detail.push('\n');
+            detail.push_str(&tr_with("hub.bails_at_heat", &[("cap", &heat_cap.to_string())]));
         }
         if let Some(evidence_cap) = persona.evidence_threshold {
-            detail.push_str(&format!("\nBAILS AT EVIDENCE {evidence_cap}"));
+            detail.push('\n');
+            detail.push_str(&tr_with("hub.bails_at_evidence", &[("cap", &evidence_cap.to_string())]));
+        }
+        if let Some(regular) = view::regular_detail(persona) {
+            detail.push_str(&format!("\n\n{regular}"));
         }
         set_text(detail_query.single(), detail);
     }
@@ -658,7 +667,7 @@ pub fn populate_roster_panel_system(
                             ));
                             if dealer.is_kingpin {
                                 parent.spawn((
-                                    Text::new("BOSS"),
+                                    Text::new(tr("hub.boss")),
                                     TextFont::from_font_size(11.0),
                                     TextColor(theme::ROSTER_KINGPIN_BADGE),
                                 ));
@@ -677,7 +686,10 @@ pub fn populate_roster_panel_system(
                         let tier = dealer.character.heat_tier();
                         let (r, g, b) = tier.color();
                         parent.spawn((
-                            Text::new(format!("Heat {} [{}]", dealer.character.heat, tier.name())),
+                            Text::new(tr_with(
+                                "roster.heat",
+                                &[("heat", &dealer.character.heat.to_string()), ("tier", tier.name())],
+                            )),
                             TextFont::from_font_size(12.0),
                             TextColor(Color::srgb(r, g, b)),
                         ));
@@ -690,51 +702,35 @@ pub fn populate_roster_panel_system(
                             .map(|a| a.name.to_uppercase())
                             .unwrap_or_else(|| dealer.station.to_uppercase());
                         parent.spawn((
-                            Text::new(format!(
-                                "{} · CRED {}",
-                                station_name,
-                                dealer.cred_in(&dealer.station)
+                            Text::new(tr_with(
+                                "roster.station_cred",
+                                &[
+                                    ("station", &station_name),
+                                    ("cred", &dealer.cred_in(&dealer.station).to_string()),
+                                ],
                             )),
                             TextFont::from_font_size(11.0),
                             TextColor(theme::ROSTER_STATION_TEXT),
                         ));
 
-                        // Status
-                        if let Some(runs) = jailed {
-                            parent.spawn((
-                                Text::new(format!(
-                                    "JAILED · {} RUN{}",
-                                    runs,
-                                    if runs == 1 { "" } else { "S" }
-                                )),
-                                TextFont::from_font_size(12.0),
-                                TextColor(theme::ROSTER_STATUS_JAILED),
-                            ));
-                        } else if let Some(runs) = dealer.relocating_remaining() {
-                            // SOW-025: getting established in the new station
-                            parent.spawn((
-                                Text::new(format!(
-                                    "MOVING · {} RUN{}",
-                                    runs,
-                                    if runs == 1 { "" } else { "S" }
-                                )),
-                                TextFont::from_font_size(12.0),
-                                TextColor(theme::ROSTER_STATUS_MOVING),
-                            ));
-                        } else if let Some(runs) = dealer.laying_low_remaining() {
-                            // SOW-027: gone dark - resurfaces cooler
+                        // Status (the map chip's note - SOW-025 moving: getting
+                        // established; SOW-027 laying low: resurfaces cooler)
+                        let status_color = if jailed.is_some() {
+                            theme::ROSTER_STATUS_JAILED
+                        } else if dealer.relocating_remaining().is_some() {
+                            theme::ROSTER_STATUS_MOVING
+                        } else {
+                            theme::ROSTER_STATUS_LAYING_LOW
+                        };
+                        if let Some(note) = crate::ui::map_view::chip_status_note(dealer) {
                             parent.spawn((
-                                Text::new(format!(
-                                    "LAYING LOW · {} RUN{}",
-                                    runs,
-                                    if runs == 1 { "" } else { "S" }
-                                )),
+                                Text::new(note),
                                 TextFont::from_font_size(12.0),
-                                TextColor(theme::ROSTER_STATUS_LAYING_LOW),
+                                TextColor(status_color),
                             ));
                         } else {
                             parent.spawn((
-                                Text::new("READY"),
+                                Text::new(tr("hub.ready")),
                                 TextFont::from_font_size(12.0),
                                 TextColor(theme::ROSTER_STATUS_READY),
                             ));
@@ -764,7 +760,7 @@ pub fn populate_roster_panel_system(
                             ))
                             .with_children(|parent| {
                                 parent.spawn((
-                                    Text::new(format!("BAIL\n${cost}")),
+                                    Text::new(tr_with("roster.bail", &[("cost", &cost.to_string())])),
                                     TextFont::from_font_size(12.0),
                                     TextColor(Color::WHITE),
                                     TextLayout::new_with_justify(bevy::text::Justify::Center),
@@ -787,21 +783,54 @@ pub fn populate_roster_panel_system(
                                 ..default()
                             })
                             .with_children(|parent| {
+                                // SOW-042: ORDER - what this dealer does with
+                                // the day while someone else runs in person
+                                if !is_active {
+                                    parent
+                                        .spawn((
+                                            Button,
+                                            Node {
+                                                padding: UiRect::axes(Val::Px(8.0), Val::Px(6.0)),
+                                                border_radius: BorderRadius::all(Val::Px(6.0)),
+                                                justify_content: JustifyContent::Center,
+                                                ..default()
+                                            },
+                                            BackgroundColor(theme::ROSTER_ORDER_BG),
+                                            RosterOrderButton { dealer_index: index },
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                Text::new(crate::ui::day_view::order_label(
+                                                    &dealer.order,
+                                                    &game_assets.shop_locations,
+                                                )),
+                                                TextFont::from_font_size(10.0),
+                                                TextColor(Color::WHITE),
+                                                TextLayout::new_with_justify(
+                                                    bevy::text::Justify::Center,
+                                                ),
+                                            ));
+                                        });
+                                }
+
                                 // SOW-025: MOVE - first unlocked area that
                                 // isn't their station; the full area picker
                                 // arrives with the map screen SOW.
-                                let move_target = game_assets
-                                    .shop_locations
-                                    .iter()
-                                    .find(|a| {
-                                        a.id != dealer.station
-                                            && save_data
-                                                .account
-                                                .unlocked_locations
-                                                .contains(&a.id)
-                                    });
-                                if let Some(target) = move_target {
-                                    let fee = save_data.move_fee();
+                                // SOW-042: shared with the MOVE order
+                                let move_target = crate::ui::day_view::move_target(
+                                    &game_assets.shop_locations,
+                                    &save_data,
+                                    dealer,
+                                );
+                                // SOW-043: priced by the zone graph's route
+                                let move_route = move_target.and_then(|target| {
+                                    game_assets
+                                        .zone_graph
+                                        .route(&dealer.station, &target.id)
+                                        .map(|route| (target, route))
+                                });
+                                if let Some((target, route)) = move_route {
+                                    let fee = route.cost;
                                     let affordable = cash >= fee;
                                     parent
                                         .spawn((
@@ -824,9 +853,13 @@ pub fn populate_roster_panel_system(
                                         ))
                                         .with_children(|parent| {
                                             parent.spawn((
-                                                Text::new(format!(
-                                                    "MOVE TO\n{}\n${fee}",
-                                                    target.name.to_uppercase()
+                                                Text::new(tr_with(
+                                                    "roster.move_to",
+                                                    &[
+                                                        ("area", &target.name.to_uppercase()),
+                                                        ("fee", &fee.to_string()),
+                                                        ("runs", &crate::ui::map_view::runs_out(route.runs)),
+                                                    ],
                                                 )),
                                                 TextFont::from_font_size(10.0),
                                                 TextColor(Color::WHITE),
@@ -861,12 +894,15 @@ pub fn populate_roster_panel_system(
                                     ))
                                     .with_children(|parent| {
                                         let label = if !has_heat {
-                                            "LAY LOW\nNO HEAT".to_string()
+                                            tr("roster.lay_low_no_heat")
                                         } else {
-                                            format!(
-                                                "LAY LOW\n{} RUNS · -{}\n${lay_low_cost}",
-                                                crate::save::LAY_LOW_RUNS,
-                                                crate::save::LAY_LOW_COOLING,
+                                            tr_with(
+                                                "roster.lay_low",
+                                                &[
+                                                    ("runs", &crate::ui::map_view::runs_text(crate::save::LAY_LOW_RUNS)),
+                                                    ("cooling", &crate::save::LAY_LOW_COOLING.to_string()),
+                                                    ("cost", &lay_low_cost.to_string()),
+                                                ],
                                             )
                                         };
                                         parent.spawn((
@@ -899,11 +935,14 @@ pub fn populate_roster_panel_system(
                                     ))
                                     .with_children(|parent| {
                                         let label = if !has_heat {
-                                            "LAWYER\nNO HEAT".to_string()
+                                            tr("roster.lawyer_no_heat")
                                         } else {
-                                            format!(
-                                                "LAWYER\n-{} NOW\n${lawyer_cost}",
-                                                crate::save::LAWYER_COOLING,
+                                            tr_with(
+                                                "roster.lawyer",
+                                                &[
+                                                    ("cooling", &crate::save::LAWYER_COOLING.to_string()),
+                                                    ("cost", &lawyer_cost.to_string()),
+                                                ],
                                             )
                                         };
                                         parent.spawn((
@@ -938,7 +977,7 @@ pub fn populate_roster_panel_system(
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("Hire dealers on the\nCITY MAP"),
+                    Text::new(tr("hub.hire_on_map")),
                     TextFont::from_font_size(12.0),
                     TextColor(theme::ROSTER_STATION_TEXT),
                     TextLayout::new_with_justify(bevy::text::Justify::Center),
@@ -963,9 +1002,10 @@ pub fn update_deck_builder_ui_system(
         let validation = validate_deck(&deck_builder.selected_cards);
 
         let is_valid = validation.is_ok();
+        let (count, max) = (count.to_string(), crate::save::MAX_DECK_SIZE.to_string());
         **text = match validation {
-            Ok(_) => format!("Deck: {count}/20 cards ✓ VALID"),
-            Err(msg) => format!("Deck: {count}/20 cards ✗ {msg}"),
+            Ok(_) => tr_with("deck.valid", &[("count", &count), ("max", &max)]),
+            Err(msg) => tr_with("deck.invalid", &[("count", &count), ("max", &max), ("reason", &msg)]),
         };
 
         text_color.0 = if is_valid {
@@ -976,6 +1016,97 @@ pub fn update_deck_builder_ui_system(
     }
 }
 
+/// SOW-041: the active dealer's loadout shelf - one LOAD + SAVE pair per
+/// filled slot, then a single SAVE into the next free slot while the shelf
+/// has room. A LOAD shows how many of its cards are upgraded. Rebuilt when
+/// the save changes (dealer switch, save, load).
+pub fn populate_loadout_row_system(
+    mut commands: Commands,
+    save_data: Option<Res<crate::save::SaveData>>,
+    deck_builder: Option<Res<DeckBuilder>>,
+    row_query: Query<Entity, With<LoadoutRow>>,
+    children_query: Query<&Children>,
+) {
+    let (Some(save_data), Some(deck_builder)) = (save_data, deck_builder) else {
+        return;
+    };
+    let Ok(row) = row_query.single() else {
+        return; // row only exists on the deck-builder screen
+    };
+
+    let is_empty = children_query.get(row).map(|c| c.is_empty()).unwrap_or(true);
+    if !save_data.is_changed() && !is_empty {
+        return;
+    }
+
+    if let Ok(children) = children_query.get(row) {
+        for child in children.iter() {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let loadouts = &save_data.active_dealer_state().loadouts;
+    commands.entity(row).with_children(|parent| {
+        for (slot, loadout) in loadouts.iter().enumerate() {
+            let upgraded = deck_builder.upgraded_in(&loadout.cards, save_data.active_character());
+            let (number, name) = ((slot + 1).to_string(), loadout.name.to_uppercase());
+            let label = match upgraded {
+                0 => tr_with("deck.load", &[("slot", &number), ("name", &name)]),
+                n => tr_with(
+                    "deck.load_upgraded",
+                    &[("slot", &number), ("name", &name), ("upgraded", &n.to_string())],
+                ),
+            };
+            spawn_loadout_button(
+                parent,
+                label,
+                theme::ROSTER_MOVE_BG,
+                LoadoutLoadButton { slot },
+            );
+            spawn_loadout_button(
+                parent,
+                tr("deck.save"),
+                theme::BUTTON_NEUTRAL_BG,
+                LoadoutSaveButton { slot },
+            );
+        }
+        if loadouts.len() < crate::save::MAX_LOADOUTS {
+            spawn_loadout_button(
+                parent,
+                tr_with("deck.save_as", &[("slot", &(loadouts.len() + 1).to_string())]),
+                theme::BUTTON_NEUTRAL_BG,
+                LoadoutSaveButton { slot: loadouts.len() },
+            );
+        }
+    });
+}
+
+fn spawn_loadout_button(
+    parent: &mut ChildSpawnerCommands,
+    label: String,
+    bg: Color,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                border_radius: BorderRadius::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(bg),
+            marker,
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(label),
+                TextFont::from_font_size(12.0),
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
 pub fn populate_deck_builder_cards_system(
     mut commands: Commands,
     deck_builder: Option<Res<DeckBuilder>>,
@@ -1120,11 +1251,14 @@ pub fn update_actor_portraits_system(
 
     // SOW-033: narc portrait follows the current run area (keyed by area id
     // in actor_portraits). Every area is mapped at load, so the run-area
-    // lookup resolves; the fallback is defensive.
+    // lookup resolves; the fallback is defensive. SOW-061: an operation
+    // with its own face (keyed by operation id) shows it instead.
     if let Ok(mut narc_image) = narc_portrait_query.single_mut() {
-        let portrait_handle = game_assets
-            .actor_portraits
-            .get(&hand_state.run_area)
+        let portrait_handle = hand_state
+            .operation
+            .as_ref()
+            .and_then(|op| game_assets.actor_portraits.get(&op.id))
+            .or_else(|| game_assets.actor_portraits.get(&hand_state.run_area))
             .or_else(|| game_assets.actor_portraits.get("red_light_district"));
         if let Some(portrait_handle) = portrait_handle {
             if narc_image.image != *portrait_handle {
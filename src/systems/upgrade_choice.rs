@@ -2,10 +2,18 @@
 // SOW-021: All pending upgrades are presented on ONE batched screen (rows per
 // card) instead of one full-screen modal per card, and choices are deferrable
 // via DECIDE LATER (skipped upgrades stay pending for the next return home).
+// SOW-057: a card that qualifies for an authored evolution gets a third
+// option on its row - EVOLVE into the variant instead of a stat bonus.
+// SOW-058: options may be perks (rarer, qualitative upgrades), and each row
+// has a paid REROLL that draws two new options.
 
 use bevy::prelude::*;
+use crate::assets::GameAssets;
+use crate::locale::{tr, tr_with};
 use crate::game_state::GameState;
-use crate::save::{SaveData, SaveManager, UpgradeableStat};
+use crate::models::deck_builder::DeckBuilder;
+use crate::save::{SaveData, SaveManager, UpgradeChoice, UpgradePerk, UpgradeableStat};
+use crate::ui::view::format_cash;
 
 // Color constants for upgrade UI
 const HEADING_COLOR: Color = Color::srgb(1.0, 0.9, 0.4);
@@ -14,6 +22,8 @@ const BUTTON_BG: Color = Color::srgb(0.2, 0.2, 0.25);
 const BUTTON_BG_HOVER: Color = Color::srgb(0.3, 0.3, 0.35);
 const BUTTON_BORDER: Color = Color::srgb(0.4, 0.4, 0.5);
 const BUTTON_BORDER_HOVER: Color = Color::srgb(0.6, 0.6, 0.7);
+const EVOLVE_BORDER: Color = Color::srgb(1.0, 0.84, 0.0); // Foil gold
+const PERK_BORDER: Color = Color::srgb(0.65, 0.4, 0.95); // SOW-058: rare purple
 
 /// Marker for upgrade choice UI root
 #[derive(Component)]
@@ -24,7 +34,18 @@ pub struct UpgradeChoiceUI;
 #[derive(Component)]
 pub struct UpgradeOptionButton {
     pub card_name: String,
-    pub stat: UpgradeableStat,
+    pub option: UpgradeOption,
+}
+
+/// SOW-057: what an option button resolves the row with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeOption {
+    /// One of the two drawn options (SOW-058: a stat or a perk)
+    Choice(UpgradeChoice),
+    /// The row's evolution offer
+    Evolve,
+    /// SOW-058: pay to draw two new options
+    Reroll,
 }
 
 /// SOW-021: Marker for the DECIDE LATER button
@@ -86,7 +107,7 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
         ));
 
         parent.spawn((
-            Text::new("Choose a bonus for each card, or decide later"),
+            Text::new(tr("upgrade.hint")),
             TextFont {
                 font_size: 18.0,
                 ..default()
@@ -114,8 +135,26 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
             ScrollPosition::default(),
         )).with_children(|parent| {
         for pending in &character.pending_upgrades {
-            let card_type = pending.card_type.clone();
-            let options = pending.options;
+            let mut options: Vec<(UpgradeOption, String, String)> = pending
+                .options
+                .iter()
+                .map(|&choice| {
+                    let (stat_name, effect_desc) = get_choice_description(choice);
+                    (UpgradeOption::Choice(choice), stat_name, effect_desc)
+                })
+                .collect();
+            if let Some(offer) = &pending.evolution {
+                options.push((
+                    UpgradeOption::Evolve,
+                    tr("upgrade.evolve"),
+                    tr_with("upgrade.evolve_into", &[("name", &offer.into_name)]),
+                ));
+            }
+            options.push((
+                UpgradeOption::Reroll,
+                tr("upgrade.reroll"),
+                format_cash(pending.reroll_cost()),
+            ));
             let card_name = pending.card_name.clone();
             let tier_label = format!("{} → ★ Tier upgrade", pending.card_name);
 
@@ -143,14 +182,12 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
                 });
 
                 // Option buttons
-                for stat in options {
-                    let (stat_name, effect_desc) = get_stat_description(stat, &card_type);
-
+                for (option, stat_name, effect_desc) in options {
                     row.spawn((
                         Button,
                         Interaction::default(), // Explicitly add for Bevy 0.18
                         Node {
-                            width: Val::Px(180.0),
+                            width: Val::Px(if option == UpgradeOption::Reroll { 110.0 } else { 180.0 }),
                             height: Val::Px(70.0),
                             flex_direction: FlexDirection::Column,
                             justify_content: JustifyContent::Center,
@@ -159,9 +196,9 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
                             border: UiRect::all(Val::Px(3.0)),
                             ..default()
                         },
-                        BorderColor::all(BUTTON_BORDER),
+                        BorderColor::all(idle_border(option)),
                         BackgroundColor(BUTTON_BG),
-                        UpgradeOptionButton { card_name: card_name.clone(), stat },
+                        UpgradeOptionButton { card_name: card_name.clone(), option },
                     )).with_children(|btn| {
                         btn.spawn((
                             Text::new(stat_name),
@@ -201,7 +238,7 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
             UpgradeDeferButton,
         )).with_children(|btn| {
             btn.spawn((
-                Text::new("DECIDE LATER"),
+                Text::new(tr("upgrade.decide_later")),
                 TextFont {
                     font_size: 18.0,
                     ..default()
@@ -212,17 +249,48 @@ fn spawn_upgrade_choice_ui(commands: &mut Commands, save_data: &SaveData) {
     });
 }
 
-fn get_stat_description(stat: UpgradeableStat, _card_type: &crate::models::card::CardType) -> (String, String) {
-    match stat {
-        UpgradeableStat::Price => ("PRICE".to_string(), "+10% profit".to_string()),
-        UpgradeableStat::Cover => ("COVER".to_string(), "+10% cover".to_string()),
-        UpgradeableStat::Evidence => ("EVIDENCE".to_string(), "-10% evidence".to_string()),
-        UpgradeableStat::Heat => ("HEAT".to_string(), "-10% heat".to_string()),
-        UpgradeableStat::HeatPenalty => ("HEAT PENALTY".to_string(), "-10% penalty".to_string()),
-        UpgradeableStat::PriceMultiplier => ("PRICE BONUS".to_string(), "+10% multiplier".to_string()),
+/// SOW-057: an evolution stands out in foil gold (SOW-058: a perk in
+/// purple)
+fn idle_border(option: UpgradeOption) -> Color {
+    match option {
+        UpgradeOption::Evolve => EVOLVE_BORDER,
+        UpgradeOption::Choice(UpgradeChoice::Perk(_)) => PERK_BORDER,
+        UpgradeOption::Choice(UpgradeChoice::Stat(_)) | UpgradeOption::Reroll => BUTTON_BORDER,
+    }
+}
+
+fn get_choice_description(choice: UpgradeChoice) -> (String, String) {
+    match choice {
+        UpgradeChoice::Stat(stat) => get_stat_description(stat),
+        UpgradeChoice::Perk(perk) => get_perk_description(perk),
     }
 }
 
+/// SOW-058 (SOW-053: upgrade.perk.<name> and its _desc line)
+fn get_perk_description(perk: UpgradePerk) -> (String, String) {
+    let key = match perk {
+        UpgradePerk::ConvictionImmunity => "upgrade.perk.immunity",
+        UpgradePerk::FoldRefund => "upgrade.perk.refund",
+        UpgradePerk::StreetCred => "upgrade.perk.street_cred",
+        UpgradePerk::CheapInsurance => "upgrade.perk.discount",
+    };
+    let pct = crate::save::CHEAP_INSURANCE_DISCOUNT_PCT.to_string();
+    (tr(key), tr_with(&format!("{key}_desc"), &[("pct", &pct)]))
+}
+
+/// SOW-053: upgrade.stat.<name> and its _desc line
+fn get_stat_description(stat: UpgradeableStat) -> (String, String) {
+    let key = match stat {
+        UpgradeableStat::Price => "upgrade.stat.price",
+        UpgradeableStat::Cover => "upgrade.stat.cover",
+        UpgradeableStat::Evidence => "upgrade.stat.evidence",
+        UpgradeableStat::Heat => "upgrade.stat.heat",
+        UpgradeableStat::HeatPenalty => "upgrade.stat.heat_penalty",
+        UpgradeableStat::PriceMultiplier => "upgrade.stat.price_multiplier",
+    };
+    (tr(key), tr(&format!("{key}_desc")))
+}
+
 /// Cleanup the upgrade choice UI
 pub fn cleanup_upgrade_choice_ui(
     mut commands: Commands,
@@ -263,13 +331,29 @@ pub fn upgrade_option_click_system(
         .map(|p| (p.card_name.clone(), p.options));
 
     // Helper to apply an upgrade for a specific card and refresh the screen
-    let mut handle_upgrade = |card_name: &str, stat: UpgradeableStat| {
-        let (applied, has_more) = {
-            let character = save_data.active_character_mut();
-            let applied = character.apply_upgrade_choice_for(card_name, stat);
-            let has_more = character.has_pending_upgrades();
-            (applied, has_more)
+    let mut handle_upgrade = |card_name: &str, option: UpgradeOption| {
+        let applied = match option {
+            UpgradeOption::Choice(choice) => save_data.active_character_mut().apply_upgrade_choice_for(card_name, choice),
+            UpgradeOption::Evolve => save_data.active_character_mut().apply_evolution_for(card_name),
+            // SOW-058: a reroll redraws the row in place
+            UpgradeOption::Reroll => match save_data.buy_upgrade_reroll(card_name) {
+                Ok(cost) => {
+                    info!("Rerolled the {} upgrade for ${}", card_name, cost);
+                    true
+                }
+                Err(reason) => {
+                    info!("Reroll refused: {}", reason);
+                    false
+                }
+            },
         };
+        let has_more = save_data.active_character().has_pending_upgrades();
+
+        // SOW-057: the hub's deck builder still holds the original - drop
+        // it so the hub rebuilds it with the variant
+        if applied && option == UpgradeOption::Evolve {
+            commands.remove_resource::<DeckBuilder>();
+        }
 
         if applied {
             if let Err(e) = save_manager.save(&save_data) {
@@ -289,14 +373,19 @@ pub fn upgrade_option_click_system(
         }
     };
 
-    // Keyboard shortcuts: 1 / 2 resolve the FIRST pending upgrade's options
+    // Keyboard shortcuts: 1 / 2 (/ 3 to evolve) resolve the FIRST pending
+    // upgrade's options
     if let Some((card_name, options)) = first_pending {
         if keyboard.just_pressed(KeyCode::Digit1) {
-            handle_upgrade(&card_name, options[0]);
+            handle_upgrade(&card_name, UpgradeOption::Choice(options[0]));
             return;
         }
         if keyboard.just_pressed(KeyCode::Digit2) {
-            handle_upgrade(&card_name, options[1]);
+            handle_upgrade(&card_name, UpgradeOption::Choice(options[1]));
+            return;
+        }
+        if keyboard.just_pressed(KeyCode::Digit3) {
+            handle_upgrade(&card_name, UpgradeOption::Evolve);
             return;
         }
     }
@@ -305,8 +394,7 @@ pub fn upgrade_option_click_system(
         match *interaction {
             Interaction::Pressed => {
                 let card_name = option.card_name.clone();
-                let stat = option.stat;
-                handle_upgrade(&card_name, stat);
+                handle_upgrade(&card_name, option.option);
             }
             Interaction::Hovered => {
                 *bg_color = BackgroundColor(BUTTON_BG_HOVER);
@@ -314,7 +402,27 @@ pub fn upgrade_option_click_system(
             }
             Interaction::None => {
                 *bg_color = BackgroundColor(BUTTON_BG);
-                *border_color = BorderColor::all(BUTTON_BORDER);
+                *border_color = BorderColor::all(idle_border(option.option));
+            }
+        }
+    }
+}
+
+/// SOW-057: offer an evolution on every pending upgrade whose card
+/// qualifies, for the whole roster (off-screen runs queue upgrades too).
+/// Runs before the upgrade screen is checked.
+pub fn offer_evolutions_system(mut save_data: ResMut<SaveData>, game_assets: Res<GameAssets>) {
+    for dealer in &mut save_data.dealers {
+        let unoffered: Vec<String> = dealer
+            .character
+            .pending_upgrades
+            .iter()
+            .filter(|p| p.evolution.is_none())
+            .map(|p| p.card_name.clone())
+            .collect();
+        for name in unoffered {
+            if let Some(card) = game_assets.player_card_named(&name) {
+                dealer.character.offer_evolution(card, |id| game_assets.player_card(id));
             }
         }
     }
@@ -345,3 +453,32 @@ pub fn check_pending_upgrades_system(
         next_state.set(GameState::UpgradeChoice);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_choice_reads_from_the_table() {
+        let perks = [
+            UpgradePerk::ConvictionImmunity,
+            UpgradePerk::FoldRefund,
+            UpgradePerk::StreetCred,
+            UpgradePerk::CheapInsurance,
+        ];
+        let stats = [
+            UpgradeableStat::Price,
+            UpgradeableStat::Cover,
+            UpgradeableStat::Evidence,
+            UpgradeableStat::Heat,
+            UpgradeableStat::HeatPenalty,
+            UpgradeableStat::PriceMultiplier,
+        ];
+        let choices = perks.map(UpgradeChoice::Perk).into_iter().chain(stats.map(UpgradeChoice::Stat));
+        for (name, desc) in choices.map(get_choice_description) {
+            assert!(!name.starts_with("upgrade.") && !desc.starts_with("upgrade."), "{name} / {desc} has no string");
+        }
+        let discount = format!("-{}% insurance cost", crate::save::CHEAP_INSURANCE_DISCOUNT_PCT);
+        assert_eq!(get_perk_description(UpgradePerk::CheapInsurance), ("DISCOUNT".to_string(), discount));
+    }
+}
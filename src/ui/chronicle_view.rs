@@ -0,0 +1,653 @@
+// SOW-051: Empire chronicle - the Kingpin Ledger, written down.
+//
+// Pure presentation, same rule as ledger_view.rs: everything here takes
+// &SaveData (or an epitaph's archive) and returns a document; writing it
+// to disk and copying the portraits is save::chronicle's job. The output is
+// deterministic for a given save - no "exported at" stamp, stable ordering
+// everywhere - so two exports of the same empire diff line by line.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::hand_state::HandOutcome;
+use crate::models::shop_location::ShopLocationDef;
+use crate::save::{
+    ChronicleEntry, ChronicleEvent, DealerState, FrontState, Retirement, SaveData, StoryRecord,
+    SupplierStanding,
+};
+
+use super::ledger_view::outcome_label;
+use super::view::format_cash;
+
+/// Which empire to write up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChronicleSubject {
+    /// The living empire
+    Current,
+    /// Index into save.fallen_empires (oldest first)
+    Fallen(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChronicleFormat {
+    Markdown,
+    Html,
+}
+
+impl ChronicleFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" => Some(Self::Html),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+/// Everything a chronicle reads, from the living save or an epitaph
+pub struct Chronicle<'a> {
+    pub title: String,
+    /// None while the empire stands
+    pub ended_at: Option<u64>,
+    pub lifetime_revenue: u64,
+    /// Cash on hand, or at the fall
+    pub cash: u64,
+    pub roster: &'a [DealerState],
+    /// Outstanding fronts (the fallen carry none - debts die with the empire)
+    pub fronts: &'a [FrontState],
+    pub standings: Option<&'a HashMap<String, SupplierStanding>>,
+    pub entries: &'a [ChronicleEntry],
+    /// Every story, oldest first
+    pub stories: Vec<&'a StoryRecord>,
+    /// SOW-062: Some for an empire that cashed out rather than fell
+    pub retirement: Option<&'a Retirement>,
+}
+
+impl<'a> Chronicle<'a> {
+    /// Gather a subject from the save. None for a fallen index that isn't there.
+    pub fn of(save: &'a SaveData, subject: ChronicleSubject) -> Option<Self> {
+        let mut chronicle = match subject {
+            ChronicleSubject::Current => Self {
+                title: "The Living Empire".to_string(),
+                ended_at: None,
+                lifetime_revenue: save.account.lifetime_revenue,
+                cash: save.account.cash_on_hand,
+                roster: &save.dealers,
+                fronts: &save.fronts,
+                standings: Some(&save.supplier_standing),
+                entries: &save.chronicle,
+                stories: save
+                    .dynasty
+                    .iter()
+                    .map(|reign| &reign.kingpin)
+                    .chain(&save.dealers)
+                    .flat_map(|d| d.character.story_history.iter())
+                    .collect(),
+                retirement: None,
+            },
+            ChronicleSubject::Fallen(i) => {
+                let epitaph = save.fallen_empires.get(i)?;
+                let kind = if epitaph.retirement.is_some() { "Retired" } else { "Fallen" };
+                Self {
+                    title: format!("{kind} Empire #{}", i + 1),
+                    ended_at: Some(epitaph.ended_at),
+                    lifetime_revenue: epitaph.lifetime_revenue,
+                    cash: epitaph.cash_at_fall,
+                    roster: &epitaph.roster,
+                    fronts: &[],
+                    standings: None,
+                    entries: &epitaph.chronicle,
+                    stories: epitaph.stories.iter().collect(),
+                    retirement: epitaph.retirement.as_ref(),
+                }
+            }
+        };
+        // Stable: same-second stories keep their per-dealer archive order
+        chronicle.stories.sort_by_key(|s| s.timestamp);
+        Some(chronicle)
+    }
+}
+
+// ============================================================================
+// Document model - one tree, two renderers
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(u8, String),
+    Para(String),
+    List(Vec<String>),
+    Table { header: Vec<String>, rows: Vec<Vec<String>> },
+    /// Relative path (portraits/...) and alt text
+    Image { src: String, alt: String },
+}
+
+/// "2024-03-09" (UTC) from Unix seconds - civil-from-days, no date crate
+pub fn format_date(secs: u64) -> String {
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Area display name, raw id for areas missing from content
+fn zone_name(areas: &[ShopLocationDef], id: &str) -> String {
+    areas
+        .iter()
+        .find(|a| a.id == id)
+        .map_or_else(|| id.to_string(), |a| a.name.clone())
+}
+
+/// One chronicle line in plain words
+pub fn describe_event(event: &ChronicleEvent, areas: &[ShopLocationDef]) -> String {
+    let zone = |id: &str| zone_name(areas, id);
+    match event {
+        ChronicleEvent::Hired { dealer, area_id, cost } => {
+            format!("Hired {dealer} in {} for {}", zone(area_id), format_cash(*cost))
+        }
+        ChronicleEvent::ZoneBought { area_id, price } => {
+            format!("Bought into {} for {}", zone(area_id), format_cash(*price))
+        }
+        ChronicleEvent::FrontTaken { card_id, area_id, owed } => {
+            format!("Took a front on {card_id} from {} ({} owed)", zone(area_id), format_cash(*owed))
+        }
+        ChronicleEvent::FrontPaid { card_id, area_id, owed } => {
+            format!("Paid off the {card_id} front in {} ({})", zone(area_id), format_cash(*owed))
+        }
+        ChronicleEvent::CutOff { area_id } => {
+            format!("Missed a due date - {}'s supplier cut us off", zone(area_id))
+        }
+        ChronicleEvent::MuscleSeized { area_id, amount } => {
+            format!("{} muscle seized {}", zone(area_id), format_cash(*amount))
+        }
+        ChronicleEvent::MuscleBenched { area_id, dealer } => {
+            format!("{} muscle put {dealer} on the bench", zone(area_id))
+        }
+        ChronicleEvent::Soured { area_id, card_id } => {
+            format!("{} repossessed the {card_id} and burned the bridge", zone(area_id))
+        }
+        ChronicleEvent::Jailed { dealer, runs, heat } => {
+            let plural = if *runs == 1 { "" } else { "s" };
+            format!("{dealer} busted at heat {heat} - {runs} run{plural} inside")
+        }
+        ChronicleEvent::BailedOut { dealer, cost } => {
+            format!("Bailed {dealer} out for {}", format_cash(*cost))
+        }
+        ChronicleEvent::Fell { lifetime_revenue } => {
+            format!("The kingpin went down. The empire fell at {} lifetime", format_cash(*lifetime_revenue))
+        }
+        ChronicleEvent::Operation { name, dealer, beaten: true } => format!("{dealer} walked away from {name}"),
+        ChronicleEvent::Operation { name, dealer, beaten: false } => format!("{name} took {dealer} down"),
+        ChronicleEvent::Retired { score } => {
+            format!("The kingpin cashed out and retired, scoring {}", format_cash(*score))
+        }
+        ChronicleEvent::Succeeded { fallen, heir, zones_lost, seized } => {
+            let plural = if *zones_lost == 1 { "" } else { "s" };
+            format!(
+                "{fallen} went down; {heir} took over - {} seized, {zones_lost} zone{plural} lost",
+                format_cash(*seized)
+            )
+        }
+    }
+}
+
+/// Busts, jail and the fall get their own section; the rest is the books
+fn is_bust(event: &ChronicleEvent) -> bool {
+    matches!(
+        event,
+        ChronicleEvent::Jailed { .. }
+            | ChronicleEvent::BailedOut { .. }
+            | ChronicleEvent::MuscleBenched { .. }
+            | ChronicleEvent::Fell { .. }
+            | ChronicleEvent::Succeeded { .. }
+            | ChronicleEvent::Operation { beaten: false, .. }
+    )
+}
+
+fn event_area(event: &ChronicleEvent) -> Option<&str> {
+    match event {
+        ChronicleEvent::Hired { area_id, .. }
+        | ChronicleEvent::ZoneBought { area_id, .. }
+        | ChronicleEvent::FrontTaken { area_id, .. }
+        | ChronicleEvent::FrontPaid { area_id, .. }
+        | ChronicleEvent::CutOff { area_id }
+        | ChronicleEvent::MuscleSeized { area_id, .. }
+        | ChronicleEvent::MuscleBenched { area_id, .. }
+        | ChronicleEvent::Soured { area_id, .. } => Some(area_id),
+        ChronicleEvent::Jailed { .. }
+        | ChronicleEvent::BailedOut { .. }
+        | ChronicleEvent::Fell { .. }
+        | ChronicleEvent::Operation { .. }
+        | ChronicleEvent::Retired { .. }
+        | ChronicleEvent::Succeeded { .. } => None,
+    }
+}
+
+fn dealer_status(dealer: &DealerState) -> String {
+    match super::map_view::chip_status_note(dealer) {
+        Some(note) => note,
+        None => "AVAILABLE".to_string(),
+    }
+}
+
+/// The portrait path inside the export (the exporter copies the file there)
+pub fn portrait_src(dealer: &DealerState) -> String {
+    format!("portraits/{}", crate::assets::loader::dealer_portrait_file(&dealer.portrait))
+}
+
+/// The whole chronicle as a document
+pub fn chronicle_blocks(chronicle: &Chronicle, areas: &[ShopLocationDef]) -> Vec<Block> {
+    let mut blocks = vec![Block::Heading(1, chronicle.title.clone())];
+    blocks.push(Block::Para(match (chronicle.ended_at, chronicle.retirement) {
+        (Some(at), Some(retirement)) => format!(
+            "Retired {} with {} lifetime revenue, scoring {}.",
+            format_date(at),
+            format_cash(chronicle.lifetime_revenue),
+            format_cash(retirement.score)
+        ),
+        (Some(at), None) => format!("Fell {} with {} lifetime revenue.", format_date(at), format_cash(chronicle.lifetime_revenue)),
+        (None, _) => format!("Still standing, with {} lifetime revenue.", format_cash(chronicle.lifetime_revenue)),
+    }));
+
+    // -- summary --
+    let roster = chronicle.roster;
+    let mut summary = vec![
+        format!("Lifetime revenue: {}", format_cash(chronicle.lifetime_revenue)),
+        format!(
+            "{}: {}",
+            match (chronicle.ended_at, chronicle.retirement) {
+                (Some(_), Some(_)) => "Cash at retirement",
+                (Some(_), None) => "Cash at the fall",
+                (None, _) => "Cash on hand",
+            },
+            format_cash(chronicle.cash)
+        ),
+        format!("Decks played: {}", roster.iter().map(|d| d.character.decks_played).sum::<u32>()),
+        format!("Dealers hired: {}", roster.len().saturating_sub(1)),
+        format!("Convictions: {}", roster.iter().map(|d| d.prior_convictions).sum::<u32>()),
+        format!("Stories told: {}", chronicle.stories.len()),
+    ];
+    let debt: u64 = chronicle.fronts.iter().map(|f| f.owed).sum();
+    if debt > 0 {
+        summary.push(format!("Owed to suppliers: {}", format_cash(debt)));
+    }
+    if let Some(retirement) = chronicle.retirement.filter(|r| !r.victories.is_empty()) {
+        summary.push(format!("Victories: {}", retirement.victories.join(", ")));
+    }
+    blocks.push(Block::Heading(2, "Summary".to_string()));
+    blocks.push(Block::List(summary));
+
+    // -- roster dossiers --
+    blocks.push(Block::Heading(2, "Roster".to_string()));
+    if roster.is_empty() {
+        blocks.push(Block::Para("No roster was archived with this empire.".to_string()));
+    }
+    for dealer in roster {
+        let name = if dealer.is_kingpin { format!("{} (boss)", dealer.name) } else { dealer.name.clone() };
+        blocks.push(Block::Heading(3, name));
+        blocks.push(Block::Image { src: portrait_src(dealer), alt: dealer.name.clone() });
+        let mut cred: Vec<(&String, &u32)> = dealer.street_cred.iter().filter(|(_, c)| **c > 0).collect();
+        cred.sort_by_key(|(id, _)| id.as_str());
+        let cred = cred
+            .into_iter()
+            .map(|(id, c)| format!("{} {c}", zone_name(areas, id)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let tier = dealer.character.heat_tier();
+        let mut lines = vec![
+            format!("Station: {}", zone_name(areas, &dealer.station)),
+            format!("Status: {}", dealer_status(dealer)),
+            format!("Heat: {} ({})", dealer.character.heat, tier.name()),
+            format!("Decks played: {}", dealer.character.decks_played),
+            format!("Deals closed: {}", super::ledger_view::deals_closed(dealer)),
+            format!("Prior convictions: {}", dealer.prior_convictions),
+            format!("Stories: {}", dealer.character.story_history.len()),
+        ];
+        if !cred.is_empty() {
+            lines.push(format!("Street cred: {cred}"));
+        }
+        blocks.push(Block::List(lines));
+    }
+
+    // -- zone history --
+    let mut zones: BTreeMap<String, [u32; 4]> = BTreeMap::new(); // deals, stories, busts, fronts
+    for dealer in roster {
+        for (id, cred) in &dealer.street_cred {
+            zones.entry(id.clone()).or_default()[0] += cred;
+        }
+    }
+    for story in &chronicle.stories {
+        if story.area.is_empty() {
+            continue;
+        }
+        let zone = zones.entry(story.area.clone()).or_default();
+        zone[1] += 1;
+        if story.outcome == Some(HandOutcome::Busted) {
+            zone[2] += 1;
+        }
+    }
+    for entry in chronicle.entries {
+        if let Some(area) = event_area(&entry.event) {
+            let zone = zones.entry(area.to_string()).or_default();
+            if matches!(entry.event, ChronicleEvent::FrontTaken { .. }) {
+                zone[3] += 1;
+            }
+        }
+    }
+    blocks.push(Block::Heading(2, "Zones".to_string()));
+    if zones.is_empty() {
+        blocks.push(Block::Para("Nothing happened anywhere yet.".to_string()));
+    } else {
+        let rows = zones
+            .iter()
+            .map(|(id, [deals, stories, busts, fronts])| {
+                let best = roster
+                    .iter()
+                    .filter(|d| d.cred_in(id) > 0)
+                    .max_by_key(|d| d.cred_in(id))
+                    .map_or_else(|| "-".to_string(), |d| format!("{} ({})", d.name, d.cred_in(id)));
+                let supplier = match chronicle.standings.and_then(|s| s.get(id)) {
+                    Some(SupplierStanding::CutOff) => "cut off",
+                    Some(SupplierStanding::Soured) => "burned",
+                    Some(SupplierStanding::Good) | None => "good",
+                };
+                vec![
+                    zone_name(areas, id),
+                    deals.to_string(),
+                    best,
+                    stories.to_string(),
+                    busts.to_string(),
+                    fronts.to_string(),
+                    supplier.to_string(),
+                ]
+            })
+            .collect();
+        blocks.push(Block::Table {
+            header: ["Zone", "Deals", "Best dealer", "Stories", "Busts", "Fronts", "Supplier"]
+                .map(String::from)
+                .to_vec(),
+            rows,
+        });
+    }
+
+    // -- the books and the busts --
+    let lines = |busts: bool| -> Vec<Vec<String>> {
+        chronicle
+            .entries
+            .iter()
+            .filter(|e| is_bust(&e.event) == busts)
+            .map(|e| vec![format_date(e.at), describe_event(&e.event, areas)])
+            .collect()
+    };
+    for (heading, busts, empty) in [
+        ("The Books", false, "No fronts, hires or buys on the record."),
+        ("Busts and Jail Terms", true, "Nobody went inside."),
+    ] {
+        blocks.push(Block::Heading(2, heading.to_string()));
+        let rows = lines(busts);
+        if rows.is_empty() {
+            blocks.push(Block::Para(empty.to_string()));
+        } else {
+            blocks.push(Block::Table { header: vec!["Date".to_string(), "What happened".to_string()], rows });
+        }
+    }
+    if !chronicle.fronts.is_empty() {
+        blocks.push(Block::Heading(3, "Outstanding fronts".to_string()));
+        blocks.push(Block::List(
+            chronicle
+                .fronts
+                .iter()
+                .map(|f| {
+                    format!(
+                        "{} from {}: {} due in {} run{}",
+                        f.card_id,
+                        zone_name(areas, &f.area_id),
+                        format_cash(f.owed),
+                        f.runs_remaining,
+                        if f.runs_remaining == 1 { "" } else { "s" }
+                    )
+                })
+                .collect(),
+        ));
+    }
+
+    // -- stories --
+    blocks.push(Block::Heading(2, "Stories".to_string()));
+    if chronicle.stories.is_empty() {
+        blocks.push(Block::Para("Nothing on the record.".to_string()));
+    }
+    for story in &chronicle.stories {
+        let mut meta = Vec::new();
+        if story.timestamp > 0 {
+            meta.push(format_date(story.timestamp));
+        }
+        if story.run > 0 {
+            meta.push(format!("run {}", story.run));
+        }
+        if !story.dealer.is_empty() {
+            meta.push(story.dealer.clone());
+        }
+        if !story.area.is_empty() {
+            meta.push(zone_name(areas, &story.area));
+        }
+        if let Some(outcome) = story.outcome {
+            meta.push(outcome_label(outcome).to_lowercase());
+        }
+        blocks.push(Block::Para(if meta.is_empty() {
+            story.text.clone()
+        } else {
+            format!("[{}] {}", meta.join(" · "), story.text)
+        }));
+    }
+    blocks
+}
+
+// ============================================================================
+// Renderers
+// ============================================================================
+
+fn md_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+pub fn render_markdown(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                out.push_str(&format!("{} {}\n\n", "#".repeat(*level as usize), text));
+            }
+            Block::Para(text) => out.push_str(&format!("{text}\n\n")),
+            Block::List(items) => {
+                for item in items {
+                    out.push_str(&format!("- {item}\n"));
+                }
+                out.push('\n');
+            }
+            Block::Table { header, rows } => {
+                out.push_str(&format!("| {} |\n", header.iter().map(|h| md_cell(h)).collect::<Vec<_>>().join(" | ")));
+                out.push_str(&format!("|{}\n", "---|".repeat(header.len())));
+                for row in rows {
+                    out.push_str(&format!("| {} |\n", row.iter().map(|c| md_cell(c)).collect::<Vec<_>>().join(" | ")));
+                }
+                out.push('\n');
+            }
+            Block::Image { src, alt } => out.push_str(&format!("![{alt}]({src})\n\n")),
+        }
+    }
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone page - inline styles, no external assets besides the
+/// portraits copied next to it
+pub fn render_html(blocks: &[Block]) -> String {
+    let title = blocks
+        .iter()
+        .find_map(|b| match b {
+            Block::Heading(1, text) => Some(text.as_str()),
+            _ => None,
+        })
+        .unwrap_or("Chronicle");
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>body{{font-family:Georgia,serif;max-width:60em;margin:2em auto;padding:0 1em;background:#15120f;color:#e8dcc8}}\
+         table{{border-collapse:collapse}}td,th{{border:1px solid #5a4a38;padding:.3em .6em;text-align:left}}\
+         img{{width:96px;height:96px;object-fit:cover;border-radius:8px}}</style>\n</head>\n<body>\n",
+        escape_html(title)
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => out.push_str(&format!("<h{level}>{}</h{level}>\n", escape_html(text))),
+            Block::Para(text) => out.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::List(items) => {
+                out.push_str("<ul>\n");
+                for item in items {
+                    out.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Table { header, rows } => {
+                out.push_str("<table>\n<tr>");
+                for h in header {
+                    out.push_str(&format!("<th>{}</th>", escape_html(h)));
+                }
+                out.push_str("</tr>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+            Block::Image { src, alt } => out.push_str(&format!(
+                "<img src=\"{}\" alt=\"{}\">\n",
+                escape_html(src),
+                escape_html(alt)
+            )),
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn render(blocks: &[Block], format: ChronicleFormat) -> String {
+    match format {
+        ChronicleFormat::Markdown => render_markdown(blocks),
+        ChronicleFormat::Html => render_html(blocks),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::{DealerState, EmpireEpitaph};
+
+    fn empire() -> SaveData {
+        let mut save = SaveData::new();
+        save.account.cash_on_hand = 10_000;
+        save.account.lifetime_revenue = 2_500;
+        save.account.unlocked_cards.insert("shrooms".to_string());
+        save.dealers.push(DealerState::zone_dealer("trailer_park", "Slim", "Gladys"));
+        save.dealers[1].add_cred("trailer_park");
+        save.dealers[1].add_cred("trailer_park");
+        save.take_front("shrooms", "trailer_park", 100).unwrap();
+        save.jail_for_bust(1, 30);
+        let story = |text: &str, timestamp: u64, outcome: HandOutcome| StoryRecord {
+            text: text.to_string(),
+            outcome: Some(outcome),
+            area: "trailer_park".to_string(),
+            dealer: "Slim".to_string(),
+            run: 1,
+            timestamp,
+            ..Default::default()
+        };
+        save.dealers[1].character.story_history.push(story("Later | busted.", 200, HandOutcome::Busted));
+        save.dealers[0].character.story_history.push(story("Earlier.", 100, HandOutcome::Safe));
+        save
+    }
+
+    #[test]
+    fn dates_are_utc_calendar_days() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_700_000_000), "2023-11-14");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+    }
+
+    #[test]
+    fn living_chronicle_has_every_section_and_stories_in_order() {
+        let save = empire();
+        let chronicle = Chronicle::of(&save, ChronicleSubject::Current).unwrap();
+        let md = render_markdown(&chronicle_blocks(&chronicle, &[]));
+        for heading in ["# The Living Empire", "## Summary", "## Roster", "### Slim", "## Zones", "## The Books", "## Busts and Jail Terms", "### Outstanding fronts", "## Stories"] {
+            assert!(md.contains(&format!("{heading}\n")), "missing {heading}:\n{md}");
+        }
+        assert!(md.contains("![Slim](portraits/dealer-gladys.png)"), "{md}");
+        assert!(md.contains("Took a front on shrooms from trailer_park ($125 owed)"), "{md}");
+        assert!(md.contains("Slim busted at heat 30"), "{md}");
+        // Oldest first across dealers; table pipes escaped
+        let earlier = md.find("Earlier.").unwrap();
+        let later = md.find("Later \\| busted.").unwrap_or_else(|| md.find("Later | busted.").unwrap());
+        assert!(earlier < later);
+        // Zone row: 2 deals, Slim best, 2 stories, 1 bust, 1 front
+        assert!(md.contains("| trailer_park | 2 | Slim (2) | 2 | 1 | 1 | good |"), "{md}");
+    }
+
+    #[test]
+    fn fallen_chronicle_reads_the_archive() {
+        let mut save = empire();
+        save.reset_empire();
+        assert!(Chronicle::of(&save, ChronicleSubject::Fallen(1)).is_none());
+        let chronicle = Chronicle::of(&save, ChronicleSubject::Fallen(0)).unwrap();
+        assert_eq!(chronicle.roster.len(), 2);
+        assert!(chronicle.fronts.is_empty(), "debts die with the empire");
+        let md = render_markdown(&chronicle_blocks(&chronicle, &[]));
+        assert!(md.starts_with("# Fallen Empire #1\n"));
+        assert!(md.contains("The kingpin went down"), "{md}");
+        assert!(!md.contains("Outstanding fronts"));
+    }
+
+    #[test]
+    fn export_is_deterministic_and_html_is_escaped() {
+        let save = empire();
+        let blocks = || chronicle_blocks(&Chronicle::of(&save, ChronicleSubject::Current).unwrap(), &[]);
+        assert_eq!(render_html(&blocks()), render_html(&blocks()));
+
+        let html = render(&[Block::Heading(1, "A <b> & C".to_string())], ChronicleFormat::Html);
+        assert!(html.contains("<title>A &lt;b&gt; &amp; C</title>"));
+        assert!(html.contains("<h1>A &lt;b&gt; &amp; C</h1>"));
+        assert_eq!(ChronicleFormat::parse("md"), Some(ChronicleFormat::Markdown));
+        assert_eq!(ChronicleFormat::parse("pdf"), None);
+    }
+
+    #[test]
+    fn empty_fallen_archive_still_renders() {
+        let mut save = SaveData::new();
+        save.fallen_empires.push(EmpireEpitaph::from_save(&SaveData::new(), 0));
+        save.fallen_empires[0].roster.clear();
+        let chronicle = Chronicle::of(&save, ChronicleSubject::Fallen(0)).unwrap();
+        let md = render_markdown(&chronicle_blocks(&chronicle, &[]));
+        assert!(md.contains("No roster was archived"));
+        assert!(md.contains("Nobody went inside."));
+    }
+}
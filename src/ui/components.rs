@@ -53,8 +53,18 @@ pub struct FoldButton;
 // Restart/Navigation Buttons (SOW-004)
 // ============================================================================
 
+// SOW-060: RestartButton ("NEW DEAL") removed - the next hand is a stop
+// picked on the run map (RunNodeButton)
+
+/// The resolution overlay's route row - the next stops on the run map
 #[derive(Component)]
-pub struct RestartButton; // "NEW DEAL" button
+pub struct RunRouteRow;
+
+/// A reachable stop on the run map (column in the next row)
+#[derive(Component)]
+pub struct RunNodeButton {
+    pub col: usize,
+}
 
 #[derive(Component)]
 pub struct GoHomeButton; // "GO HOME" button
@@ -372,6 +382,75 @@ pub struct RosterLawyerButton {
     pub dealer_index: usize,
 }
 
+/// SOW-042: cycle a (non-running) dealer's order for the day
+#[derive(Component)]
+pub struct RosterOrderButton {
+    pub dealer_index: usize,
+}
+
+// ============================================================================
+// SOW-041: Per-dealer deck loadouts (deck-builder screen)
+// ============================================================================
+
+/// Row of loadout slots under the deck stats (children rebuilt by
+/// populate_loadout_row_system)
+#[derive(Component)]
+pub struct LoadoutRow;
+
+/// Store the active dealer's current deck in a loadout slot
+#[derive(Component)]
+pub struct LoadoutSaveButton {
+    pub slot: usize,
+}
+
+/// Swap a saved loadout in as the active dealer's deck
+#[derive(Component)]
+pub struct LoadoutLoadButton {
+    pub slot: usize,
+}
+
+// ============================================================================
+// SOW-042: End-of-day summary overlay (deck-builder screen)
+// ============================================================================
+
+/// Overlay root - visible while a DaySummary resource is pending
+#[derive(Component)]
+pub struct DaySummaryOverlay;
+
+/// Summary lines container (children rebuilt by populate_day_summary_system)
+#[derive(Component)]
+pub struct DaySummaryBody;
+
+#[derive(Component)]
+pub struct DaySummaryDismissButton;
+
+// ============================================================================
+// SOW-052: Morning paper overlay (deck-builder screen)
+// ============================================================================
+
+/// Overlay root - visible while a MorningPaper resource is pending
+#[derive(Component)]
+pub struct MorningPaperOverlay;
+
+/// Front pages container (children rebuilt by populate_morning_paper_system)
+#[derive(Component)]
+pub struct MorningPaperBody;
+
+#[derive(Component)]
+pub struct MorningPaperDismissButton;
+
+// ============================================================================
+// SOW-054: Hot reload error banner (every screen, dev mode only)
+// ============================================================================
+
+/// Banner root - visible while the last reload's content was rejected
+#[derive(Component)]
+pub struct ContentErrorBanner;
+
+/// The validation error itself
+#[derive(Component)]
+pub struct ContentErrorText;
+
 // ============================================================================
 // SOW-020: Shop UI Components
 // ============================================================================
@@ -442,7 +521,7 @@ pub struct ShopFeedbackText;
 pub struct FrontTakeButton {
     pub card_id: String,
     pub area_id: String,
-    /// The full batch cost the front is against (owed = front_owed(batch_cost))
+    /// The full batch cost the front is against (owed = SaveData::front_owed_for(batch_cost))
     pub batch_cost: u32,
 }
 
@@ -456,6 +535,20 @@ pub struct FrontPayButton {
 #[derive(Component)]
 pub struct FrontPressureText;
 
+// ============================================================================
+// SOW-059: Relics
+// ============================================================================
+
+/// The hub's relic shelf under the lifetime line
+#[derive(Component)]
+pub struct RelicShelfText;
+
+/// BUY button on a zone shop's rare slot
+#[derive(Component)]
+pub struct RelicBuyButton {
+    pub relic_id: String,
+}
+
 // ============================================================================
 // SOW-029: City Map overlay (deck-builder screen)
 // ============================================================================
@@ -472,8 +565,9 @@ pub struct MapOverlay;
 #[derive(Component)]
 pub struct MapCloseButton;
 
-/// Container for the three zone node cards (children rebuilt on save or
-/// map-state change by populate_map_nodes_system)
+/// Container for the zone node cards (children rebuilt on save or
+/// map-state change by populate_map_nodes_system). SOW-043: an absolutely
+/// laid-out canvas holding the nodes, roads and dealers on the road.
 #[derive(Component)]
 pub struct MapNodesRow;
 
@@ -560,6 +654,11 @@ pub struct LedgerOverlay;
 #[derive(Component)]
 pub struct LedgerCloseButton;
 
+/// SOW-051: header button - writes the focused fallen empire's chronicle
+/// (or the living empire's, with no epitaph focused) under the save dir
+#[derive(Component)]
+pub struct LedgerExportButton;
+
 /// Body container (children rebuilt on save or ledger-state change by
 /// populate_ledger_system)
 #[derive(Component)]
@@ -576,3 +675,35 @@ pub struct LedgerDossierButton {
 pub struct LedgerEpitaphButton {
     pub epitaph_index: usize,
 }
+
+/// SOW-062: CASH OUT & RETIRE - the first click arms it, the second ends
+/// the empire as a retirement
+#[derive(Component)]
+pub struct LedgerRetireButton;
+
+/// SOW-063: SUCCESSION ON/OFF - flips the empire's succession rule
+#[derive(Component)]
+pub struct LedgerSuccessionButton;
+
+/// SOW-065: one ascension level - a click starts the fresh empire at it
+#[derive(Component)]
+pub struct LedgerAscensionButton {
+    pub level: crate::models::ascension::Ascension,
+}
+
+/// SOW-064: one legacy unlock on offer - a click buys it
+#[derive(Component)]
+pub struct LedgerLegacyButton {
+    pub unlock: crate::save::LegacyUnlock,
+}
+
+/// SOW-050: story-panel filter chip - a click cycles the value that field
+/// narrows the feed by (CLEAR drops every filter and the search)
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LedgerStoryFilterButton {
+    Dealer,
+    Zone,
+    Outcome,
+    Buyer,
+    Clear,
+}
@@ -0,0 +1,312 @@
+// SOW-042: Day planning view-model - pure presentation for the roster's
+// ORDER button and the end-of-day summary. Same rule as the other _view
+// modules: unit-testable without ECS; systems/day_planning.rs only
+// orchestrates.
+
+use crate::locale::{tr, tr_with};
+use crate::models::shop_location::ShopLocationDef;
+use crate::save::{DealerOrder, DealerState, OrderOutcome, RunPolicy, SaveData};
+use crate::systems::day_planning::DayEntry;
+use crate::ui::view::format_cash;
+
+/// Display name for an area id ("THE BLOCK"); the raw id upper-cased when
+/// content doesn't know it
+pub fn area_name(areas: &[ShopLocationDef], area_id: &str) -> String {
+    areas
+        .iter()
+        .find(|a| a.id == area_id)
+        .map(|a| a.name.to_uppercase())
+        .unwrap_or_else(|| area_id.to_uppercase())
+}
+
+/// Where a MOVE (roster button or order) sends a dealer: the first unlocked
+/// area that isn't their station. The full area picker lives on the map.
+pub fn move_target<'a>(
+    areas: &'a [ShopLocationDef],
+    save: &SaveData,
+    dealer: &DealerState,
+) -> Option<&'a ShopLocationDef> {
+    areas
+        .iter()
+        .find(|a| a.id != dealer.station && save.account.unlocked_locations.contains(&a.id))
+}
+
+/// The ORDER button's cycle: REST → RUN·CAUTIOUS → RUN·BALANCED →
+/// RUN·GREEDY → SCOUT → LAY LOW → MOVE → REST. Run orders are skipped for
+/// the kingpin (set_order refuses them) and MOVE when there's nowhere to go.
+pub fn next_order(current: &DealerOrder, is_kingpin: bool, move_to: Option<&str>) -> DealerOrder {
+    let mut cycle = vec![DealerOrder::Rest];
+    if !is_kingpin {
+        cycle.extend([
+            DealerOrder::Run(RunPolicy::Cautious),
+            DealerOrder::Run(RunPolicy::Balanced),
+            DealerOrder::Run(RunPolicy::Greedy),
+        ]);
+    }
+    cycle.extend([DealerOrder::Scout, DealerOrder::LayLow]);
+    if let Some(to_area) = move_to {
+        cycle.push(DealerOrder::Move { to_area: to_area.to_string() });
+    }
+
+    let position = cycle.iter().position(|order| match (order, current) {
+        // Any pending move counts as the MOVE slot, wherever it was headed
+        (DealerOrder::Move { .. }, DealerOrder::Move { .. }) => true,
+        _ => order == current,
+    });
+    match position {
+        Some(i) => cycle[(i + 1) % cycle.len()].clone(),
+        // An order this dealer can't hold any more (kingpin Run, stale move)
+        None => DealerOrder::Rest,
+    }
+}
+
+/// The ORDER button label ("ORDER\nRUN · GREEDY")
+pub fn order_label(order: &DealerOrder, areas: &[ShopLocationDef]) -> String {
+    let what = match order {
+        DealerOrder::Rest => tr("order.rest"),
+        DealerOrder::Run(policy) => {
+            let policy = match policy {
+                RunPolicy::Cautious => tr("order.policy_cautious"),
+                RunPolicy::Balanced => tr("order.policy_balanced"),
+                RunPolicy::Greedy => tr("order.policy_greedy"),
+            };
+            tr_with("order.run", &[("policy", &policy)])
+        }
+        DealerOrder::Scout => tr("order.scout"),
+        DealerOrder::LayLow => tr("order.lay_low"),
+        DealerOrder::Move { to_area } => tr_with("order.move", &[("area", &area_name(areas, to_area))]),
+    };
+    format!("{}\n{what}", tr("order.heading"))
+}
+
+/// Cash the roster brought home off-screen today
+pub fn day_take(entries: &[DayEntry]) -> u32 {
+    entries
+        .iter()
+        .map(|entry| match entry {
+            DayEntry::Ran { profit, .. } => *profit,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The summary's headline: the day's take
+pub fn crew_take_line(take: u32) -> String {
+    tr_with("day.crew_take", &[("cash", &format_cash(u64::from(take)))])
+}
+
+/// One line per entry, in the order the day happened
+pub fn summary_lines(entries: &[DayEntry], areas: &[ShopLocationDef]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| match entry {
+            DayEntry::Ran { dealer, area, hands, deals, profit, jailed_for } => {
+                let area = area_name(areas, area);
+                let (plural_hands, hands, deals) = (*hands != 1, hands.to_string(), deals.to_string());
+                match jailed_for {
+                    Some(runs) => {
+                        let key = if *runs == 1 { "day.jailed.one" } else { "day.jailed.many" };
+                        let runs = runs.to_string();
+                        tr_with(key, &[("dealer", dealer), ("area", &area), ("hands", &hands), ("runs", &runs)])
+                    }
+                    None => {
+                        let key = if plural_hands { "day.ran.many" } else { "day.ran.one" };
+                        let cash = format_cash(u64::from(*profit));
+                        tr_with(
+                            key,
+                            &[("dealer", dealer), ("area", &area), ("deals", &deals), ("hands", &hands), ("cash", &cash)],
+                        )
+                    }
+                }
+            }
+            DayEntry::StayedHome { dealer, reason } => {
+                tr_with("day.stayed_home", &[("dealer", dealer), ("reason", &tr(reason))])
+            }
+            DayEntry::Order { dealer, outcome } => match outcome {
+                OrderOutcome::Rested { cooled: 0 } => tr_with("day.rested", &[("dealer", dealer)]),
+                OrderOutcome::Rested { cooled } => {
+                    tr_with("day.rested_cooled", &[("dealer", dealer), ("heat", &cooled.to_string())])
+                }
+                OrderOutcome::Scouted { area, cred } => tr_with(
+                    "day.scouted",
+                    &[("dealer", dealer), ("area", &area_name(areas, area)), ("cred", &cred.to_string())],
+                ),
+                OrderOutcome::LayingLow => tr_with("day.laying_low", &[("dealer", dealer)]),
+                OrderOutcome::Moved { to_area } => {
+                    tr_with("day.moving", &[("dealer", dealer), ("area", &area_name(areas, to_area))])
+                }
+                OrderOutcome::Refused(reason) => {
+                    tr_with("day.refused", &[("dealer", dealer), ("reason", &tr(reason))])
+                }
+            },
+            DayEntry::BackInAction { dealers } => tr_with("day.back_in_action", &[("dealers", &dealers.join(", "))]),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(id: &str, name: &str) -> ShopLocationDef {
+        ShopLocationDef {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            unlocked: true,
+            price: 0,
+            identity: String::new(),
+            narc_hint: String::new(),
+            supplier: None,
+            signature_dealer: None,
+            unlockable_dealers: vec![],
+            narc_portrait: None,
+            restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn order_cycle_walks_every_order_and_wraps() {
+        let mut order = DealerOrder::Rest;
+        let mut seen = vec![];
+        for _ in 0..7 {
+            order = next_order(&order, false, Some("suburbia"));
+            seen.push(order_label(&order, &[]));
+        }
+        assert_eq!(
+            seen,
+            vec![
+                "ORDER\nRUN · CAUTIOUS",
+                "ORDER\nRUN · BALANCED",
+                "ORDER\nRUN · GREEDY",
+                "ORDER\nSCOUT",
+                "ORDER\nLAY LOW",
+                "ORDER\nMOVE → SUBURBIA",
+                "ORDER\nREST",
+            ]
+        );
+    }
+
+    /// SOW-053: characters per line the ORDER button holds (10px text on a
+    /// roster card)
+    const ORDER_LABEL_BUDGET: usize = 26;
+
+    #[test]
+    fn order_labels_fit_the_button_once_translated() {
+        let areas = [area("trailer_park", "Trailer Park")];
+        crate::locale::scoped(crate::locale::Locale::pseudo(), || {
+            let mut order = DealerOrder::Rest;
+            for _ in 0..7 {
+                order = next_order(&order, false, Some("trailer_park"));
+                let label = order_label(&order, &areas);
+                assert!(crate::models::test_helpers::fits(&label, ORDER_LABEL_BUDGET), "{label:?} overflows");
+            }
+        });
+    }
+
+    #[test]
+    fn order_cycle_skips_what_the_dealer_cant_do() {
+        // The boss never runs off-screen
+        assert_eq!(next_order(&DealerOrder::Rest, true, None), DealerOrder::Scout);
+        // Nowhere to move: LAY LOW wraps to REST
+        assert_eq!(next_order(&DealerOrder::LayLow, false, None), DealerOrder::Rest);
+        // A stale move (its zone no longer a target) resets
+        let stale = DealerOrder::Move { to_area: "downtown".into() };
+        assert_eq!(next_order(&stale, false, None), DealerOrder::Rest);
+        assert_eq!(next_order(&stale, false, Some("suburbia")), DealerOrder::Rest);
+    }
+
+    #[test]
+    fn summary_reads_the_day() {
+        let areas = [area("trailer_park", "The Trailer Park")];
+        let entries = vec![
+            DayEntry::Ran {
+                dealer: "Slim".into(),
+                area: "trailer_park".into(),
+                hands: 3,
+                deals: 2,
+                profit: 1_250,
+                jailed_for: None,
+            },
+            DayEntry::Ran {
+                dealer: "Ray".into(),
+                area: "suburbia".into(),
+                hands: 2,
+                deals: 1,
+                profit: 40,
+                jailed_for: Some(1),
+            },
+            DayEntry::StayedHome { dealer: "Dee".into(), reason: "day.reason_deck" },
+            DayEntry::Order { dealer: "Boss".into(), outcome: OrderOutcome::Rested { cooled: 5 } },
+            DayEntry::Order {
+                dealer: "Gus".into(),
+                outcome: OrderOutcome::Scouted { area: "trailer_park".into(), cred: 4 },
+            },
+            DayEntry::BackInAction { dealers: vec!["Lou".into(), "Max".into()] },
+        ];
+
+        assert_eq!(
+            summary_lines(&entries, &areas),
+            vec![
+                "Slim worked THE TRAILER PARK: 2 of 3 deals closed, +$1,250",
+                "Ray worked SUBURBIA: busted on hand 2 - jailed 1 run",
+                "Dee stayed home: their deck isn't ready",
+                "Boss rested (-5 heat)",
+                "Gus scouted THE TRAILER PARK (cred 4)",
+                "Back in action: Lou, Max",
+            ]
+        );
+        assert_eq!(day_take(&entries), 1_290);
+        assert_eq!(crew_take_line(1_290), "The crew brought home $1,290");
+    }
+
+    #[test]
+    fn summary_plurals_and_refusals_read_from_the_table() {
+        let entries = vec![
+            DayEntry::Ran {
+                dealer: "Slim".into(),
+                area: "suburbia".into(),
+                hands: 1,
+                deals: 1,
+                profit: 80,
+                jailed_for: None,
+            },
+            DayEntry::Ran {
+                dealer: "Ray".into(),
+                area: "suburbia".into(),
+                hands: 1,
+                deals: 0,
+                profit: 0,
+                jailed_for: Some(2),
+            },
+            DayEntry::Order { dealer: "Gus".into(), outcome: OrderOutcome::Refused("day.reason_no_heat") },
+        ];
+        assert_eq!(
+            summary_lines(&entries, &[]),
+            vec![
+                "Slim worked SUBURBIA: 1 of 1 deal closed, +$80",
+                "Ray worked SUBURBIA: busted on hand 1 - jailed 2 runs",
+                "Gus stayed put: no heat to shed",
+            ]
+        );
+    }
+
+    #[test]
+    fn summary_is_translated_reasons_included() {
+        let entries = vec![
+            DayEntry::StayedHome { dealer: "Dee".into(), reason: "day.reason_deck" },
+            DayEntry::Order { dealer: "Boss".into(), outcome: OrderOutcome::Rested { cooled: 0 } },
+            DayEntry::BackInAction { dealers: vec!["Lou".into()] },
+        ];
+        crate::locale::scoped(crate::locale::Locale::pseudo(), || {
+            let lines = summary_lines(&entries, &[]);
+            // The line and the reason inside it are both looked up
+            assert!(lines[0].starts_with("[Dee stáyéd hömé: [théír déçk"), "{:?}", lines[0]);
+            assert!(lines[1].starts_with("[Boss réstéd"), "{:?}", lines[1]);
+            assert!(lines[2].starts_with("[Báçk íñ áçtíöñ: Lou"), "{:?}", lines[2]);
+            assert!(crew_take_line(5).starts_with("[Thé çréw"));
+        });
+    }
+}
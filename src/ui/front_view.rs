@@ -30,13 +30,10 @@ pub fn standing_label(standing: SupplierStanding) -> Option<&'static str> {
 }
 
 /// The FRONT button's face: full cost + window BEFORE commit. SOW-034: the
-/// front is against a BATCH, so `batch_cost` is the batch's cash price.
-pub fn front_button_label(batch_cost: u32) -> String {
-    format!(
-        "FRONT ${} · DUE {} RUNS",
-        crate::save::front_owed(batch_cost),
-        FRONT_WINDOW_RUNS
-    )
+/// front is against a BATCH. SOW-059: `owed` is SaveData::front_owed_for
+/// the batch (relics can cut the vig).
+pub fn front_button_label(owed: u64) -> String {
+    format!("FRONT ${} · DUE {} RUNS", owed, FRONT_WINDOW_RUNS)
 }
 
 /// The shop header under a zone's supplier: what the relationship looks
@@ -164,6 +161,8 @@ mod tests {
             unlockable_dealers: Vec::new(),
             narc_portrait: None,
             restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
         }
     }
 
@@ -191,7 +190,7 @@ mod tests {
 
     #[test]
     fn front_button_shows_vig_and_window() {
-        assert_eq!(front_button_label(500), "FRONT $625 · DUE 4 RUNS");
+        assert_eq!(front_button_label(crate::save::front_owed_at(500, crate::save::FRONT_VIG_PCT)), "FRONT $625 · DUE 4 RUNS");
     }
 
     // -- supplier header (shop) --
@@ -6,8 +6,21 @@
 // existing SaveData - this module takes &SaveData everywhere and never
 // mutates. If a stat can't be derived, it doesn't ship this SOW.
 
+use crate::locale::{tr, tr_with};
+use std::collections::HashMap;
+
+use crate::models::ascension::{Ascension, AscensionModifier, MAX_ASCENSION};
+use crate::models::card::Card;
+use crate::models::hand_state::HandOutcome;
+use crate::models::relic::RelicDef;
 use crate::models::shop_location::ShopLocationDef;
-use crate::save::{DealerState, EmpireEpitaph, SaveData, SupplierStanding};
+use crate::models::victory::VictoryDef;
+use crate::save::{
+    AccountState, DealerState, EmpireEpitaph, Headline, HeadlineKind, LegacyUnlock, OwnedRelic, SaveData, StoryRecord,
+    SupplierStanding,
+};
+
+use super::view::format_cash;
 
 // ============================================================================
 // Panel 1: THE EMPIRE - the tombstone being carved
@@ -133,7 +146,7 @@ pub fn dossier_rows(save: &SaveData, areas: &[ShopLocationDef]) -> Vec<DossierRo
 
 /// A dealer's story feed, newest first (story_history appends
 /// chronologically). Missing dealer -> empty.
-pub fn dealer_stories(save: &SaveData, dealer_index: usize) -> Vec<String> {
+pub fn dealer_stories(save: &SaveData, dealer_index: usize) -> Vec<StoryRecord> {
     save.dealers
         .get(dealer_index)
         .map(|d| d.character.story_history.iter().rev().cloned().collect())
@@ -157,11 +170,14 @@ pub struct BoardRow {
     pub dealers_hired: u32,
     pub convictions: u32,
     pub ended_at: Option<u64>,
+    /// SOW-065: the level it played at (ranks first)
+    pub ascension: Ascension,
 }
 
-/// The full board: every epitaph ranked by lifetime revenue, with the
-/// living empire slotted UNRANKED at its would-be position. Ties go to the
-/// dead - the record stands until strictly beaten.
+/// The full board: every fallen epitaph ranked by lifetime revenue, with
+/// the living empire slotted UNRANKED at its would-be position. Ties go to
+/// the dead - the record stands until strictly beaten. SOW-062: retired
+/// empires rank on their own board.
 pub fn board_rows(save: &SaveData) -> Vec<BoardRow> {
     let fallen = &save.fallen_empires;
     let mut rows: Vec<BoardRow> = crate::save::leaderboard_top(fallen, fallen.len())
@@ -178,6 +194,7 @@ pub fn board_rows(save: &SaveData) -> Vec<BoardRow> {
                 dealers_hired: e.dealers_hired,
                 convictions: e.total_prior_convictions,
                 ended_at: Some(e.ended_at),
+                ascension: e.ascension,
             }
         })
         .collect();
@@ -185,7 +202,7 @@ pub fn board_rows(save: &SaveData) -> Vec<BoardRow> {
     let live = empire_summary(save);
     let position = rows
         .iter()
-        .take_while(|r| r.lifetime_revenue >= live.lifetime_revenue)
+        .take_while(|r| (r.ascension, r.lifetime_revenue) >= (save.ascension, live.lifetime_revenue))
         .count();
     rows.insert(
         position,
@@ -198,6 +215,7 @@ pub fn board_rows(save: &SaveData) -> Vec<BoardRow> {
             dealers_hired: live.dealers_hired,
             convictions: live.convictions,
             ended_at: None,
+            ascension: save.ascension,
         },
     );
     rows
@@ -208,13 +226,549 @@ pub fn board_rows(save: &SaveData) -> Vec<BoardRow> {
 /// flat-maps the roster). The archive carries no global chronology, so
 /// "newest first" is underivable across dealers - read it like a case
 /// file, front to back. Missing index -> empty.
-pub fn epitaph_stories(save: &SaveData, epitaph_index: usize) -> Vec<String> {
+pub fn epitaph_stories(save: &SaveData, epitaph_index: usize) -> Vec<StoryRecord> {
     save.fallen_empires
         .get(epitaph_index)
         .map(|e| e.stories.clone())
         .unwrap_or_default()
 }
 
+// ============================================================================
+// Retirement (SOW-062) - the way out, and the board of those who took it
+// ============================================================================
+
+/// One authored victory, met or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct VictoryLine {
+    pub name: String,
+    pub description: String,
+    pub met: bool,
+    pub bonus: u64,
+}
+
+/// What cashing out would look like right now
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetireView {
+    pub victories: Vec<VictoryLine>,
+    pub score: u64,
+    pub clean_cash: u64,
+}
+
+/// The retire panel: every victory checked against the living empire and
+/// the score it would retire with. `zones` is how many zones the map has.
+pub fn retire_view(save: &SaveData, victories: &[VictoryDef], zones: usize) -> RetireView {
+    let met = save.victories_met(victories, zones);
+    let retirement = save.retirement(victories, zones);
+    RetireView {
+        victories: victories
+            .iter()
+            .map(|def| VictoryLine {
+                name: def.name.clone(),
+                description: def.description.clone(),
+                met: met.iter().any(|m| m.id == def.id),
+                bonus: def.bonus,
+            })
+            .collect(),
+        score: retirement.score,
+        clean_cash: retirement.clean_cash,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetirementRow {
+    /// 1-based rank on the retirement board
+    pub rank: usize,
+    /// Index into save.fallen_empires for click-to-stories
+    pub epitaph_index: usize,
+    pub score: u64,
+    pub lifetime_revenue: u64,
+    /// "Own the City · Clean Money", empty when none were met
+    pub victories: String,
+    /// SOW-065: the level it retired at
+    pub ascension: Ascension,
+}
+
+/// The retirement board: every retired empire ranked by score. The living
+/// empire isn't slotted in - its would-be score is the retire panel's.
+pub fn retirement_rows(save: &SaveData) -> Vec<RetirementRow> {
+    let epitaphs = &save.fallen_empires;
+    crate::save::retirement_top(epitaphs, epitaphs.len())
+        .into_iter()
+        .enumerate()
+        .filter_map(|(rank, idx)| {
+            let e = &epitaphs[idx];
+            let retirement = e.retirement.as_ref()?;
+            Some(RetirementRow {
+                rank: rank + 1,
+                epitaph_index: idx,
+                score: retirement.score,
+                lifetime_revenue: e.lifetime_revenue,
+                victories: retirement.victories.join(" · "),
+                ascension: e.ascension,
+            })
+        })
+        .collect()
+}
+
+/// "✓ OWN THE CITY · +$5,000" once met, "· CLEAN MONEY — Bank it." until then
+pub fn victory_text(line: &VictoryLine) -> String {
+    let name = line.name.to_uppercase();
+    if line.met {
+        tr_with("ledger.victory_met", &[("name", &name), ("bonus", &format_cash(line.bonus))])
+    } else {
+        tr_with("ledger.victory_open", &[("name", &name), ("description", &line.description)])
+    }
+}
+
+/// "$9,000 LIFETIME · OWN THE CITY" under a retirement's score
+pub fn retirement_detail(row: &RetirementRow) -> String {
+    let victories = if row.victories.is_empty() { tr("ledger.no_victories_met") } else { row.victories.to_uppercase() };
+    tr_with(
+        "ledger.retired_detail",
+        &[("revenue", &format_cash(row.lifetime_revenue)), ("victories", &victories)],
+    )
+}
+
+// ============================================================================
+// Ascension (SOW-065) - the difficulty a fresh empire is started at
+// ============================================================================
+
+/// " · LEVEL 2" after a board row's score; empty at level 0
+pub fn level_tag(level: Ascension) -> String {
+    if level.0 == 0 {
+        String::new()
+    } else {
+        tr_with("ledger.level_tag", &[("level", &level.0.to_string())])
+    }
+}
+
+/// What a modifier does, in a line
+pub fn modifier_text(modifier: AscensionModifier) -> String {
+    match modifier {
+        AscensionModifier::HotterNarcs => tr("ledger.mod_hotter_narcs"),
+        AscensionModifier::HeavyVig => tr_with(
+            "ledger.mod_heavy_vig",
+            &[("pct", &crate::models::ascension::ASCENSION_VIG_PCT.to_string())],
+        ),
+        AscensionModifier::SlowDecay => tr("ledger.mod_slow_decay"),
+        AscensionModifier::NervousBuyers => tr("ledger.mod_nervous_buyers"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AscensionView {
+    pub level: Ascension,
+    /// Every level on offer, 0 first
+    pub levels: Vec<Ascension>,
+    /// The current level's modifiers in words
+    pub modifiers: Vec<String>,
+    /// Whether the level can still be changed (the empire hasn't dealt)
+    pub open: bool,
+    /// What opens the next level, None at the top
+    pub next: Option<String>,
+}
+
+pub fn ascension_view(save: &SaveData) -> AscensionView {
+    let unlocked = save.ascension_unlocked;
+    AscensionView {
+        level: save.ascension,
+        levels: (0..=unlocked.0).map(Ascension).collect(),
+        modifiers: save.ascension.modifiers().iter().map(|m| modifier_text(*m)).collect(),
+        open: !save.underway(),
+        next: (unlocked.0 < MAX_ASCENSION)
+            .then(|| tr_with("ledger.ascension_next", &[("level", &unlocked.0.to_string())])),
+    }
+}
+
+// ============================================================================
+// Lineage (SOW-063) - the kingpins an empire went through
+// ============================================================================
+
+/// How a kingpin's reign ended (or hasn't)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReignEnd {
+    /// Went down and a lieutenant took over
+    Busted,
+    /// Still in the chair
+    Reigning,
+    /// Went down with the empire
+    Fell,
+    /// Cashed out
+    Retired,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineageRow {
+    /// 1-based: the founder is 1
+    pub generation: usize,
+    pub name: String,
+    pub end: ReignEnd,
+    /// Revenue the empire made under this kingpin
+    pub revenue: u64,
+}
+
+/// The lineage of an archived empire (`Some(epitaph index)`) or the living
+/// one (`None`), founder first. Each reign's take is the lifetime revenue
+/// between its start and its end.
+pub fn lineage_rows(save: &SaveData, epitaph: Option<usize>) -> Vec<LineageRow> {
+    let (dynasty, roster, lifetime, end) = match epitaph {
+        Some(i) => {
+            let Some(e) = save.fallen_empires.get(i) else {
+                return Vec::new();
+            };
+            let end = if e.retirement.is_some() { ReignEnd::Retired } else { ReignEnd::Fell };
+            (&e.dynasty, &e.roster, e.lifetime_revenue, end)
+        }
+        None => (&save.dynasty, &save.dealers, save.account.lifetime_revenue, ReignEnd::Reigning),
+    };
+    let mut rows = Vec::new();
+    let mut before = 0;
+    for reign in dynasty {
+        rows.push(LineageRow {
+            generation: rows.len() + 1,
+            name: reign.kingpin.name.clone(),
+            end: ReignEnd::Busted,
+            revenue: reign.lifetime_revenue.saturating_sub(before),
+        });
+        before = reign.lifetime_revenue;
+    }
+    if let Some(kingpin) = roster.iter().find(|d| d.is_kingpin) {
+        rows.push(LineageRow {
+            generation: rows.len() + 1,
+            name: kingpin.name.clone(),
+            end,
+            revenue: lifetime.saturating_sub(before),
+        });
+    }
+    rows
+}
+
+/// "2. NOVA — BUSTED · $4,000"
+pub fn lineage_text(row: &LineageRow) -> String {
+    let end = match row.end {
+        ReignEnd::Busted => "ledger.reign_busted",
+        ReignEnd::Reigning => "ledger.reign_reigning",
+        ReignEnd::Fell => "ledger.reign_fell",
+        ReignEnd::Retired => "ledger.reign_retired",
+    };
+    tr_with(
+        "ledger.lineage_row",
+        &[
+            ("generation", &row.generation.to_string()),
+            ("name", &row.name.to_uppercase()),
+            ("end", &tr(end)),
+            ("revenue", &format_cash(row.revenue)),
+        ],
+    )
+}
+
+/// Who takes over if the kingpin goes down today - or what it would take
+pub fn succession_line(save: &SaveData) -> String {
+    if !save.succession {
+        return tr("ledger.succession_off_note");
+    }
+    match save.heir() {
+        Some(idx) => tr_with("ledger.heir", &[("name", &save.dealers[idx].name.to_uppercase())]),
+        None => tr_with(
+            "ledger.no_heir",
+            &[
+                ("runs", &crate::save::SUCCESSION_MIN_RUNS.to_string()),
+                ("cred", &crate::save::SUCCESSION_MIN_CRED.to_string()),
+                ("cash", &format_cash(crate::save::SUCCESSION_MIN_CASH)),
+            ],
+        ),
+    }
+}
+
+// ============================================================================
+// Legacy (SOW-064) - what the fallen left the next empire
+// ============================================================================
+
+/// Candidates offered per legacy slot (products, zones, relics)
+pub const LEGACY_OFFER_CAP: usize = 3;
+
+/// The content a legacy label needs to name its product or zone
+pub struct LegacyContent<'a> {
+    pub products: &'a HashMap<String, Card>,
+    pub areas: &'a [ShopLocationDef],
+    pub relics: &'a [RelicDef],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegacyOffer {
+    pub unlock: LegacyUnlock,
+    pub label: String,
+    pub cost: u64,
+    pub affordable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegacyView {
+    pub balance: u64,
+    /// Labels of the unlocks already bought
+    pub owned: Vec<String>,
+    /// Unlocks for the slots still open, a few candidates each
+    pub offers: Vec<LegacyOffer>,
+}
+
+/// "START WITH SHROOMS", "START IN SUBURBIA", "FIRST HIRE HALF PRICE"...
+pub fn legacy_label(unlock: &LegacyUnlock, content: &LegacyContent) -> String {
+    match unlock {
+        LegacyUnlock::StartingProduct(id) => {
+            let name = content.products.get(id).map_or(id.as_str(), |card| card.name.as_str());
+            tr_with("ledger.legacy_product", &[("name", &name.to_uppercase())])
+        }
+        LegacyUnlock::StartingZone(id) => {
+            tr_with("ledger.legacy_zone", &[("name", &area_display_name(content.areas, id).to_uppercase())])
+        }
+        LegacyUnlock::CheaperFirstHire => tr_with(
+            "ledger.legacy_hire",
+            &[("pct", &crate::save::LEGACY_FIRST_HIRE_PCT.to_string())],
+        ),
+        LegacyUnlock::StartingRelic(relic) => {
+            tr_with("ledger.legacy_relic", &[("name", &relic.name.to_uppercase())])
+        }
+    }
+}
+
+/// The legacy panel: the balance, what's been bought, and what it could
+/// buy next - products outside the starting collection, zones not owned
+/// from the start, authored relics, the hire discount
+pub fn legacy_view(save: &SaveData, content: &LegacyContent) -> LegacyView {
+    let starting = AccountState::starting_collection();
+    let mut products: Vec<&Card> = content.products.values().filter(|card| !starting.contains(&card.id)).collect();
+    products.sort_by(|a, b| a.name.cmp(&b.name));
+    let candidates = products
+        .into_iter()
+        .take(LEGACY_OFFER_CAP)
+        .map(|card| LegacyUnlock::StartingProduct(card.id.clone()))
+        .chain(
+            content
+                .areas
+                .iter()
+                .filter(|area| !area.unlocked)
+                .take(LEGACY_OFFER_CAP)
+                .map(|area| LegacyUnlock::StartingZone(area.id.clone())),
+        )
+        .chain(std::iter::once(LegacyUnlock::CheaperFirstHire))
+        .chain(
+            content
+                .relics
+                .iter()
+                .take(LEGACY_OFFER_CAP)
+                .map(|def| LegacyUnlock::StartingRelic(OwnedRelic::from(def))),
+        );
+
+    let balance = save.legacy_balance();
+    let owned = &save.legacy.unlocks;
+    LegacyView {
+        balance,
+        owned: owned.iter().map(|unlock| legacy_label(unlock, content)).collect(),
+        offers: candidates
+            .filter(|unlock| !owned.iter().any(|o| o.same_slot(unlock)))
+            .map(|unlock| LegacyOffer {
+                label: legacy_label(&unlock, content),
+                cost: unlock.cost(),
+                affordable: unlock.cost() <= balance,
+                unlock,
+            })
+            .collect(),
+    }
+}
+
+// ============================================================================
+// Story filters (SOW-050) - the feed narrowed by what the records know
+// ============================================================================
+
+/// What the story panel is narrowed to. None / empty passes everything;
+/// the filters combine (all must hold).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StoryFilter {
+    /// Dealer name
+    pub dealer: Option<String>,
+    /// Area id
+    pub zone: Option<String>,
+    pub outcome: Option<HandOutcome>,
+    /// Buyer persona display name
+    pub buyer: Option<String>,
+    /// Case-insensitive text search
+    pub search: String,
+}
+
+impl StoryFilter {
+    pub fn is_active(&self) -> bool {
+        self.dealer.is_some()
+            || self.zone.is_some()
+            || self.outcome.is_some()
+            || self.buyer.is_some()
+            || !self.search.is_empty()
+    }
+
+    /// Does a record pass? A session summary has no outcome or buyer, so
+    /// narrowing by either leaves it out.
+    pub fn matches(&self, record: &StoryRecord) -> bool {
+        self.dealer.as_ref().is_none_or(|d| *d == record.dealer)
+            && self.zone.as_ref().is_none_or(|z| *z == record.area)
+            && self.outcome.is_none_or(|o| record.outcome == Some(o))
+            && self.buyer.as_ref().is_none_or(|b| record.buyer.as_ref() == Some(b))
+            && (self.search.is_empty()
+                || record.text.to_lowercase().contains(&self.search.to_lowercase()))
+    }
+}
+
+/// The feed through a filter, order kept
+pub fn filter_stories(records: Vec<StoryRecord>, filter: &StoryFilter) -> Vec<StoryRecord> {
+    records.into_iter().filter(|r| filter.matches(r)).collect()
+}
+
+/// The values a feed can be narrowed to - only what its records carry,
+/// in first-seen order (so the chips cycle in feed order)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StoryFilterOptions {
+    pub dealers: Vec<String>,
+    pub zones: Vec<String>,
+    pub outcomes: Vec<HandOutcome>,
+    pub buyers: Vec<String>,
+}
+
+pub fn story_filter_options(records: &[StoryRecord]) -> StoryFilterOptions {
+    fn add<T: PartialEq + Clone>(list: &mut Vec<T>, value: &T) {
+        if !list.contains(value) {
+            list.push(value.clone());
+        }
+    }
+    let mut options = StoryFilterOptions::default();
+    for record in records {
+        if !record.dealer.is_empty() {
+            add(&mut options.dealers, &record.dealer);
+        }
+        if !record.area.is_empty() {
+            add(&mut options.zones, &record.area);
+        }
+        if let Some(outcome) = &record.outcome {
+            add(&mut options.outcomes, outcome);
+        }
+        if let Some(buyer) = &record.buyer {
+            add(&mut options.buyers, buyer);
+        }
+    }
+    options
+}
+
+/// Next filter value on a chip click: all -> each option in turn -> all.
+/// A value the feed no longer carries starts the cycle over.
+pub fn cycle_filter<T: PartialEq + Clone>(current: &Option<T>, options: &[T]) -> Option<T> {
+    match current {
+        None => options.first().cloned(),
+        Some(value) => options
+            .iter()
+            .position(|o| o == value)
+            .and_then(|i| options.get(i + 1))
+            .cloned(),
+    }
+}
+
+pub fn outcome_label(outcome: HandOutcome) -> String {
+    tr(match outcome {
+        HandOutcome::Safe => "outcome.safe",
+        HandOutcome::Busted => "outcome.busted",
+        HandOutcome::Folded => "outcome.folded",
+        HandOutcome::InvalidDeal => "outcome.no_deal",
+        HandOutcome::BuyerBailed => "outcome.buyer_bailed",
+    })
+}
+
+/// A story filter chip: "ZONE: TRAILER PARK", or "ZONE: ALL" when unset
+pub fn filter_chip_text(label: &str, value: Option<&str>) -> String {
+    match value {
+        Some(value) => tr_with("ledger.filter_value", &[("label", label), ("value", &value.to_uppercase())]),
+        None => tr_with("ledger.filter_all", &[("label", label)]),
+    }
+}
+
+/// The small print under a story: "RUN 3 · SLIM · TRAILER PARK · SAFE ·
+/// FRAT BRO · +$40 · HEAT +8". Whatever the record doesn't know (a
+/// session summary has no outcome or buyer) is left out.
+pub fn story_meta_line(record: &StoryRecord, areas: &[ShopLocationDef]) -> String {
+    let mut parts = Vec::new();
+    if record.run > 0 {
+        parts.push(format!("RUN {}", record.run));
+    }
+    if !record.dealer.is_empty() {
+        parts.push(record.dealer.to_uppercase());
+    }
+    if !record.area.is_empty() {
+        parts.push(area_display_name(areas, &record.area).to_uppercase());
+    }
+    if let Some(outcome) = record.outcome {
+        parts.push(outcome_label(outcome));
+    }
+    if let Some(buyer) = &record.buyer {
+        parts.push(buyer.to_uppercase());
+    }
+    if record.profit > 0 {
+        parts.push(format!("+${}", record.profit));
+    }
+    if record.heat_delta != 0 {
+        parts.push(format!("HEAT {:+}", record.heat_delta));
+    }
+    parts.join(" · ")
+}
+
+// ============================================================================
+// Panel 4: REGULARS (SOW-044) - the street's memory of the empire
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegularRow {
+    pub name: String,
+    /// Persona display name ("Frat Bro")
+    pub persona: String,
+    /// Area display name, raw id for areas missing from content
+    pub area: String,
+    pub relationship: u32,
+    /// Regular::mood ("LOYAL" ... "INFORMANT")
+    pub mood: String,
+    pub deals: u32,
+    /// "Weed @ Frat House" - whatever favourites they have
+    pub likes: Option<String>,
+    pub referred_by: Option<String>,
+    pub informant: bool,
+}
+
+/// Every regular the empire has made: active ones warmest first, then the
+/// informants (they stay on the record - that's the point).
+pub fn regular_rows(save: &SaveData, areas: &[ShopLocationDef]) -> Vec<RegularRow> {
+    let mut rows: Vec<RegularRow> = save
+        .regulars
+        .iter()
+        .map(|r| RegularRow {
+            name: r.name.clone(),
+            persona: r.persona.clone(),
+            area: areas
+                .iter()
+                .find(|a| a.id == r.area)
+                .map(|a| a.name.clone())
+                .unwrap_or_else(|| r.area.clone()),
+            relationship: r.relationship,
+            mood: tr(r.mood()),
+            deals: r.deals,
+            likes: match (&r.favourite_product, &r.favourite_location) {
+                (Some(product), Some(location)) => {
+                    Some(tr_with("hub.likes_pair", &[("product", product), ("location", location)]))
+                }
+                (Some(one), None) | (None, Some(one)) => Some(one.clone()),
+                (None, None) => None,
+            },
+            referred_by: r.referred_by.clone(),
+            informant: r.informant,
+        })
+        .collect();
+    // Stable: equal relationships keep the order they were made in
+    rows.sort_by_key(|r| (r.informant, std::cmp::Reverse(r.relationship)));
+    rows
+}
+
 // ============================================================================
 // Panel capping - the ledger renders into a fixed 1080px design height
 // with no scroll machinery, so every panel needs a cap and a truthful
@@ -229,6 +783,10 @@ pub const STORY_FEED_CAP: usize = 15;
 pub const ROSTER_PANEL_CAP: usize = 8;
 /// Board rows before the fallen-empires panel tails
 pub const BOARD_PANEL_CAP: usize = 10;
+/// SOW-044: regular rows before the regulars panel tails
+pub const REGULARS_PANEL_CAP: usize = 6;
+/// SOW-052: front pages before the paper panel tails
+pub const PAPER_PANEL_CAP: usize = 4;
 
 /// Truthful tail line for a capped list: None while everything fits.
 fn tail_line(hidden: usize, singular: &str, plural: &str) -> Option<String> {
@@ -240,7 +798,7 @@ fn tail_line(hidden: usize, singular: &str, plural: &str) -> Option<String> {
 }
 
 /// Cap a story feed: (visible rows, tail).
-pub fn story_feed(stories: Vec<String>, cap: usize) -> (Vec<String>, Option<String>) {
+pub fn story_feed<T>(stories: Vec<T>, cap: usize) -> (Vec<T>, Option<String>) {
     let hidden = stories.len().saturating_sub(cap);
     let mut visible = stories;
     visible.truncate(cap);
@@ -276,6 +834,48 @@ pub fn board_view(rows: Vec<BoardRow>, cap: usize) -> (Vec<BoardRow>, Option<Str
     (visible, tail_line(hidden, "fallen empire", "fallen empires"))
 }
 
+/// SOW-044: cap the regulars panel: the warmest `cap` (regular_rows order)
+pub fn regulars_view(rows: Vec<RegularRow>, cap: usize) -> (Vec<RegularRow>, Option<String>) {
+    let hidden = rows.len().saturating_sub(cap);
+    let mut visible = rows;
+    visible.truncate(cap);
+    (visible, tail_line(hidden, "regular", "regulars"))
+}
+
+// ============================================================================
+// Panel 5 (SOW-052): THE PAPER - every front page the empire made
+// ============================================================================
+
+/// The section a front page ran in
+pub fn headline_kind_label(kind: HeadlineKind) -> String {
+    tr(match kind {
+        HeadlineKind::KingpinFell => "paper.section_the_fall",
+        HeadlineKind::InfernoBust => "paper.section_most_wanted",
+        HeadlineKind::DealerJailed => "paper.section_arrests",
+        HeadlineKind::MuscleSeized => "paper.section_shakedown",
+        HeadlineKind::MuscleBenched => "paper.section_assault",
+        HeadlineKind::SupplierCutOff => "paper.section_supply",
+        HeadlineKind::SupplierSoured => "paper.section_burned",
+        HeadlineKind::ZoneOpened => "paper.section_new_turf",
+    })
+}
+
+/// "2024-03-09 · MOST WANTED" - the small print over a front page
+pub fn paper_dateline(headline: &Headline) -> String {
+    format!(
+        "{} · {}",
+        super::chronicle_view::format_date(headline.at),
+        headline_kind_label(headline.kind)
+    )
+}
+
+/// The archive newest first, capped with a truthful tail
+pub fn paper_view(headlines: &[Headline], cap: usize) -> (Vec<Headline>, Option<String>) {
+    let hidden = headlines.len().saturating_sub(cap);
+    let visible = headlines.iter().rev().take(cap).cloned().collect();
+    (visible, tail_line(hidden, "front page", "front pages"))
+}
+
 // ============================================================================
 // Map node history line (SOW-029 acceptance confirmed the placement) -
 // lives here so the ledger and the map derive the SAME numbers
@@ -336,6 +936,8 @@ mod tests {
             unlockable_dealers: Vec::new(),
             narc_portrait: None,
             restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
         }
     }
 
@@ -355,7 +957,13 @@ mod tests {
             dealers_hired: 1,
             total_prior_convictions: 2,
             decks_played: decks,
-            stories: vec!["first".to_string(), "latest".to_string()],
+            stories: vec![StoryRecord::note("first"), StoryRecord::note("latest")],
+            roster: vec![],
+            chronicle: vec![],
+            retirement: None,
+            dynasty: Vec::new(),
+            zones_unlocked: 0,
+            ascension: Default::default(),
         }
     }
 
@@ -460,13 +1068,17 @@ mod tests {
         assert!(rows[0].status_note.is_none());
     }
 
+    fn texts(records: &[StoryRecord]) -> Vec<&str> {
+        records.iter().map(|r| r.text.as_str()).collect()
+    }
+
     #[test]
     fn dossier_counts_stories() {
         let mut save = roster_save();
         save.dealers[0]
             .character
             .story_history
-            .extend(["a".to_string(), "b".to_string()]);
+            .extend([StoryRecord::note("a"), StoryRecord::note("b")]);
         assert_eq!(dossier_rows(&save, &city())[0].story_count, 2);
     }
 
@@ -478,8 +1090,8 @@ mod tests {
         save.dealers[0]
             .character
             .story_history
-            .extend(["first".to_string(), "latest".to_string()]);
-        assert_eq!(dealer_stories(&save, 0), vec!["latest", "first"]);
+            .extend([StoryRecord::note("first"), StoryRecord::note("latest")]);
+        assert_eq!(texts(&dealer_stories(&save, 0)), vec!["latest", "first"]);
         assert!(dealer_stories(&save, 99).is_empty());
     }
 
@@ -490,20 +1102,109 @@ mod tests {
         // Archive order as frozen - NOT reversed: the flat archive has no
         // global chronology once several dealers' histories are
         // concatenated, so the feed reads front-to-back like a case file
-        assert_eq!(epitaph_stories(&save, 0), vec!["first", "latest"]);
+        assert_eq!(texts(&epitaph_stories(&save, 0)), vec!["first", "latest"]);
         assert!(epitaph_stories(&save, 7).is_empty());
     }
 
     #[test]
     fn epitaph_archive_preserves_multi_dealer_grouping() {
         let mut save = roster_save();
-        save.dealers[0].character.story_history = vec!["K1".into(), "K2".into()];
-        save.dealers[1].character.story_history = vec!["H1".into()];
+        save.dealers[0].character.story_history = vec![StoryRecord::note("K1"), StoryRecord::note("K2")];
+        save.dealers[1].character.story_history = vec![StoryRecord::note("H1")];
         let e = EmpireEpitaph::from_save(&save, 1);
         save.fallen_empires.push(e);
         // Kingpin's record first, each dealer oldest-first - grouping
         // survives so a reader can follow one career at a time
-        assert_eq!(epitaph_stories(&save, 0), vec!["K1", "K2", "H1"]);
+        assert_eq!(texts(&epitaph_stories(&save, 0)), vec!["K1", "K2", "H1"]);
+    }
+
+    // -- story filters (SOW-050) --
+
+    fn record(text: &str, dealer: &str, area: &str, outcome: HandOutcome, buyer: &str) -> StoryRecord {
+        StoryRecord {
+            text: text.to_string(),
+            outcome: Some(outcome),
+            area: area.to_string(),
+            buyer: Some(buyer.to_string()),
+            dealer: dealer.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn night() -> Vec<StoryRecord> {
+        vec![
+            record("Sold weed at the trailer.", "Slim", "trailer_park", HandOutcome::Safe, "Frat Bro"),
+            record("The narc was onto me.", "Slim", "suburbia", HandOutcome::Busted, "Soccer Mom"),
+            record("I walked from the Frat Bro.", "The Kingpin", "trailer_park", HandOutcome::Folded, "Frat Bro"),
+            StoryRecord { area: "trailer_park".to_string(), dealer: "Slim".to_string(), ..StoryRecord::note("One deal that night.") },
+        ]
+    }
+
+    #[test]
+    fn empty_filter_passes_everything() {
+        let filter = StoryFilter::default();
+        assert!(!filter.is_active());
+        assert_eq!(filter_stories(night(), &filter).len(), 4);
+    }
+
+    #[test]
+    fn filters_combine() {
+        let by_dealer = StoryFilter { dealer: Some("Slim".to_string()), ..Default::default() };
+        assert_eq!(filter_stories(night(), &by_dealer).len(), 3);
+
+        let slim_at_home = StoryFilter { zone: Some("trailer_park".to_string()), ..by_dealer };
+        assert_eq!(
+            texts(&filter_stories(night(), &slim_at_home)),
+            vec!["Sold weed at the trailer.", "One deal that night."]
+        );
+
+        let frat = StoryFilter { buyer: Some("Frat Bro".to_string()), outcome: Some(HandOutcome::Folded), ..Default::default() };
+        assert!(frat.is_active());
+        assert_eq!(texts(&filter_stories(night(), &frat)), vec!["I walked from the Frat Bro."]);
+    }
+
+    #[test]
+    fn search_is_case_insensitive_text() {
+        let filter = StoryFilter { search: "NARC".to_string(), ..Default::default() };
+        assert_eq!(texts(&filter_stories(night(), &filter)), vec!["The narc was onto me."]);
+        let nothing = StoryFilter { search: "warehouse".to_string(), ..Default::default() };
+        assert!(filter_stories(night(), &nothing).is_empty());
+    }
+
+    #[test]
+    fn filter_options_are_what_the_feed_carries_in_order() {
+        let options = story_filter_options(&night());
+        assert_eq!(options.dealers, vec!["Slim", "The Kingpin"]);
+        assert_eq!(options.zones, vec!["trailer_park", "suburbia"]);
+        assert_eq!(options.outcomes, vec![HandOutcome::Safe, HandOutcome::Busted, HandOutcome::Folded]);
+        assert_eq!(options.buyers, vec!["Frat Bro", "Soccer Mom"]);
+    }
+
+    #[test]
+    fn filter_chips_cycle_back_to_all() {
+        let options = vec!["a".to_string(), "b".to_string()];
+        let first = cycle_filter(&None, &options);
+        assert_eq!(first.as_deref(), Some("a"));
+        let second = cycle_filter(&first, &options);
+        assert_eq!(second.as_deref(), Some("b"));
+        assert_eq!(cycle_filter(&second, &options), None);
+        // Gone from the feed: start over
+        assert_eq!(cycle_filter(&Some("zz".to_string()), &options), None);
+        assert_eq!(cycle_filter::<String>(&None, &[]), None);
+    }
+
+    #[test]
+    fn story_meta_line_reads_the_record() {
+        let mut r = record("x", "Slim", "trailer_park", HandOutcome::Safe, "Frat Bro");
+        r.run = 3;
+        r.profit = 40;
+        r.heat_delta = 8;
+        assert_eq!(
+            story_meta_line(&r, &city()),
+            "RUN 3 · SLIM · TRAILER PARK · SAFE · FRAT BRO · +$40 · HEAT +8"
+        );
+        // A bare note knows nothing: no small print
+        assert_eq!(story_meta_line(&StoryRecord::note("x"), &city()), "");
     }
 
     // -- panel capping --
@@ -584,6 +1285,142 @@ mod tests {
         assert_eq!(tail.as_deref(), Some("… 2 more fallen empires"));
     }
 
+    // -- retirement (SOW-062) --
+
+    #[test]
+    fn retirements_leave_the_fallen_board_for_their_own() {
+        use crate::save::Retirement;
+        let retired = |score: u64, victories: Vec<&str>| EmpireEpitaph {
+            retirement: Some(Retirement {
+                score,
+                clean_cash: 0,
+                victories: victories.into_iter().map(String::from).collect(),
+            }),
+            ..epitaph(500, 1)
+        };
+        let mut save = roster_save();
+        save.fallen_empires = vec![retired(800, vec![]), epitaph(900, 2), retired(3_000, vec!["Own the City", "Clean Money"])];
+
+        let board = board_rows(&save);
+        assert_eq!(board.iter().filter_map(|r| r.epitaph_index).collect::<Vec<_>>(), vec![1]);
+
+        let rows = retirement_rows(&save);
+        assert_eq!(rows.iter().map(|r| (r.rank, r.epitaph_index)).collect::<Vec<_>>(), vec![(1, 2), (2, 0)]);
+        assert_eq!(retirement_detail(&rows[0]), "$500 LIFETIME · OWN THE CITY · CLEAN MONEY");
+        assert_eq!(retirement_detail(&rows[1]), "$500 LIFETIME · NO VICTORIES");
+    }
+
+    #[test]
+    fn retire_view_checks_each_victory() {
+        use crate::models::victory::VictoryCondition;
+        let victory = |name: &str, condition: VictoryCondition| VictoryDef {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            description: "Get there.".to_string(),
+            condition,
+            bonus: 5_000,
+        };
+        let defs = vec![victory("Paid", VictoryCondition::Revenue(1_000)), victory("Rich", VictoryCondition::Revenue(1_000_000))];
+        let view = retire_view(&roster_save(), &defs, city().len());
+        assert_eq!(view.score, 2_000 + 10_000 + 5_000);
+        assert_eq!(view.clean_cash, 10_000);
+        assert_eq!(victory_text(&view.victories[0]), "✓ PAID · +$5,000");
+        assert_eq!(victory_text(&view.victories[1]), "· RICH — Get there.");
+    }
+
+    // -- lineage --
+
+    #[test]
+    fn lineage_splits_revenue_by_reign() {
+        let mut save = roster_save();
+        save.account.lifetime_revenue = 5_000;
+        save.dynasty.push(crate::save::Reign {
+            kingpin: DealerState::kingpin(),
+            fell_at: 1000,
+            lifetime_revenue: 2_000,
+        });
+        let live = lineage_rows(&save, None);
+        assert_eq!(live.len(), 2);
+        assert_eq!((live[0].generation, live[0].end, live[0].revenue), (1, ReignEnd::Busted, 2_000));
+        assert_eq!((live[1].generation, live[1].end, live[1].revenue), (2, ReignEnd::Reigning, 3_000));
+        assert!(lineage_text(&live[0]).contains("BUSTED · $2,000"));
+
+        save.reset_empire();
+        let archived = lineage_rows(&save, Some(0));
+        assert_eq!(archived.last().map(|r| (r.end, r.revenue)), Some((ReignEnd::Fell, 3_000)));
+        assert_eq!(lineage_rows(&save, None).len(), 1, "the fresh empire's founder");
+        assert!(lineage_rows(&save, Some(9)).is_empty());
+    }
+
+    #[test]
+    fn succession_line_names_the_heir_or_the_bar() {
+        let mut save = roster_save();
+        assert_eq!(succession_line(&save), tr("ledger.succession_off_note"));
+        save.set_succession(true);
+        assert!(succession_line(&save).starts_with("NO HEIR"));
+        save.dealers[1].character.decks_played = crate::save::SUCCESSION_MIN_RUNS;
+        save.dealers[1].street_cred.insert("trailer_park".into(), crate::save::SUCCESSION_MIN_CRED);
+        assert_eq!(succession_line(&save), "HEIR: SLIM");
+    }
+
+    // -- ascension --
+
+    #[test]
+    fn ascension_ranks_the_board_and_locks_once_underway() {
+        let mut save = roster_save();
+        save.fallen_empires.push(EmpireEpitaph { ascension: Ascension(1), ..epitaph(100, 1) });
+        save.fallen_empires.push(epitaph(50_000, 1));
+        let rows = board_rows(&save);
+        let order: Vec<Option<usize>> = rows.iter().map(|r| r.epitaph_index).collect();
+        assert_eq!(order, vec![Some(0), Some(1), None], "level before revenue, the living empire at level 0");
+        assert_eq!(level_tag(rows[0].ascension), " · LEVEL 1");
+        assert_eq!(level_tag(rows[1].ascension), "");
+
+        save.ascension_unlocked = Ascension(2);
+        let view = ascension_view(&save);
+        assert_eq!(view.levels, vec![Ascension(0), Ascension(1), Ascension(2)]);
+        assert!(view.modifiers.is_empty());
+        assert!(view.open);
+        assert!(view.next.unwrap().contains("level 2"));
+
+        save.set_ascension(Ascension(2)).unwrap();
+        save.account.hands_completed = 1;
+        let view = ascension_view(&save);
+        assert_eq!(view.modifiers, vec!["+ Narcs run one heat tier hotter", "+ Fronts carry 40% vig"]);
+        assert!(!view.open);
+    }
+
+    // -- legacy --
+
+    #[test]
+    fn legacy_offers_open_slots_and_prices_them() {
+        use crate::models::test_helpers::create_product as product;
+        let products: HashMap<String, Card> = [product("Weed", 30, 5), product("Shrooms", 60, 10)]
+            .into_iter()
+            .map(|card| (card.id.clone(), card))
+            .collect();
+        let areas = city();
+        let content = LegacyContent { products: &products, areas: &areas, relics: &[] };
+        let mut save = roster_save();
+        save.legacy.earned = epitaph(50_000, 3).legacy_earned(); // 50 + 5
+
+        let view = legacy_view(&save, &content);
+        assert_eq!(view.balance, 55);
+        let labels: Vec<&str> = view.offers.iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["START WITH SHROOMS", "START IN RED LIGHT DISTRICT", "START IN SUBURBIA", "FIRST HIRE AT 50%"],
+            "weed is already in the starting collection"
+        );
+        assert!(view.offers.iter().all(|o| o.affordable == (o.cost <= 55)));
+
+        save.buy_legacy(LegacyUnlock::StartingZone("suburbia".into())).unwrap();
+        let view = legacy_view(&save, &content);
+        assert_eq!(view.owned, vec!["START IN SUBURBIA"]);
+        assert!(view.offers.iter().all(|o| !matches!(o.unlock, LegacyUnlock::StartingZone(_))));
+        assert!(view.offers.iter().all(|o| !o.affordable), "nothing left under the balance");
+    }
+
     // -- the board --
 
     #[test]
@@ -731,4 +1568,93 @@ mod tests {
         let line = zone_history_line(&save, "trailer_park").unwrap();
         assert!(line.contains(&save.dealers[best_idx].name), "{line}");
     }
+
+    // ---- SOW-044 regulars ----
+
+    fn regular(name: &str, area: &str, relationship: u32) -> crate::save::Regular {
+        crate::save::Regular {
+            name: name.to_string(),
+            persona: "Frat Bro".to_string(),
+            area: area.to_string(),
+            relationship,
+            favourite_product: Some("Weed".to_string()),
+            favourite_location: None,
+            deals: 2,
+            referred_by: None,
+            informant: false,
+        }
+    }
+
+    #[test]
+    fn regulars_warmest_first_informants_last() {
+        let areas = [area("trailer_park", "Trailer Park")];
+        let mut save = SaveData::new();
+        let mut snitch = regular("Tyler", "trailer_park", 90);
+        snitch.informant = true;
+        save.regulars = vec![
+            regular("Chad", "trailer_park", 55),
+            snitch,
+            regular("Brock", "downtown", 80),
+            regular("Kyle", "trailer_park", 55),
+        ];
+
+        let rows = regular_rows(&save, &areas);
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Brock", "Chad", "Kyle", "Tyler"]);
+        assert_eq!(rows[0].area, "downtown"); // unknown area: raw id
+        assert_eq!(rows[1].area, "Trailer Park");
+        assert_eq!(rows[1].likes.as_deref(), Some("Weed"));
+        assert_eq!(rows[0].mood, "LOYAL");
+        assert_eq!(rows[3].mood, "INFORMANT");
+    }
+
+    #[test]
+    fn regulars_panel_caps_with_truthful_tail() {
+        let mut save = SaveData::new();
+        save.regulars = (0..8).map(|i| regular(&format!("R{i}"), "trailer_park", 50)).collect();
+        let (visible, tail) = regulars_view(regular_rows(&save, &[]), REGULARS_PANEL_CAP);
+        assert_eq!(visible.len(), REGULARS_PANEL_CAP);
+        assert_eq!(tail.as_deref(), Some("… 2 more regulars"));
+
+        save.regulars.truncate(3);
+        let (visible, tail) = regulars_view(regular_rows(&save, &[]), REGULARS_PANEL_CAP);
+        assert_eq!(visible.len(), 3);
+        assert_eq!(tail, None);
+    }
+
+    #[test]
+    fn paper_panel_is_newest_first_with_a_dateline() {
+        let page = |at: u64, kind| Headline { at, kind, headline: at.to_string(), article: String::new() };
+        let archive: Vec<Headline> = (0..6).map(|i| page(86_400 * i, HeadlineKind::ZoneOpened)).collect();
+        let (visible, tail) = paper_view(&archive, PAPER_PANEL_CAP);
+        assert_eq!(visible.iter().map(|h| h.at / 86_400).collect::<Vec<_>>(), vec![5, 4, 3, 2]);
+        assert_eq!(tail.as_deref(), Some("… 2 more front pages"));
+        assert_eq!(paper_view(&archive[..1], PAPER_PANEL_CAP).1, None);
+
+        assert_eq!(paper_dateline(&page(0, HeadlineKind::InfernoBust)), "1970-01-01 · MOST WANTED");
+    }
+
+    /// SOW-053: characters a story filter chip holds before it crowds its
+    /// row
+    const FILTER_CHIP_BUDGET: usize = 36;
+
+    #[test]
+    fn filter_chips_fit_once_translated() {
+        assert_eq!(filter_chip_text("ZONE", Some("Trailer Park")), "ZONE: TRAILER PARK");
+        assert_eq!(filter_chip_text("ZONE", None), "ZONE: ALL");
+        crate::locale::scoped(crate::locale::Locale::pseudo(), || {
+            let label = tr("ledger.filter_outcome");
+            for outcome in [
+                HandOutcome::Safe,
+                HandOutcome::Busted,
+                HandOutcome::Folded,
+                HandOutcome::InvalidDeal,
+                HandOutcome::BuyerBailed,
+            ] {
+                let chip = filter_chip_text(&label, Some(&outcome_label(outcome)));
+                assert!(crate::models::test_helpers::fits(&chip, FILTER_CHIP_BUDGET), "{chip:?} overflows");
+            }
+            assert!(crate::models::test_helpers::fits(&filter_chip_text(&label, None), FILTER_CHIP_BUDGET));
+        });
+    }
 }
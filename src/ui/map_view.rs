@@ -2,10 +2,11 @@
 // overlay. Same rule as view.rs: everything here is unit-testable without
 // ECS; systems/city_map.rs only orchestrates spawning from these values.
 
+use crate::locale::{tr, tr_with};
 use crate::models::buyer::BuyerPersona;
 use crate::models::card::Card;
-use crate::models::shop_location::ShopLocationDef;
-use crate::save::{DealerState, SaveData};
+use crate::models::shop_location::{ShopLocationDef, ZoneGraph};
+use crate::save::{DealerState, DealerStatus, SaveData};
 
 // ============================================================================
 // Zone node card
@@ -241,27 +242,38 @@ pub fn area_dealer_offers(area: &ShopLocationDef, save: &SaveData) -> Vec<AreaDe
         .collect()
 }
 
-/// Status suffix shared by map chips and ledger dossiers (SOW-030)
+/// Status suffix shared by map chips, roster rows and ledger dossiers
+/// (SOW-030)
 pub fn chip_status_note(dealer: &DealerState) -> Option<String> {
-    let plural = |n: u32| if n == 1 { "" } else { "S" };
-    if let Some(runs) = dealer.jail_remaining() {
-        Some(format!("JAILED · {} RUN{}", runs, plural(runs)))
+    let (key, runs) = if let Some(runs) = dealer.jail_remaining() {
+        ("status.jailed", runs)
     } else if let Some(runs) = dealer.relocating_remaining() {
-        Some(format!("MOVING · {} RUN{}", runs, plural(runs)))
+        ("status.moving", runs)
     } else if let Some(runs) = dealer.laying_low_remaining() {
-        Some(format!("LAYING LOW · {} RUN{}", runs, plural(runs)))
+        ("status.laying_low", runs)
     } else {
-        None
+        return None;
+    };
+    Some(tr_with(key, &[("runs", &runs_text(runs))]))
+}
+
+/// SOW-043: the road a dealer is walking (origin first), None when they
+/// aren't on one - a muscle bench relocates in place, with no road
+pub fn dealer_route(dealer: &DealerState) -> Option<&[String]> {
+    match &dealer.status {
+        DealerStatus::Relocating { route, .. } if route.len() >= 2 => Some(route),
+        _ => None,
     }
 }
 
-/// Chips for the dealers stationed in `area_id`
+/// Chips for the dealers stationed in `area_id`. SOW-043: a dealer still on
+/// the road is drawn on it (transit_chips), not at their new station.
 pub fn dealer_chips(save: &SaveData, area_id: &str) -> Vec<DealerChip> {
     let best = save.best_cred(area_id);
     save.dealers
         .iter()
         .enumerate()
-        .filter(|(_, d)| d.station == area_id)
+        .filter(|(_, d)| d.station == area_id && dealer_route(d).is_none())
         .map(|(index, d)| {
             let tier = d.character.heat_tier();
             DealerChip {
@@ -307,21 +319,28 @@ pub fn zone_node_view<'a>(
 // ============================================================================
 
 /// Whether the selected dealer can be sent to a destination node right now.
-/// Mirrors `SaveData::move_dealer`'s guards so the button never promises a
-/// move the model would refuse.
+/// Mirrors `SaveData::move_dealer_along`'s guards so the button never
+/// promises a move the model would refuse. SOW-043: priced by the zone
+/// graph's route (`runs` of downtime, `fee` up front).
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MoveEligibility {
-    Eligible { fee: u64 },
+    Eligible { fee: u64, runs: u32 },
     /// Destination is the dealer's current station
     StationedHere,
     /// Dealer is jailed/relocating/laying low (chips shouldn't offer this,
     /// but state can shift between spawn and click)
     DealerUnavailable,
-    CantAfford { fee: u64 },
+    /// SOW-043: no road connects the two zones
+    NoRoute,
+    CantAfford { fee: u64, runs: u32 },
 }
 
-pub fn move_eligibility(save: &SaveData, dealer_index: usize, to_area: &str) -> MoveEligibility {
-    let fee = save.move_fee();
+pub fn move_eligibility(
+    save: &SaveData,
+    graph: &ZoneGraph,
+    dealer_index: usize,
+    to_area: &str,
+) -> MoveEligibility {
     let Some(dealer) = save.dealers.get(dealer_index) else {
         return MoveEligibility::DealerUnavailable;
     };
@@ -331,23 +350,257 @@ pub fn move_eligibility(save: &SaveData, dealer_index: usize, to_area: &str) ->
     if !dealer.is_available() {
         return MoveEligibility::DealerUnavailable;
     }
+    let Some(route) = graph.route(&dealer.station, to_area) else {
+        return MoveEligibility::NoRoute;
+    };
+    let (fee, runs) = (route.cost, route.runs);
     if save.account.cash_on_hand < fee {
-        return MoveEligibility::CantAfford { fee };
+        return MoveEligibility::CantAfford { fee, runs };
     }
-    MoveEligibility::Eligible { fee }
+    MoveEligibility::Eligible { fee, runs }
+}
+
+/// "1 RUN" / "3 RUNS"
+pub fn runs_text(runs: u32) -> String {
+    let key = if runs == 1 { "runs.one" } else { "runs.many" };
+    tr_with(key, &[("count", &runs.to_string())])
+}
+
+/// "1 RUN OUT" / "3 RUNS OUT" - a move's downtime
+pub fn runs_out(runs: u32) -> String {
+    tr_with("map.runs_out", &[("runs", &runs_text(runs))])
+}
+
+/// "$250" or "$200–$600" across a set of prices
+fn price_band(lo: u64, hi: u64) -> String {
+    if lo == hi { format!("${lo}") } else { format!("${lo}–${hi}") }
 }
 
 /// Header hint above the nodes: idle instructions, or the armed move with
-/// its full cost (fee + downtime) BEFORE anything commits
-pub fn map_hint(save: &SaveData, selected_dealer: Option<usize>) -> String {
-    match selected_dealer.and_then(|i| save.dealers.get(i)) {
-        Some(dealer) => format!(
-            "SENDING {} — pick a destination · ${} + 1 RUN OUT",
-            dealer.name.to_uppercase(),
-            save.move_fee()
-        ),
-        None => "Click a dealer, then a destination, to relocate".to_string(),
+/// its full cost (fee + downtime) BEFORE anything commits. SOW-043: costs
+/// vary by road, so the hint quotes the band across the zones the dealer
+/// could be sent to; each node's SEND button carries its exact price.
+pub fn map_hint(save: &SaveData, graph: &ZoneGraph, selected_dealer: Option<usize>) -> String {
+    let Some(dealer) = selected_dealer.and_then(|i| save.dealers.get(i)) else {
+        return tr("map.hint_idle");
+    };
+    let routes: Vec<_> = save
+        .account
+        .unlocked_locations
+        .iter()
+        .filter_map(|to| graph.route(&dealer.station, to))
+        .collect();
+    let name = dealer.name.to_uppercase();
+    let (Some(cost_lo), Some(cost_hi), Some(runs_lo), Some(runs_hi)) = (
+        routes.iter().map(|r| r.cost).min(),
+        routes.iter().map(|r| r.cost).max(),
+        routes.iter().map(|r| r.runs).min(),
+        routes.iter().map(|r| r.runs).max(),
+    ) else {
+        return tr_with("map.hint_nowhere", &[("name", &name)]);
+    };
+    let runs = if runs_lo == runs_hi {
+        runs_out(runs_lo)
+    } else {
+        format!("{runs_lo}–{}", runs_out(runs_hi))
+    };
+    tr_with(
+        "map.hint_sending",
+        &[("name", &name), ("cost", &price_band(cost_lo, cost_hi)), ("runs", &runs)],
+    )
+}
+
+// ============================================================================
+// SOW-043: Layout - nodes, roads, dealers on the road
+// ============================================================================
+
+/// Largest node card (the original three-across size)
+pub const NODE_MAX_SIZE: (f32, f32) = (480.0, 680.0);
+/// Gap between auto-laid-out node cards
+pub const NODE_GAP: f32 = 30.0;
+/// Zones per row when the map lays itself out
+const GRID_COLUMNS: usize = 3;
+
+/// Where one zone's node card sits on the canvas (px, top-left origin)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodePlacement {
+    pub area_id: String,
+    pub center: (f32, f32),
+    pub left: f32,
+    pub top: f32,
+}
+
+/// One road drawn between two node centres
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoadView {
+    pub from: (f32, f32),
+    pub to: (f32, f32),
+    /// "2 RUNS · $400"
+    pub label: String,
+}
+
+impl RoadView {
+    pub fn midpoint(&self) -> (f32, f32) {
+        ((self.from.0 + self.to.0) / 2.0, (self.from.1 + self.to.1) / 2.0)
+    }
+
+    pub fn length(&self) -> f32 {
+        (self.to.0 - self.from.0).hypot(self.to.1 - self.from.1)
+    }
+
+    /// Clockwise angle from the +x axis in radians (UI y points down)
+    pub fn angle(&self) -> f32 {
+        (self.to.1 - self.from.1).atan2(self.to.0 - self.from.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLayout {
+    pub card_size: (f32, f32),
+    pub nodes: Vec<NodePlacement>,
+    /// Authored roads only - an unauthored map (every zone one hop apart)
+    /// draws none
+    pub roads: Vec<RoadView>,
+}
+
+impl MapLayout {
+    pub fn center_of(&self, area_id: &str) -> Option<(f32, f32)> {
+        self.nodes.iter().find(|n| n.area_id == area_id).map(|n| n.center)
+    }
+}
+
+/// Lay the zones out on a `canvas` (width, height) px. Authored
+/// `map_position`s are used when EVERY zone has one; otherwise zones fill a
+/// grid, three to a row, each row centred - so a fourth zone in
+/// shop_locations.ron lands on the map without touching code. Cards shrink
+/// to fit the grid, never past NODE_MAX_SIZE.
+pub fn layout_zones(areas: &[ShopLocationDef], graph: &ZoneGraph, canvas: (f32, f32)) -> MapLayout {
+    let (width, height) = canvas;
+    let count = areas.len().max(1);
+    let columns = count.min(GRID_COLUMNS);
+    let rows = count.div_ceil(columns);
+    let card_w = ((width - NODE_GAP * (columns - 1) as f32) / columns as f32).min(NODE_MAX_SIZE.0);
+    let card_h = ((height - NODE_GAP * (rows - 1) as f32) / rows as f32).min(NODE_MAX_SIZE.1);
+
+    let authored = !areas.is_empty() && areas.iter().all(|a| a.map_position.is_some());
+    let centers: Vec<(f32, f32)> = if authored {
+        areas
+            .iter()
+            .filter_map(|a| a.map_position)
+            .map(|pos| {
+                // Keep the whole card on the canvas
+                (
+                    (pos.x * width).clamp(card_w / 2.0, (width - card_w / 2.0).max(card_w / 2.0)),
+                    (pos.y * height).clamp(card_h / 2.0, (height - card_h / 2.0).max(card_h / 2.0)),
+                )
+            })
+            .collect()
+    } else {
+        let block_h = rows as f32 * card_h + (rows - 1) as f32 * NODE_GAP;
+        (0..areas.len())
+            .map(|i| {
+                let (row, col) = (i / columns, i % columns);
+                let in_row = (areas.len() - row * columns).min(columns);
+                let row_w = in_row as f32 * card_w + (in_row - 1) as f32 * NODE_GAP;
+                (
+                    (width - row_w) / 2.0 + col as f32 * (card_w + NODE_GAP) + card_w / 2.0,
+                    (height - block_h) / 2.0 + row as f32 * (card_h + NODE_GAP) + card_h / 2.0,
+                )
+            })
+            .collect()
+    };
+
+    let nodes: Vec<NodePlacement> = areas
+        .iter()
+        .zip(centers)
+        .map(|(area, center)| NodePlacement {
+            area_id: area.id.clone(),
+            center,
+            left: center.0 - card_w / 2.0,
+            top: center.1 - card_h / 2.0,
+        })
+        .collect();
+
+    let center_of = |id: &str| nodes.iter().find(|n| n.area_id == id).map(|n| n.center);
+    let roads = graph
+        .edges()
+        .filter_map(|(a, b, runs, cost)| {
+            Some(RoadView {
+                from: center_of(a)?,
+                to: center_of(b)?,
+                label: tr_with("map.road", &[("runs", &runs_text(runs)), ("cost", &cost.to_string())]),
+            })
+        })
+        .collect();
+
+    MapLayout { card_size: (card_w, card_h), nodes, roads }
+}
+
+/// A dealer drawn on the road mid-move
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitChip {
+    pub dealer_index: usize,
+    /// "SLIM → SUBURBIA · 2 RUNS"
+    pub label: String,
+    /// Canvas px the chip is centred on
+    pub at: (f32, f32),
+}
+
+/// Where along its road a move currently is: the leg being walked (its two
+/// zone ids) and the fraction of that leg covered, mid-run. Each leg takes
+/// its graph runs; a leg the graph no longer knows counts as one run.
+pub fn transit_position<'a>(
+    route: &'a [String],
+    total_runs: u32,
+    runs_remaining: u32,
+    graph: &ZoneGraph,
+) -> Option<(&'a str, &'a str, f32)> {
+    let elapsed = total_runs.saturating_sub(runs_remaining);
+    let mut covered = 0;
+    let legs: Vec<_> = route.windows(2).collect();
+    for (i, leg) in legs.iter().enumerate() {
+        let runs = graph.leg(&leg[0], &leg[1]).map(|(r, _)| r).unwrap_or(1).max(1);
+        if elapsed < covered + runs || i == legs.len() - 1 {
+            let into = (elapsed.saturating_sub(covered) as f32 + 0.5) / runs as f32;
+            return Some((leg[0].as_str(), leg[1].as_str(), into.min(1.0)));
+        }
+        covered += runs;
     }
+    None
+}
+
+/// SOW-043: every dealer on the road, placed along the leg they're walking
+pub fn transit_chips(
+    save: &SaveData,
+    graph: &ZoneGraph,
+    layout: &MapLayout,
+    areas: &[ShopLocationDef],
+) -> Vec<TransitChip> {
+    save.dealers
+        .iter()
+        .enumerate()
+        .filter_map(|(index, dealer)| {
+            let DealerStatus::Relocating { total_runs, runs_remaining, .. } = &dealer.status else {
+                return None;
+            };
+            let route = dealer_route(dealer)?;
+            let (from, to, t) = transit_position(route, *total_runs, *runs_remaining, graph)?;
+            let (a, b) = (layout.center_of(from)?, layout.center_of(to)?);
+            let destination = super::day_view::area_name(areas, &dealer.station);
+            Some(TransitChip {
+                dealer_index: index,
+                label: tr_with(
+                    "map.transit",
+                    &[
+                        ("name", &dealer.name.to_uppercase()),
+                        ("destination", &destination),
+                        ("runs", &runs_text(*runs_remaining)),
+                    ],
+                ),
+                at: (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t),
+            })
+        })
+        .collect()
 }
 
 // ============================================================================
@@ -360,6 +613,7 @@ mod tests {
     use crate::models::buyer::BuyerDemand;
     use crate::models::card::CardType;
     use crate::save::DealerStatus;
+    use crate::models::shop_location::MOVE_FEE;
 
     fn persona(name: &str, area: &str, mult: f32) -> BuyerPersona {
         BuyerPersona {
@@ -378,6 +632,9 @@ mod tests {
             reaction_deck: vec![],
             scenarios: vec![],
             active_scenario_index: None,
+            personality: Default::default(),
+            regular_names: Vec::new(),
+            regular: None,
         }
     }
 
@@ -390,6 +647,7 @@ mod tests {
             shop_location: Some(area.to_string()),
             shop_price: Some(price),
             shop_cred_required: None,
+            evolutions: Vec::new(),
         }
     }
 
@@ -413,6 +671,8 @@ mod tests {
             unlockable_dealers: Vec::new(),
             narc_portrait: None,
             restock_margin: 0.5,
+            map_position: None,
+            links: Vec::new(),
         }
     }
 
@@ -564,29 +824,34 @@ mod tests {
         assert_eq!(chips[0].heat, 95);
     }
 
-    // -- move eligibility (mirrors SaveData::move_dealer's guards) --
+    // -- move eligibility (mirrors SaveData::move_dealer_along's guards) --
+
+    /// Eligibility on an unauthored map (every zone one flat hop apart)
+    fn eligibility(save: &SaveData, dealer_index: usize, to_area: &str) -> MoveEligibility {
+        move_eligibility(save, &ZoneGraph::default(), dealer_index, to_area)
+    }
 
     #[test]
     fn move_eligible_when_available_elsewhere_and_funded() {
         let save = save_with_cash(1000);
         assert_eq!(
-            move_eligibility(&save, 0, "suburbia"),
-            MoveEligibility::Eligible { fee: save.move_fee() }
+            eligibility(&save, 0, "suburbia"),
+            MoveEligibility::Eligible { fee: MOVE_FEE, runs: 1 }
         );
     }
 
     #[test]
     fn move_to_own_station_is_stationed_here() {
         let save = save_with_cash(1000);
-        assert_eq!(move_eligibility(&save, 0, "trailer_park"), MoveEligibility::StationedHere);
+        assert_eq!(eligibility(&save, 0, "trailer_park"), MoveEligibility::StationedHere);
     }
 
     #[test]
     fn broke_empire_cant_afford_the_move() {
         let save = save_with_cash(0);
         assert_eq!(
-            move_eligibility(&save, 0, "suburbia"),
-            MoveEligibility::CantAfford { fee: save.move_fee() }
+            eligibility(&save, 0, "suburbia"),
+            MoveEligibility::CantAfford { fee: MOVE_FEE, runs: 1 }
         );
     }
 
@@ -598,30 +863,35 @@ mod tests {
             sentence_total: 1,
             heat_at_bust: 10,
         };
-        assert_eq!(move_eligibility(&save, 0, "suburbia"), MoveEligibility::DealerUnavailable);
+        assert_eq!(eligibility(&save, 0, "suburbia"), MoveEligibility::DealerUnavailable);
 
-        save.dealers[0].status = DealerStatus::Relocating { runs_remaining: 1 };
-        assert_eq!(move_eligibility(&save, 0, "suburbia"), MoveEligibility::DealerUnavailable);
+        save.dealers[0].status = DealerStatus::Relocating {
+            runs_remaining: 1,
+            route: vec![],
+            total_runs: 1,
+        };
+        assert_eq!(eligibility(&save, 0, "suburbia"), MoveEligibility::DealerUnavailable);
 
-        assert_eq!(move_eligibility(&save, 99, "suburbia"), MoveEligibility::DealerUnavailable);
+        assert_eq!(eligibility(&save, 99, "suburbia"), MoveEligibility::DealerUnavailable);
     }
 
     // -- hint line --
 
     #[test]
     fn hint_shows_full_cost_before_commit() {
-        let save = save_with_cash(1000);
-        let hint = map_hint(&save, Some(0));
+        let mut save = save_with_cash(1000);
+        save.account.unlocked_locations.insert("suburbia".to_string());
+        let hint = map_hint(&save, &ZoneGraph::default(), Some(0));
         assert!(hint.contains("THE KINGPIN"), "{hint}");
-        assert!(hint.contains(&format!("${}", save.move_fee())), "{hint}");
+        assert!(hint.contains(&format!("${}", MOVE_FEE)), "{hint}");
         assert!(hint.contains("1 RUN OUT"), "{hint}");
     }
 
     #[test]
     fn hint_idle_and_stale_selection_fall_back_to_instructions() {
         let save = save_with_cash(0);
-        assert!(map_hint(&save, None).contains("Click a dealer"));
-        assert!(map_hint(&save, Some(99)).contains("Click a dealer"));
+        assert!(map_hint(&save, &ZoneGraph::default(), None).contains("Click a dealer"));
+        assert!(map_hint(&save, &ZoneGraph::default(), Some(99)).contains("Click a dealer"));
     }
 
     // -- full node assembly --
@@ -802,4 +1072,154 @@ mod tests {
             AreaDealerOfferState::Hired
         );
     }
+
+    // -- SOW-043: routed moves, layout, dealers on the road --
+
+    use crate::models::shop_location::{MapPosition, ZoneLink};
+
+    /// trailer_park - suburbia - red_light_district in a line
+    fn linked_areas() -> Vec<ShopLocationDef> {
+        let mut park = area("trailer_park", true, 0);
+        park.links = vec![ZoneLink { to: "suburbia".into(), runs: 1, cost: 200 }];
+        let mut burbs = area("suburbia", false, 800);
+        burbs.links = vec![ZoneLink { to: "red_light_district".into(), runs: 2, cost: 300 }];
+        vec![park, burbs, area("red_light_district", false, 1200)]
+    }
+
+    #[test]
+    fn move_eligibility_prices_the_route() {
+        let graph = ZoneGraph::from_areas(&linked_areas());
+        let save = save_with_cash(400);
+        assert_eq!(
+            move_eligibility(&save, &graph, 0, "suburbia"),
+            MoveEligibility::Eligible { fee: 200, runs: 1 }
+        );
+        assert_eq!(
+            move_eligibility(&save, &graph, 0, "red_light_district"),
+            MoveEligibility::CantAfford { fee: 500, runs: 3 }
+        );
+        assert_eq!(move_eligibility(&save, &graph, 0, "harbor"), MoveEligibility::NoRoute);
+    }
+
+    #[test]
+    fn hint_quotes_the_band_across_destinations() {
+        let graph = ZoneGraph::from_areas(&linked_areas());
+        let mut save = save_with_cash(1000);
+        save.account.unlocked_locations.insert("suburbia".into());
+        save.account.unlocked_locations.insert("red_light_district".into());
+        let hint = map_hint(&save, &graph, Some(0));
+        assert!(hint.contains("$200–$500 + 1–3 RUNS OUT"), "{hint}");
+
+        let lonely = save_with_cash(1000);
+        assert!(map_hint(&lonely, &graph, Some(0)).contains("no other zone"));
+    }
+
+    #[test]
+    fn three_zones_sit_in_one_row_at_full_size() {
+        let areas = linked_areas();
+        let layout = layout_zones(&areas, &ZoneGraph::default(), (1872.0, 940.0));
+        assert_eq!(layout.card_size, NODE_MAX_SIZE);
+        let tops: Vec<f32> = layout.nodes.iter().map(|n| n.top).collect();
+        assert!(tops.iter().all(|t| *t == tops[0]));
+        // Centred: equal margins either side of the row
+        let left = layout.nodes[0].left;
+        let right = 1872.0 - (layout.nodes[2].left + NODE_MAX_SIZE.0);
+        assert!((left - right).abs() < 0.01);
+        // Unauthored map: no roads drawn
+        assert!(layout.roads.is_empty());
+    }
+
+    #[test]
+    fn fourth_zone_wraps_to_a_second_row_without_code_changes() {
+        let mut areas = linked_areas();
+        areas.push(area("penthouse_row", false, 5000));
+        let layout = layout_zones(&areas, &ZoneGraph::default(), (1872.0, 940.0));
+        let (w, h) = layout.card_size;
+        assert!(h < NODE_MAX_SIZE.1 && w <= NODE_MAX_SIZE.0);
+        assert_eq!(layout.nodes.len(), 4);
+        // Second row, centred on its own
+        let fourth = &layout.nodes[3];
+        assert!(fourth.top > layout.nodes[0].top + h);
+        assert!((fourth.center.0 - 936.0).abs() < 0.01);
+        // Nothing spills off the canvas
+        for node in &layout.nodes {
+            assert!(node.left >= 0.0 && node.top >= 0.0);
+            assert!(node.left + w <= 1872.0 && node.top + h <= 940.0);
+        }
+    }
+
+    #[test]
+    fn authored_positions_place_nodes_and_roads() {
+        let mut areas = linked_areas();
+        areas[0].map_position = Some(MapPosition { x: 0.0, y: 0.5 });
+        areas[1].map_position = Some(MapPosition { x: 0.5, y: 0.5 });
+        areas[2].map_position = Some(MapPosition { x: 1.0, y: 0.5 });
+        let graph = ZoneGraph::from_areas(&areas);
+        let layout = layout_zones(&areas, &graph, (1872.0, 940.0));
+
+        // Edge positions clamp so the card stays on the canvas
+        assert_eq!(layout.nodes[0].left, 0.0);
+        assert_eq!(layout.nodes[1].center, (936.0, 470.0));
+        assert_eq!(layout.roads.len(), 2);
+        let road = layout
+            .roads
+            .iter()
+            .find(|r| r.label == "2 RUNS · $300")
+            .expect("suburbia - red light road");
+        assert!(road.angle().sin().abs() < 1e-6, "a level road");
+        assert_eq!(road.length(), 696.0);
+        assert_eq!(road.midpoint().1, 470.0);
+
+        // One zone without a position: the whole map falls back to the grid
+        areas[2].map_position = None;
+        let grid = layout_zones(&areas, &graph, (1872.0, 940.0));
+        assert!(grid.nodes[0].left > 0.0);
+    }
+
+    #[test]
+    fn transit_walks_the_legs_run_by_run() {
+        let graph = ZoneGraph::from_areas(&linked_areas());
+        let route: Vec<String> =
+            vec!["trailer_park".into(), "suburbia".into(), "red_light_district".into()];
+        // 3 runs: one on the first leg, two on the second
+        assert_eq!(
+            transit_position(&route, 3, 3, &graph),
+            Some(("trailer_park", "suburbia", 0.5))
+        );
+        assert_eq!(
+            transit_position(&route, 3, 2, &graph),
+            Some(("suburbia", "red_light_district", 0.25))
+        );
+        assert_eq!(
+            transit_position(&route, 3, 1, &graph),
+            Some(("suburbia", "red_light_district", 0.75))
+        );
+        assert_eq!(transit_position(&route[..1], 1, 1, &graph), None);
+    }
+
+    #[test]
+    fn moving_dealer_leaves_the_node_for_the_road() {
+        let areas = linked_areas();
+        let graph = ZoneGraph::from_areas(&areas);
+        let mut save = save_with_cash(1000);
+        let route = graph.route("trailer_park", "suburbia").unwrap();
+        assert!(save.move_dealer_along(0, &route));
+
+        assert!(dealer_chips(&save, "suburbia").is_empty());
+        let layout = layout_zones(&areas, &graph, (1872.0, 940.0));
+        let chips = transit_chips(&save, &graph, &layout, &areas);
+        assert_eq!(chips.len(), 1);
+        assert_eq!(chips[0].label, "THE KINGPIN → SUBURBIA · 1 RUN");
+        let (a, b) = (layout.nodes[0].center, layout.nodes[1].center);
+        assert_eq!(chips[0].at, ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0));
+
+        // Benched in place (no road): stays on the node with its MOVING note
+        save.dealers[0].status = DealerStatus::Relocating {
+            runs_remaining: 1,
+            route: vec![],
+            total_runs: 1,
+        };
+        assert_eq!(dealer_chips(&save, "suburbia").len(), 1);
+        assert!(transit_chips(&save, &graph, &layout, &areas).is_empty());
+    }
 }
@@ -16,6 +16,10 @@ pub mod ledger_view; // SOW-030: pure view-model logic for the kingpin ledger
 pub mod front_view; // SOW-031: pure view-model logic for supplier fronts
 pub mod stock_view; // SOW-034: pure view-model logic for consumable product stock
 pub mod tutorial_view; // SOW-032: pure beat detection + goal-strip presentation
+pub mod day_view; // SOW-042: pure order cycle + day summary presentation
+pub mod chronicle_view; // SOW-051: pure empire chronicle document (Markdown/HTML)
+pub mod relic_view; // SOW-059: pure relic shelf + rare slot presentation
+pub mod run_map_view; // SOW-060: pure run-map route row presentation
 
 // Re-exports for convenience
 pub use helpers::*;
@@ -0,0 +1,75 @@
+// SOW-059: Relic view-model - pure presentation for relics on their two
+// surfaces (the hub shelf under the lifetime line, the shop's rare slot).
+// Same rule as the other _view modules: unit-testable without ECS; systems
+// only orchestrate.
+
+use crate::locale::{tr, tr_with};
+use crate::models::relic::RelicEffect;
+use crate::save::OwnedRelic;
+
+/// One effect, as the shelf and the rare slot print it ("+5 COVER")
+pub fn effect_label(effect: RelicEffect) -> String {
+    match effect {
+        RelicEffect::Cover(n) => format!("+{n} COVER"),
+        RelicEffect::Profit(n) => format!("+{n}% PROFIT"),
+        RelicEffect::Margin(n) => format!("+{n} EVIDENCE MARGIN"),
+        RelicEffect::Sentence(n) => format!("-{n} JAIL RUN{}", if n == 1 { "" } else { "S" }),
+        RelicEffect::Vig(n) => format!("-{n}% VIG"),
+        RelicEffect::Restock(n) => format!("-{n}% RESTOCK"),
+        RelicEffect::Decay(n) => format!("+{n}% HEAT DECAY"),
+    }
+}
+
+/// Every effect on one line ("+5 COVER · -10% VIG")
+pub fn effects_line(effects: &[RelicEffect]) -> String {
+    effects.iter().map(|e| effect_label(*e)).collect::<Vec<_>>().join(" · ")
+}
+
+/// The hub shelf: a heading, then one line per relic with its effects, in
+/// the order they came
+pub fn shelf_text(relics: &[OwnedRelic]) -> String {
+    if relics.is_empty() {
+        return tr("relic.none");
+    }
+    let mut lines = vec![tr_with("relic.shelf", &[("count", &relics.len().to_string())])];
+    lines.extend(relics.iter().map(|r| format!("{}: {}", r.name, effects_line(&r.effects))));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(name: &str, effects: Vec<RelicEffect>) -> OwnedRelic {
+        OwnedRelic {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            description: String::new(),
+            effects,
+        }
+    }
+
+    #[test]
+    fn effects_read_as_signed_figures() {
+        assert_eq!(effect_label(RelicEffect::Cover(5)), "+5 COVER");
+        assert_eq!(effect_label(RelicEffect::Sentence(1)), "-1 JAIL RUN");
+        assert_eq!(effect_label(RelicEffect::Sentence(2)), "-2 JAIL RUNS");
+        assert_eq!(
+            effects_line(&[RelicEffect::Vig(10), RelicEffect::Decay(50)]),
+            "-10% VIG · +50% HEAT DECAY"
+        );
+    }
+
+    #[test]
+    fn shelf_lists_every_relic_or_says_none() {
+        assert_eq!(shelf_text(&[]), "RELICS: none yet");
+        let relics = [
+            owned("Scanner", vec![RelicEffect::Cover(5)]),
+            owned("Dice", vec![RelicEffect::Profit(10), RelicEffect::Margin(2)]),
+        ];
+        assert_eq!(
+            shelf_text(&relics),
+            "RELICS (2)\nScanner: +5 COVER\nDice: +10% PROFIT · +2 EVIDENCE MARGIN"
+        );
+    }
+}
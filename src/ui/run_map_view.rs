@@ -0,0 +1,122 @@
+// SOW-060: Run map view-model - pure presentation for the route row on the
+// resolution overlay (the next stops, their stakes, where the night
+// stands). Same rule as the other _view modules: unit-testable without
+// ECS; systems only orchestrate.
+
+use crate::locale::{tr, tr_with};
+use crate::models::run_map::{RunMap, RunNodeKind, REST_STOP_COOLING};
+
+/// A stop's name on its button
+pub fn node_label(kind: RunNodeKind) -> String {
+    tr(match kind {
+        RunNodeKind::Deal => "run.deal",
+        RunNodeKind::HighRoller => "run.high_roller",
+        RunNodeKind::Tip => "run.tip",
+        RunNodeKind::SupplierMeet => "run.supplier_meet",
+        RunNodeKind::Sting => "run.sting",
+        RunNodeKind::RestStop => "run.rest_stop",
+        RunNodeKind::Operation => "run.operation",
+    })
+}
+
+/// SOW-061: the operation stop's name, with who's running it when known
+pub fn operation_label(name: Option<&str>) -> String {
+    match name {
+        Some(name) => tr_with("run.operation_named", &[("name", &name.to_uppercase())]),
+        None => node_label(RunNodeKind::Operation),
+    }
+}
+
+/// A stop's stakes under its name ("×1.5 · NARCS +1")
+pub fn node_stakes(kind: RunNodeKind) -> String {
+    let Some(params) = kind.params() else {
+        return tr_with("run.stakes_rest", &[("heat", &REST_STOP_COOLING.to_string())]);
+    };
+    let mut parts = vec![format!("×{}", params.profit_pct as f32 / 100.0)];
+    if params.narc_shift != 0 {
+        parts.push(tr_with("run.stakes_narcs", &[("shift", &format!("{:+}", params.narc_shift))]));
+    }
+    if params.opening_heat != 0 {
+        parts.push(tr_with("run.stakes_heat", &[("heat", &format!("{:+}", params.opening_heat))]));
+    }
+    parts.join(" · ")
+}
+
+/// Whether a stop can be taken: a hand needs three cards left to deal,
+/// a rest stop always can
+pub fn stop_open(kind: RunNodeKind, playable_cards: usize) -> bool {
+    kind == RunNodeKind::RestStop || playable_cards >= 3
+}
+
+/// The line over the route row: where the night stands
+pub fn route_heading(map: &RunMap) -> String {
+    if map.is_finished() {
+        return tr("run.night_over");
+    }
+    let heading = tr_with(
+        "run.next_stop",
+        &[("stop", &(map.row + 2).to_string()), ("stops", &map.rows.len().to_string())],
+    );
+    if map.current().kind == RunNodeKind::RestStop {
+        format!("{} · {}", tr("run.rested"), heading)
+    } else {
+        heading
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::run_map::RunNode;
+
+    fn line_map(kinds: &[RunNodeKind]) -> RunMap {
+        let rows = kinds
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| {
+                let next = if i + 1 < kinds.len() { vec![0] } else { vec![] };
+                vec![RunNode { kind, next }]
+            })
+            .collect();
+        RunMap { rows, row: 0, col: 0 }
+    }
+
+    #[test]
+    fn stakes_read_as_multiplier_narcs_and_heat() {
+        assert_eq!(node_stakes(RunNodeKind::Deal), "×1");
+        assert_eq!(node_stakes(RunNodeKind::HighRoller), "×1.5 · NARCS +1");
+        assert_eq!(node_stakes(RunNodeKind::Tip), "×2 · +15 HEAT");
+        assert_eq!(node_stakes(RunNodeKind::SupplierMeet), "×0.75 · NARCS -1");
+        assert_eq!(node_stakes(RunNodeKind::RestStop), "RESHUFFLE · -10 HEAT");
+        assert_eq!(node_label(RunNodeKind::Sting), "NARC STING");
+        assert_eq!(node_stakes(RunNodeKind::Operation), "×2 · NARCS +1");
+        assert_eq!(operation_label(Some("Agent Reyes")), "TASK FORCE: AGENT REYES");
+        assert_eq!(operation_label(None), "TASK FORCE");
+    }
+
+    #[test]
+    fn stakes_come_from_the_locale() {
+        crate::locale::scoped(crate::locale::Locale::pseudo(), || {
+            assert!(node_stakes(RunNodeKind::RestStop).starts_with("[RÉSHÜFFLÉ · -10 HÉÅT"));
+            assert_eq!(node_stakes(RunNodeKind::HighRoller), "×1.5 · [ÑÅRÇS +1~~]");
+            assert!(node_stakes(RunNodeKind::Tip).ends_with("[+15 HÉÅT~~]"), "{}", node_stakes(RunNodeKind::Tip));
+        });
+    }
+
+    #[test]
+    fn only_a_rest_stop_opens_on_an_empty_deck() {
+        assert!(stop_open(RunNodeKind::Deal, 3));
+        assert!(!stop_open(RunNodeKind::Sting, 2));
+        assert!(stop_open(RunNodeKind::RestStop, 0));
+    }
+
+    #[test]
+    fn heading_tracks_the_night() {
+        let mut map = line_map(&[RunNodeKind::Deal, RunNodeKind::RestStop, RunNodeKind::Deal]);
+        assert_eq!(route_heading(&map), "NEXT STOP (2/3)");
+        map.advance(0);
+        assert_eq!(route_heading(&map), "RESTED · NEXT STOP (3/3)");
+        map.advance(0);
+        assert_eq!(route_heading(&map), "LAST CALL - GO HOME");
+    }
+}
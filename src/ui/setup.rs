@@ -3,6 +3,7 @@
 // Updated for Bevy 0.18
 
 use bevy::prelude::*;
+use crate::locale::tr;
 use super::theme;
 use super::components::*;
 use crate::models::fonts::EmojiFont;
@@ -126,7 +127,7 @@ pub fn setup_deck_builder(
             ))
             .with_children(|btn| {
                 btn.spawn((
-                    Text::new("YOUR CARDS"),
+                    Text::new(tr("hub.your_cards")),
                     TextFont::from_font_size(16.0),
                     TextColor(Color::WHITE),
                 ));
@@ -147,7 +148,7 @@ pub fn setup_deck_builder(
             ))
             .with_children(|btn| {
                 btn.spawn((
-                    Text::new("SHOP"),
+                    Text::new(tr("hub.shop")),
                     TextFont::from_font_size(16.0),
                     TextColor(Color::WHITE),
                 ));
@@ -169,7 +170,7 @@ pub fn setup_deck_builder(
             ))
             .with_children(|btn| {
                 btn.spawn((
-                    Text::new("CITY MAP"),
+                    Text::new(tr("hub.city_map")),
                     TextFont::from_font_size(16.0),
                     TextColor(Color::WHITE),
                 ));
@@ -191,7 +192,7 @@ pub fn setup_deck_builder(
             ))
             .with_children(|btn| {
                 btn.spawn((
-                    Text::new("LEDGER"),
+                    Text::new(tr("hub.ledger")),
                     TextFont::from_font_size(16.0),
                     TextColor(Color::WHITE),
                 ));
@@ -331,7 +332,7 @@ pub fn setup_deck_builder(
             })
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("Click cards to toggle selection (Green = Selected)"),
+                    Text::new(tr("hub.deck_hint")),
                     TextFont::from_font_size(20.0),
                     TextColor(Color::WHITE),
                 ));
@@ -384,6 +385,17 @@ pub fn setup_deck_builder(
                     DeckStatsDisplay,
                 ));
 
+                // SOW-041: the active dealer's loadout shelf (children rebuilt
+                // by populate_loadout_row_system)
+                parent.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    LoadoutRow,
+                ));
+
                 // SOW-023: the "Heat: N [Tier]" line is gone - per-dealer heat
                 // lives on the roster panel now (Reed: it duplicated the panel)
 
@@ -418,6 +430,15 @@ pub fn setup_deck_builder(
                     TextColor(Color::srgb(0.6, 0.6, 0.6)), // Grey for lifetime
                     LifetimeRevenueText,
                 ));
+
+                // SOW-059: the relic shelf (update_relic_shelf_system owns
+                // the content)
+                parent.spawn((
+                    Text::new(""),
+                    TextFont::from_font_size(13.0),
+                    TextColor(theme::LEDGER_BOARD_CURRENT),
+                    RelicShelfText,
+                ));
             });
 
             // Right side: Story History button + START RUN button
@@ -478,7 +499,7 @@ pub fn setup_deck_builder(
                 ))
                 .with_children(|parent| {
                     parent.spawn((
-                        Text::new("START RUN"),
+                        Text::new(tr("hub.start_run")),
                         TextFont::from_font_size(24.0),
                         TextColor(Color::WHITE),
                     ));
@@ -492,6 +513,12 @@ pub fn setup_deck_builder(
         // SOW-030: Kingpin ledger overlay (hidden; body populated on open)
         crate::systems::kingpin_ledger::spawn_ledger_overlay(parent);
 
+        // SOW-042: end-of-day summary (hidden until GO HOME leaves a DaySummary)
+        crate::systems::day_planning::spawn_day_summary_overlay(parent);
+
+        // SOW-052: the morning paper (hidden until the press prints something)
+        crate::systems::morning_paper::spawn_morning_paper_overlay(parent);
+
         // SOW-032: one-time guided-start offer (Block overlay), only at empire
         // start. Spawned here - AFTER the pending-upgrade early-return guard at
         // the top of setup_deck_builder (GUIDANCE lesson 2) - so the
@@ -571,7 +598,7 @@ pub fn setup_deck_builder(
                         TextColor(Color::WHITE),
                     ));
                     parent.spawn((
-                        Text::new("Story History"),
+                        Text::new(tr("hub.story_history")),
                         TextFont::from_font_size(28.0),
                         TextColor(Color::WHITE),
                     ));
@@ -614,7 +641,7 @@ pub fn setup_deck_builder(
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("No stories yet..."),
+                    Text::new(tr("hub.no_stories_yet")),
                     TextFont::from_font_size(14.0),
                     TextColor(Color::srgb(0.7, 0.7, 0.7)),
                     StoryHistoryText,
@@ -754,7 +781,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("YOUR STANDING"),
+                Text::new(tr("hub.your_standing")),
                 TextFont::from_font_size(11.0),
                 TextColor(theme::V2_LABEL),
             ));
@@ -777,7 +804,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                 .with_children(|parent| {
                     spawn_emoji(parent, "💵", 13.0, theme::STANDING_CASH_LABEL, emoji_font);
                     parent.spawn((
-                        Text::new("CASH"),
+                        Text::new(tr("hub.cash")),
                         TextFont::from_font_size(13.0),
                         TextColor(theme::STANDING_CASH_LABEL),
                     ));
@@ -826,7 +853,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                     .with_children(|parent| {
                         spawn_emoji(parent, "🔥", 13.0, theme::STANDING_HEAT_LABEL, emoji_font);
                         parent.spawn((
-                            Text::new("HEAT"),
+                            Text::new(tr("hub.heat")),
                             TextFont::from_font_size(13.0),
                             TextColor(theme::STANDING_HEAT_LABEL),
                         ));
@@ -851,7 +878,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                         ))
                         .with_children(|parent| {
                             parent.spawn((
-                                Text::new("COLD"),
+                                Text::new(tr("hub.cold")),
                                 TextFont::from_font_size(10.0),
                                 TextColor(theme::STANDING_HEAT_VALUE),
                                 StandingHeatTierText,
@@ -1035,7 +1062,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             })
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("NARC"),
+                    Text::new(tr("hub.narc")),
                     TextFont::from_font_size(14.0),
                     TextColor(theme::NARC_NAME),
                 ));
@@ -1063,7 +1090,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("INTENT"),
+                    Text::new(tr("hub.intent")),
                     TextFont::from_font_size(11.0),
                     TextColor(theme::NARC_BUBBLE_TITLE),
                     NarcIntentTitleText,
@@ -1139,7 +1166,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             })
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("BUYER"),
+                    Text::new(tr("hub.buyer")),
                     TextFont::from_font_size(14.0),
                     TextColor(theme::BUYER_NAME),
                     BuyerNameText,
@@ -1170,7 +1197,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("PLAYED"),
+                    Text::new(tr("hub.played")),
                     TextFont::from_font_size(11.0),
                     TextColor(theme::BUYER_BUBBLE_TITLE),
                     BuyerPlayedTitleText,
@@ -1220,7 +1247,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("WANTS"),
+                    Text::new(tr("hub.wants")),
                     TextFont::from_font_size(11.0),
                     TextColor(theme::BUYER_BUBBLE_TITLE),
                     TextLayout::new_with_justify(bevy::text::Justify::Right),
@@ -1240,7 +1267,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                 })
                 .with_children(|parent| {
                     parent.spawn((
-                        Text::new("DEMAND"),
+                        Text::new(tr("hub.demand")),
                         TextFont::from_font_size(13.0),
                         TextColor(theme::BUYER_BUBBLE_LABEL),
                     ));
@@ -1259,7 +1286,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                 })
                 .with_children(|parent| {
                     parent.spawn((
-                        Text::new("PAYOUT"),
+                        Text::new(tr("hub.payout")),
                         TextFont::from_font_size(13.0),
                         TextColor(theme::BUYER_BUBBLE_LABEL),
                     ));
@@ -1291,14 +1318,14 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                         BuyerConfidenceEmoji,
                     ));
                     parent.spawn((
-                        Text::new("CONFIDENT"),
+                        Text::new(tr("hub.confident")),
                         TextFont::from_font_size(12.0),
                         TextColor(theme::SAFE_CHIP_TEXT),
                         BuyerConfidenceText,
                     ));
                 });
                 parent.spawn((
-                    Text::new("▾ HOVER FOR DETAIL"),
+                    Text::new(tr("hub.hover_for_detail")),
                     TextFont::from_font_size(9.0),
                     TextColor(theme::BUYER_BUBBLE_HINT),
                     TextLayout::new_with_justify(bevy::text::Justify::Right),
@@ -1359,7 +1386,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("THE DEAL ON THE TABLE"),
+                    Text::new(tr("hub.the_deal_on_the_table")),
                     TextFont::from_font_size(11.0),
                     TextColor(theme::V2_LABEL),
                 ));
@@ -1457,7 +1484,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                         ))
                         .with_children(|parent| {
                             parent.spawn((
-                                Text::new("SAFE"),
+                                Text::new(tr("hub.safe")),
                                 TextFont::from_font_size(12.0),
                                 TextColor(theme::SAFE_CHIP_TEXT),
                                 BalanceStatusChipText,
@@ -1745,7 +1772,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("PASS"),
+                    Text::new(tr("hub.pass")),
                     TextFont::from_font_size(17.0),
                     TextColor(theme::PASS_BUTTON_TEXT),
                 ));
@@ -1768,7 +1795,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("BAIL OUT"),
+                    Text::new(tr("hub.bail_out")),
                     TextFont::from_font_size(15.0),
                     TextColor(theme::BAIL_BUTTON_TEXT),
                 ));
@@ -1823,7 +1850,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
             .with_children(|parent| {
                 // Title
                 parent.spawn((
-                    Text::new("HAND COMPLETE"),
+                    Text::new(tr("hub.hand_complete")),
                     TextFont::from_font_size(32.0),
                     TextColor(theme::TEXT_HEADER),
                     ResolutionTitle,
@@ -1852,7 +1879,20 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                     ResolutionResults,
                 ));
 
-                // Action buttons (NEW DEAL / GO HOME)
+                // SOW-060: the route row - the next stops on the run map
+                // (populate_run_route_system owns the content; it replaced
+                // the NEW DEAL button)
+                parent.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    RunRouteRow,
+                ));
+
+                // Action buttons (GO HOME)
                 parent.spawn(Node {
                     flex_direction: FlexDirection::Row,
                     column_gap: Val::Px(20.0),
@@ -1860,27 +1900,6 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                     ..default()
                 })
                 .with_children(|parent| {
-                    // New Deal button
-                    parent.spawn((
-                        Button,
-                        Node {
-                            width: Val::Px(180.0),
-                            height: Val::Px(60.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(theme::CONTINUE_BUTTON_BG),
-                        RestartButton,
-                    ))
-                    .with_children(|parent| {
-                        parent.spawn((
-                            Text::new("NEW DEAL"),
-                            TextFont::from_font_size(24.0),
-                            TextColor(Color::WHITE),
-                        ));
-                    });
-
                     // Go Home button
                     parent.spawn((
                         Button,
@@ -1896,7 +1915,7 @@ pub fn create_ui(commands: &mut Commands, emoji_font: &EmojiFont) {
                     ))
                     .with_children(|parent| {
                         parent.spawn((
-                            Text::new("GO HOME"),
+                            Text::new(tr("hub.go_home")),
                             TextFont::from_font_size(24.0),
                             TextColor(Color::WHITE),
                         ));
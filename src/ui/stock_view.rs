@@ -2,13 +2,15 @@
 // (the shop status line and, in Phase 5, the hand-card badge). Same rule as
 // the other _view modules: unit-testable without ECS; systems only orchestrate.
 
+use crate::locale::{tr, tr_with};
+
 /// The shop status line for a product's on-hand stock. Returns
 /// `(label, in_stock)`; 0 charges reads OUT OF STOCK (the caller greys it).
 pub fn shop_stock_line(charges: u32) -> (String, bool) {
     if charges == 0 {
-        ("OUT OF STOCK".to_string(), false)
+        (tr("stock.out"), false)
     } else {
-        (format!("IN STOCK: {charges}"), true)
+        (tr_with("stock.in_stock", &[("charges", &charges.to_string())]), true)
     }
 }
 
@@ -16,9 +18,9 @@ pub fn shop_stock_line(charges: u32) -> (String, bool) {
 /// `(label, in_stock)`; 0 reads OUT OF STOCK (the caller greys the card too).
 pub fn hand_badge(charges: u32) -> (String, bool) {
     if charges == 0 {
-        ("OUT OF STOCK".to_string(), false)
+        (tr("stock.out"), false)
     } else {
-        (format!("{charges} LEFT"), true)
+        (tr_with("stock.left", &[("charges", &charges.to_string())]), true)
     }
 }
 
@@ -39,4 +41,21 @@ mod tests {
         assert_eq!(hand_badge(1), ("1 LEFT".to_string(), true));
         assert_eq!(hand_badge(0), ("OUT OF STOCK".to_string(), false));
     }
+
+    /// SOW-053: characters the stock line and the hand badge hold (11px
+    /// text across a hand card)
+    const STOCK_LABEL_BUDGET: usize = 20;
+
+    #[test]
+    fn stock_labels_fit_once_translated() {
+        crate::locale::scoped(crate::locale::Locale::pseudo(), || {
+            for charges in [0, 1, 12, 100] {
+                let (shop, _) = shop_stock_line(charges);
+                let (badge, _) = hand_badge(charges);
+                for label in [shop, badge] {
+                    assert!(crate::models::test_helpers::fits(&label, STOCK_LABEL_BUDGET), "{label:?} overflows");
+                }
+            }
+        });
+    }
 }
@@ -422,7 +422,7 @@ pub fn update_resolution_overlay_system(
         let mut results_text = results_query.single_mut()
             .expect("Expected exactly one ResolutionResults");
 
-        let totals = hand_state.calculate_totals(true);
+        let totals = hand_state.resolution_totals();
         // Heat is already accumulated when cards are played, use current_heat directly
         let cumulative_heat = hand_state.current_heat;
         let mut results = String::new();
@@ -444,7 +444,7 @@ pub fn update_resolution_overlay_system(
                 // fabricates this outcome), so always explain the actual cause.
                 // The old deck.len() < 3 special case here mislabeled real late-run
                 // busts as "Deck Exhausted" - exhaustion messaging lives on the
-                // NEW DEAL button ("OUT OF CARDS") instead.
+                // route row's stops ("OUT OF CARDS", SOW-060) instead.
                 results.push_str(&format!("Evidence: {} > Cover: {} ✗\n\n", totals.evidence, totals.cover));
                 if kingpin_fell {
                     // SOW-023: the boss went down - the empire falls onto the
@@ -584,7 +584,7 @@ pub fn scale_ui_to_fit_system(
 /// stream in asynchronously, and the old change-gated version consumed the
 /// trigger even when the asset wasn't loaded yet - a location played while its
 /// art was still loading left a stale background until the next state change
-/// (or until NEW DEAL if it was the hand's final play). All writes below are
+/// (or until the next stop if it was the hand's final play). All writes below are
 /// guarded by comparisons, so nothing is dirtied once the display is correct;
 /// this also picks up window resizes, which the old version ignored.
 pub fn update_background_system(
@@ -749,7 +749,7 @@ pub fn update_turn_display_system(
     if let Ok((mut text, mut color)) = pill_text_query.single_mut() {
         // Label change gates all pill restyling (avoids per-frame dirtying)
         if **text != label {
-            **text = label.to_string();
+            **text = label.clone();
             color.0 = text_color;
             if let Ok((mut pill_bg, mut pill_border)) = pill_query.single_mut() {
                 *pill_bg = bg.into();
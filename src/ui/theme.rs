@@ -296,6 +296,39 @@ pub const LEDGER_CANVAS_BG: Color = Color::srgb(0.035, 0.025, 0.05);         //
 pub const LEDGER_BOARD_CURRENT: Color = Color::srgb(1.0, 0.85, 0.3);         // IN PROGRESS row
 pub const LEDGER_STORY_TEXT: Color = Color::srgb(0.82, 0.82, 0.87);          // story feed body
 
+// SOW-042: Day planning
+pub const ROSTER_ORDER_BG: Color = Color::srgb(0.35, 0.35, 0.2);             // ORDER button
+pub const DAY_SUMMARY_SCRIM: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);       // behind the summary
+
+// SOW-043: Zone graph on the city map
+pub const MAP_ROAD: Color = Color::srgba(0.45, 0.42, 0.3, 0.6);                // road between zones
+pub const MAP_ROAD_LABEL_BG: Color = Color::srgba(0.02, 0.03, 0.06, 0.9);      // "2 RUNS · $400" tag
+
+// SOW-052: The morning paper
+pub const PAPER_BG: Color = Color::srgb(0.9, 0.87, 0.78);                    // newsprint
+pub const PAPER_INK: Color = Color::srgb(0.1, 0.09, 0.08);                   // headline + body
+pub const PAPER_RULE: Color = Color::srgb(0.35, 0.32, 0.28);                 // masthead rules, dateline
+
+// SOW-054: Hot reload
+pub const CONTENT_ERROR_BG: Color = Color::srgba(0.35, 0.04, 0.04, 0.95);     // rejected-reload banner
+pub const CONTENT_ERROR_TEXT: Color = Color::srgb(1.0, 0.8, 0.3);            // its heading
+
+// SOW-062: Retirement
+pub const LEDGER_RETIRE_BG: Color = Color::srgb(0.2, 0.45, 0.3);             // CASH OUT & RETIRE
+pub const LEDGER_RETIRE_ARMED_BG: Color = Color::srgb(0.65, 0.2, 0.15);      // ...waiting on the confirm
+
+// SOW-063: Succession
+pub const LEDGER_SUCCESSION_ON_BG: Color = Color::srgb(0.25, 0.35, 0.55);    // SUCCESSION ON
+pub const LEDGER_SUCCESSION_OFF_BG: Color = Color::srgb(0.25, 0.25, 0.28);   // SUCCESSION OFF
+
+// SOW-065: Ascension
+pub const LEDGER_LEVEL_BG: Color = Color::srgb(0.25, 0.25, 0.28);            // a level on offer
+pub const LEDGER_LEVEL_ACTIVE_BG: Color = Color::srgb(0.6, 0.25, 0.2);       // the level in play
+
+// SOW-064: Legacy
+pub const LEDGER_LEGACY_BG: Color = Color::srgb(0.45, 0.35, 0.15);           // an unlock within reach
+pub const LEDGER_LEGACY_LOCKED_BG: Color = Color::srgb(0.22, 0.2, 0.18);     // ...not yet
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -2,6 +2,8 @@
 // SOW-022: All screen-derivation logic lives here as pure, unit-testable
 // functions. Systems in ui_update.rs/systems.rs only orchestrate.
 
+use crate::locale::{tr, tr_with};
+use crate::models::buyer::BuyerPersona;
 use crate::models::card::{Card, CardType};
 use crate::models::hand_state::{HandPhase, HandState};
 use crate::Owner;
@@ -107,7 +109,7 @@ pub type IntentRow = (&'static str, String);
 #[derive(Debug, Clone, PartialEq)]
 pub struct IntentView {
     /// "INTENT" while the Narc is about to act, "PLAYED" afterwards
-    pub verb: &'static str,
+    pub verb: String,
     pub card_name: String,
     pub rows: Vec<IntentRow>,
 }
@@ -122,7 +124,7 @@ fn narc_card_rows(card: &Card) -> Vec<IntentRow> {
             ("🔥", format!("{heat:+}")),
         ],
         CardType::Conviction { heat_threshold } => {
-            vec![("⚠", format!("busts at {heat_threshold}"))]
+            vec![("⚠", tr_with("hub.busts_at", &[("heat", &heat_threshold.to_string())]))]
         }
         // Narc decks only contain Evidence/Conviction today; degrade gracefully
         _ => Vec::new(),
@@ -143,7 +145,7 @@ pub fn narc_intent(hand_state: &HandState) -> Option<IntentView> {
     if narc_pending {
         let next = hand_state.cards(Owner::Narc).hand.iter().flatten().next()?;
         return Some(IntentView {
-            verb: "INTENT",
+            verb: tr("hub.intent"),
             card_name: next.name.to_uppercase(),
             rows: narc_card_rows(next),
         });
@@ -156,7 +158,7 @@ pub fn narc_intent(hand_state: &HandState) -> Option<IntentView> {
             .rev()
             .find(|c| matches!(c.card_type, CardType::Evidence { .. } | CardType::Conviction { .. }))?;
         return Some(IntentView {
-            verb: "PLAYED",
+            verb: tr("hub.played"),
             card_name: played.name.to_uppercase(),
             rows: narc_card_rows(played),
         });
@@ -210,12 +212,32 @@ pub fn buyer_played(hand_state: &HandState) -> Option<IntentView> {
     }
     let card = hand_state.cards(Owner::Buyer).played.last()?;
     Some(IntentView {
-        verb: "PLAYED",
+        verb: tr("hub.played"),
         card_name: card.name.to_uppercase(),
         rows: buyer_card_rows(card),
     })
 }
 
+/// SOW-045: the buyer bubble telegraphs the reaction policy the way the narc
+/// bubble does: while the round is still being played, the intent and the
+/// exact card it will pull ("CALMING DOWN · ICE COLD") - the policy is
+/// deterministic on the deal, so the telegraph moves as the player plays.
+/// Once the buyer has reacted (or holds nothing), the last reaction as
+/// before (buyer_played).
+pub fn buyer_bubble(hand_state: &HandState) -> Option<IntentView> {
+    if hand_state.current_state == HandPhase::PlayerPhase {
+        if let Some(slot) = hand_state.buyer_reaction_slot() {
+            let card = hand_state.cards(Owner::Buyer).hand[slot].as_ref()?;
+            return Some(IntentView {
+                verb: hand_state.buyer_intent().label(),
+                card_name: card.name.to_uppercase(),
+                rows: buyer_card_rows(card),
+            });
+        }
+    }
+    buyer_played(hand_state)
+}
+
 // ============================================================================
 // Buyer confidence
 // ============================================================================
@@ -244,12 +266,12 @@ impl BuyerConfidence {
         }
     }
 
-    pub fn label(self) -> &'static str {
-        match self {
-            BuyerConfidence::Confident => "CONFIDENT",
-            BuyerConfidence::Nervous => "NERVOUS",
-            BuyerConfidence::Scared => "SCARED",
-        }
+    pub fn label(self) -> String {
+        tr(match self {
+            BuyerConfidence::Confident => "hub.confident",
+            BuyerConfidence::Nervous => "hub.nervous",
+            BuyerConfidence::Scared => "hub.scared",
+        })
     }
 }
 
@@ -286,6 +308,47 @@ pub fn buyer_confidence(hand_state: &HandState) -> Option<BuyerConfidence> {
     })
 }
 
+/// SOW-044: the buyer panel's name - a regular by name and persona
+/// ("CHAD · FRAT BRO"), a walk-in by persona alone
+pub fn buyer_name(persona: &BuyerPersona) -> String {
+    match &persona.regular {
+        Some(regular) => format!(
+            "{} · {}",
+            regular.name.to_uppercase(),
+            persona.display_name.to_uppercase()
+        ),
+        None => persona.display_name.to_uppercase(),
+    }
+}
+
+/// SOW-044: the detail panel's regular line - rapport, track record and
+/// favourites ("REGULAR · LOYAL (80) · 6 DEALS\nLIKES: Weed @ Frat House").
+/// None for a walk-in.
+pub fn regular_detail(persona: &BuyerPersona) -> Option<String> {
+    let regular = persona.regular.as_ref()?;
+    let deals_key = if regular.deals == 1 { "hub.deals_one" } else { "hub.deals_many" };
+    let mut line = tr_with(
+        "hub.regular",
+        &[
+            ("mood", &tr(regular.mood())),
+            ("rapport", &regular.relationship.to_string()),
+            ("deals", &tr_with(deals_key, &[("count", &regular.deals.to_string())])),
+        ],
+    );
+    let likes = match (&regular.favourite_product, &regular.favourite_location) {
+        (Some(product), Some(location)) => {
+            Some(tr_with("hub.likes_pair", &[("product", product), ("location", location)]))
+        }
+        (Some(one), None) | (None, Some(one)) => Some(one.clone()),
+        (None, None) => None,
+    };
+    if let Some(likes) = likes {
+        line.push('\n');
+        line.push_str(&tr_with("hub.likes", &[("likes", &likes)]));
+    }
+    Some(line)
+}
+
 // ============================================================================
 // Turn pill
 // ============================================================================
@@ -300,31 +363,44 @@ pub enum PillActor {
 }
 
 /// Turn pill label + actor for the current phase
-pub fn turn_pill(hand_state: &HandState) -> (&'static str, PillActor) {
-    match hand_state.current_state {
-        HandPhase::Draw => ("DEALING...", PillActor::Neutral),
+pub fn turn_pill(hand_state: &HandState) -> (String, PillActor) {
+    let (key, actor) = match hand_state.current_state {
+        HandPhase::Draw => ("hub.dealing", PillActor::Neutral),
         HandPhase::PlayerPhase => {
             if hand_state.all_players_acted() {
-                ("RESOLVING...", PillActor::Neutral)
+                ("hub.resolving", PillActor::Neutral)
             } else if hand_state.current_player() == Owner::Player {
-                ("YOUR MOVE", PillActor::Player)
+                ("hub.your_move", PillActor::Player)
             } else {
-                ("NARC'S MOVE", PillActor::Narc)
+                ("hub.narcs_move", PillActor::Narc)
             }
         }
-        HandPhase::DealerReveal => ("BUYER REACTING", PillActor::Buyer),
-        HandPhase::Resolve | HandPhase::Bust => ("DEAL COMPLETE", PillActor::Neutral),
-    }
+        HandPhase::DealerReveal => ("hub.buyer_reacting", PillActor::Buyer),
+        HandPhase::Resolve | HandPhase::Bust => ("hub.deal_complete", PillActor::Neutral),
+    };
+    (tr(key), actor)
 }
 
-/// Header line above the pill
+/// Header line above the pill. SOW-061: an operation's extra rounds count,
+/// and its name rides along.
 pub fn round_header(hand_state: &HandState) -> String {
     let status = if hand_state.current_state == HandPhase::Bust {
-        "DEAL COMPLETE"
+        tr("hub.deal_complete")
     } else {
-        "DEAL IN PROGRESS"
+        tr("hub.deal_in_progress")
     };
-    format!("ROUND {} / 3  ·  {}", hand_state.current_round, status)
+    let header = tr_with(
+        "hub.round_header",
+        &[
+            ("round", &hand_state.current_round.to_string()),
+            ("rounds", &hand_state.rounds().to_string()),
+            ("status", &status),
+        ],
+    );
+    match &hand_state.operation {
+        Some(op) => format!("{header}  ·  {}", op.name.to_uppercase()),
+        None => header,
+    }
 }
 
 // ============================================================================
@@ -372,13 +448,16 @@ pub fn game_over_board(fallen: &[crate::save::EmpireEpitaph]) -> String {
         .enumerate()
         .map(|(rank, idx)| {
             let epitaph = &fallen[idx];
-            let marker = if idx == latest { "  ← THIS RUN" } else { "" };
-            format!(
-                "{}. {} · {} decks{}",
-                rank + 1,
-                format_cash(epitaph.lifetime_revenue),
-                epitaph.decks_played,
-                marker
+            let marker = if idx == latest { tr("game_over.this_run") } else { String::new() };
+            tr_with(
+                "game_over.board_row",
+                &[
+                    ("rank", &(rank + 1).to_string()),
+                    ("revenue", &format_cash(epitaph.lifetime_revenue)),
+                    ("level", &super::ledger_view::level_tag(epitaph.ascension)),
+                    ("decks", &epitaph.decks_played.to_string()),
+                    ("marker", &marker),
+                ],
             )
         })
         .collect::<Vec<_>>()
@@ -591,6 +670,33 @@ mod tests {
         assert!(buyer_played(&hs).is_none());
     }
 
+    #[test]
+    fn buyer_bubble_telegraphs_the_intent_until_the_reaction() {
+        let mut hs = HandState {
+            buyer_persona: Some(create_mock_buyer_persona()),
+            current_state: HandPhase::PlayerPhase,
+            ..Default::default()
+        };
+        hs.cards_mut(Owner::Buyer).hand[0] = Some(create_buyer_modifier("Big Spender", 1.5, 0, 0, 5));
+        hs.cards_mut(Owner::Buyer).hand[1] = Some(create_buyer_modifier("Ice Cold", 1.0, 0, 0, -15));
+        hs.cards_played.push(create_product("Weed", 30, 0));
+        hs.cards_played.push(create_location("Safe House", 0, 10, 0));
+
+        let calm = buyer_bubble(&hs).unwrap();
+        assert_eq!((calm.verb.as_str(), calm.card_name.as_str()), ("GOING ALONG", "BIG SPENDER"));
+        // The telegraph follows the deal: heat closing on the line
+        hs.current_heat = 35;
+        let hot = buyer_bubble(&hs).unwrap();
+        assert_eq!((hot.verb.as_str(), hot.card_name.as_str()), ("CALMING DOWN", "ICE COLD"));
+        assert_eq!(hot.rows, vec![("🔥", "-15".to_string())]);
+
+        // The buyer reacts: the bubble shows what they played
+        hs.current_state = HandPhase::DealerReveal;
+        hs.buyer_plays_card();
+        let played = buyer_bubble(&hs).unwrap();
+        assert_eq!((played.verb.as_str(), played.card_name.as_str()), ("PLAYED", "ICE COLD"));
+    }
+
     #[test]
     fn buyer_modifier_rows_skip_zero_stats() {
         let mut hs = HandState::default();
@@ -629,6 +735,9 @@ mod tests {
                 narrative_fragments: None,
             }],
             active_scenario_index: Some(0),
+            personality: Default::default(),
+            regular_names: Vec::new(),
+            regular: None,
         });
         hs
     }
@@ -658,6 +767,39 @@ mod tests {
         assert_eq!(buyer_confidence(&hs), Some(BuyerConfidence::Scared));
     }
 
+    // ---- SOW-044 regulars ----
+
+    #[test]
+    fn regular_is_named_on_the_buyer_panel() {
+        let hs = hand_state_with_scenario_threshold(None);
+        let mut persona = hs.buyer_persona.unwrap();
+        assert_eq!(buyer_name(&persona), "TEST BUYER");
+        assert_eq!(regular_detail(&persona), None);
+
+        persona.regular = Some(crate::save::Regular {
+            name: "Chad".to_string(),
+            persona: "Test Buyer".to_string(),
+            area: "trailer_park".to_string(),
+            relationship: 80,
+            favourite_product: Some("Weed".to_string()),
+            favourite_location: Some("Frat House".to_string()),
+            deals: 6,
+            referred_by: None,
+            informant: false,
+        });
+        assert_eq!(buyer_name(&persona), "CHAD · TEST BUYER");
+        assert_eq!(
+            regular_detail(&persona).unwrap(),
+            "REGULAR · LOYAL (80) · 6 DEALS\nLIKES: Weed @ Frat House"
+        );
+
+        let regular = persona.regular.as_mut().unwrap();
+        regular.relationship = 30;
+        regular.deals = 1;
+        regular.favourite_location = None;
+        assert_eq!(regular_detail(&persona).unwrap(), "REGULAR · WARY (30) · 1 DEAL\nLIKES: Weed");
+    }
+
     #[test]
     fn fearless_buyer_is_always_confident() {
         let mut hs = hand_state_with_scenario_threshold(None);
@@ -673,13 +815,13 @@ mod tests {
         let mut hs = HandState::default();
         hs.current_state = HandPhase::PlayerPhase;
         hs.current_player_index = 0;
-        assert_eq!(turn_pill(&hs), ("NARC'S MOVE", PillActor::Narc));
+        assert_eq!(turn_pill(&hs), ("NARC'S MOVE".to_string(), PillActor::Narc));
         hs.current_player_index = 1;
-        assert_eq!(turn_pill(&hs), ("YOUR MOVE", PillActor::Player));
+        assert_eq!(turn_pill(&hs), ("YOUR MOVE".to_string(), PillActor::Player));
         hs.current_state = HandPhase::DealerReveal;
-        assert_eq!(turn_pill(&hs), ("BUYER REACTING", PillActor::Buyer));
+        assert_eq!(turn_pill(&hs), ("BUYER REACTING".to_string(), PillActor::Buyer));
         hs.current_state = HandPhase::Bust;
-        assert_eq!(turn_pill(&hs), ("DEAL COMPLETE", PillActor::Neutral));
+        assert_eq!(turn_pill(&hs), ("DEAL COMPLETE".to_string(), PillActor::Neutral));
     }
 
     #[test]
@@ -692,6 +834,27 @@ mod tests {
         assert_eq!(round_header(&hs), "ROUND 2 / 3  ·  DEAL COMPLETE");
     }
 
+    #[test]
+    fn table_labels_come_from_the_locale() {
+        use crate::locale::{scoped, Locale};
+        let hs = HandState { current_state: HandPhase::Bust, ..Default::default() };
+        scoped(Locale::pseudo(), || {
+            assert!(round_header(&hs).starts_with("[RÖÜÑD 1 / 3"), "{}", round_header(&hs));
+            assert!(round_header(&hs).contains("[DÉÅL ÇÖMPLÉTÉ"), "{}", round_header(&hs));
+            assert!(turn_pill(&hs).0.starts_with("[DÉÅL"));
+            assert!(BuyerConfidence::Scared.label().starts_with("[SÇÅRÉD"));
+        });
+    }
+
+    #[test]
+    fn round_header_counts_an_operations_rounds() {
+        use crate::models::boss::{BossRule, BossTrigger, Operation};
+        let dea = crate::models::test_helpers::create_boss("dea", vec![BossRule::ExtraRounds(1)], BossTrigger::Zones(1));
+        let mut hs = HandState { operation: Some(Operation::from(&dea)), ..Default::default() };
+        hs.current_state = HandPhase::PlayerPhase;
+        assert_eq!(round_header(&hs), "ROUND 1 / 4  ·  DEAL IN PROGRESS  ·  DEA");
+    }
+
     // ---- conviction_ticks ----
 
     #[test]
@@ -744,6 +907,12 @@ mod tests {
             total_prior_convictions: 0,
             decks_played: decks,
             stories: vec![],
+            roster: vec![],
+            chronicle: vec![],
+            retirement: None,
+            dynasty: Vec::new(),
+            zones_unlocked: 0,
+            ascension: Default::default(),
         };
         // Latest fall (600) places 2nd on the board and gets the marker
         let fallen = vec![epitaph(900, 3), epitaph(100, 1), epitaph(600, 9)];